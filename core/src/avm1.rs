@@ -11,12 +11,14 @@ mod activation;
 mod callable_value;
 mod clamp;
 mod debug;
+mod decode;
 mod error;
 mod flv;
 mod fscommand;
 pub(crate) mod globals;
 mod object;
 mod object_reference;
+mod print;
 mod property;
 mod property_map;
 mod runtime;
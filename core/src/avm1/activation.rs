@@ -1,11 +1,13 @@
 use crate::avm1::callable_value::CallableValue;
+use crate::avm1::decode::{
+    decode_actions, CatchVar, DecodedActions, FunctionDefinition, Instruction, PushValue, TryBlock,
+};
 use crate::avm1::error::Error;
 use crate::avm1::function::{Avm1Function, ExecutionReason, FunctionObject};
 use crate::avm1::object::{Object, TObject};
 use crate::avm1::property::Attribute;
-use crate::avm1::runtime::skip_actions;
 use crate::avm1::scope::{Scope, ScopeClass};
-use crate::avm1::{fscommand, globals, scope, ArrayObject, ScriptObject, Value};
+use crate::avm1::{fscommand, globals, print, scope, ArrayObject, ScriptObject, Value};
 use crate::backend::navigator::{NavigationMethod, Request};
 use crate::context::UpdateContext;
 use crate::display_object::{
@@ -13,7 +15,7 @@ use crate::display_object::{
 };
 use crate::ecma_conversions::{f64_to_wrapping_i32, f64_to_wrapping_u32};
 use crate::loader::MovieLoaderVMData;
-use crate::string::{AvmString, SwfStrExt as _, WStr, WString};
+use crate::string::{AvmString, WStr, WString};
 use crate::tag_utils::SwfSlice;
 use crate::vminterface::Instantiator;
 use crate::{avm_error, avm_warn};
@@ -428,10 +430,12 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     }
 
     pub fn run_actions(&mut self, code: SwfSlice) -> Result<ReturnType<'gc>, Error<'gc>> {
-        let mut read = Reader::new(&code.movie.data()[code.start..], self.swf_version());
+        let swf_version = self.swf_version();
+        let actions = decode_actions(&mut self.context, &code, swf_version);
+        let mut index = 0;
 
         loop {
-            let result = self.do_action(&code, &mut read);
+            let result = self.do_action(&code, &actions, &mut index);
             match result {
                 Ok(FrameControl::Return(return_type)) => break Ok(return_type),
                 Ok(FrameControl::Continue) => {}
@@ -440,11 +444,12 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         }
     }
 
-    /// Run a single action from a given action reader.
-    fn do_action<'b>(
+    /// Run the action at `index`, and move `index` on to the next one.
+    fn do_action(
         &mut self,
-        data: &'b SwfSlice,
-        reader: &mut Reader<'b>,
+        data: &SwfSlice,
+        actions: &DecodedActions<'gc>,
+        index: &mut usize,
     ) -> Result<FrameControl<'gc>, Error<'gc>> {
         *self.context.actions_since_timeout_check += 1;
         if *self.context.actions_since_timeout_check >= 2000 {
@@ -454,18 +459,20 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             }
         }
 
-        if reader.get_ref().as_ptr() as usize >= data.as_ref().as_ptr_range().end as usize {
+        let Some(action) = actions.get(*index) else {
             //Executing beyond the end of a function constitutes an implicit return.
-            Ok(FrameControl::Return(ReturnType::Implicit))
-        } else {
-            let action = reader.read_action()?;
-            avm_debug!(
-                self.context.avm1,
-                "({}) Action: {action:?}",
-                self.id.depth(),
-            );
+            return Ok(FrameControl::Return(ReturnType::Implicit));
+        };
+        *index = action.next;
+        avm_debug!(
+            self.context.avm1,
+            "({}) Action: {:?}",
+            self.id.depth(),
+            action.instruction,
+        );
 
-            match action {
+        match &action.instruction {
+            Instruction::Action(action) => match action.clone() {
                 Action::Add => self.action_add(),
                 Action::Add2 => self.action_add_2(),
                 Action::And => self.action_and(),
@@ -482,10 +489,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                 Action::CastOp => self.action_cast_op(),
                 Action::CharToAscii => self.action_char_to_ascii(),
                 Action::CloneSprite => self.action_clone_sprite(),
-                Action::ConstantPool(action) => self.action_constant_pool(action),
                 Action::Decrement => self.action_decrement(),
-                Action::DefineFunction(action) => self.action_define_function(action.into(), data),
-                Action::DefineFunction2(action) => self.action_define_function(action, data),
                 Action::DefineLocal => self.action_define_local(),
                 Action::DefineLocal2 => self.action_define_local_2(),
                 Action::Delete => self.action_delete(),
@@ -502,19 +506,15 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                 Action::GetProperty => self.action_get_property(),
                 Action::GetTime => self.action_get_time(),
                 Action::GetVariable => self.action_get_variable(),
-                Action::GetUrl(action) => self.action_get_url(action),
                 Action::GetUrl2(action) => self.action_get_url_2(action),
                 Action::GotoFrame(action) => self.action_goto_frame(action),
                 Action::GotoFrame2(action) => self.action_goto_frame_2(action),
                 Action::Greater => self.action_greater(),
-                Action::GotoLabel(action) => self.action_goto_label(action),
-                Action::If(action) => self.action_if(action, reader, data),
                 Action::Increment => self.action_increment(),
                 Action::InitArray => self.action_init_array(),
                 Action::InitObject => self.action_init_object(),
                 Action::ImplementsOp => self.action_implements_op(),
                 Action::InstanceOf => self.action_instance_of(),
-                Action::Jump(action) => self.action_jump(action, reader, data),
                 Action::Less => self.action_less(),
                 Action::Less2 => self.action_less_2(),
                 Action::MBAsciiToChar => self.action_mb_ascii_to_char(),
@@ -531,14 +531,12 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                 Action::Play => self.action_play(),
                 Action::Pop => self.action_pop(),
                 Action::PreviousFrame => self.action_prev_frame(),
-                Action::Push(action) => self.action_push(action),
                 Action::PushDuplicate => self.action_push_duplicate(),
                 Action::RandomNumber => self.action_random_number(),
                 Action::RemoveSprite => self.action_remove_sprite(),
                 Action::Return => self.action_return(),
                 Action::SetMember => self.action_set_member(),
                 Action::SetProperty => self.action_set_property(),
-                Action::SetTarget(action) => self.action_set_target(action),
                 Action::SetTarget2 => self.action_set_target_2(),
                 Action::SetVariable => self.action_set_variable(),
                 Action::StackSwap => self.action_stack_swap(),
@@ -561,12 +559,31 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                 Action::ToNumber => self.action_to_number(),
                 Action::ToString => self.action_to_string(),
                 Action::Trace => self.action_trace(),
-                Action::Try(action) => self.action_try(&action, data),
                 Action::TypeOf => self.action_type_of(),
-                Action::WaitForFrame(action) => self.action_wait_for_frame(action, reader),
-                Action::WaitForFrame2(action) => self.action_wait_for_frame_2(action, reader),
-                Action::With(action) => self.action_with(action, data),
-                Action::Unknown(action) => self.action_unknown(action),
+                action => unreachable!("{action:?} should have been decoded"),
+            },
+            Instruction::ConstantPool(constant_pool) => self.action_constant_pool(*constant_pool),
+            Instruction::DefineFunction(definition) => {
+                self.action_define_function(definition, data)
+            }
+            Instruction::GetUrl { url, target } => self.action_get_url(*url, *target),
+            Instruction::GotoLabel(label) => self.action_goto_label(*label),
+            Instruction::If(target) => self.action_if(*target, index),
+            Instruction::Jump(target) => self.action_jump(*target, index),
+            Instruction::Push(values) => self.action_push(values),
+            Instruction::SetTarget(target) => self.action_set_target(*target),
+            Instruction::Try(block) => self.action_try(block, data),
+            Instruction::WaitForFrame { frame, skip_to } => {
+                self.action_wait_for_frame(*frame, *skip_to, index)
+            }
+            Instruction::WaitForFrame2 { skip_to } => self.action_wait_for_frame_2(*skip_to, index),
+            Instruction::With(body) => self.action_with(body.slice(data)),
+            Instruction::Unknown { opcode } => self.action_unknown(*opcode),
+            Instruction::Invalid => {
+                // Read the action again to report why it couldn't be parsed.
+                let data = data.movie.data();
+                Reader::new(&data[action.offset..], self.swf_version()).read_action()?;
+                Ok(FrameControl::Continue)
             }
         }
     }
@@ -864,23 +881,10 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
     fn action_constant_pool(
         &mut self,
-        action: ConstantPool,
+        constant_pool: Gc<'gc, Vec<Value<'gc>>>,
     ) -> Result<FrameControl<'gc>, Error<'gc>> {
-        let constants = action
-            .strings
-            .iter()
-            .map(|s| {
-                self.context
-                    .interner
-                    .intern_wstr(self.context.gc_context, s.decode(self.encoding()))
-                    .into()
-            })
-            .collect();
-
-        self.context
-            .avm1
-            .set_constant_pool(Gc::new(self.context.gc_context, constants));
-        self.set_constant_pool(self.context.avm1.constant_pool());
+        self.context.avm1.set_constant_pool(constant_pool);
+        self.set_constant_pool(constant_pool);
 
         Ok(FrameControl::Continue)
     }
@@ -894,17 +898,16 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
     fn action_define_function(
         &mut self,
-        action: DefineFunction2,
+        definition: &FunctionDefinition<'gc>,
         parent_data: &SwfSlice,
     ) -> Result<FrameControl<'gc>, Error<'gc>> {
         let swf_version = self.swf_version();
-        let func_data = parent_data.to_unbounded_subslice(action.actions);
+        let func_data = definition.body.slice(parent_data);
         let constant_pool = self.constant_pool();
-        let func = Avm1Function::from_swf_function(
-            self.context.gc_context,
+        let func = Avm1Function::from_definition(
             swf_version,
             func_data,
-            action,
+            definition,
             self.scope(),
             constant_pool,
             self.base_clip(),
@@ -1197,9 +1200,16 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         Ok(FrameControl::Continue)
     }
 
-    fn action_get_url(&mut self, action: GetUrl) -> Result<FrameControl<'gc>, Error<'gc>> {
-        let target = action.target.decode(self.encoding());
-        let url = action.url.decode(self.encoding());
+    fn action_get_url(
+        &mut self,
+        url: AvmString<'gc>,
+        target: AvmString<'gc>,
+    ) -> Result<FrameControl<'gc>, Error<'gc>> {
+        if let Some(bounds) = print::parse(&url) {
+            print::handle(bounds, target.into(), self)?;
+            return Ok(FrameControl::Continue);
+        }
+
         // TODO: Use `StageObject::get_level_by_path`.
         if target.starts_with(WStr::from_units(b"_level")) && target.len() > 6 {
             match target[6..].parse::<i32>() {
@@ -1259,6 +1269,11 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             return Ok(FrameControl::Continue);
         }
 
+        if let Some(bounds) = print::parse(&url) {
+            print::handle(bounds, target_val, self)?;
+            return Ok(FrameControl::Continue);
+        }
+
         // TODO: Use `StageObject::get_level_by_path`.
         let level_target = if target.starts_with(WStr::from_units(b"_level")) && target.len() >= 6 {
             match target[6..].parse::<f64>() {
@@ -1429,10 +1444,12 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         Ok(FrameControl::Continue)
     }
 
-    fn action_goto_label(&mut self, action: GotoLabel) -> Result<FrameControl<'gc>, Error<'gc>> {
+    fn action_goto_label(
+        &mut self,
+        label: AvmString<'gc>,
+    ) -> Result<FrameControl<'gc>, Error<'gc>> {
         if let Some(clip) = self.target_clip() {
             if let Some(clip) = clip.as_movie_clip() {
-                let label = action.label.decode(self.encoding());
                 if let Some(frame) = clip.frame_label_to_number(&label, &self.context) {
                     clip.goto_frame(&mut self.context, frame, true);
                 } else {
@@ -1447,15 +1464,14 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         Ok(FrameControl::Continue)
     }
 
-    fn action_if<'b>(
+    fn action_if(
         &mut self,
-        action: If,
-        reader: &mut Reader<'b>,
-        data: &'b SwfSlice,
+        target: usize,
+        index: &mut usize,
     ) -> Result<FrameControl<'gc>, Error<'gc>> {
         let val = self.context.avm1.pop();
         if val.as_bool(self.swf_version()) {
-            reader.seek(data.movie.data(), action.offset);
+            *index = target;
         }
         Ok(FrameControl::Continue)
     }
@@ -1555,13 +1571,12 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         Ok(FrameControl::Continue)
     }
 
-    fn action_jump<'b>(
+    fn action_jump(
         &mut self,
-        action: Jump,
-        reader: &mut Reader<'b>,
-        data: &'b SwfSlice,
+        target: usize,
+        index: &mut usize,
     ) -> Result<FrameControl<'gc>, Error<'gc>> {
-        reader.seek(data.movie.data(), action.offset);
+        *index = target;
         Ok(FrameControl::Continue)
     }
 
@@ -1815,21 +1830,12 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         Ok(FrameControl::Continue)
     }
 
-    fn action_push(&mut self, action: Push) -> Result<FrameControl<'gc>, Error<'gc>> {
-        for value in action.values {
-            use swf::avm1::types::Value as SwfValue;
-            let value = match value {
-                SwfValue::Undefined => Value::Undefined,
-                SwfValue::Null => Value::Null,
-                SwfValue::Bool(v) => v.into(),
-                SwfValue::Int(v) => v.into(),
-                SwfValue::Float(v) => v.into(),
-                SwfValue::Double(v) => v.into(),
-                SwfValue::Str(v) => {
-                    AvmString::new(self.context.gc_context, v.decode(self.encoding())).into()
-                }
-                SwfValue::Register(v) => self.current_register(v),
-                SwfValue::ConstantPool(i) => {
+    fn action_push(&mut self, values: &[PushValue<'gc>]) -> Result<FrameControl<'gc>, Error<'gc>> {
+        for value in values {
+            let value = match *value {
+                PushValue::Value(value) => value,
+                PushValue::Register(v) => self.current_register(v),
+                PushValue::ConstantPool(i) => {
                     if let Some(value) = self.constant_pool().get(i as usize) {
                         *value
                     } else {
@@ -1964,8 +1970,10 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         Ok(FrameControl::Continue)
     }
 
-    fn action_set_target(&mut self, action: SetTarget) -> Result<FrameControl<'gc>, Error<'gc>> {
-        let target = action.target.decode(self.encoding());
+    fn action_set_target(
+        &mut self,
+        target: AvmString<'gc>,
+    ) -> Result<FrameControl<'gc>, Error<'gc>> {
         self.set_target(&target)
     }
 
@@ -2258,12 +2266,12 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
     fn action_try(
         &mut self,
-        action: &Try,
+        block: &TryBlock<'gc>,
         parent_data: &SwfSlice,
     ) -> Result<FrameControl<'gc>, Error<'gc>> {
-        let mut result = self.run_actions(parent_data.to_unbounded_subslice(action.try_body));
+        let mut result = self.run_actions(block.try_body.slice(parent_data));
 
-        if let Some((catch_vars, actions)) = &action.catch_body {
+        if let Some((catch_vars, actions)) = &block.catch_body {
             if let Err(Error::ThrownValue(value)) = &result {
                 let mut activation = Activation::from_action(
                     self.context.reborrow(),
@@ -2279,26 +2287,18 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                 activation.local_registers = self.local_registers;
 
                 match catch_vars {
-                    CatchVar::Var(name) => {
-                        let name = AvmString::new(
-                            activation.context.gc_context,
-                            name.decode(activation.encoding()),
-                        );
-                        activation.set_variable(name, value.to_owned())?
-                    }
+                    CatchVar::Var(name) => activation.set_variable(*name, value.to_owned())?,
                     CatchVar::Register(id) => {
                         activation.set_current_register(*id, value.to_owned())
                     }
                 }
 
-                result = activation.run_actions(parent_data.to_unbounded_subslice(actions));
+                result = activation.run_actions(actions.slice(parent_data));
             }
         }
 
-        if let Some(actions) = action.finally_body {
-            if let ReturnType::Explicit(value) =
-                self.run_actions(parent_data.to_unbounded_subslice(actions))?
-            {
+        if let Some(actions) = block.finally_body {
+            if let ReturnType::Explicit(value) = self.run_actions(actions.slice(parent_data))? {
                 return Ok(FrameControl::Return(ReturnType::Explicit(value)));
             }
         }
@@ -2317,10 +2317,10 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
     fn action_wait_for_frame(
         &mut self,
-        action: WaitForFrame,
-        r: &mut Reader<'_>,
+        frame_num: u16,
+        skip_to: usize,
+        index: &mut usize,
     ) -> Result<FrameControl<'gc>, Error<'gc>> {
-        let frame_num = action.frame;
         let loaded = if frame_num > 16000 {
             // Exceeded maximum number of frames.
             false
@@ -2333,8 +2333,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         if !loaded {
             // Note that the offset is given in # of actions, NOT in bytes.
-            // Read the actions and toss them away.
-            skip_actions(r, action.num_actions_to_skip);
+            *index = skip_to;
         }
 
         Ok(FrameControl::Continue)
@@ -2342,8 +2341,8 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
     fn action_wait_for_frame_2(
         &mut self,
-        action: WaitForFrame2,
-        r: &mut Reader<'_>,
+        skip_to: usize,
+        index: &mut usize,
     ) -> Result<FrameControl<'gc>, Error<'gc>> {
         let frame_val = self.context.avm1.pop();
         let frame_num = match frame_val {
@@ -2376,19 +2375,13 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         if !loaded {
             // Note that the offset is given in # of actions, NOT in bytes.
-            // Read the actions and toss them away.
-            skip_actions(r, action.num_actions_to_skip);
+            *index = skip_to;
         }
 
         Ok(FrameControl::Continue)
     }
 
-    fn action_with(
-        &mut self,
-        action: With,
-        parent_data: &SwfSlice,
-    ) -> Result<FrameControl<'gc>, Error<'gc>> {
-        let code = parent_data.to_unbounded_subslice(action.actions);
+    fn action_with(&mut self, code: SwfSlice) -> Result<FrameControl<'gc>, Error<'gc>> {
         let value = self.context.avm1.pop();
         match value {
             // Undefined/null with is ignored.
@@ -2417,11 +2410,8 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         }
     }
 
-    fn action_unknown(
-        &mut self,
-        unknown: swf::avm1::types::Unknown,
-    ) -> Result<FrameControl<'gc>, Error<'gc>> {
-        avm_error!(self, "Unknown AVM1 opcode: {:?}", unknown);
+    fn action_unknown(&mut self, opcode: u8) -> Result<FrameControl<'gc>, Error<'gc>> {
+        avm_error!(self, "Unknown AVM1 opcode: {:#04x}", opcode);
         Ok(FrameControl::Continue)
     }
 
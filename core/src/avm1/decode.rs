@@ -0,0 +1,679 @@
+//! Pre-decoded AVM1 bytecode
+//!
+//! AVM1 code is decoded once, the first time it runs, into a list of
+//! instructions that the interpreter can run without touching the SWF data
+//! again. Jumps are resolved to indices into that list, and strings are
+//! decoded up-front, with the constant pool interned the same way
+//! `ActionConstantPool` would.
+//!
+//! Branches in AVM1 are byte offsets, which may land anywhere in the movie,
+//! including the middle of another action or before the start of the code
+//! being run. We therefore decode whatever the code can reach rather than
+//! just reading it front-to-back.
+
+use crate::avm1::function::Param;
+use crate::avm1::runtime::skip_actions;
+use crate::avm1::Value;
+use crate::context::UpdateContext;
+use crate::string::{AvmString, SwfStrExt as _};
+use crate::tag_utils::{SwfMovie, SwfSlice};
+use gc_arena::{Collect, Gc};
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+use swf::avm1::read::Reader;
+use swf::avm1::types::{Action, DefineFunction2, FunctionFlags};
+use swf::extensions::ReadSwfExt;
+use swf::SwfStr;
+
+/// The index used for "past the end of the code", where execution ends with
+/// an implicit return.
+const END: usize = usize::MAX;
+
+/// A single decoded instruction.
+#[derive(Debug, Collect)]
+#[collect(no_drop)]
+pub enum Instruction<'gc> {
+    /// An action that doesn't refer to any other part of the SWF, run as-is.
+    Action(#[collect(require_static)] Action<'static>),
+
+    /// `ActionConstantPool`, with its strings already interned.
+    ConstantPool(Gc<'gc, Vec<Value<'gc>>>),
+
+    /// `ActionDefineFunction` or `ActionDefineFunction2`.
+    DefineFunction(Box<FunctionDefinition<'gc>>),
+
+    GetUrl {
+        url: AvmString<'gc>,
+        target: AvmString<'gc>,
+    },
+
+    GotoLabel(AvmString<'gc>),
+
+    /// `ActionIf`, which continues at the instruction with the given index if
+    /// its condition holds.
+    If(usize),
+
+    /// `ActionJump` to the instruction with the given index.
+    Jump(usize),
+
+    Push(Vec<PushValue<'gc>>),
+
+    SetTarget(AvmString<'gc>),
+
+    Try(Box<TryBlock<'gc>>),
+
+    /// `ActionWaitForFrame`, which continues at the given index if the frame
+    /// isn't loaded yet.
+    WaitForFrame {
+        frame: u16,
+        skip_to: usize,
+    },
+
+    /// `ActionWaitForFrame2`, which continues at the given index if the frame
+    /// isn't loaded yet.
+    WaitForFrame2 {
+        skip_to: usize,
+    },
+
+    With(CodeRange),
+
+    Unknown {
+        opcode: u8,
+    },
+
+    /// An action that failed to parse. Running it reports the parse error.
+    Invalid,
+}
+
+/// A value pushed by `ActionPush`.
+#[derive(Debug, Collect)]
+#[collect(no_drop)]
+pub enum PushValue<'gc> {
+    /// A value known when the code is decoded.
+    Value(Value<'gc>),
+
+    /// The value of a register.
+    Register(u8),
+
+    /// An entry in whichever constant pool is active when the value is pushed.
+    ConstantPool(u16),
+}
+
+/// The contents of a `DefineFunction` or `DefineFunction2` action.
+#[derive(Debug, Collect)]
+#[collect(no_drop)]
+pub struct FunctionDefinition<'gc> {
+    pub name: Option<AvmString<'gc>>,
+    pub params: Vec<Param<'gc>>,
+    pub register_count: u8,
+    #[collect(require_static)]
+    pub flags: FunctionFlags,
+    pub body: CodeRange,
+}
+
+/// The contents of a `Try` action.
+#[derive(Debug, Collect)]
+#[collect(no_drop)]
+pub struct TryBlock<'gc> {
+    pub try_body: CodeRange,
+    pub catch_body: Option<(CatchVar<'gc>, CodeRange)>,
+    pub finally_body: Option<CodeRange>,
+}
+
+/// Where a `Try` action stores the value it caught.
+#[derive(Debug, Collect)]
+#[collect(no_drop)]
+pub enum CatchVar<'gc> {
+    Var(AvmString<'gc>),
+    Register(u8),
+}
+
+/// A nested block of code, such as a function body, given as offsets into
+/// the movie data.
+///
+/// We don't hold on to the movie itself here, so that cached code doesn't
+/// keep it alive.
+#[derive(Debug, Clone, Copy, Collect)]
+#[collect(require_static)]
+pub struct CodeRange {
+    start: usize,
+    end: usize,
+}
+
+impl CodeRange {
+    fn new(code: &SwfSlice, slice: &[u8]) -> Self {
+        let slice = code.to_unbounded_subslice(slice);
+        Self {
+            start: slice.start,
+            end: slice.end,
+        }
+    }
+
+    /// The code this range refers to, in the same movie as `code`.
+    pub fn slice(&self, code: &SwfSlice) -> SwfSlice {
+        SwfSlice {
+            movie: code.movie.clone(),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+/// An instruction, along with where to go after it.
+#[derive(Debug, Collect)]
+#[collect(no_drop)]
+pub struct DecodedAction<'gc> {
+    /// The offset of the action in the movie data.
+    pub offset: usize,
+
+    pub instruction: Instruction<'gc>,
+
+    /// The index of the instruction that follows this one.
+    pub next: usize,
+}
+
+/// A block of AVM1 code, decoded and ready to run.
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct DecodedActions<'gc> {
+    /// The movie the code was decoded from.
+    #[collect(require_static)]
+    movie: Weak<SwfMovie>,
+
+    actions: Vec<DecodedAction<'gc>>,
+}
+
+impl<'gc> DecodedActions<'gc> {
+    /// The instruction with the given index.
+    ///
+    /// This returns `None` once execution has run past the end of the code.
+    pub fn get(&self, index: usize) -> Option<&DecodedAction<'gc>> {
+        self.actions.get(index)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Collect)]
+#[collect(require_static)]
+struct CacheKey {
+    movie: usize,
+    start: usize,
+    end: usize,
+    swf_version: u8,
+}
+
+/// Every block of AVM1 code we've decoded so far.
+#[derive(Collect, Default)]
+#[collect(no_drop)]
+pub struct ActionCache<'gc> {
+    entries: HashMap<CacheKey, Gc<'gc, DecodedActions<'gc>>>,
+
+    /// The number of entries at which we next drop code from unloaded movies.
+    prune_at: usize,
+}
+
+impl<'gc> ActionCache<'gc> {
+    fn get(&self, key: &CacheKey, movie: &Arc<SwfMovie>) -> Option<Gc<'gc, DecodedActions<'gc>>> {
+        // The movie may have been unloaded, and another one loaded at the same
+        // address.
+        self.entries
+            .get(key)
+            .filter(|actions| {
+                actions
+                    .movie
+                    .upgrade()
+                    .is_some_and(|cached| Arc::ptr_eq(&cached, movie))
+            })
+            .copied()
+    }
+
+    fn insert(&mut self, key: CacheKey, actions: Gc<'gc, DecodedActions<'gc>>) {
+        if self.entries.len() >= self.prune_at {
+            self.entries
+                .retain(|_, actions| actions.movie.strong_count() > 0);
+            self.prune_at = (self.entries.len() * 2).max(64);
+        }
+
+        self.entries.insert(key, actions);
+    }
+}
+
+/// Get the decoded form of a block of code, decoding it if we haven't seen
+/// it before.
+pub fn decode_actions<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    code: &SwfSlice,
+    swf_version: u8,
+) -> Gc<'gc, DecodedActions<'gc>> {
+    let key = CacheKey {
+        movie: Arc::as_ptr(&code.movie) as usize,
+        start: code.start,
+        end: code.end,
+        swf_version,
+    };
+    if let Some(actions) = context.avm1.action_cache_mut().get(&key, &code.movie) {
+        return actions;
+    }
+
+    let actions = DecodedActions {
+        movie: Arc::downgrade(&code.movie),
+        actions: decode(context, code, swf_version),
+    };
+    let actions = Gc::new(context.gc_context, actions);
+    context.avm1.action_cache_mut().insert(key, actions);
+    actions
+}
+
+/// Decode all code reachable from the start of `code`.
+fn decode<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    code: &SwfSlice,
+    swf_version: u8,
+) -> Vec<DecodedAction<'gc>> {
+    let data = code.movie.data();
+    let mut actions = Vec::new();
+    let mut indices = HashMap::new();
+    let mut pending = vec![code.start];
+
+    while let Some(offset) = pending.pop() {
+        if offset >= code.end || indices.contains_key(&offset) {
+            continue;
+        }
+        indices.insert(offset, actions.len());
+
+        let mut reader = Reader::new(&data[offset..], swf_version);
+        let (instruction, next) = match reader.read_action() {
+            Ok(action) => {
+                let next = reader.pos(data);
+                let instruction = decode_action(context, code, swf_version, action, next);
+                (instruction, next)
+            }
+            Err(_) => (Instruction::Invalid, END),
+        };
+
+        // Visit the following action first, so that straight-line code stays
+        // in order.
+        match instruction {
+            Instruction::If(target)
+            | Instruction::WaitForFrame {
+                skip_to: target, ..
+            }
+            | Instruction::WaitForFrame2 { skip_to: target } => pending.extend([target, next]),
+            Instruction::Jump(target) => pending.push(target),
+            Instruction::Invalid => {}
+            _ => pending.push(next),
+        }
+
+        actions.push(DecodedAction {
+            offset,
+            instruction,
+            next,
+        });
+    }
+
+    // Everything so far refers to offsets; turn them into indices.
+    let index_of = |offset: usize| indices.get(&offset).copied().unwrap_or(END);
+    for action in &mut actions {
+        action.next = index_of(action.next);
+        match &mut action.instruction {
+            Instruction::If(target)
+            | Instruction::Jump(target)
+            | Instruction::WaitForFrame {
+                skip_to: target, ..
+            }
+            | Instruction::WaitForFrame2 { skip_to: target } => *target = index_of(*target),
+            _ => {}
+        }
+    }
+
+    actions
+}
+
+/// Decode a single action. Any branch targets are left as offsets into the
+/// movie data.
+fn decode_action<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    code: &SwfSlice,
+    swf_version: u8,
+    action: Action<'_>,
+    next: usize,
+) -> Instruction<'gc> {
+    let data = code.movie.data();
+    let encoding = SwfStr::encoding_for_version(swf_version);
+    let mc = context.gc_context;
+    let string = |s: &SwfStr| AvmString::new(mc, s.decode(encoding));
+    let skip = |num_actions: u8| {
+        let mut reader = Reader::new(&data[next..], swf_version);
+        skip_actions(&mut reader, num_actions);
+        reader.pos(data)
+    };
+
+    match action {
+        Action::ConstantPool(action) => {
+            let constants = action
+                .strings
+                .iter()
+                .map(|s| context.interner.intern_wstr(mc, s.decode(encoding)).into())
+                .collect();
+            Instruction::ConstantPool(Gc::new(mc, constants))
+        }
+        Action::DefineFunction(action) => {
+            Instruction::DefineFunction(Box::new(decode_function(code, action.into(), string)))
+        }
+        Action::DefineFunction2(action) => {
+            Instruction::DefineFunction(Box::new(decode_function(code, action, string)))
+        }
+        Action::GetUrl(action) => Instruction::GetUrl {
+            url: string(action.url),
+            target: string(action.target),
+        },
+        Action::GotoLabel(action) => Instruction::GotoLabel(string(action.label)),
+        Action::If(action) => Instruction::If(jump_target(data, next, action.offset)),
+        Action::Jump(action) => Instruction::Jump(jump_target(data, next, action.offset)),
+        Action::Push(action) => {
+            use swf::avm1::types::Value as SwfValue;
+            let values = action
+                .values
+                .into_iter()
+                .map(|value| match value {
+                    SwfValue::Undefined => PushValue::Value(Value::Undefined),
+                    SwfValue::Null => PushValue::Value(Value::Null),
+                    SwfValue::Bool(v) => PushValue::Value(v.into()),
+                    SwfValue::Int(v) => PushValue::Value(v.into()),
+                    SwfValue::Float(v) => PushValue::Value(v.into()),
+                    SwfValue::Double(v) => PushValue::Value(v.into()),
+                    SwfValue::Str(v) => PushValue::Value(string(v).into()),
+                    SwfValue::Register(v) => PushValue::Register(v),
+                    SwfValue::ConstantPool(i) => PushValue::ConstantPool(i),
+                })
+                .collect();
+            Instruction::Push(values)
+        }
+        Action::SetTarget(action) => Instruction::SetTarget(string(action.target)),
+        Action::Try(action) => Instruction::Try(Box::new(TryBlock {
+            try_body: CodeRange::new(code, action.try_body),
+            catch_body: action.catch_body.map(|(var, body)| {
+                let var = match var {
+                    swf::avm1::types::CatchVar::Var(name) => CatchVar::Var(string(name)),
+                    swf::avm1::types::CatchVar::Register(id) => CatchVar::Register(id),
+                };
+                (var, CodeRange::new(code, body))
+            }),
+            finally_body: action.finally_body.map(|body| CodeRange::new(code, body)),
+        })),
+        Action::WaitForFrame(action) => Instruction::WaitForFrame {
+            frame: action.frame,
+            skip_to: skip(action.num_actions_to_skip),
+        },
+        Action::WaitForFrame2(action) => Instruction::WaitForFrame2 {
+            skip_to: skip(action.num_actions_to_skip),
+        },
+        Action::With(action) => Instruction::With(CodeRange::new(code, action.actions)),
+        Action::Unknown(action) => Instruction::Unknown {
+            opcode: action.opcode,
+        },
+        action => Instruction::Action(into_static(action)),
+    }
+}
+
+fn decode_function<'gc>(
+    code: &SwfSlice,
+    action: DefineFunction2<'_>,
+    string: impl Fn(&SwfStr) -> AvmString<'gc>,
+) -> FunctionDefinition<'gc> {
+    FunctionDefinition {
+        name: (!action.name.is_empty()).then(|| string(action.name)),
+        params: action
+            .params
+            .iter()
+            .map(|p| Param {
+                register: p.register_index,
+                name: string(p.name),
+            })
+            .collect(),
+        register_count: action.register_count,
+        flags: action.flags,
+        body: CodeRange::new(code, action.actions),
+    }
+}
+
+/// The offset a branch from the action ending at `next` lands on.
+///
+/// This matches how the reader seeks: a branch before the start of the movie
+/// ends up at the end of it.
+fn jump_target(data: &[u8], next: usize, offset: i16) -> usize {
+    next.checked_add_signed(offset.into())
+        .unwrap_or(usize::MAX)
+        .min(data.len())
+}
+
+/// Convert an action that doesn't borrow from the SWF data.
+///
+/// Actions that do borrow are decoded into their own instructions by
+/// `decode_action`, and never reach here.
+fn into_static(action: Action<'_>) -> Action<'static> {
+    match action {
+        Action::Add => Action::Add,
+        Action::Add2 => Action::Add2,
+        Action::And => Action::And,
+        Action::AsciiToChar => Action::AsciiToChar,
+        Action::BitAnd => Action::BitAnd,
+        Action::BitLShift => Action::BitLShift,
+        Action::BitOr => Action::BitOr,
+        Action::BitRShift => Action::BitRShift,
+        Action::BitURShift => Action::BitURShift,
+        Action::BitXor => Action::BitXor,
+        Action::Call => Action::Call,
+        Action::CallFunction => Action::CallFunction,
+        Action::CallMethod => Action::CallMethod,
+        Action::CastOp => Action::CastOp,
+        Action::CharToAscii => Action::CharToAscii,
+        Action::CloneSprite => Action::CloneSprite,
+        Action::Decrement => Action::Decrement,
+        Action::DefineLocal => Action::DefineLocal,
+        Action::DefineLocal2 => Action::DefineLocal2,
+        Action::Delete => Action::Delete,
+        Action::Delete2 => Action::Delete2,
+        Action::Divide => Action::Divide,
+        Action::End => Action::End,
+        Action::EndDrag => Action::EndDrag,
+        Action::Enumerate => Action::Enumerate,
+        Action::Enumerate2 => Action::Enumerate2,
+        Action::Equals => Action::Equals,
+        Action::Equals2 => Action::Equals2,
+        Action::Extends => Action::Extends,
+        Action::GetMember => Action::GetMember,
+        Action::GetProperty => Action::GetProperty,
+        Action::GetTime => Action::GetTime,
+        Action::GetUrl2(action) => Action::GetUrl2(action),
+        Action::GetVariable => Action::GetVariable,
+        Action::GotoFrame(action) => Action::GotoFrame(action),
+        Action::GotoFrame2(action) => Action::GotoFrame2(action),
+        Action::Greater => Action::Greater,
+        Action::ImplementsOp => Action::ImplementsOp,
+        Action::Increment => Action::Increment,
+        Action::InitArray => Action::InitArray,
+        Action::InitObject => Action::InitObject,
+        Action::InstanceOf => Action::InstanceOf,
+        Action::Less => Action::Less,
+        Action::Less2 => Action::Less2,
+        Action::MBAsciiToChar => Action::MBAsciiToChar,
+        Action::MBCharToAscii => Action::MBCharToAscii,
+        Action::MBStringExtract => Action::MBStringExtract,
+        Action::MBStringLength => Action::MBStringLength,
+        Action::Modulo => Action::Modulo,
+        Action::Multiply => Action::Multiply,
+        Action::NewMethod => Action::NewMethod,
+        Action::NewObject => Action::NewObject,
+        Action::NextFrame => Action::NextFrame,
+        Action::Not => Action::Not,
+        Action::Or => Action::Or,
+        Action::Play => Action::Play,
+        Action::Pop => Action::Pop,
+        Action::PreviousFrame => Action::PreviousFrame,
+        Action::PushDuplicate => Action::PushDuplicate,
+        Action::RandomNumber => Action::RandomNumber,
+        Action::RemoveSprite => Action::RemoveSprite,
+        Action::Return => Action::Return,
+        Action::SetMember => Action::SetMember,
+        Action::SetProperty => Action::SetProperty,
+        Action::SetTarget2 => Action::SetTarget2,
+        Action::SetVariable => Action::SetVariable,
+        Action::StackSwap => Action::StackSwap,
+        Action::StartDrag => Action::StartDrag,
+        Action::Stop => Action::Stop,
+        Action::StopSounds => Action::StopSounds,
+        Action::StoreRegister(action) => Action::StoreRegister(action),
+        Action::StrictEquals => Action::StrictEquals,
+        Action::StringAdd => Action::StringAdd,
+        Action::StringEquals => Action::StringEquals,
+        Action::StringExtract => Action::StringExtract,
+        Action::StringGreater => Action::StringGreater,
+        Action::StringLength => Action::StringLength,
+        Action::StringLess => Action::StringLess,
+        Action::Subtract => Action::Subtract,
+        Action::TargetPath => Action::TargetPath,
+        Action::Throw => Action::Throw,
+        Action::ToInteger => Action::ToInteger,
+        Action::ToNumber => Action::ToNumber,
+        Action::ToString => Action::ToString,
+        Action::ToggleQuality => Action::ToggleQuality,
+        Action::Trace => Action::Trace,
+        Action::TypeOf => Action::TypeOf,
+        Action::ConstantPool(_)
+        | Action::DefineFunction(_)
+        | Action::DefineFunction2(_)
+        | Action::GetUrl(_)
+        | Action::GotoLabel(_)
+        | Action::If(_)
+        | Action::Jump(_)
+        | Action::Push(_)
+        | Action::SetTarget(_)
+        | Action::Try(_)
+        | Action::WaitForFrame(_)
+        | Action::WaitForFrame2(_)
+        | Action::With(_)
+        | Action::Unknown(_) => unreachable!("{action:?} should have been decoded"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm1::error::Error;
+    use crate::avm1::test_utils::with_avm;
+    use crate::string::WStr;
+    use swf::avm1::types::{ConstantPool, Jump, Push};
+    use swf::avm1::write::Writer;
+
+    /// Put the given actions in a movie, and return the slice of it they
+    /// occupy.
+    fn code(actions: &[Action]) -> SwfSlice {
+        let mut code = Vec::new();
+        let mut writer = Writer::new(&mut code, 10);
+        for action in actions {
+            writer.write_action(action).unwrap();
+        }
+
+        let mut data = Vec::new();
+        let header = swf::Header::default_with_swf_version(10);
+        swf::write_swf(&header, &[swf::Tag::DoAction(&code)], &mut data).unwrap();
+        let movie = SwfMovie::from_data(&data, "file:///test.swf".into(), None).unwrap();
+        let start = movie
+            .data()
+            .windows(code.len())
+            .position(|window| window == code)
+            .unwrap();
+        SwfSlice {
+            movie: Arc::new(movie),
+            start,
+            end: start + code.len(),
+        }
+    }
+
+    #[test]
+    fn resolves_jumps() {
+        with_avm(10, |activation, _root| -> Result<(), Error> {
+            // play; jump over stop; stop; jump back to play
+            let code = code(&[
+                Action::Play,
+                Action::Jump(Jump { offset: 1 }),
+                Action::Stop,
+                Action::Jump(Jump { offset: -12 }),
+            ]);
+            let actions = decode_actions(&mut activation.context, &code, 10);
+
+            assert!(matches!(
+                actions.get(0).unwrap().instruction,
+                Instruction::Action(Action::Play)
+            ));
+            let Instruction::Jump(target) = actions.get(1).unwrap().instruction else {
+                panic!("expected a jump");
+            };
+            let Instruction::Jump(back) = actions.get(target).unwrap().instruction else {
+                panic!("expected a jump");
+            };
+            assert_eq!(back, 0);
+            // The stop is never reached, so it isn't decoded.
+            assert!(actions.get(3).is_none());
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn runs_off_the_end() {
+        with_avm(10, |activation, _root| -> Result<(), Error> {
+            let code = code(&[Action::Play, Action::Jump(Jump { offset: 100 })]);
+            let actions = decode_actions(&mut activation.context, &code, 10);
+
+            let Instruction::Jump(target) = actions.get(1).unwrap().instruction else {
+                panic!("expected a jump");
+            };
+            assert!(actions.get(target).is_none());
+            assert!(actions.get(actions.get(1).unwrap().next).is_none());
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn interns_constant_pool() {
+        with_avm(10, |activation, _root| -> Result<(), Error> {
+            let code = code(&[
+                Action::ConstantPool(ConstantPool {
+                    strings: vec![SwfStr::from_utf8_str("foo")],
+                }),
+                Action::Push(Push {
+                    values: vec![swf::avm1::types::Value::ConstantPool(0)],
+                }),
+            ]);
+            let actions = decode_actions(&mut activation.context, &code, 10);
+
+            let Instruction::ConstantPool(constants) = actions.get(0).unwrap().instruction else {
+                panic!("expected a constant pool");
+            };
+            let [Value::String(foo)] = constants[..] else {
+                panic!("expected a single string");
+            };
+            let interned = activation
+                .context
+                .interner
+                .get(activation.gc(), WStr::from_units(b"foo"));
+            assert!(foo.as_interned().is_some() && foo.as_interned() == interned);
+            assert!(matches!(
+                actions.get(1).unwrap().instruction,
+                Instruction::Push(ref values) if matches!(values[..], [PushValue::ConstantPool(0)])
+            ));
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn caches_decoded_code() {
+        with_avm(10, |activation, _root| -> Result<(), Error> {
+            let code = code(&[Action::Play]);
+            let first = decode_actions(&mut activation.context, &code, 10);
+            let second = decode_actions(&mut activation.context, &code, 10);
+            assert!(Gc::ptr_eq(first, second));
+            Ok(())
+        })
+    }
+}
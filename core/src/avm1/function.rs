@@ -1,6 +1,7 @@
 //! Code relating to executable functions + calling conventions.
 
 use crate::avm1::activation::Activation;
+use crate::avm1::decode::FunctionDefinition;
 use crate::avm1::error::Error;
 use crate::avm1::object::super_object::SuperObject;
 use crate::avm1::property::Attribute;
@@ -8,11 +9,11 @@ use crate::avm1::scope::Scope;
 use crate::avm1::value::Value;
 use crate::avm1::{ArrayObject, Object, ObjectPtr, ScriptObject, TObject};
 use crate::display_object::{DisplayObject, TDisplayObject};
-use crate::string::{AvmString, SwfStrExt as _};
+use crate::string::AvmString;
 use crate::tag_utils::SwfSlice;
 use gc_arena::{Collect, Gc, GcCell, Mutation};
 use std::{borrow::Cow, fmt, num::NonZeroU8};
-use swf::{avm1::types::FunctionFlags, SwfStr};
+use swf::avm1::types::FunctionFlags;
 
 /// Represents a function defined in Ruffle's code.
 ///
@@ -82,45 +83,25 @@ pub struct Avm1Function<'gc> {
 }
 
 impl<'gc> Avm1Function<'gc> {
-    /// Construct a function from a DefineFunction2 action.
-    pub fn from_swf_function(
-        gc_context: &Mutation<'gc>,
+    /// Construct a function from a DefineFunction or DefineFunction2 action.
+    pub fn from_definition(
         swf_version: u8,
         actions: SwfSlice,
-        swf_function: swf::avm1::types::DefineFunction2,
+        definition: &FunctionDefinition<'gc>,
         scope: Gc<'gc, Scope<'gc>>,
         constant_pool: Gc<'gc, Vec<Value<'gc>>>,
         base_clip: DisplayObject<'gc>,
     ) -> Self {
-        let encoding = SwfStr::encoding_for_version(swf_version);
-        let name = if swf_function.name.is_empty() {
-            None
-        } else {
-            Some(AvmString::new(
-                gc_context,
-                swf_function.name.decode(encoding),
-            ))
-        };
-
-        let params = swf_function
-            .params
-            .iter()
-            .map(|p| Param {
-                register: p.register_index,
-                name: AvmString::new(gc_context, p.name.decode(encoding)),
-            })
-            .collect();
-
         Avm1Function {
             swf_version,
             data: actions,
-            name,
-            register_count: swf_function.register_count,
-            params,
+            name: definition.name,
+            register_count: definition.register_count,
+            params: definition.params.clone(),
             scope,
             constant_pool,
             base_clip,
-            flags: swf_function.flags,
+            flags: definition.flags,
         }
     }
 
@@ -273,7 +254,7 @@ impl<'gc> Avm1Function<'gc> {
 
 #[derive(Debug, Clone, Collect)]
 #[collect(no_drop)]
-struct Param<'gc> {
+pub struct Param<'gc> {
     /// The register the argument will be preloaded into.
     ///
     /// If `register` is `None`, then this parameter will be stored in a named variable in the
@@ -281,10 +262,10 @@ struct Param<'gc> {
     /// Otherwise, the parameter is loaded into a register and must be accessed with
     /// `Push`/`StoreRegister`.
     #[collect(require_static)]
-    register: Option<NonZeroU8>,
+    pub register: Option<NonZeroU8>,
 
     /// The name of the parameter.
-    name: AvmString<'gc>,
+    pub name: AvmString<'gc>,
 }
 
 /// Represents a function that can be defined in the Ruffle runtime or by the
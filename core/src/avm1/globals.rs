@@ -46,6 +46,7 @@ pub(crate) mod netstream;
 pub(crate) mod number;
 mod object;
 mod point;
+mod print_job;
 mod rectangle;
 mod selection;
 pub(crate) mod shared_object;
@@ -58,6 +59,7 @@ pub(crate) mod system_ime;
 pub(crate) mod system_security;
 pub(crate) mod text_field;
 mod text_format;
+pub(crate) mod text_snapshot;
 pub(crate) mod transform;
 mod video;
 pub(crate) mod xml;
@@ -492,6 +494,7 @@ pub struct SystemPrototypes<'gc> {
     pub rectangle: Object<'gc>,
     pub rectangle_constructor: Object<'gc>,
     pub transform_constructor: Object<'gc>,
+    pub text_snapshot_constructor: Object<'gc>,
     pub shared_object_constructor: Object<'gc>,
     pub color_transform: Object<'gc>,
     pub color_transform_constructor: Object<'gc>,
@@ -582,6 +585,7 @@ pub fn create_globals<'gc>(
     let video_proto = video::create_proto(context, object_proto, function_proto);
     let netstream_proto = netstream::create_proto(context, object_proto, function_proto);
     let xml_socket_proto = xml_socket::create_proto(context, object_proto, function_proto);
+    let print_job_proto = print_job::create_proto(context, object_proto, function_proto);
 
     //TODO: These need to be constructors and should also set `.prototype` on each one
     let object = object::create_object_object(context, object_proto, function_proto);
@@ -676,6 +680,7 @@ pub fn create_globals<'gc>(
     let date = date::create_constructor(context, object_proto, function_proto);
     let netstream = netstream::create_class(context, netstream_proto, function_proto);
     let xml_socket = xml_socket::create_class(context, xml_socket_proto, function_proto);
+    let print_job = print_job::create_class(context, print_job_proto, function_proto);
 
     let flash = ScriptObject::new(gc_context, Some(object_proto));
 
@@ -941,6 +946,13 @@ pub fn create_globals<'gc>(
         text_format.into(),
         Attribute::DONT_ENUM,
     );
+    let text_snapshot = text_snapshot::create_constructor(context, object_proto, function_proto);
+    globals.define_value(
+        gc_context,
+        "TextSnapshot",
+        text_snapshot.into(),
+        Attribute::DONT_ENUM,
+    );
     globals.define_value(gc_context, "XMLNode", xmlnode.into(), Attribute::DONT_ENUM);
     globals.define_value(gc_context, "XML", xml.into(), Attribute::DONT_ENUM);
     globals.define_value(gc_context, "String", string.into(), Attribute::DONT_ENUM);
@@ -1082,6 +1094,12 @@ pub fn create_globals<'gc>(
         xml_socket.into(),
         Attribute::DONT_ENUM,
     );
+    globals.define_value(
+        gc_context,
+        "PrintJob",
+        print_job.into(),
+        Attribute::DONT_ENUM,
+    );
 
     define_properties_on(GLOBAL_DECLS, context, globals, function_proto);
 
@@ -1109,6 +1127,7 @@ pub fn create_globals<'gc>(
             rectangle: rectangle_proto,
             rectangle_constructor: rectangle,
             transform_constructor: transform,
+            text_snapshot_constructor: text_snapshot,
             shared_object_constructor: shared_object,
             color_transform: color_transform_proto,
             color_transform_constructor: color_transform,
@@ -2,8 +2,8 @@
 
 use super::matrix::object_to_matrix;
 use crate::avm1::function::{Executable, FunctionObject};
-use crate::avm1::globals::bitmap_filter;
 use crate::avm1::globals::color_transform::ColorTransformObject;
+use crate::avm1::globals::{bitmap_filter, movie_clip};
 use crate::avm1::object::NativeObject;
 use crate::avm1::property_decl::{define_properties_on, Declaration};
 use crate::avm1::{Activation, Attribute, Error, Object, ScriptObject, TObject, Value};
@@ -644,12 +644,31 @@ fn apply_filter<'gc>(
 fn generate_filter_rect<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let NativeObject::BitmapData(bitmap_data) = this.native() {
         if !bitmap_data.disposed() {
-            avm1_stub!(activation, "BitmapData", "generateFilterRect");
-            return Ok(Value::Undefined);
+            let source_rect = args
+                .get(0)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+            let Some(source_rect) = movie_clip::object_to_rectangle(activation, source_rect)?
+            else {
+                return Ok(Value::Undefined);
+            };
+
+            let filter_object = args
+                .get(1)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+            let Some(filter) =
+                bitmap_filter::avm1_to_filter(filter_object, &mut activation.context)
+            else {
+                return movie_clip::new_rectangle(activation, source_rect);
+            };
+
+            let dest_rect = filter.calculate_generated_rect(source_rect);
+            return movie_clip::new_rectangle(activation, dest_rect);
         }
     }
 
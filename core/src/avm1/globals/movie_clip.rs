@@ -84,6 +84,7 @@ const PROTO_DECLS: &[Declaration] = declare_properties! {
     "getNextHighestDepth" => method(mc_method!(get_next_highest_depth); DONT_ENUM | DONT_DELETE | VERSION_7);
     "getRect" => method(mc_method!(get_rect); DONT_ENUM | DONT_DELETE | VERSION_8);
     "getSWFVersion" => method(mc_method!(get_swf_version); DONT_ENUM | DONT_DELETE);
+    "getTextSnapshot" => method(mc_method!(get_text_snapshot); DONT_ENUM | DONT_DELETE | VERSION_6);
     "getURL" => method(mc_method!(get_url); DONT_ENUM | DONT_DELETE);
     "globalToLocal" => method(mc_method!(global_to_local); DONT_ENUM | DONT_DELETE);
     "gotoAndPlay" => method(mc_method!(goto_and_play); DONT_ENUM | DONT_DELETE);
@@ -1664,6 +1665,19 @@ fn unload_movie<'gc>(
     Ok(Value::Undefined)
 }
 
+fn get_text_snapshot<'gc>(
+    movie_clip: MovieClip<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let constructor = activation
+        .context
+        .avm1
+        .prototypes()
+        .text_snapshot_constructor;
+    constructor.construct(activation, &[movie_clip.object()])
+}
+
 fn transform<'gc>(
     this: MovieClip<'gc>,
     activation: &mut Activation<'_, 'gc>,
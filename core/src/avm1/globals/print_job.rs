@@ -0,0 +1,116 @@
+//! PrintJob object
+
+use crate::avm1::activation::Activation;
+use crate::avm1::error::Error;
+use crate::avm1::function::{Executable, FunctionObject};
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{Object, ScriptObject, TObject, Value};
+use crate::context::GcContext;
+use crate::prelude::*;
+use crate::printing::PrintJob;
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "start" => method(start; DONT_ENUM | DONT_DELETE);
+    "addPage" => method(add_page; DONT_ENUM | DONT_DELETE);
+    "send" => method(send; DONT_ENUM | DONT_DELETE);
+};
+
+pub fn constructor<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(this.into())
+}
+
+fn start<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(paper) = PrintJob::start(&mut activation.context) else {
+        return Ok(false.into());
+    };
+
+    // The paper properties only appear once the job has started.
+    this.set("paperWidth", paper.paper_width.into(), activation)?;
+    this.set("paperHeight", paper.paper_height.into(), activation)?;
+    this.set("pageWidth", paper.page_width.into(), activation)?;
+    this.set("pageHeight", paper.page_height.into(), activation)?;
+    let orientation = if paper.is_landscape() {
+        "landscape"
+    } else {
+        "portrait"
+    };
+    this.set("orientation", orientation.into(), activation)?;
+    Ok(true.into())
+}
+
+fn add_page<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let target = match args.get(0).copied().unwrap_or(Value::Undefined) {
+        Value::Number(level) => activation.get_level(level as i32),
+        target => {
+            let start = activation.target_clip_or_root();
+            activation.resolve_target_display_object(start, target, false)?
+        }
+    };
+    let Some(target) = target else {
+        return Ok(false.into());
+    };
+
+    // Pages are always rasterised, so the `printAsBitmap` option (argument 2) is ignored.
+    let area = match args.get(1) {
+        Some(Value::Object(area)) => Some(Rectangle {
+            x_min: Twips::from_pixels(area.get("xMin", activation)?.coerce_to_f64(activation)?),
+            y_min: Twips::from_pixels(area.get("yMin", activation)?.coerce_to_f64(activation)?),
+            x_max: Twips::from_pixels(area.get("xMax", activation)?.coerce_to_f64(activation)?),
+            y_max: Twips::from_pixels(area.get("yMax", activation)?.coerce_to_f64(activation)?),
+        }),
+        _ => None,
+    };
+
+    let frame = match args.get(3) {
+        Some(frame) => frame.coerce_to_f64(activation)?,
+        None => 0.0,
+    };
+    let frame = (frame >= 1.0).then(|| frame.min(u16::MAX.into()) as u16);
+
+    Ok(PrintJob::add_page(&mut activation.context, target, area, frame).into())
+}
+
+fn send<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    PrintJob::send(&mut activation.context);
+    Ok(Value::Undefined)
+}
+
+pub fn create_proto<'gc>(
+    context: &mut GcContext<'_, 'gc>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let print_job_proto = ScriptObject::new(context.gc_context, Some(proto));
+    define_properties_on(PROTO_DECLS, context, print_job_proto, fn_proto);
+    print_job_proto.into()
+}
+
+pub fn create_class<'gc>(
+    context: &mut GcContext<'_, 'gc>,
+    print_job_proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    FunctionObject::constructor(
+        context.gc_context,
+        Executable::Native(constructor),
+        constructor_to_fn!(constructor),
+        fn_proto,
+        print_job_proto,
+    )
+}
@@ -0,0 +1,238 @@
+//! TextSnapshot object
+
+use crate::avm1::function::{Executable, FunctionObject};
+use crate::avm1::object::NativeObject;
+use crate::avm1::object_reference::MovieClipReference;
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{Activation, ArrayObject, Error, Object, ScriptObject, TObject, Value};
+use crate::context::GcContext;
+use crate::display_object::{DisplayObjectContainer, TDisplayObject};
+use crate::string::AvmString;
+use crate::text_snapshot::TextSnapshot;
+use gc_arena::Collect;
+use std::ops::Range;
+use swf::{Color, Point, Twips};
+
+#[derive(Clone, Debug, Collect)]
+#[collect(no_drop)]
+pub struct TextSnapshotObject<'gc> {
+    clip: Option<MovieClipReference<'gc>>,
+}
+
+impl<'gc> TextSnapshotObject<'gc> {
+    fn new(activation: &mut Activation<'_, 'gc>, args: &[Value<'gc>]) -> Option<Self> {
+        let clip = match args.first()? {
+            Value::MovieClip(clip) => Some(*clip),
+            Value::Object(clip) => {
+                let stage_object = clip.as_stage_object()?;
+                MovieClipReference::try_from_stage_object(activation, stage_object)
+            }
+            _ => return None,
+        };
+        Some(Self { clip })
+    }
+
+    fn container(
+        &self,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Option<DisplayObjectContainer<'gc>> {
+        let (_, _, clip) = self.clip?.resolve_reference(activation)?;
+        clip.as_container()
+    }
+}
+
+macro_rules! text_snapshot_method {
+    ($index:literal) => {
+        |activation, this, args| method(activation, this, args, $index)
+    };
+}
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "getCount" => method(text_snapshot_method!(1); DONT_ENUM | DONT_DELETE);
+    "setSelected" => method(text_snapshot_method!(2); DONT_ENUM | DONT_DELETE);
+    "getSelected" => method(text_snapshot_method!(3); DONT_ENUM | DONT_DELETE);
+    "getText" => method(text_snapshot_method!(4); DONT_ENUM | DONT_DELETE);
+    "getSelectedText" => method(text_snapshot_method!(5); DONT_ENUM | DONT_DELETE);
+    "hitTestTextNearPos" => method(text_snapshot_method!(6); DONT_ENUM | DONT_DELETE);
+    "findText" => method(text_snapshot_method!(7); DONT_ENUM | DONT_DELETE);
+    "setSelectColor" => method(text_snapshot_method!(8); DONT_ENUM | DONT_DELETE);
+    "getTextRunInfo" => method(text_snapshot_method!(9); DONT_ENUM | DONT_DELETE);
+};
+
+/// Converts a pair of character indices to a range, treating negative indices as 0.
+fn index_range<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    args: &[Value<'gc>],
+) -> Result<Range<usize>, Error<'gc>> {
+    let begin = args.get(0).unwrap_or(&Value::Undefined);
+    let end = args.get(1).unwrap_or(&Value::Undefined);
+    let begin = begin.coerce_to_i32(activation)?.max(0) as usize;
+    let end = end.coerce_to_i32(activation)?.max(0) as usize;
+    Ok(begin..end)
+}
+
+fn method<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+    index: u8,
+) -> Result<Value<'gc>, Error<'gc>> {
+    const CONSTRUCTOR: u8 = 0;
+    const GET_COUNT: u8 = 1;
+    const SET_SELECTED: u8 = 2;
+    const GET_SELECTED: u8 = 3;
+    const GET_TEXT: u8 = 4;
+    const GET_SELECTED_TEXT: u8 = 5;
+    const HIT_TEST_TEXT_NEAR_POS: u8 = 6;
+    const FIND_TEXT: u8 = 7;
+    const SET_SELECT_COLOR: u8 = 8;
+    const GET_TEXT_RUN_INFO: u8 = 9;
+
+    if index == CONSTRUCTOR {
+        let Some(text_snapshot) = TextSnapshotObject::new(activation, args) else {
+            return Ok(Value::Undefined);
+        };
+        this.set_native(
+            activation.context.gc_context,
+            NativeObject::TextSnapshot(text_snapshot),
+        );
+        return Ok(this.into());
+    }
+
+    let NativeObject::TextSnapshot(this) = this.native() else {
+        return Ok(Value::Undefined);
+    };
+    let Some(container) = this.container(activation) else {
+        return Ok(Value::Undefined);
+    };
+    let snapshot = TextSnapshot::new(&activation.context, container);
+    let swf_version = activation.swf_version();
+    let bool_arg = |index: usize| {
+        args.get(index)
+            .map_or(false, |value| value.as_bool(swf_version))
+    };
+
+    Ok(match index {
+        GET_COUNT => snapshot.char_count().into(),
+        SET_SELECTED => {
+            let range = index_range(activation, args)?;
+            let selected = bool_arg(2);
+            snapshot.set_selected(activation.context.gc_context, range, selected);
+            Value::Undefined
+        }
+        GET_SELECTED => {
+            let range = index_range(activation, args)?;
+            snapshot.is_selected(range).into()
+        }
+        GET_TEXT => {
+            let range = index_range(activation, args)?;
+            let include_line_endings = bool_arg(2);
+            let text = snapshot.text(range, include_line_endings);
+            AvmString::new(activation.context.gc_context, text).into()
+        }
+        GET_SELECTED_TEXT => {
+            let include_line_endings = bool_arg(0);
+            let text = snapshot.selected_text(include_line_endings);
+            AvmString::new(activation.context.gc_context, text).into()
+        }
+        HIT_TEST_TEXT_NEAR_POS => {
+            let x = args.get(0).unwrap_or(&Value::Undefined);
+            let y = args.get(1).unwrap_or(&Value::Undefined);
+            let point = Point::new(
+                Twips::from_pixels(x.coerce_to_f64(activation)?),
+                Twips::from_pixels(y.coerce_to_f64(activation)?),
+            );
+            let max_distance = match args.get(2) {
+                Some(max_distance) => Twips::from_pixels(max_distance.coerce_to_f64(activation)?),
+                None => Twips::ZERO,
+            };
+            snapshot
+                .hit_test_text_near_pos(point, max_distance)
+                .map_or(-1.0, |index| index as f64)
+                .into()
+        }
+        FIND_TEXT => {
+            let start = args.get(0).unwrap_or(&Value::Undefined);
+            let start = start.coerce_to_i32(activation)?.max(0) as usize;
+            let needle = args
+                .get(1)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_string(activation)?;
+            let case_sensitive = bool_arg(2);
+            snapshot
+                .find_text(start, &needle, case_sensitive)
+                .map_or(-1, |index| index as i32)
+                .into()
+        }
+        SET_SELECT_COLOR => {
+            let color = match args.get(0) {
+                Some(color) => color.coerce_to_u32(activation)?,
+                None => 0xFFFF00,
+            };
+            snapshot
+                .set_selection_color(activation.context.gc_context, Color::from_rgb(color, 255));
+            Value::Undefined
+        }
+        GET_TEXT_RUN_INFO => {
+            let range = index_range(activation, args)?;
+            let mut runs = Vec::new();
+            for run in snapshot.text_run_info(range) {
+                let info = ScriptObject::new(
+                    activation.context.gc_context,
+                    Some(activation.context.avm1.prototypes().object),
+                );
+                let font_name = AvmString::new_utf8(activation.context.gc_context, run.font_name);
+                let matrix = run.matrix;
+                let [c0, c1, c2, c3] = run.corners;
+                let properties: [(&'static str, Value<'gc>); 19] = [
+                    ("indexInRun", run.index_in_run.into()),
+                    ("selected", run.selected.into()),
+                    ("font", font_name.into()),
+                    ("color", run.color.to_rgb().into()),
+                    ("height", run.height.to_pixels().into()),
+                    ("matrix_a", matrix.a.into()),
+                    ("matrix_b", matrix.b.into()),
+                    ("matrix_c", matrix.c.into()),
+                    ("matrix_d", matrix.d.into()),
+                    ("matrix_tx", matrix.tx.to_pixels().into()),
+                    ("matrix_ty", matrix.ty.to_pixels().into()),
+                    ("corner0x", c0.x.to_pixels().into()),
+                    ("corner0y", c0.y.to_pixels().into()),
+                    ("corner1x", c1.x.to_pixels().into()),
+                    ("corner1y", c1.y.to_pixels().into()),
+                    ("corner2x", c2.x.to_pixels().into()),
+                    ("corner2y", c2.y.to_pixels().into()),
+                    ("corner3x", c3.x.to_pixels().into()),
+                    ("corner3y", c3.y.to_pixels().into()),
+                ];
+                for (name, value) in properties {
+                    info.set(name, value, activation)?;
+                }
+                runs.push(info.into());
+            }
+            ArrayObject::new(
+                activation.context.gc_context,
+                activation.context.avm1.prototypes().array,
+                runs,
+            )
+            .into()
+        }
+        _ => Value::Undefined,
+    })
+}
+
+pub fn create_constructor<'gc>(
+    context: &mut GcContext<'_, 'gc>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let text_snapshot_proto = ScriptObject::new(context.gc_context, Some(proto));
+    define_properties_on(PROTO_DECLS, context, text_snapshot_proto, fn_proto);
+    FunctionObject::constructor(
+        context.gc_context,
+        Executable::Native(text_snapshot_method!(0)),
+        constructor_to_fn!(text_snapshot_method!(0)),
+        fn_proto,
+        text_snapshot_proto.into(),
+    )
+}
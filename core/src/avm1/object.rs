@@ -13,6 +13,7 @@ use crate::avm1::globals::file_reference::FileReferenceObject;
 use crate::avm1::globals::glow_filter::GlowFilter;
 use crate::avm1::globals::gradient_filter::GradientFilter;
 use crate::avm1::globals::shared_object::SharedObject;
+use crate::avm1::globals::text_snapshot::TextSnapshotObject;
 use crate::avm1::globals::transform::TransformObject;
 use crate::avm1::globals::xml::Xml;
 use crate::avm1::globals::xml_socket::XmlSocket;
@@ -64,6 +65,7 @@ pub enum NativeObject<'gc> {
     SharedObject(GcCell<'gc, SharedObject>),
     XmlSocket(XmlSocket<'gc>),
     FileReference(FileReferenceObject<'gc>),
+    TextSnapshot(TextSnapshotObject<'gc>),
 }
 
 /// Represents an object that can be directly interacted with by the AVM
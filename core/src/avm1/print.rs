@@ -0,0 +1,121 @@
+//! Handling of the `print` family of functions, which are compiled to `getURL` calls
+
+use crate::avm1::activation::Activation;
+use crate::avm1::error::Error;
+use crate::avm1::Value;
+use crate::avm_warn;
+use crate::context::UpdateContext;
+use crate::prelude::*;
+use crate::printing::{self, PrintJob};
+use crate::string::WStr;
+
+/// How the printed area of each frame is chosen, as given by the second argument of `print`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PrintBounds {
+    /// `"bmovie"`: the bounds of the frame labelled `#b` are used for every frame.
+    Movie,
+    /// `"bmax"`: the union of the bounds of all printed frames is used for every frame.
+    Max,
+    /// `"bframe"`: each frame is printed using its own bounds.
+    Frame,
+}
+
+/// Parse a `print:` or `printasbitmap:` URL.
+pub fn parse(url: &WStr) -> Option<PrintBounds> {
+    let (scheme, option) = url.split_at(url.find(b':')?);
+    let option = &option[1..];
+    if !scheme.eq_ignore_case(WStr::from_units(b"print"))
+        && !scheme.eq_ignore_case(WStr::from_units(b"printasbitmap"))
+    {
+        return None;
+    }
+
+    // Pages are always rasterised, so `printAsBitmap` prints the same as `print`.
+    let option = option.strip_prefix(b'#').unwrap_or(option);
+    if option.eq_ignore_case(WStr::from_units(b"bmax")) {
+        Some(PrintBounds::Max)
+    } else if option.eq_ignore_case(WStr::from_units(b"bframe")) {
+        Some(PrintBounds::Frame)
+    } else {
+        Some(PrintBounds::Movie)
+    }
+}
+
+/// Prints the frames of `target` as a single print job.
+///
+/// Only frames labelled `#p` are printed, or every frame if there are none.
+pub fn handle<'gc>(
+    bounds: PrintBounds,
+    target: Value<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<(), Error<'gc>> {
+    let start = activation.target_clip_or_root();
+    let Some(target) = activation.resolve_target_display_object(start, target, false)? else {
+        avm_warn!(activation, "print: Target not found");
+        return Ok(());
+    };
+
+    if PrintJob::start(&mut activation.context).is_none() {
+        return Ok(());
+    }
+
+    let Some(clip) = target.as_movie_clip() else {
+        let area = target.bounds();
+        PrintJob::add_page(&mut activation.context, target, Some(area), None);
+        PrintJob::send(&mut activation.context);
+        return Ok(());
+    };
+
+    let labels = clip.labels_in_range(1, clip.total_frames() + 1);
+    let mut frames: Vec<u16> = labels
+        .iter()
+        .filter(|(label, _)| label.eq_ignore_case(WStr::from_units(b"#p")))
+        .map(|(_, frame)| *frame)
+        .collect();
+    if frames.is_empty() {
+        frames = (1..=clip.total_frames()).collect();
+    }
+
+    // Flash doesn't run any ActionScript on the frames it prints, so each frame other than
+    // the current one is rendered from a fresh copy of the clip, and whatever that copy
+    // queues up is thrown away afterwards.
+    let context = &mut activation.context;
+    let checkpoint = context.action_queue.checkpoint();
+    let mut copies = Vec::new();
+    let mut frame_clip = |context: &mut UpdateContext<'_, 'gc>, frame: u16| {
+        if frame == clip.current_frame() {
+            return clip;
+        }
+        if let Some((_, copy)) = copies.iter().find(|(copied, _)| *copied == frame) {
+            return *copy;
+        }
+        let copy = printing::frame_copy(context, clip, frame);
+        copies.push((frame, copy));
+        copy
+    };
+
+    let shared_area = match bounds {
+        PrintBounds::Movie => labels
+            .iter()
+            .find(|(label, _)| label.eq_ignore_case(WStr::from_units(b"#b")))
+            .map(|(_, frame)| frame_clip(context, *frame).bounds()),
+        PrintBounds::Max => Some(frames.iter().fold(Rectangle::default(), |area, frame| {
+            area.union(&frame_clip(context, *frame).bounds())
+        })),
+        PrintBounds::Frame => None,
+    };
+
+    for frame in frames {
+        let page = frame_clip(context, frame);
+        let area = shared_area.clone().unwrap_or_else(|| page.bounds());
+        PrintJob::add_page(context, page.into(), Some(area), None);
+    }
+
+    for (_, copy) in copies {
+        printing::release_copy(context, copy);
+    }
+    context.action_queue.discard_since(checkpoint);
+
+    PrintJob::send(context);
+    Ok(())
+}
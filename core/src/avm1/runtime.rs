@@ -1,3 +1,4 @@
+use crate::avm1::decode::ActionCache;
 use crate::avm1::function::{ExecutionReason, FunctionObject};
 use crate::avm1::globals::as_broadcaster::BroadcasterFunctions;
 use crate::avm1::globals::{as_broadcaster, create_globals};
@@ -27,6 +28,9 @@ pub struct Avm1<'gc> {
     /// don't close over the constant pool they were defined with.
     constant_pool: Gc<'gc, Vec<Value<'gc>>>,
 
+    /// Every block of code we've decoded so far.
+    action_cache: ActionCache<'gc>,
+
     /// The global scope (pre-allocated so that it can be reused by fresh `Activation`s).
     global_scope: Gc<'gc, Scope<'gc>>,
 
@@ -97,6 +101,7 @@ impl<'gc> Avm1<'gc> {
         Self {
             player_version,
             constant_pool: Gc::new(gc_context, vec![]),
+            action_cache: ActionCache::default(),
             global_scope: Gc::new(gc_context, Scope::from_global_object(globals)),
             prototypes,
             broadcaster_functions,
@@ -381,6 +386,11 @@ impl<'gc> Avm1<'gc> {
         self.constant_pool = constant_pool;
     }
 
+    /// The cache of decoded AVM1 code.
+    pub(super) fn action_cache_mut(&mut self) -> &mut ActionCache<'gc> {
+        &mut self.action_cache
+    }
+
     /// DisplayObject property map.
     pub fn display_properties(&self) -> &stage_object::DisplayPropertyMap<'gc> {
         &self.display_properties
@@ -4,8 +4,10 @@ use std::rc::Rc;
 
 use crate::avm2::class::AllocatorFn;
 use crate::avm2::function::Executable;
+use crate::avm2::globals::flash::display::shader_job::PendingShaderJob;
 use crate::avm2::globals::SystemClasses;
 use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::sampler::Sampler;
 use crate::avm2::script::{Script, TranslationUnit};
 use crate::context::{GcContext, UpdateContext};
 use crate::display_object::{DisplayObject, DisplayObjectWeak, TDisplayObject};
@@ -13,9 +15,11 @@ use crate::string::AvmString;
 use crate::tag_utils::SwfMovie;
 
 use fnv::FnvHashMap;
-use gc_arena::{Collect, GcCell, Mutation};
+use gc_arena::{Collect, Finalization, GcCell, Mutation};
 use std::sync::Arc;
 use swf::avm2::read::Reader;
+#[cfg(feature = "debugger")]
+use swf::avm2::types::{AbcFile, Op};
 use swf::DoAbc2Flag;
 
 #[macro_export]
@@ -48,11 +52,13 @@ mod method;
 mod multiname;
 mod namespace;
 pub mod object;
+mod optimize;
 mod parameters;
 pub mod property;
 mod property_map;
 mod qname;
 mod regexp;
+pub mod sampler;
 mod scope;
 mod script;
 mod string;
@@ -60,6 +66,7 @@ mod stubs;
 mod traits;
 mod value;
 pub mod vector;
+mod verify;
 mod vtable;
 
 pub use crate::avm2::activation::Activation;
@@ -68,6 +75,7 @@ pub use crate::avm2::call_stack::{CallNode, CallStack};
 pub use crate::avm2::domain::Domain;
 pub use crate::avm2::error::Error;
 pub use crate::avm2::flv::FlvValueAvm2Ext;
+pub use crate::avm2::globals::flash::display::shader_job::run_pending_shader_jobs;
 pub use crate::avm2::globals::flash::ui::context_menu::make_context_menu_state;
 pub use crate::avm2::multiname::Multiname;
 pub use crate::avm2::namespace::Namespace;
@@ -79,7 +87,7 @@ pub use crate::avm2::qname::QName;
 pub use crate::avm2::value::Value;
 
 use self::api_version::ApiVersion;
-use self::object::WeakObject;
+use self::object::{DictionaryObject, DictionaryObjectWeak, WeakObject};
 use self::scope::Scope;
 use num_traits::FromPrimitive;
 
@@ -133,8 +141,10 @@ pub struct Avm2<'gc> {
     pub flash_utils_internal: Namespace<'gc>,
     pub flash_geom_internal: Namespace<'gc>,
     pub flash_events_internal: Namespace<'gc>,
+    pub flash_text_internal: Namespace<'gc>,
     pub flash_text_engine_internal: Namespace<'gc>,
     pub flash_net_internal: Namespace<'gc>,
+    pub flash_system_internal: Namespace<'gc>,
 
     #[collect(require_static)]
     native_method_table: &'static [Option<(&'static str, NativeMethodImpl)>],
@@ -166,6 +176,21 @@ pub struct Avm2<'gc> {
     /// strong references around (this matches Flash's behavior).
     orphan_objects: Rc<Vec<DisplayObjectWeak<'gc>>>,
 
+    /// Every `Dictionary` constructed with `weakKeys`, whose values have to be
+    /// kept alive manually by `finalize_weak_dictionaries`.
+    weak_dictionaries: Vec<DictionaryObjectWeak<'gc>>,
+
+    /// `ShaderJob`s started with `waitForCompletion = false` that haven't
+    /// completed yet. They're completed on the next frame by `run_pending_shader_jobs`.
+    pending_shader_jobs: Vec<PendingShaderJob<'gc>>,
+
+    /// The application domains of every started background `Worker`,
+    /// used to find the worker that is currently running code.
+    worker_domains: Vec<(Domain<'gc>, Object<'gc>)>,
+
+    /// Allocation samples recorded for `flash.sampler`.
+    pub sampler: Sampler<'gc>,
+
     /// The api version of our root movie clip. Note - this is used as the
     /// api version for swfs loaded via `Loader`, overriding the api version
     /// specified in the loaded SWF. This is only used for API versioning (hiding
@@ -224,8 +249,10 @@ impl<'gc> Avm2<'gc> {
             flash_utils_internal: Namespace::internal("flash.utils", context),
             flash_geom_internal: Namespace::internal("flash.geom", context),
             flash_events_internal: Namespace::internal("flash.events", context),
+            flash_text_internal: Namespace::internal("flash.text", context),
             flash_text_engine_internal: Namespace::internal("flash.text.engine", context),
             flash_net_internal: Namespace::internal("flash.net", context),
+            flash_system_internal: Namespace::internal("flash.system", context),
 
             native_method_table: Default::default(),
             native_instance_allocator_table: Default::default(),
@@ -234,6 +261,11 @@ impl<'gc> Avm2<'gc> {
             broadcast_list: Default::default(),
 
             orphan_objects: Default::default(),
+            weak_dictionaries: Vec::new(),
+
+            pending_shader_jobs: Vec::new(),
+            worker_domains: Vec::new(),
+            sampler: Default::default(),
 
             // Set the lowest version for now - this be overriden when we set our movie
             root_api_version: ApiVersion::AllVersions,
@@ -342,6 +374,30 @@ impl<'gc> Avm2<'gc> {
         }
     }
 
+    pub fn register_weak_dictionary(&mut self, dictionary: DictionaryObject<'gc>) {
+        self.weak_dictionaries.push(dictionary.downgrade());
+    }
+
+    /// Called by the garbage collector once marking has finished, to give
+    /// weak dictionaries a chance to keep alive the values of their live keys.
+    ///
+    /// Returns whether anything was resurrected; if so, marking has to
+    /// continue before this is called again. Once nothing else is
+    /// resurrected, the entries of every dead key are removed.
+    pub fn finalize_weak_dictionaries(&mut self, fc: &Finalization<'gc>) -> bool {
+        let mut resurrected = false;
+        for dictionary in &self.weak_dictionaries {
+            resurrected |= dictionary.resurrect_live_values(fc);
+        }
+
+        if !resurrected {
+            self.weak_dictionaries
+                .retain(|dictionary| dictionary.remove_dead_entries(fc));
+        }
+
+        resurrected
+    }
+
     /// Called at the end of `run_all_phases_avm2` - removes any movies
     /// that have been garbage collected, or are no longer orphans
     /// (they've since acquired a parent).
@@ -541,6 +597,11 @@ impl<'gc> Avm2<'gc> {
             }
         };
 
+        #[cfg(feature = "debugger")]
+        if let Some(debugger) = context.debugger.as_mut() {
+            debugger.register_files(debug_file_names(&abc));
+        }
+
         let num_scripts = abc.scripts.len();
         let tunit = TranslationUnit::from_abc(abc, domain, name, movie, context.gc_context);
         for i in 0..num_scripts {
@@ -561,6 +622,10 @@ impl<'gc> Avm2<'gc> {
         self.stage_domain
     }
 
+    pub fn playerglobals_domain(&self) -> Domain<'gc> {
+        self.playerglobals_domain
+    }
+
     /// Pushes an executable on the call stack
     pub fn push_call(&self, mc: &Mutation<'gc>, calling: &Executable<'gc>) {
         self.call_stack.write(mc).push(calling)
@@ -699,3 +764,24 @@ fn valid_orphan<'gc>(
     }
     None
 }
+
+/// Every source file named by a `debugfile` opcode in the given ABC file.
+#[cfg(feature = "debugger")]
+fn debug_file_names(abc: &AbcFile) -> Vec<String> {
+    let mut names = Vec::new();
+    for body in &abc.method_bodies {
+        let mut reader = Reader::new(&body.code);
+        while let Ok(op) = reader.read_op() {
+            if let Op::DebugFile { file_name } = op {
+                let name = file_name
+                    .0
+                    .checked_sub(1)
+                    .and_then(|index| abc.constant_pool.strings.get(index as usize));
+                if let Some(name) = name.filter(|name| !names.contains(*name)) {
+                    names.push(name.clone());
+                }
+            }
+        }
+    }
+    names
+}
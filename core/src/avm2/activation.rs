@@ -14,14 +14,20 @@ use crate::avm2::object::{
     XmlListObject,
 };
 use crate::avm2::object::{Object, TObject};
+use crate::avm2::optimize::{optimize_method, Instruction, OptimizedCode, OptimizedOp};
+use crate::avm2::property::Property;
+use crate::avm2::sampler::Sampler;
 use crate::avm2::scope::{search_scope_stack, Scope, ScopeChain};
 use crate::avm2::script::Script;
 use crate::avm2::value::Value;
+use crate::avm2::verify::verify_method;
 use crate::avm2::Multiname;
 use crate::avm2::Namespace;
 use crate::avm2::QName;
 use crate::avm2::{value, Avm2, Error};
 use crate::context::{GcContext, UpdateContext};
+#[cfg(feature = "debugger")]
+use crate::debugger::{suspend_avm2, Location};
 use crate::string::{AvmAtom, AvmString};
 use crate::swf::extensions::ReadSwfExt;
 use crate::tag_utils::SwfMovie;
@@ -143,6 +149,16 @@ pub struct Activation<'a, 'gc: 'a> {
     /// Maximum size for the scope frame.
     max_scope_size: usize,
 
+    /// The debugger's id for the source file this method came from, as given
+    /// by its `debugfile` opcode. Only tracked while a debugger is attached.
+    #[cfg(feature = "debugger")]
+    debug_file: Option<u16>,
+
+    /// The names of this method's local registers, as given by its `debug`
+    /// opcodes. Only tracked while a debugger is attached.
+    #[cfg(feature = "debugger")]
+    debug_locals: Vec<(String, u32)>,
+
     pub context: UpdateContext<'a, 'gc>,
 }
 
@@ -177,6 +193,10 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             scope_depth: context.avm2.scope_stack.len(),
             max_stack_size: 0,
             max_scope_size: 0,
+            #[cfg(feature = "debugger")]
+            debug_file: None,
+            #[cfg(feature = "debugger")]
+            debug_locals: Vec::new(),
             context,
         }
     }
@@ -205,6 +225,10 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             scope_depth: context.avm2.scope_stack.len(),
             max_stack_size: 0,
             max_scope_size: 0,
+            #[cfg(feature = "debugger")]
+            debug_file: None,
+            #[cfg(feature = "debugger")]
+            debug_locals: Vec::new(),
             context,
         }
     }
@@ -246,6 +270,10 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             scope_depth: context.avm2.scope_stack.len(),
             max_stack_size: max_stack as usize,
             max_scope_size: max_scope as usize,
+            #[cfg(feature = "debugger")]
+            debug_file: None,
+            #[cfg(feature = "debugger")]
+            debug_locals: Vec::new(),
             context,
         })
     }
@@ -436,6 +464,10 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             scope_depth: context.avm2.scope_stack.len(),
             max_stack_size: body.max_stack as usize,
             max_scope_size: (body.max_scope_depth - body.init_scope_depth) as usize,
+            #[cfg(feature = "debugger")]
+            debug_file: None,
+            #[cfg(feature = "debugger")]
+            debug_locals: Vec::new(),
             context,
         };
 
@@ -534,6 +566,10 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             scope_depth: context.avm2.scope_stack.len(),
             max_stack_size: 0,
             max_scope_size: 0,
+            #[cfg(feature = "debugger")]
+            debug_file: None,
+            #[cfg(feature = "debugger")]
+            debug_locals: Vec::new(),
             context,
         })
     }
@@ -855,10 +891,21 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             .body()
             .ok_or_else(|| "Cannot execute non-native method without body".into());
         let body = body?;
+
+        if !method.is_verified() {
+            verify_method(self, method)?;
+            method.set_verified();
+        }
+
+        let code = BytecodeMethod::get_or_init_optimized_code(method, self.gc(), || {
+            optimize_method(self, method)
+        })?;
+        let code = Some(code).filter(|code| code.applies_to(self.subclass_object));
+
         let mut reader = Reader::new(&body.code);
 
         let val = loop {
-            let result = self.do_next_opcode(method, &mut reader, &body.code);
+            let result = self.do_next_opcode(method, code, &mut reader, &body.code);
             match result {
                 Ok(FrameControl::Return(value)) => break Ok(value),
                 Ok(FrameControl::Continue) => {}
@@ -924,6 +971,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     fn do_next_opcode<'b>(
         &mut self,
         method: Gc<'gc, BytecodeMethod<'gc>>,
+        code: Option<Gc<'gc, OptimizedCode<'gc>>>,
         reader: &mut Reader<'b>,
         full_data: &'b [u8],
     ) -> Result<FrameControl<'gc>, Error<'gc>> {
@@ -939,7 +987,27 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         }
 
         let instruction_start = reader.pos(full_data);
-        let op = reader.read_op();
+        let optimized = code.and_then(|code| {
+            let (instruction, next) = Gc::as_ref(code).op_at(instruction_start)?;
+            Some((code, instruction.clone(), next))
+        });
+        let op = match optimized {
+            Some((code, Instruction::Optimized(op), next)) => {
+                reader.seek_absolute(full_data, next);
+                avm_debug!(self.avm2(), "Opcode: {op:?}");
+
+                let result = self.do_optimized_op(method, code, op);
+                if let Err(error) = result {
+                    return self.handle_err(method, reader, full_data, instruction_start, error);
+                }
+                return result;
+            }
+            Some((_, Instruction::Op(op), next)) => {
+                reader.seek_absolute(full_data, next);
+                Ok(op)
+            }
+            None => reader.read_op(),
+        };
         if let Ok(op) = op {
             avm_debug!(self.avm2(), "Opcode: {op:?}");
 
@@ -1142,6 +1210,178 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         }
     }
 
+    /// Run an op that was rewritten by the optimizer.
+    fn do_optimized_op(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        code: Gc<'gc, OptimizedCode<'gc>>,
+        op: OptimizedOp,
+    ) -> Result<FrameControl<'gc>, Error<'gc>> {
+        match op {
+            OptimizedOp::Nop => Ok(FrameControl::Continue),
+            OptimizedOp::GetProperty { index, cache } => {
+                self.op_get_property_cached(method, code, index, cache)
+            }
+            OptimizedOp::SetProperty { index, cache } => {
+                self.op_set_property_cached(method, code, index, cache)
+            }
+            OptimizedOp::InitProperty { index, cache } => {
+                self.op_init_property_cached(method, code, index, cache)
+            }
+            OptimizedOp::CallProperty {
+                index,
+                num_args,
+                cache,
+                push_result,
+            } => self.op_call_property_cached(method, code, index, num_args, cache, push_result),
+            OptimizedOp::GetLex { index, cache } => {
+                self.op_get_lex_cached(method, code, index, cache)
+            }
+        }
+    }
+
+    fn op_get_property_cached(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        code: Gc<'gc, OptimizedCode<'gc>>,
+        index: Index<AbcMultiname>,
+        cache: u32,
+    ) -> Result<FrameControl<'gc>, Error<'gc>> {
+        let multiname = self.pool_maybe_uninitialized_multiname(method, index)?;
+        if multiname.has_lazy_component() {
+            return self.op_get_property(method, index);
+        }
+
+        let object = self.pop_stack();
+        let object = object.coerce_to_object_or_typeerror(self, Some(&multiname))?;
+        let property = code.lookup_trait(self.gc(), cache, object, &multiname);
+        let value = object.get_resolved_property(property, &multiname, self)?;
+        self.push_stack(value);
+
+        Ok(FrameControl::Continue)
+    }
+
+    fn op_set_property_cached(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        code: Gc<'gc, OptimizedCode<'gc>>,
+        index: Index<AbcMultiname>,
+        cache: u32,
+    ) -> Result<FrameControl<'gc>, Error<'gc>> {
+        let multiname = self.pool_maybe_uninitialized_multiname(method, index)?;
+        if multiname.has_lazy_component() {
+            return self.op_set_property(method, index);
+        }
+
+        let value = self.pop_stack();
+        let object = self.pop_stack();
+        let object = object.coerce_to_object_or_typeerror(self, Some(&multiname))?;
+        let property = code.lookup_trait(self.gc(), cache, object, &multiname);
+        object.set_resolved_property(property, &multiname, value, self)?;
+
+        Ok(FrameControl::Continue)
+    }
+
+    fn op_init_property_cached(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        code: Gc<'gc, OptimizedCode<'gc>>,
+        index: Index<AbcMultiname>,
+        cache: u32,
+    ) -> Result<FrameControl<'gc>, Error<'gc>> {
+        let multiname = self.pool_maybe_uninitialized_multiname(method, index)?;
+        if multiname.has_lazy_component() {
+            return self.op_init_property(method, index);
+        }
+
+        let value = self.pop_stack();
+        let object = self
+            .pop_stack()
+            .coerce_to_object_or_typeerror(self, Some(&multiname))?;
+        let property = code.lookup_trait(self.gc(), cache, object, &multiname);
+        object.init_resolved_property(property, &multiname, value, self)?;
+
+        Ok(FrameControl::Continue)
+    }
+
+    fn op_call_property_cached(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        code: Gc<'gc, OptimizedCode<'gc>>,
+        index: Index<AbcMultiname>,
+        arg_count: u32,
+        cache: u32,
+        push_result: bool,
+    ) -> Result<FrameControl<'gc>, Error<'gc>> {
+        let multiname = self.pool_maybe_uninitialized_multiname(method, index)?;
+        if multiname.has_lazy_component() {
+            return if push_result {
+                self.op_call_property(method, index, arg_count)
+            } else {
+                self.op_call_prop_void(method, index, arg_count)
+            };
+        }
+
+        let args = self.pop_stack_args(arg_count);
+        let receiver = self
+            .pop_stack()
+            .coerce_to_object_or_typeerror(self, Some(&multiname))?;
+        let property = code.lookup_trait(self.gc(), cache, receiver, &multiname);
+        let value = receiver.call_resolved_property(property, &multiname, &args, self)?;
+
+        if push_result {
+            self.push_stack(value);
+        }
+
+        Ok(FrameControl::Continue)
+    }
+
+    fn op_get_lex_cached(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        code: Gc<'gc, OptimizedCode<'gc>>,
+        index: Index<AbcMultiname>,
+        cache: u32,
+    ) -> Result<FrameControl<'gc>, Error<'gc>> {
+        let this_vtable = self
+            .local_register(0)?
+            .as_object()
+            .and_then(|this| this.vtable());
+        if let Some(class) = code.cached_class(cache, this_vtable) {
+            self.push_stack(class);
+            return Ok(FrameControl::Continue);
+        }
+
+        let multiname = self.pool_multiname_static(method, index)?;
+        let outer_scope = self.outer;
+        if search_scope_stack(self.scope_frame(), &multiname, outer_scope.is_empty())?.is_some() {
+            return self.op_get_lex(method, index);
+        }
+
+        // Classes live in const slots of their script's global object, so
+        // once we've found one it can't change.
+        let Some(object) = outer_scope.find(&multiname, self)? else {
+            return self.op_get_lex(method, index);
+        };
+        let is_const = object
+            .vtable()
+            .and_then(|vtable| vtable.get_trait(&multiname))
+            .is_some_and(|property| matches!(property, Property::ConstSlot { .. }));
+        let value = object.get_property(&multiname, self)?;
+        if is_const
+            && value
+                .as_object()
+                .and_then(|o| o.as_class_object())
+                .is_some()
+        {
+            code.cache_class(self.gc(), cache, value, this_vtable);
+        }
+
+        self.push_stack(value);
+
+        Ok(FrameControl::Continue)
+    }
+
     fn unknown_op(&mut self, op: swf::avm2::types::Op) -> Result<FrameControl<'gc>, Error<'gc>> {
         tracing::error!("Unknown AVM2 opcode: {:?}", op);
         Err("Unknown op".into())
@@ -1472,7 +1712,10 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                 if let Some(dictionary) = object.as_dictionary_object() {
                     let _ = self.pop_stack();
                     let _ = self.pop_stack();
-                    let value = dictionary.get_property_by_object(name_value.as_object().unwrap());
+                    let value = dictionary.get_property_by_object(
+                        name_value.as_object().unwrap(),
+                        self.context.gc_context,
+                    );
                     self.push_stack(value);
 
                     return Ok(FrameControl::Continue);
@@ -1650,7 +1893,9 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         if let Some(dictionary) = obj.as_dictionary_object() {
             if !name_value.is_primitive() {
                 let obj_key = name_value.as_object().unwrap();
-                self.push_stack(dictionary.has_property_by_object(obj_key));
+                self.push_stack(
+                    dictionary.has_property_by_object(obj_key, self.context.gc_context),
+                );
 
                 return Ok(FrameControl::Continue);
             }
@@ -1922,7 +2167,9 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             activation_class.construct(self, &[])?
         } else {
             // TODO: we might want this to be a proper Object instance, just in case
-            ScriptObject::custom_object(self.context.gc_context, None, None)
+            let instance = ScriptObject::custom_object(self.context.gc_context, None, None);
+            Sampler::record_new_object(self, instance);
+            instance
         };
 
         self.push_stack(instance);
@@ -3272,6 +3519,20 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     ) -> Result<FrameControl<'gc>, Error<'gc>> {
         if is_local_register {
             let register_name = self.pool_string(&method, register_name)?;
+
+            #[cfg(feature = "debugger")]
+            if self.context.debugger.is_some()
+                && !self
+                    .debug_locals
+                    .iter()
+                    .any(|(_, id)| *id == register as u32)
+            {
+                self.debug_locals.push((
+                    register_name.as_wstr().to_utf8_lossy().into_owned(),
+                    register as u32,
+                ));
+            }
+
             if (register as usize) < self.local_registers.0.len() {
                 let value = self.local_register(register as u32)?;
 
@@ -3310,6 +3571,11 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         avm_debug!(self.avm2(), "File: {file_name}");
 
+        #[cfg(feature = "debugger")]
+        if let Some(debugger) = self.context.debugger.as_mut() {
+            self.debug_file = Some(debugger.file_id(&file_name.as_wstr().to_utf8_lossy()));
+        }
+
         Ok(FrameControl::Continue)
     }
 
@@ -3325,6 +3591,21 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     fn op_debug_line(&mut self, line_num: u32) -> Result<FrameControl<'gc>, Error<'gc>> {
         avm_debug!(self.avm2(), "Line: {line_num}");
 
+        #[cfg(feature = "debugger")]
+        if let Some(file) = self.debug_file {
+            let location = Location::new(file, line_num);
+            let depth = self.avm2().call_stack().read().len();
+            if self
+                .context
+                .debugger
+                .as_ref()
+                .is_some_and(|debugger| debugger.should_break(location, depth))
+            {
+                let locals = self.debug_locals.clone();
+                suspend_avm2(self, location, &locals);
+            }
+        }
+
         Ok(FrameControl::Continue)
     }
 
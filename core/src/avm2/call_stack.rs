@@ -1,6 +1,7 @@
 use crate::avm2::function::{display_function, Executable};
 use crate::avm2::method::Method;
 use crate::avm2::object::ClassObject;
+use crate::avm2::Domain;
 use crate::string::WString;
 use gc_arena::Collect;
 
@@ -16,6 +17,32 @@ pub enum CallNode<'gc> {
     },
 }
 
+impl<'gc> CallNode<'gc> {
+    pub fn display(&self, output: &mut WString) {
+        match self {
+            CallNode::GlobalInit(script) => {
+                let name = if let Some(tuint) = script.translation_unit() {
+                    if let Some(name) = tuint.name() {
+                        name.to_utf8_lossy().to_string()
+                    } else {
+                        "<No name>".to_string()
+                    }
+                } else {
+                    "<No translation unit>".to_string()
+                };
+
+                // NOTE: We intentionally diverge from Flash Player's output
+                // here - everything with the [] brackets is extra information
+                // added by Ruffle
+                output.push_utf8(&format!("global$init() [TU={}]", name));
+            }
+            CallNode::Method { method, superclass } => {
+                display_function(output, method, *superclass)
+            }
+        }
+    }
+}
+
 #[derive(Collect, Clone)]
 #[collect(no_drop)]
 pub struct CallStack<'gc> {
@@ -45,30 +72,40 @@ impl<'gc> CallStack<'gc> {
     pub fn display(&self, output: &mut WString) {
         for call in self.stack.iter().rev() {
             output.push_utf8("\n\tat ");
-            match call {
-                CallNode::GlobalInit(script) => {
-                    let name = if let Some(tuint) = script.translation_unit() {
-                        if let Some(name) = tuint.name() {
-                            name.to_utf8_lossy().to_string()
-                        } else {
-                            "<No name>".to_string()
-                        }
-                    } else {
-                        "<No translation unit>".to_string()
-                    };
-
-                    // NOTE: We intentionally diverge from Flash Player's output
-                    // here - everything with the [] brackets is extra information
-                    // added by Ruffle
-                    output.push_utf8(&format!("global$init() [TU={}]", name));
-                }
-                CallNode::Method { method, superclass } => {
-                    display_function(output, method, *superclass)
-                }
-            }
+            call.display(output);
         }
     }
 
+    /// The name of every function on the call stack, innermost first.
+    pub fn frame_names(&self) -> Vec<String> {
+        self.stack
+            .iter()
+            .rev()
+            .map(|call| {
+                let mut output = WString::new();
+                call.display(&mut output);
+                output.to_utf8_lossy().into_owned()
+            })
+            .collect()
+    }
+
+    /// The domain of every script and bytecode method on the call stack,
+    /// innermost first.
+    pub fn domains(&self) -> impl Iterator<Item = Domain<'gc>> + '_ {
+        self.stack.iter().rev().filter_map(|call| match call {
+            CallNode::GlobalInit(script) => Some(script.domain()),
+            CallNode::Method {
+                method: Method::Bytecode(method),
+                ..
+            } => Some(method.translation_unit().domain()),
+            CallNode::Method { .. } => None,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.stack.is_empty()
     }
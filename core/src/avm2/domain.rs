@@ -70,6 +70,10 @@ impl<'gc> Domain<'gc> {
         ))
     }
 
+    pub fn ptr_eq(a: Domain<'gc>, b: Domain<'gc>) -> bool {
+        GcCell::ptr_eq(a.0, b.0)
+    }
+
     pub fn is_playerglobals_domain(&self, avm2: &Avm2<'gc>) -> bool {
         avm2.playerglobals_domain.0.as_ptr() == self.0.as_ptr()
     }
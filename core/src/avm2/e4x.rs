@@ -6,17 +6,18 @@ use std::{
 use gc_arena::{Collect, GcCell, Mutation};
 use quick_xml::{
     events::{attributes::AttrError as XmlAttrError, BytesStart, Event},
-    name::ResolveResult,
+    name::{PrefixDeclaration, ResolveResult},
     Error as XmlError, NsReader,
 };
 
 use crate::{avm2::TObject, xml::custom_unescape};
 
 use super::{
+    api_version::ApiVersion,
     error::{make_error_1010, make_error_1118, type_error},
-    object::{E4XOrXml, FunctionObject},
+    object::{E4XOrXml, FunctionObject, NamespaceObject, Object, XmlObject},
     string::AvmString,
-    Activation, Error, Multiname, Value,
+    Activation, Error, Multiname, Namespace, Value,
 };
 use crate::string::{WStr, WString};
 
@@ -35,10 +36,79 @@ pub struct E4XNode<'gc>(GcCell<'gc, E4XNodeData<'gc>>);
 #[collect(no_drop)]
 pub struct E4XNodeData<'gc> {
     parent: Option<E4XNode<'gc>>,
-    namespace: Option<AvmString<'gc>>,
+    namespace: Option<E4XNamespace<'gc>>,
     local_name: Option<AvmString<'gc>>,
     kind: E4XNodeKind<'gc>,
     notification: Option<FunctionObject<'gc>>,
+    /// The namespaces declared on this node (`[[InScopeNamespaces]]` in the spec).
+    /// Namespaces declared by ancestors are not included.
+    namespaces: Vec<E4XNamespace<'gc>>,
+}
+
+/// A namespace as used by E4X, which unlike `Namespace` also carries a prefix.
+#[derive(Copy, Clone, Collect, Debug)]
+#[collect(no_drop)]
+pub struct E4XNamespace<'gc> {
+    /// The prefix of this namespace, `None` if it is undefined.
+    pub prefix: Option<AvmString<'gc>>,
+    pub uri: AvmString<'gc>,
+}
+
+impl<'gc> E4XNamespace<'gc> {
+    /// A namespace with the given URI and an undefined prefix.
+    pub fn new_uri(uri: AvmString<'gc>) -> Self {
+        E4XNamespace { prefix: None, uri }
+    }
+
+    /// The namespace with an empty prefix and an empty URI.
+    pub fn default_namespace() -> Self {
+        E4XNamespace {
+            prefix: Some(AvmString::default()),
+            uri: AvmString::default(),
+        }
+    }
+
+    /// Converts a value to a namespace, as if by calling `Namespace(value)`.
+    pub fn from_value(
+        activation: &mut Activation<'_, 'gc>,
+        value: Value<'gc>,
+    ) -> Result<Self, Error<'gc>> {
+        if let Some(ns) = value.as_object().and_then(|o| o.as_namespace_object()) {
+            return Ok(E4XNamespace {
+                prefix: ns.prefix(),
+                uri: ns.namespace().as_uri(),
+            });
+        }
+
+        let namespace = activation
+            .avm2()
+            .classes()
+            .namespace
+            .construct(activation, &[value])?;
+        let namespace = namespace
+            .as_namespace_object()
+            .expect("Namespace constructor should return a Namespace");
+        Ok(E4XNamespace {
+            prefix: namespace.prefix(),
+            uri: namespace.namespace().as_uri(),
+        })
+    }
+
+    pub fn as_namespace(&self, activation: &mut Activation<'_, 'gc>) -> Namespace<'gc> {
+        Namespace::package(
+            self.uri,
+            ApiVersion::AllVersions,
+            &mut activation.context.borrow_gc(),
+        )
+    }
+
+    pub fn as_namespace_object(
+        &self,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Result<Object<'gc>, Error<'gc>> {
+        let namespace = self.as_namespace(activation);
+        NamespaceObject::from_ns_and_prefix(activation, namespace, self.prefix)
+    }
 }
 
 impl<'gc> Debug for E4XNodeData<'gc> {
@@ -126,6 +196,7 @@ impl<'gc> E4XNode<'gc> {
                     children: vec![],
                 },
                 notification: None,
+                namespaces: vec![],
             },
         ))
     }
@@ -139,13 +210,14 @@ impl<'gc> E4XNode<'gc> {
                 local_name: None,
                 kind: E4XNodeKind::Text(text),
                 notification: None,
+                namespaces: vec![],
             },
         ))
     }
 
     pub fn element(
         mc: &Mutation<'gc>,
-        namespace: Option<AvmString<'gc>>,
+        namespace: Option<E4XNamespace<'gc>>,
         name: AvmString<'gc>,
         parent: Option<Self>,
     ) -> Self {
@@ -160,6 +232,7 @@ impl<'gc> E4XNode<'gc> {
                     children: vec![],
                 },
                 notification: None,
+                namespaces: vec![],
             },
         ))
     }
@@ -178,6 +251,7 @@ impl<'gc> E4XNode<'gc> {
                 local_name: Some(name),
                 kind: E4XNodeKind::Attribute(value),
                 notification: None,
+                namespaces: vec![],
             },
         ))
     }
@@ -282,6 +356,7 @@ impl<'gc> E4XNode<'gc> {
                 local_name: this.local_name,
                 kind,
                 notification: None,
+                namespaces: this.namespaces.clone(),
             },
         ));
 
@@ -708,6 +783,7 @@ impl<'gc> E4XNode<'gc> {
                             E4XNodeKind::CData(text)
                         },
                         notification: None,
+                        namespaces: vec![],
                     },
                 ));
                 push_childless_node(node, open_tags, top_level, activation)?;
@@ -781,6 +857,7 @@ impl<'gc> E4XNode<'gc> {
                             local_name: None,
                             kind: E4XNodeKind::Comment(text),
                             notification: None,
+                            namespaces: vec![],
                         },
                     ));
 
@@ -820,6 +897,7 @@ impl<'gc> E4XNode<'gc> {
                             local_name: Some(name),
                             kind: E4XNodeKind::ProcessingInstruction(value),
                             notification: None,
+                            namespaces: vec![],
                         },
                     ));
 
@@ -844,22 +922,48 @@ impl<'gc> E4XNode<'gc> {
         decoder: quick_xml::Decoder,
     ) -> Result<Self, Error<'gc>> {
         let mut attribute_nodes = Vec::new();
+        let mut namespaces = Vec::new();
 
         let attributes: Result<Vec<_>, _> = bs.attributes().collect();
         for attribute in
             attributes.map_err(|e| make_xml_error(activation, XmlError::InvalidAttr(e)))?
         {
+            let value_str = custom_unescape(&attribute.value, decoder)
+                .map_err(|e| make_xml_error(activation, e))?;
+            let value =
+                AvmString::new_utf8_bytes(activation.context.gc_context, value_str.as_bytes());
+
+            if let Some(declaration) = attribute.key.as_namespace_binding() {
+                let prefix = match declaration {
+                    PrefixDeclaration::Default => AvmString::default(),
+                    PrefixDeclaration::Named(prefix) => {
+                        AvmString::new_utf8_bytes(activation.context.gc_context, prefix)
+                    }
+                };
+                namespaces.push(E4XNamespace {
+                    prefix: Some(prefix),
+                    uri: value,
+                });
+                continue;
+            }
+
             let (ns, local_name) = parser.resolve_attribute(attribute.key);
             let name =
                 AvmString::new_utf8_bytes(activation.context.gc_context, local_name.into_inner());
             let namespace = match ns {
-                ResolveResult::Bound(ns) if ns.into_inner() == b"http://www.w3.org/2000/xmlns/" => {
-                    continue
+                ResolveResult::Bound(ns) => {
+                    let prefix = attribute.key.prefix().map_or(&[][..], |p| p.into_inner());
+                    Some(E4XNamespace {
+                        prefix: Some(AvmString::new_utf8_bytes(
+                            activation.context.gc_context,
+                            prefix,
+                        )),
+                        uri: AvmString::new_utf8_bytes(
+                            activation.context.gc_context,
+                            ns.into_inner(),
+                        ),
+                    })
                 }
-                ResolveResult::Bound(ns) => Some(AvmString::new_utf8_bytes(
-                    activation.context.gc_context,
-                    ns.into_inner(),
-                )),
                 ResolveResult::Unknown(ns) => {
                     return Err(Error::AvmError(type_error(
                         activation,
@@ -874,17 +978,13 @@ impl<'gc> E4XNode<'gc> {
                 ResolveResult::Unbound => None,
             };
 
-            let value_str = custom_unescape(&attribute.value, decoder)
-                .map_err(|e| make_xml_error(activation, e))?;
-            let value =
-                AvmString::new_utf8_bytes(activation.context.gc_context, value_str.as_bytes());
-
             let attribute_data = E4XNodeData {
                 parent: None,
                 namespace,
                 local_name: Some(name),
                 kind: E4XNodeKind::Attribute(value),
                 notification: None,
+                namespaces: vec![],
             };
             let attribute = E4XNode(GcCell::new(activation.context.gc_context, attribute_data));
             attribute_nodes.push(attribute);
@@ -894,10 +994,16 @@ impl<'gc> E4XNode<'gc> {
         let name =
             AvmString::new_utf8_bytes(activation.context.gc_context, local_name.into_inner());
         let namespace = match ns {
-            ResolveResult::Bound(ns) => Some(AvmString::new_utf8_bytes(
-                activation.context.gc_context,
-                ns.into_inner(),
-            )),
+            ResolveResult::Bound(ns) => {
+                let prefix = bs.name().prefix().map_or(&[][..], |p| p.into_inner());
+                Some(E4XNamespace {
+                    prefix: Some(AvmString::new_utf8_bytes(
+                        activation.context.gc_context,
+                        prefix,
+                    )),
+                    uri: AvmString::new_utf8_bytes(activation.context.gc_context, ns.into_inner()),
+                })
+            }
             ResolveResult::Unknown(ns) => {
                 return Err(Error::AvmError(type_error(
                     activation,
@@ -921,6 +1027,7 @@ impl<'gc> E4XNode<'gc> {
                 children: Vec::new(),
             },
             notification: None,
+            namespaces,
         };
 
         let result = E4XNode(GcCell::new(activation.context.gc_context, data));
@@ -935,14 +1042,227 @@ impl<'gc> E4XNode<'gc> {
         Ok(result)
     }
 
-    pub fn set_namespace(&self, namespace: AvmString<'gc>, mc: &Mutation<'gc>) {
-        self.0.write(mc).namespace = Some(namespace);
+    pub fn set_namespace(&self, namespace: Option<E4XNamespace<'gc>>, mc: &Mutation<'gc>) {
+        self.0.write(mc).namespace = namespace;
     }
 
-    pub fn namespace(&self) -> Option<AvmString<'gc>> {
+    pub fn namespace(&self) -> Option<E4XNamespace<'gc>> {
         self.0.read().namespace
     }
 
+    /// The namespaces declared on this node itself.
+    pub fn namespaces(&self) -> Ref<'_, Vec<E4XNamespace<'gc>>> {
+        Ref::map(self.0.read(), |r| &r.namespaces)
+    }
+
+    /// Returns every namespace in scope for this node, including those declared by its
+    /// ancestors. When several namespaces share a prefix, only the closest one is returned.
+    pub fn in_scope_namespaces(&self) -> Vec<E4XNamespace<'gc>> {
+        let mut result: Vec<E4XNamespace<'gc>> = Vec::new();
+        for node in self.ancestors() {
+            for ns in node.namespaces().iter() {
+                if !result.iter().any(|other| other.prefix == ns.prefix) {
+                    result.push(*ns);
+                }
+            }
+        }
+        result
+    }
+
+    // ECMA-357 13.3.5.4 [[GetNamespace]] ( [ InScopeNamespaces ] )
+    pub fn get_namespace(&self, in_scope: &[E4XNamespace<'gc>]) -> E4XNamespace<'gc> {
+        let namespace = self.namespace();
+        let uri = namespace.map(|ns| ns.uri).unwrap_or_default();
+        let prefix = namespace.and_then(|ns| ns.prefix);
+
+        // NOTE: The spec allows any namespace with a matching URI to be picked, so prefer
+        // one whose prefix matches too.
+        in_scope
+            .iter()
+            .find(|ns| ns.uri == uri && prefix.is_some() && ns.prefix == prefix)
+            .or_else(|| in_scope.iter().find(|ns| ns.uri == uri))
+            .copied()
+            .unwrap_or_else(|| {
+                if uri.is_empty() {
+                    E4XNamespace::default_namespace()
+                } else {
+                    E4XNamespace { prefix, uri }
+                }
+            })
+    }
+
+    // ECMA-357 9.1.1.13 [[AddInScopeNamespace]] ( N )
+    pub fn add_in_scope_namespace(&self, mc: &Mutation<'gc>, namespace: E4XNamespace<'gc>) {
+        // 1. If x.[[Class]] ∈ {"text", "comment", "processing-instruction", "attribute"}, return
+        if !self.is_element() {
+            return;
+        }
+
+        // 2. If N.prefix != undefined
+        let Some(prefix) = namespace.prefix else {
+            return;
+        };
+
+        // 2.a. If N.prefix == "" and x.[[Name]].uri == "", return
+        let own_uri = self.namespace().map(|ns| ns.uri).unwrap_or_default();
+        if prefix.is_empty() && own_uri.is_empty() {
+            return;
+        }
+
+        let mut this = self.0.write(mc);
+
+        // 2.b. Let match be null
+        // 2.c. For each ns in x.[[InScopeNamespaces]]
+        // 2.c.i. If N.prefix == ns.prefix, let match = ns
+        // 2.d. If match is not null and match.uri is not equal to N.uri
+        // 2.d.i. Remove match from x.[[InScopeNamespaces]]
+        if let Some(index) = this
+            .namespaces
+            .iter()
+            .position(|ns| ns.prefix == Some(prefix))
+        {
+            if this.namespaces[index].uri == namespace.uri {
+                return;
+            }
+            this.namespaces.remove(index);
+        }
+
+        // 2.e. Let x.[[InScopeNamespaces]] = x.[[InScopeNamespaces]] ∪ { N }
+        this.namespaces.push(namespace);
+
+        // 2.f. If x.[[Name]].[[Prefix]] == N.prefix
+        // 2.f.i. Let x.[[Name]].prefix = undefined
+        if let Some(ns) = &mut this.namespace {
+            if ns.prefix == Some(prefix) {
+                ns.prefix = None;
+            }
+        }
+
+        // 2.g. For each attr in x.[[Attributes]]
+        // 2.g.i. If attr.[[Name]].[[Prefix]] == N.prefix, let attr.[[Name]].prefix = undefined
+        if let E4XNodeKind::Element { attributes, .. } = &this.kind {
+            for attribute in attributes {
+                let mut attribute = attribute.0.write(mc);
+                if let Some(ns) = &mut attribute.namespace {
+                    if ns.prefix == Some(prefix) {
+                        ns.prefix = None;
+                    }
+                }
+            }
+        }
+    }
+
+    // ECMA-357 13.4.4.31 XML.prototype.removeNamespace ( namespace ), steps 3 - 6
+    pub fn remove_namespace(&self, mc: &Mutation<'gc>, namespace: E4XNamespace<'gc>) {
+        if !self.is_element() {
+            return;
+        }
+
+        // 3. Let thisNS be the result of calling [[GetNamespace]] on x.[[Name]] with argument x.[[InScopeNamespaces]]
+        // 4. If (thisNS == ns), return x
+        let this_ns = self.get_namespace(&self.namespaces());
+        if this_ns.uri == namespace.uri {
+            return;
+        }
+
+        let mut this = self.0.write(mc);
+        let E4XNodeKind::Element {
+            attributes,
+            children,
+        } = &this.kind
+        else {
+            unreachable!("Node should be of Element kind");
+        };
+
+        // 5. For each a in x.[[Attributes]]
+        // 5.a. Let aNS be the result of calling [[GetNamespace]] on a.[[Name]] with argument x.[[InScopeNamespaces]]
+        // 5.b. If (aNS == ns), return x
+        for attribute in attributes {
+            if attribute.namespace().map(|ns| ns.uri).unwrap_or_default() == namespace.uri {
+                return;
+            }
+        }
+
+        let children = children.clone();
+
+        // 6. If ns.prefix == undefined
+        // 6.a. If there exists a namespace n ∈ x.[[InScopeNamespaces]], such that n.uri == ns.uri, remove the namespace n from x.[[InScopeNamespaces]]
+        // 7. Else
+        // 7.a. If there exists a namespace n ∈ x.[[InScopeNamespaces]], such that n.uri == ns.uri and n.prefix == ns.prefix, remove the namespace n from x.[[InScopeNamespaces]]
+        this.namespaces.retain(|ns| {
+            ns.uri != namespace.uri || (namespace.prefix.is_some() && ns.prefix != namespace.prefix)
+        });
+        drop(this);
+
+        // 8. For each property p of x
+        // 8.a. If p.[[Class]] = "element", call the removeNamespace method of p with argument ns
+        for child in children {
+            child.remove_namespace(mc, namespace);
+        }
+    }
+
+    // ECMA-357 13.4.4.26 XML.prototype.normalize ( )
+    pub fn normalize(&self, mc: &Mutation<'gc>) {
+        let E4XNodeKind::Element { children, .. } = &mut *self.kind_mut(mc) else {
+            return;
+        };
+
+        // 1. Let i = 0
+        let mut index = 0;
+
+        // 2. While i < x.[[Length]]
+        while index < children.len() {
+            let child = children[index];
+
+            // 2.a. If x[i].[[Class]] == "element"
+            if child.is_element() {
+                // 2.a.i. Call the normalize method of x[i]
+                child.normalize(mc);
+                // 2.a.ii. Let i = i + 1
+                index += 1;
+            // 2.b. Else if x[i].[[Class]] == "text"
+            } else if child.is_text() {
+                // 2.b.i. While ((i+1) < x.[[Length]]) and (x[i + 1].[[Class]] == "text")
+                while index + 1 < children.len() && children[index + 1].is_text() {
+                    // 2.b.i.1. Let x[i].[[Value]] be the result of concatenating x[i].[[Value]] and x[i + 1].[[Value]]
+                    let next = children.remove(index + 1);
+                    next.set_parent(None, mc);
+
+                    let next_text = match &*next.kind() {
+                        E4XNodeKind::Text(text) | E4XNodeKind::CData(text) => *text,
+                        _ => unreachable!("Node should be text"),
+                    };
+                    if let E4XNodeKind::Text(text) | E4XNodeKind::CData(text) =
+                        &mut *child.kind_mut(mc)
+                    {
+                        *text = AvmString::concat(mc, *text, next_text);
+                    }
+                    // 2.b.i.2. Call the [[DeleteByIndex]] method of x with argument ToString(i + 1)
+                }
+
+                let is_empty = match &*child.kind() {
+                    E4XNodeKind::Text(text) | E4XNodeKind::CData(text) => text.is_empty(),
+                    _ => false,
+                };
+
+                // 2.b.ii. If x[i].[[Value]].length == 0
+                if is_empty {
+                    // 2.b.ii.1. Call the [[DeleteByIndex]] method of x with argument ToString(i)
+                    children.remove(index);
+                    child.set_parent(None, mc);
+                // 2.b.iii. Else
+                } else {
+                    // 2.b.iii.1. Let i = i + 1
+                    index += 1;
+                }
+            // 2.c. Else
+            } else {
+                // 2.c.i. Let i = i + 1
+                index += 1;
+            }
+        }
+    }
+
     pub fn set_local_name(&self, name: AvmString<'gc>, mc: &Mutation<'gc>) {
         self.0.write(mc).local_name = Some(name);
     }
@@ -967,6 +1287,35 @@ impl<'gc> E4XNode<'gc> {
         self.0.read().notification
     }
 
+    /// Calls the notification function of this node and of each of its ancestors,
+    /// like avmplus does whenever an XML tree is modified.
+    ///
+    /// Each function is called with `(currentTarget, type, target, value, detail)`,
+    /// where `target` is this node and `currentTarget` is the node the function was set on.
+    pub fn notify(
+        &self,
+        activation: &mut Activation<'_, 'gc>,
+        kind: &'static str,
+        value: Value<'gc>,
+        detail: Value<'gc>,
+    ) -> Result<(), Error<'gc>> {
+        let mut target = None;
+        for node in self.ancestors() {
+            let Some(notification) = node.notification() else {
+                continue;
+            };
+
+            let target = *target.get_or_insert_with(|| XmlObject::new(*self, activation).into());
+            let current_target = XmlObject::new(node, activation).into();
+            notification.call(
+                Value::Null,
+                &[current_target, kind.into(), target, value, detail],
+                activation,
+            )?;
+        }
+        Ok(())
+    }
+
     // FIXME - avmplus constructs an actual QName here, and does the normal
     // Multiname matching logic. We should do the same.
     pub fn matches_name(&self, name: &Multiname<'gc>) -> bool {
@@ -988,7 +1337,7 @@ impl<'gc> E4XNode<'gc> {
             return true;
         }
 
-        let self_ns = self.namespace().unwrap_or_default();
+        let self_ns = self.namespace().map(|ns| ns.uri).unwrap_or_default();
 
         name.namespace_set()
             .iter()
@@ -1138,9 +1487,102 @@ pub fn escape_element_value(s: AvmString) -> WString {
     r
 }
 
-fn to_xml_string_inner(xml: E4XOrXml, buf: &mut WString, pretty: Option<(u32, u32)>) {
-    // FIXME: Namespace support.
+/// Finds the prefix to serialize a name in the given namespace with, declaring a
+/// new namespace in `declarations` if no suitable one is in scope.
+///
+/// `declarations` are the namespaces declared on the element being serialized,
+/// and `ancestors` are the namespaces declared by the elements enclosing it.
+fn namespace_prefix<'gc>(
+    mc: &Mutation<'gc>,
+    namespace: Option<E4XNamespace<'gc>>,
+    is_attribute: bool,
+    ancestors: &[E4XNamespace<'gc>],
+    declarations: &mut Vec<E4XNamespace<'gc>>,
+) -> AvmString<'gc> {
+    // Returns the URI that a prefix is currently bound to.
+    fn lookup<'gc>(
+        prefix: AvmString<'gc>,
+        ancestors: &[E4XNamespace<'gc>],
+        declarations: &[E4XNamespace<'gc>],
+    ) -> Option<AvmString<'gc>> {
+        declarations
+            .iter()
+            .chain(ancestors)
+            .find(|ns| ns.prefix == Some(prefix))
+            .map(|ns| ns.uri)
+    }
+
+    let uri = namespace.map(|ns| ns.uri).unwrap_or_default();
+
+    if uri.is_empty() {
+        // Unprefixed attributes never belong to a namespace, but unprefixed elements
+        // belong to the default namespace, which may have to be reset.
+        let default_uri = lookup(AvmString::default(), ancestors, declarations);
+        if !is_attribute && default_uri.is_some_and(|uri| !uri.is_empty()) {
+            declarations.push(E4XNamespace::default_namespace());
+        }
+        return AvmString::default();
+    }
+
+    let wanted_prefix = namespace.and_then(|ns| ns.prefix);
+    let usable = |prefix: AvmString<'gc>| !is_attribute || !prefix.is_empty();
+
+    // Reuse a namespace already in scope, preferring one with the same prefix.
+    let in_scope = declarations
+        .iter()
+        .chain(ancestors)
+        .filter_map(|ns| ns.prefix.filter(|_| ns.uri == uri))
+        .filter(|prefix| usable(*prefix) && lookup(*prefix, ancestors, declarations) == Some(uri))
+        .min_by_key(|prefix| Some(*prefix) != wanted_prefix);
+    if let Some(prefix) = in_scope {
+        return prefix;
+    }
+
+    let is_free = |prefix: AvmString<'gc>, declarations: &[E4XNamespace<'gc>]| {
+        usable(prefix) && !declarations.iter().any(|ns| ns.prefix == Some(prefix))
+    };
+
+    let prefix = match wanted_prefix {
+        Some(prefix) if is_free(prefix, declarations) => prefix,
+        None if is_free(AvmString::default(), declarations) => AvmString::default(),
+        _ => {
+            let mut index = 0;
+            loop {
+                let prefix = AvmString::new_utf8(mc, format!("ns{index}"));
+                if is_free(prefix, declarations) && lookup(prefix, ancestors, &[]).is_none() {
+                    break prefix;
+                }
+                index += 1;
+            }
+        }
+    };
+
+    declarations.push(E4XNamespace {
+        prefix: Some(prefix),
+        uri,
+    });
+    prefix
+}
+
+fn push_qualified_name(buf: &mut WString, prefix: AvmString, local_name: AvmString) {
+    if !prefix.is_empty() {
+        buf.push_str(&prefix);
+        buf.push_char(':');
+    }
+    buf.push_str(&local_name);
+}
 
+/// Serializes a node, as described by `ToXMLString` in ECMA-357 (10.2.1).
+///
+/// `ancestors` holds the namespaces declared by the enclosing elements that are
+/// being serialized too, or `None` if this node is the first one serialized.
+fn to_xml_string_inner<'gc>(
+    mc: &Mutation<'gc>,
+    xml: E4XOrXml<'gc>,
+    buf: &mut WString,
+    pretty: Option<(u32, u32)>,
+    ancestors: Option<&[E4XNamespace<'gc>]>,
+) {
     let node = xml.node();
     let node_kind = node.kind();
 
@@ -1186,13 +1628,65 @@ fn to_xml_string_inner(xml: E4XOrXml, buf: &mut WString, pretty: Option<(u32, u3
         } => (children, attributes),
     };
 
+    // NOTE: Like avmplus, the node being serialized declares every namespace in
+    // scope for it, even those that were declared by its ancestors.
+    let (ancestors, mut declarations) = match ancestors {
+        Some(ancestors) => {
+            let declarations = node
+                .namespaces()
+                .iter()
+                .filter(|ns| {
+                    !ancestors
+                        .iter()
+                        .any(|other| other.prefix == ns.prefix && other.uri == ns.uri)
+                })
+                .copied()
+                .collect();
+            (ancestors, declarations)
+        }
+        None => {
+            let mut declarations = node.in_scope_namespaces();
+            // The empty default namespace doesn't need to be declared at the root.
+            declarations.retain(|ns| ns.prefix != Some(AvmString::default()) || !ns.uri.is_empty());
+            (&[][..], declarations)
+        }
+    };
+
+    let prefix = namespace_prefix(mc, node.namespace(), false, ancestors, &mut declarations);
+    let attribute_prefixes: Vec<_> = attributes
+        .iter()
+        .map(|attribute| {
+            namespace_prefix(
+                mc,
+                attribute.namespace(),
+                true,
+                ancestors,
+                &mut declarations,
+            )
+        })
+        .collect();
+
     buf.push_char('<');
-    buf.push_str(&node.local_name().unwrap());
+    push_qualified_name(buf, prefix, node.local_name().unwrap());
+
+    for namespace in &declarations {
+        let Some(ns_prefix) = namespace.prefix else {
+            continue;
+        };
+        buf.push_utf8(" xmlns");
+        if !ns_prefix.is_empty() {
+            buf.push_char(':');
+            buf.push_str(&ns_prefix);
+        }
+        buf.push_utf8("=\"");
+        buf.push_str(&escape_attribute_value(namespace.uri));
+        buf.push_char('"');
+    }
 
-    for attribute in attributes {
+    for (attribute, attribute_prefix) in attributes.iter().zip(attribute_prefixes) {
         if let E4XNodeKind::Attribute(value) = &*attribute.kind() {
             buf.push_char(' ');
-            buf.push_str(&attribute.local_name().unwrap());
+            push_qualified_name(buf, attribute_prefix, attribute.local_name().unwrap());
             buf.push_char('=');
             buf.push_char('"');
             buf.push_str(&escape_attribute_value(*value));
@@ -1218,11 +1712,20 @@ fn to_xml_string_inner(xml: E4XOrXml, buf: &mut WString, pretty: Option<(u32, u3
         None
     };
 
+    let mut child_ancestors = declarations;
+    child_ancestors.extend_from_slice(ancestors);
+
     for child in children {
         if pretty.is_some() && indent_children {
             buf.push_char('\n');
         }
-        to_xml_string_inner(E4XOrXml::E4X(*child), buf, child_pretty);
+        to_xml_string_inner(
+            mc,
+            E4XOrXml::E4X(*child),
+            buf,
+            child_pretty,
+            Some(&child_ancestors),
+        );
     }
 
     if let Some((indent_level, _)) = pretty {
@@ -1235,7 +1738,7 @@ fn to_xml_string_inner(xml: E4XOrXml, buf: &mut WString, pretty: Option<(u32, u3
     }
 
     buf.push_utf8("</");
-    buf.push_str(&node.local_name().unwrap());
+    push_qualified_name(buf, prefix, node.local_name().unwrap());
     buf.push_char('>');
 }
 
@@ -1272,7 +1775,7 @@ pub fn to_xml_string<'gc>(
     };
 
     let mut buf = WString::new();
-    to_xml_string_inner(xml, &mut buf, pretty);
+    to_xml_string_inner(activation.gc(), xml, &mut buf, pretty, None);
     AvmString::new(activation.context.gc_context, buf)
 }
 
@@ -268,6 +268,12 @@ impl<'gc> DispatchList<'gc> {
         }
     }
 
+    /// Remove every event handler for a given event type from this dispatch
+    /// list, regardless of priority or capture-phase flag.
+    pub fn remove_all_event_listeners(&mut self, event: impl Into<AvmString<'gc>>) {
+        self.0.remove(&event.into());
+    }
+
     /// Determine if there are any event listeners in this dispatch list.
     pub fn has_event_listener(&self, event: impl Into<AvmString<'gc>>) -> bool {
         if let Some(event_sheaf) = self.get_event(event) {
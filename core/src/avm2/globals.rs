@@ -120,6 +120,8 @@ pub struct SystemClasses<'gc> {
     pub transform: ClassObject<'gc>,
     pub colortransform: ClassObject<'gc>,
     pub matrix: ClassObject<'gc>,
+    pub matrix3d: ClassObject<'gc>,
+    pub perspectiveprojection: ClassObject<'gc>,
     pub illegaloperationerror: ClassObject<'gc>,
     pub eventdispatcher: ClassObject<'gc>,
     pub rectangle: ClassObject<'gc>,
@@ -140,6 +142,7 @@ pub struct SystemClasses<'gc> {
     pub uncaughterrorevents: ClassObject<'gc>,
     pub statictext: ClassObject<'gc>,
     pub textlinemetrics: ClassObject<'gc>,
+    pub textsnapshot: ClassObject<'gc>,
     pub stage3d: ClassObject<'gc>,
     pub context3d: ClassObject<'gc>,
     pub indexbuffer3d: ClassObject<'gc>,
@@ -159,6 +162,7 @@ pub struct SystemClasses<'gc> {
     pub texture: ClassObject<'gc>,
     pub cubetexture: ClassObject<'gc>,
     pub rectangletexture: ClassObject<'gc>,
+    pub videotexture: ClassObject<'gc>,
     pub morphshape: ClassObject<'gc>,
     pub shader: ClassObject<'gc>,
     pub shaderinput: ClassObject<'gc>,
@@ -170,6 +174,10 @@ pub struct SystemClasses<'gc> {
     pub font: ClassObject<'gc>,
     pub textline: ClassObject<'gc>,
     pub sampledataevent: ClassObject<'gc>,
+    pub shaderevent: ClassObject<'gc>,
+    pub newobjectsample: ClassObject<'gc>,
+    pub deleteobjectsample: ClassObject<'gc>,
+    pub stackframe: ClassObject<'gc>,
     pub avm1movie: ClassObject<'gc>,
 }
 
@@ -249,6 +257,8 @@ impl<'gc> SystemClasses<'gc> {
             transform: object,
             colortransform: object,
             matrix: object,
+            matrix3d: object,
+            perspectiveprojection: object,
             illegaloperationerror: object,
             eventdispatcher: object,
             rectangle: object,
@@ -269,6 +279,7 @@ impl<'gc> SystemClasses<'gc> {
             uncaughterrorevents: object,
             statictext: object,
             textlinemetrics: object,
+            textsnapshot: object,
             stage3d: object,
             context3d: object,
             indexbuffer3d: object,
@@ -288,6 +299,7 @@ impl<'gc> SystemClasses<'gc> {
             texture: object,
             cubetexture: object,
             rectangletexture: object,
+            videotexture: object,
             morphshape: object,
             shader: object,
             shaderinput: object,
@@ -299,6 +311,10 @@ impl<'gc> SystemClasses<'gc> {
             font: object,
             textline: object,
             sampledataevent: object,
+            shaderevent: object,
+            newobjectsample: object,
+            deleteobjectsample: object,
+            stackframe: object,
             avm1movie: object,
         }
     }
@@ -751,6 +767,7 @@ fn load_playerglobal<'gc>(
                 graphicstrianglepath
             ),
             ("flash.display", "GraphicsSolidFill", graphicssolidfill),
+            ("flash.display", "GraphicsShaderFill", graphicsshaderfill),
             ("flash.display", "GraphicsStroke", graphicsstroke),
             ("flash.display", "Graphics", graphics),
             ("flash.display", "Loader", loader),
@@ -774,6 +791,7 @@ fn load_playerglobal<'gc>(
                 "RectangleTexture",
                 rectangletexture
             ),
+            ("flash.display3D.textures", "VideoTexture", videotexture),
             ("flash.display3D", "VertexBuffer3D", vertexbuffer3d),
             (
                 "flash.errors",
@@ -798,6 +816,8 @@ fn load_playerglobal<'gc>(
             ("flash.events", "StatusEvent", statusevent),
             ("flash.events", "ContextMenuEvent", contextmenuevent),
             ("flash.geom", "Matrix", matrix),
+            ("flash.geom", "Matrix3D", matrix3d),
+            ("flash.geom", "PerspectiveProjection", perspectiveprojection),
             ("flash.geom", "Point", point),
             ("flash.geom", "Rectangle", rectangle),
             ("flash.geom", "Transform", transform),
@@ -813,6 +833,7 @@ fn load_playerglobal<'gc>(
             ("flash.text", "TextFormat", textformat),
             ("flash.text", "TextField", textfield),
             ("flash.text", "TextLineMetrics", textlinemetrics),
+            ("flash.text", "TextSnapshot", textsnapshot),
             ("flash.text.engine", "TextLine", textline),
             ("flash.filters", "BevelFilter", bevelfilter),
             ("flash.filters", "BitmapFilter", bitmapfilter),
@@ -830,6 +851,10 @@ fn load_playerglobal<'gc>(
             ("flash.filters", "GradientGlowFilter", gradientglowfilter),
             ("flash.filters", "ShaderFilter", shaderfilter),
             ("flash.events", "SampleDataEvent", sampledataevent),
+            ("flash.events", "ShaderEvent", shaderevent),
+            ("flash.sampler", "NewObjectSample", newobjectsample),
+            ("flash.sampler", "DeleteObjectSample", deleteobjectsample),
+            ("flash.sampler", "StackFrame", stackframe),
         ]
     );
 
@@ -2,13 +2,6 @@ package {
     [Ruffle(InstanceAllocator)]
     [Ruffle(CallHandler)]
     public final dynamic class XML {
-        import __ruffle__.stub_method;
-
-        AS3 function normalize(): XML {
-            stub_method("XML", "normalize");
-            return this;
-        }
-
         AS3 static function setSettings(settings:Object = null): void {
             if (settings == null) {
                 settings = XML.AS3::defaultSettings();
@@ -88,6 +81,12 @@ package {
         // NOTE: Docs lie, value can be anything not just XML.
         AS3 native function replace(propertyName:Object, value:*):XML;
         AS3 native function setChildren(value:Object):XML;
+        AS3 native function normalize():XML;
+        AS3 native function inScopeNamespaces():Array;
+        AS3 native function namespaceDeclarations():Array;
+        AS3 native function addNamespace(ns:*):XML;
+        AS3 native function removeNamespace(ns:*):XML;
+        AS3 native function setNamespace(ns:*):void;
 
         AS3 function valueOf():XML {
             return this;
@@ -131,6 +130,31 @@ package {
             return self.AS3::namespace.apply(self, arguments);
         }
 
+        prototype.inScopeNamespaces = function():Array {
+            var self:XML = this;
+            return self.AS3::inScopeNamespaces();
+        }
+
+        prototype.namespaceDeclarations = function():Array {
+            var self:XML = this;
+            return self.AS3::namespaceDeclarations();
+        }
+
+        prototype.addNamespace = function(ns:*):XML {
+            var self:XML = this;
+            return self.AS3::addNamespace(ns);
+        }
+
+        prototype.removeNamespace = function(ns:*):XML {
+            var self:XML = this;
+            return self.AS3::removeNamespace(ns);
+        }
+
+        prototype.setNamespace = function(ns:*):void {
+            var self:XML = this;
+            self.AS3::setNamespace(ns);
+        }
+
         prototype.localName = function():Object {
             var self:XML = this;
             return self.AS3::localName();
@@ -7,8 +7,11 @@ pub mod display3D;
 pub mod events;
 pub mod external;
 pub mod geom;
+pub mod globalization;
 pub mod media;
 pub mod net;
+pub mod printing;
+pub mod sampler;
 pub mod system;
 pub mod text;
 pub mod ui;
@@ -1,7 +1,59 @@
 package flash.concurrent {
+    import flash.system.Worker;
+
     public final class Condition {
-        public static const isSupported: Boolean = false;
+        public static const isSupported: Boolean = true;
+
+        private var _mutex:Mutex;
+
+        // One object per waiting worker, in the order they started waiting.
+        private var _waiters:Array = [];
+
+        public function Condition(mutex: Mutex) {
+            if (mutex == null) {
+                throw new TypeError("Error #2007: Parameter mutex must be non-null.", 2007);
+            }
+            this._mutex = mutex;
+        }
+
+        public function get mutex():Mutex {
+            return this._mutex;
+        }
+
+        public function wait(timeout:Number = -1):Boolean {
+            this._mutex.checkOwner();
+
+            var waiter:Object = {notified: false};
+            this._waiters.push(waiter);
+            var lockCount:int = this._mutex.release();
+
+            var notified:Boolean = Worker.ruffle::park(function():Boolean { return waiter.notified; }, timeout);
+            if (!notified) {
+                this._waiters.splice(this._waiters.indexOf(waiter), 1);
+            }
+
+            this._mutex.reacquire(lockCount);
+            if (!notified && timeout < 0) {
+                // Flash Player would wait forever, as there's no worker left
+                // that could notify us.
+                throw new Error("There is no worker left that could notify this condition.");
+            }
+            return notified;
+        }
+
+        public function notify():void {
+            this._mutex.checkOwner();
+            if (this._waiters.length > 0) {
+                this._waiters.shift().notified = true;
+            }
+        }
 
-        public function Condition(mutex: Mutex) {}
+        public function notifyAll():void {
+            this._mutex.checkOwner();
+            for each (var waiter:Object in this._waiters) {
+                waiter.notified = true;
+            }
+            this._waiters = [];
+        }
     }
-}
\ No newline at end of file
+}
@@ -1,9 +1,84 @@
 package flash.concurrent {
+    import flash.errors.IllegalOperationError;
+    import flash.system.Worker;
+
+    namespace ruffle = "__ruffle__";
+
     public final class Mutex {
-        public static const isSupported: Boolean = false;
-        
-        public function Mutex() {
-            throw new Error("Error #1520: Mutex cannot be initialized.", 1520);
+        public static const isSupported: Boolean = true;
+
+        private var _owner:Worker;
+        private var _lockCount:int = 0;
+
+        public function Mutex() {}
+
+        public function lock():void {
+            var self:Mutex = this;
+            var owner:Worker = Worker.current;
+            if (this.isHeldByOther(owner)
+                    && !Worker.ruffle::park(function():Boolean { return !self.isHeldByOther(owner); }, -1)) {
+                // Flash Player would wait forever here, as the owner can't run
+                // again until we give up.
+                throw deadlockError();
+            }
+            this.acquire(owner, 1);
+        }
+
+        public function tryLock():Boolean {
+            var owner:Worker = Worker.current;
+            if (this.isHeldByOther(owner)) {
+                return false;
+            }
+            this.acquire(owner, 1);
+            return true;
+        }
+
+        public function unlock():void {
+            this.checkOwner();
+            this._lockCount--;
+            if (this._lockCount == 0) {
+                this._owner = null;
+            }
+        }
+
+        internal function checkOwner():void {
+            if (this._owner == null || this._owner != Worker.current) {
+                throw new IllegalOperationError("The current worker does not own this mutex.");
+            }
+        }
+
+        // Fully releases the mutex, returning how many times it had been locked.
+        internal function release():int {
+            var count:int = this._lockCount;
+            this._lockCount = 0;
+            this._owner = null;
+            return count;
+        }
+
+        // Takes the mutex back after `release`, waiting for whoever holds it now.
+        internal function reacquire(count:int):void {
+            var self:Mutex = this;
+            var owner:Worker = Worker.current;
+            if (this.isHeldByOther(owner)
+                    && !Worker.ruffle::park(function():Boolean { return !self.isHeldByOther(owner); }, -1)) {
+                // As in `lock`, Flash Player would wait forever here. Taking the
+                // mutex anyway would let two workers hold it at once.
+                throw deadlockError();
+            }
+            this.acquire(owner, count);
+        }
+
+        private static function deadlockError():Error {
+            return new Error("The worker that owns this mutex can't unlock it until the current worker continues.");
+        }
+
+        private function acquire(owner:Worker, count:int):void {
+            this._owner = owner;
+            this._lockCount += count;
+        }
+
+        private function isHeldByOther(worker:Worker):Boolean {
+            return this._owner != null && this._owner != worker;
         }
     }
-}
\ No newline at end of file
+}
@@ -4,9 +4,7 @@ package flash.display {
     import flash.geom.Point;
     import flash.geom.Matrix;
     import flash.filters.BitmapFilter;
-    import flash.filters.ShaderFilter;
     import flash.utils.ByteArray;
-    import __ruffle__.stub_method;
 
     [Ruffle(InstanceAllocator)]
     public class BitmapData implements IBitmapDrawable {
@@ -24,6 +22,7 @@ package flash.display {
         public native function getPixels(rect:Rectangle):ByteArray;
         public native function copyPixelsToByteArray(rect:Rectangle, data:ByteArray):void;
         public native function getVector(rect:Rectangle):Vector.<uint>;
+        public native function histogram(hRect:Rectangle = null):Vector.<Vector.<Number>>;
         public native function getPixel(x:int, y:int):uint;
         public native function getPixel32(x:int, y:int):uint;
         public native function setPixel(x:int, y:int, color:uint):void;
@@ -69,13 +68,6 @@ package flash.display {
         public native function merge(
             sourceBitmapData:BitmapData, sourceRect:Rectangle, destPoint:Point, redMultiplier:uint, greenMultiplier:uint, blueMultiplier:uint, alphaMultiplier:uint
         ):void 
-        public function generateFilterRect(sourceRect:Rectangle, filter:BitmapFilter):Rectangle {
-            // Flash always reports that a ShaderFilter affects the entire BitampData, ignoring SourceRect.
-            if (filter is ShaderFilter) {
-                return this.rect.clone();
-            }
-            stub_method("flash.display.BitmapData", "generateFilterRect");
-            return sourceRect.clone();
-        }
+        public native function generateFilterRect(sourceRect:Rectangle, filter:BitmapFilter):Rectangle;
     }
 }
@@ -7,6 +7,7 @@ package flash.display {
     import flash.media.SoundTransform;
     import flash.display.DisplayObject;
     import flash.display.InteractiveObject;
+    import flash.text.TextSnapshot;
 
     [Ruffle(NativeInstanceInit)]
     public class DisplayObjectContainer extends InteractiveObject {
@@ -20,6 +21,7 @@ package flash.display {
         public native function set mouseChildren(value:Boolean):void;
         public native function get tabChildren():Boolean;
         public native function set tabChildren(value:Boolean):void;
+        public native function get textSnapshot():TextSnapshot;
 
         public native function addChild(child:DisplayObject):DisplayObject;
         public native function addChildAt(child:DisplayObject, index:int):DisplayObject;
@@ -1,39 +1,44 @@
 package flash.display {
-    import __ruffle__.stub_method;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_setter;
-    import __ruffle__.stub_constructor;
     import flash.events.EventDispatcher;
 
     public class ShaderJob extends EventDispatcher {
 
         private var _shader:Shader;
         private var _target:Object;
-        
+        internal var _width:int;
+        internal var _height:int;
+
+        internal var _progress:Number = 0;
+
         public function ShaderJob(shader:Shader = null, target:Object = null, width:int = 0, height:int = 0) {
             this._shader = shader;
             this._target = target;
-            stub_constructor("flash.display.ShaderJob");
+            this._width = width;
+            this._height = height;
         }
 
-        public function cancel():void {
-            stub_method("flash.display.ShaderJob", "cancel")
-        }
+        public native function cancel():void;
 
         public native function start(waitForCompletion:Boolean = false):void;
 
+        public function get width():int {
+            return this._width;
+        }
+
+        public function set width(value:int):void {
+            this._width = value;
+        }
+
         public function get height():int {
-            stub_getter("flash.display.ShaderJob", "height");
-            return 0;
+            return this._height;
         }
 
         public function set height(value:int):void {
-            stub_setter("flash.display.ShaderJob", "height");
+            this._height = value;
         }
 
         public function get progress():Number {
-            stub_getter("flash.display.ShaderJob", "progress")
-            return 0;
+            return this._progress;
         }
 
         public function get shader():Shader {
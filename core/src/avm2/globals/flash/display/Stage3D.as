@@ -1,32 +1,47 @@
 package flash.display {
     import flash.events.ErrorEvent;
+    import flash.events.Event;
     import flash.events.EventDispatcher;
     import flash.display3D.Context3D;
     import flash.utils.setTimeout;
-    import __ruffle__.stub_method;
 
     [Ruffle(InstanceAllocator)]
     public class Stage3D extends EventDispatcher {
 
         public native function get context3D():Context3D;
-        private native function requestContext3D_internal(context3DRenderMode:String, profile:String):void;
+        private native function requestContext3D_internal(context3DRenderMode:String, profiles:Vector.<String>):Boolean;
 
         public function requestContext3D(context3DRenderMode:String = "auto", profile:String = "baseline"):void {
+            var profiles:Vector.<String> = new Vector.<String>();
+            profiles.push(profile);
+            requestContext3DWithProfiles(context3DRenderMode, profiles);
+        }
+
+        public function requestContext3DMatchingProfiles(profiles:Vector.<String>):void {
+            requestContext3DWithProfiles("auto", profiles);
+        }
+
+        private function requestContext3DWithProfiles(context3DRenderMode:String, profiles:Vector.<String>):void {
+            if (this.context3D != null) {
+                return;
+            }
+
+            var created:Boolean = requestContext3D_internal(context3DRenderMode, profiles);
+
             // Several SWFS (the examples from the Context3D documentation, and the Starling framework)
             // rely on the `context3DCreate` being fired asynchronously - they initialize variables
             // after the call to `requestContext3D`, and then use those variables in the event handler.
             // Currently, we create a `Context3D` synchronously, so we need to delay the event dispatch
             var stage3d = this;
             setTimeout(function() {
-                stage3d.requestContext3D_internal(context3DRenderMode, profile);
+                if (created) {
+                    stage3d.dispatchEvent(new Event(Event.CONTEXT3D_CREATE));
+                } else {
+                    stage3d.dispatchEvent(new ErrorEvent(ErrorEvent.ERROR, false, false, "Error #3702: Context3D not available.", 3702));
+                }
             }, 0);
         }
 
-        public function requestContext3DMatchingProfiles(profiles:Vector.<String>):void {
-            stub_method("flash.display.Stage3D", "requestContext3DMatchingProfiles");
-            requestContext3D();
-        }
-
         // FIXME - actually implement this
         public var x:Number;
         public var y:Number;
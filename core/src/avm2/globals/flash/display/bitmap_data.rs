@@ -365,6 +365,44 @@ pub fn get_vector<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `BitmapData.histogram`.
+pub fn histogram<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(bitmap_data) = this.as_bitmap_data() {
+        bitmap_data.check_valid(activation)?;
+        let (x, y, width, height) = match args.try_get_object(activation, 0) {
+            Some(rectangle) => get_rectangle_x_y_width_height(activation, rectangle)?,
+            None => (
+                0,
+                0,
+                bitmap_data.width() as i32,
+                bitmap_data.height() as i32,
+            ),
+        };
+
+        let channels = operations::histogram(bitmap_data, x, y, width, height);
+
+        let number_class = activation.avm2().classes().number;
+        let generic_vector = activation.avm2().classes().generic_vector;
+        let vector_number_class = generic_vector.apply(activation, &[number_class.into()])?;
+
+        let mut vectors = Vec::with_capacity(channels.len());
+        for channel in channels {
+            let values = channel.into_iter().map(Value::from).collect();
+            let storage = VectorStorage::from_values(values, false, Some(number_class));
+            vectors.push(VectorObject::from_vector(storage, activation)?.into());
+        }
+
+        let storage = VectorStorage::from_values(vectors, false, Some(vector_number_class));
+        return Ok(VectorObject::from_vector(storage, activation)?.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Implements `BitmapData.getPixel`.
 pub fn get_pixel<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -1154,6 +1192,50 @@ pub fn apply_filter<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implement `BitmapData.generateFilterRect`
+pub fn generate_filter_rect<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(bitmap_data) = this.as_bitmap_data() {
+        bitmap_data.check_valid(activation)?;
+        let source_rect = args.get_object(activation, 0, "sourceRect")?;
+        let source_rect = super::display_object::object_to_rectangle(activation, source_rect)?;
+        let filter = args.get_object(activation, 1, "filter")?;
+        let filter = Filter::from_avm2_object(activation, filter)?;
+
+        // Flash always reports that a ShaderFilter affects the entire BitmapData, ignoring sourceRect.
+        let (x, y, width, height) = if matches!(filter, Filter::ShaderFilter(_)) {
+            (
+                0.0,
+                0.0,
+                bitmap_data.width() as f64,
+                bitmap_data.height() as f64,
+            )
+        } else {
+            let dest_rect = filter.calculate_generated_rect(source_rect);
+            (
+                dest_rect.x_min.to_pixels(),
+                dest_rect.y_min.to_pixels(),
+                dest_rect.width().to_pixels(),
+                dest_rect.height().to_pixels(),
+            )
+        };
+
+        return Ok(activation
+            .avm2()
+            .classes()
+            .rectangle
+            .construct(
+                activation,
+                &[x.into(), y.into(), width.into(), height.into()],
+            )?
+            .into());
+    }
+    Ok(Value::Undefined)
+}
+
 /// Implement `BitmapData.clone`
 pub fn clone<'gc>(
     activation: &mut Activation<'_, 'gc>,
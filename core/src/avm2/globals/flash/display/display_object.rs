@@ -9,7 +9,7 @@ use crate::avm2::value::Value;
 use crate::avm2::StageObject;
 use crate::avm2::{ArrayObject, ArrayStorage};
 use crate::avm2::{ClassObject, Error};
-use crate::display_object::{DisplayObject, HitTestOptions, TDisplayObject};
+use crate::display_object::{DisplayObject, HitTestOptions, TDisplayObject, Transform3D};
 use crate::ecma_conversions::round_to_even;
 use crate::prelude::*;
 use crate::string::AvmString;
@@ -387,93 +387,148 @@ pub fn set_y<'gc>(
     Ok(Value::Undefined)
 }
 
-pub fn get_z<'gc>(
+/// Modifies the 3D parts of the transform of a display object, making it 3D if it wasn't already.
+fn update_transform_3d<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    this: Object<'gc>,
+    update: impl FnOnce(&mut Transform3D),
+) {
+    if let Some(dobj) = this.as_display_object() {
+        let mut transform_3d = dobj.transform_3d().unwrap_or_default();
+        update(&mut transform_3d);
+        dobj.set_transform_3d(activation.context.gc_context, Some(transform_3d));
+    }
+}
+
+/// Implements `z`'s getter.
+pub fn get_z<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "z");
-    Ok(0.into())
+    if let Some(dobj) = this.as_display_object() {
+        let transform_3d = dobj.transform_3d().unwrap_or_default();
+        return Ok(transform_3d.z.into());
+    }
+
+    Ok(Value::Undefined)
 }
 
+/// Implements `z`'s setter.
 pub fn set_z<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "z");
+    let z = args.get_f64(activation, 0)?;
+    update_transform_3d(activation, this, |transform_3d| transform_3d.z = z);
+
     Ok(Value::Undefined)
 }
 
+/// Implements `rotationX`'s getter.
 pub fn get_rotation_x<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "rotationX");
-    Ok(0.into())
+    if let Some(dobj) = this.as_display_object() {
+        let transform_3d = dobj.transform_3d().unwrap_or_default();
+        return Ok(f64::from(transform_3d.rotation_x).into());
+    }
+
+    Ok(Value::Undefined)
 }
 
+/// Implements `rotationX`'s setter.
 pub fn set_rotation_x<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "rotationX");
+    let rotation = Degrees::from(args.get_f64(activation, 0)?);
+    update_transform_3d(activation, this, |transform_3d| {
+        transform_3d.rotation_x = rotation
+    });
+
     Ok(Value::Undefined)
 }
 
+/// Implements `rotationY`'s getter.
 pub fn get_rotation_y<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "rotationY");
-    Ok(0.into())
+    if let Some(dobj) = this.as_display_object() {
+        let transform_3d = dobj.transform_3d().unwrap_or_default();
+        return Ok(f64::from(transform_3d.rotation_y).into());
+    }
+
+    Ok(Value::Undefined)
 }
 
+/// Implements `rotationY`'s setter.
 pub fn set_rotation_y<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "rotationY");
+    let rotation = Degrees::from(args.get_f64(activation, 0)?);
+    update_transform_3d(activation, this, |transform_3d| {
+        transform_3d.rotation_y = rotation
+    });
+
     Ok(Value::Undefined)
 }
 
+/// Implements `rotationZ`'s getter.
 pub fn get_rotation_z<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "rotationZ");
-    Ok(0.into())
+    // The Z rotation is the same as the 2D rotation.
+    get_rotation(activation, this, args)
 }
 
+/// Implements `rotationZ`'s setter.
 pub fn set_rotation_z<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "rotationZ");
+    set_rotation(activation, this, args)?;
+    update_transform_3d(activation, this, |_| {});
+
     Ok(Value::Undefined)
 }
 
+/// Implements `scaleZ`'s getter.
 pub fn get_scale_z<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "scaleZ");
-    Ok(1.into())
+    if let Some(dobj) = this.as_display_object() {
+        let transform_3d = dobj.transform_3d().unwrap_or_default();
+        return Ok(transform_3d.scale_z.into());
+    }
+
+    Ok(Value::Undefined)
 }
 
+/// Implements `scaleZ`'s setter.
 pub fn set_scale_z<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "scaleZ");
+    let scale_z = args.get_f64(activation, 0)?;
+    update_transform_3d(activation, this, |transform_3d| {
+        transform_3d.scale_z = scale_z
+    });
+
     Ok(Value::Undefined)
 }
 
@@ -662,3 +662,17 @@ pub fn set_tab_children<'gc>(
 
     Ok(Value::Undefined)
 }
+
+/// Implements `DisplayObjectContainer.textSnapshot`
+pub fn get_text_snapshot<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation
+        .avm2()
+        .classes()
+        .textsnapshot
+        .construct(activation, &[this.into()])?
+        .into())
+}
@@ -2,17 +2,19 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::error::make_error_2008;
-use crate::avm2::globals::flash::geom::transform::object_to_matrix;
-use crate::avm2::object::{Object, TObject, VectorObject};
+use crate::avm2::globals::flash::display::shader_job::get_shader_args;
+use crate::avm2::globals::flash::geom::transform::{matrix_to_object, object_to_matrix};
+use crate::avm2::object::{ArrayObject, Object, TObject, VectorObject};
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
 use crate::avm2::vector::VectorStorage;
 use crate::avm2::{ArrayStorage, Error};
 use crate::avm2_stub_method;
-use crate::display_object::TDisplayObject;
+use crate::display_object::{DisplayObject, TDisplayObject, TDisplayObjectContainer};
 use crate::drawing::Drawing;
 use crate::string::{AvmString, WStr};
-use ruffle_render::shape_utils::{DrawCommand, GradientType};
+use ruffle_render::matrix::Matrix as RenderMatrix;
+use ruffle_render::shape_utils::{DrawCommand, DrawPath, FillRule, GradientType};
 use std::f64::consts::FRAC_1_SQRT_2;
 use swf::{
     Color, FillStyle, Fixed16, Fixed8, Gradient, GradientInterpolation, GradientRecord,
@@ -90,6 +92,35 @@ pub fn begin_bitmap_fill<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `Graphics.beginShaderFill`.
+pub fn begin_shader_fill<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this.as_display_object() {
+        let shader = args.get_object(activation, 0, "shader")?;
+        let matrix = if let Some(matrix) = args.try_get_object(activation, 1) {
+            Matrix::from(object_to_matrix(matrix, activation)?)
+        } else {
+            Matrix::IDENTITY
+        };
+
+        let (shader_handle, arguments) = get_shader_args(shader, activation)?;
+        let scale_matrix = Matrix::scale(
+            (Twips::TWIPS_PER_PIXEL as i16).into(),
+            (Twips::TWIPS_PER_PIXEL as i16).into(),
+        );
+
+        if let Some(mut draw) = this.as_drawing(activation.context.gc_context) {
+            let style = draw.add_shader_fill(shader_handle, arguments, matrix * scale_matrix);
+            draw.set_fill_style(Some(style));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Implements `Graphics.beginGradientFill`.
 pub fn begin_gradient_fill<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -666,6 +697,97 @@ fn draw_round_rect_internal(
     });
 }
 
+/// Draw a quarter of a circle with the given center and radius, continuing
+/// from the current point.
+///
+/// `quadrant` is the number of clockwise quarter turns to rotate the
+/// bottom-right quadrant described by `UNIT_CIRCLE_POINTS`.
+fn draw_quarter_circle(
+    draw: &mut Drawing,
+    center_x: f64,
+    center_y: f64,
+    radius: f64,
+    quadrant: u8,
+) {
+    if radius <= 0.0 {
+        return;
+    }
+
+    let point = |(x, y): (f64, f64)| {
+        let (x, y) = match quadrant {
+            0 => (x, y),
+            1 => (-y, x),
+            2 => (-x, -y),
+            _ => (y, -x),
+        };
+        Point::from_pixels(center_x + radius * x, center_y + radius * y)
+    };
+
+    let ucp = UNIT_CIRCLE_POINTS;
+    draw.draw_command(DrawCommand::QuadraticCurveTo {
+        control: point(ucp[1]),
+        anchor: point(ucp[2]),
+    });
+    draw.draw_command(DrawCommand::QuadraticCurveTo {
+        control: point(ucp[3]),
+        anchor: point(ucp[4]),
+    });
+}
+
+/// Draw a roundrect with a separate radius for each corner.
+///
+/// `radii` are given in the order top-left, top-right, bottom-left,
+/// bottom-right. Like `draw_round_rect_internal`, we start from the
+/// bottom-right corner.
+fn draw_round_rect_complex_internal(
+    draw: &mut Drawing,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    radii: [f64; 4],
+) {
+    // No corner can be rounder than half of the rectangle's shortest side.
+    let max_radius = width.min(height) / 2.0;
+    let [top_left, top_right, bottom_left, bottom_right] =
+        radii.map(|radius| radius.clamp(0.0, max_radius.max(0.0)));
+
+    let right = x + width;
+    let bottom = y + height;
+
+    draw.draw_command(DrawCommand::MoveTo(Point::from_pixels(
+        right,
+        bottom - bottom_right,
+    )));
+    draw_quarter_circle(
+        draw,
+        right - bottom_right,
+        bottom - bottom_right,
+        bottom_right,
+        0,
+    );
+
+    draw.draw_command(DrawCommand::LineTo(Point::from_pixels(
+        x + bottom_left,
+        bottom,
+    )));
+    draw_quarter_circle(draw, x + bottom_left, bottom - bottom_left, bottom_left, 1);
+
+    draw.draw_command(DrawCommand::LineTo(Point::from_pixels(x, y + top_left)));
+    draw_quarter_circle(draw, x + top_left, y + top_left, top_left, 2);
+
+    draw.draw_command(DrawCommand::LineTo(Point::from_pixels(
+        right - top_right,
+        y,
+    )));
+    draw_quarter_circle(draw, right - top_right, y + top_right, top_right, 3);
+
+    draw.draw_command(DrawCommand::LineTo(Point::from_pixels(
+        right,
+        bottom - bottom_right,
+    )));
+}
+
 /// Implements `Graphics.drawRoundRect`.
 pub fn draw_round_rect<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -891,10 +1013,31 @@ pub fn draw_path<'gc>(
 /// Implements `Graphics.drawRoundRectComplex`
 pub fn draw_round_rect_complex<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.display.Graphics", "drawRoundRectComplex");
+    if let Some(this) = this.as_display_object() {
+        let x = args.get_f64(activation, 0)?;
+        let y = args.get_f64(activation, 1)?;
+        let width = args.get_f64(activation, 2)?;
+        let height = args.get_f64(activation, 3)?;
+        let top_left = args.get_f64(activation, 4)?;
+        let top_right = args.get_f64(activation, 5)?;
+        let bottom_left = args.get_f64(activation, 6)?;
+        let bottom_right = args.get_f64(activation, 7)?;
+
+        if let Some(mut draw) = this.as_drawing(activation.context.gc_context) {
+            draw_round_rect_complex_internal(
+                &mut draw,
+                x,
+                y,
+                width,
+                height,
+                [top_left, top_right, bottom_left, bottom_right],
+            );
+        }
+    }
+
     Ok(Value::Undefined)
 }
 
@@ -1162,15 +1305,308 @@ pub fn line_bitmap_style<'gc>(
 /// Implements `Graphics.readGraphicsData`
 pub fn read_graphics_data<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.display.Graphics", "readGraphicsData");
+    let recurse = args.get_bool(0);
+    let mut data = Vec::new();
+
+    if let Some(this) = this.as_display_object() {
+        read_graphics_data_internal(activation, this, RenderMatrix::IDENTITY, recurse, &mut data)?;
+    }
+
     let value_type = activation.avm2().classes().igraphicsdata;
-    let new_storage = VectorStorage::new(0, false, Some(value_type), activation);
+    let new_storage = VectorStorage::from_values(data, false, Some(value_type));
     Ok(VectorObject::from_vector(new_storage, activation)?.into())
 }
 
+/// Append the graphics data of `dobj` to `data`, transformed by `matrix`.
+fn read_graphics_data_internal<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    dobj: DisplayObject<'gc>,
+    matrix: RenderMatrix,
+    recurse: bool,
+    data: &mut Vec<Value<'gc>>,
+) -> Result<(), Error<'gc>> {
+    let drawing = if let DisplayObject::Graphic(graphic) = dobj {
+        Some(graphic.current_drawing())
+    } else {
+        dobj.as_drawing(activation.context.gc_context)
+            .map(|drawing| drawing.clone())
+    };
+
+    if let Some(drawing) = drawing {
+        for path in drawing.draw_paths() {
+            match path {
+                DrawPath::Fill {
+                    style,
+                    commands,
+                    winding_rule,
+                } => {
+                    data.push(fill_style_to_object(activation, style, matrix)?);
+                    data.push(commands_to_graphics_path(
+                        activation,
+                        &commands,
+                        winding_rule,
+                        matrix,
+                    )?);
+                    let end_fill = activation
+                        .avm2()
+                        .classes()
+                        .graphicsendfill
+                        .construct(activation, &[])?;
+                    data.push(end_fill.into());
+                }
+                DrawPath::Stroke {
+                    style, commands, ..
+                } => {
+                    data.push(line_style_to_object(activation, style, matrix)?);
+                    data.push(commands_to_graphics_path(
+                        activation,
+                        &commands,
+                        FillRule::EvenOdd,
+                        matrix,
+                    )?);
+                    // A stroke with the default `NaN` thickness ends the line.
+                    let end_stroke = activation
+                        .avm2()
+                        .classes()
+                        .graphicsstroke
+                        .construct(activation, &[])?;
+                    data.push(end_stroke.into());
+                }
+            }
+        }
+    }
+
+    if recurse {
+        if let Some(container) = dobj.as_container() {
+            for child in container.iter_render_list() {
+                let child_matrix = matrix * *child.base().matrix();
+                read_graphics_data_internal(activation, child, child_matrix, recurse, data)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert a fill style into a `GraphicsSolidFill`, `GraphicsGradientFill`
+/// or `GraphicsBitmapFill`.
+fn fill_style_to_object<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    style: &FillStyle,
+    matrix: RenderMatrix,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let (gradient_type, gradient, focal_point) = match style {
+        FillStyle::Color(color) => {
+            let args = [color.to_rgb().into(), (color.a as f64 / 255.0).into()];
+            let fill = activation
+                .avm2()
+                .classes()
+                .graphicssolidfill
+                .construct(activation, &args)?;
+            return Ok(fill.into());
+        }
+        FillStyle::Bitmap {
+            matrix: bitmap_matrix,
+            is_smoothed,
+            is_repeating,
+            ..
+        } => {
+            avm2_stub_method!(
+                activation,
+                "flash.display.Graphics",
+                "readGraphicsData",
+                "with bitmap fills"
+            );
+
+            // Bitmap fill matrices are in twips per bitmap pixel.
+            let scale_matrix = RenderMatrix::scale(
+                1.0 / Twips::TWIPS_PER_PIXEL as f32,
+                1.0 / Twips::TWIPS_PER_PIXEL as f32,
+            );
+            let bitmap_matrix = matrix_to_object(
+                matrix * RenderMatrix::from(*bitmap_matrix) * scale_matrix,
+                activation,
+            )?;
+            let args = [
+                Value::Null,
+                bitmap_matrix,
+                (*is_repeating).into(),
+                (*is_smoothed).into(),
+            ];
+            let fill = activation
+                .avm2()
+                .classes()
+                .graphicsbitmapfill
+                .construct(activation, &args)?;
+            return Ok(fill.into());
+        }
+        FillStyle::LinearGradient(gradient) => ("linear", gradient, 0.0),
+        FillStyle::RadialGradient(gradient) => ("radial", gradient, 0.0),
+        FillStyle::FocalGradient {
+            gradient,
+            focal_point,
+        } => ("radial", gradient, focal_point.to_f64()),
+    };
+
+    let colors: Vec<_> = gradient
+        .records
+        .iter()
+        .map(|record| record.color.to_rgb().into())
+        .collect();
+    let alphas: Vec<_> = gradient
+        .records
+        .iter()
+        .map(|record| (record.color.a as f64 / 255.0).into())
+        .collect();
+    let ratios: Vec<_> = gradient
+        .records
+        .iter()
+        .map(|record| record.ratio.into())
+        .collect();
+
+    let spread_method = match gradient.spread {
+        GradientSpread::Pad => "pad",
+        GradientSpread::Reflect => "reflect",
+        GradientSpread::Repeat => "repeat",
+    };
+    let interpolation_method = match gradient.interpolation {
+        GradientInterpolation::Rgb => "rgb",
+        GradientInterpolation::LinearRgb => "linearRGB",
+    };
+
+    let args = [
+        gradient_type.into(),
+        ArrayObject::from_storage(activation, ArrayStorage::from_args(&colors))?.into(),
+        ArrayObject::from_storage(activation, ArrayStorage::from_args(&alphas))?.into(),
+        ArrayObject::from_storage(activation, ArrayStorage::from_args(&ratios))?.into(),
+        matrix_to_object(matrix * RenderMatrix::from(gradient.matrix), activation)?,
+        spread_method.into(),
+        interpolation_method.into(),
+        focal_point.into(),
+    ];
+    let fill = activation
+        .avm2()
+        .classes()
+        .graphicsgradientfill
+        .construct(activation, &args)?;
+    Ok(fill.into())
+}
+
+/// Convert a line style into a `GraphicsStroke`.
+fn line_style_to_object<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    style: &LineStyle,
+    matrix: RenderMatrix,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let scale_mode = match (style.allow_scale_x(), style.allow_scale_y()) {
+        (true, true) => "normal",
+        (true, false) => "horizontal",
+        (false, true) => "vertical",
+        (false, false) => "none",
+    };
+    let caps = match style.start_cap() {
+        LineCapStyle::None => "none",
+        LineCapStyle::Round => "round",
+        LineCapStyle::Square => "square",
+    };
+    let (joints, miter_limit) = match style.join_style() {
+        LineJoinStyle::Round => ("round", 3.0),
+        LineJoinStyle::Bevel => ("bevel", 3.0),
+        LineJoinStyle::Miter(miter_limit) => ("miter", miter_limit.to_f64()),
+    };
+    let fill = fill_style_to_object(activation, style.fill_style(), matrix)?;
+
+    let args = [
+        style.width().to_pixels().into(),
+        style.is_pixel_hinted().into(),
+        scale_mode.into(),
+        caps.into(),
+        joints.into(),
+        miter_limit.into(),
+        fill,
+    ];
+    let stroke = activation
+        .avm2()
+        .classes()
+        .graphicsstroke
+        .construct(activation, &args)?;
+    Ok(stroke.into())
+}
+
+/// Convert a list of draw commands into a `GraphicsPath`.
+fn commands_to_graphics_path<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    commands: &[DrawCommand],
+    winding_rule: FillRule,
+    matrix: RenderMatrix,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mut command_values = Vec::with_capacity(commands.len());
+    let mut data_values = Vec::new();
+
+    let mut push_point = |point: Point<Twips>| {
+        let point = matrix * point;
+        data_values.push(point.x.to_pixels().into());
+        data_values.push(point.y.to_pixels().into());
+    };
+
+    // These are the values of the `GraphicsPathCommand` constants.
+    for (i, command) in commands.iter().enumerate() {
+        match *command {
+            // A move that is immediately replaced by another one draws nothing.
+            DrawCommand::MoveTo(_)
+                if matches!(commands.get(i + 1), Some(DrawCommand::MoveTo(_))) => {}
+            DrawCommand::MoveTo(point) => {
+                command_values.push(1.into());
+                push_point(point);
+            }
+            DrawCommand::LineTo(point) => {
+                command_values.push(2.into());
+                push_point(point);
+            }
+            DrawCommand::QuadraticCurveTo { control, anchor } => {
+                command_values.push(3.into());
+                push_point(control);
+                push_point(anchor);
+            }
+            DrawCommand::CubicCurveTo {
+                control_a,
+                control_b,
+                anchor,
+            } => {
+                command_values.push(6.into());
+                push_point(control_a);
+                push_point(control_b);
+                push_point(anchor);
+            }
+        }
+    }
+
+    let int_class = activation.avm2().classes().int;
+    let number_class = activation.avm2().classes().number;
+    let commands = VectorObject::from_vector(
+        VectorStorage::from_values(command_values, false, Some(int_class)),
+        activation,
+    )?;
+    let data = VectorObject::from_vector(
+        VectorStorage::from_values(data_values, false, Some(number_class)),
+        activation,
+    )?;
+    let winding = match winding_rule {
+        FillRule::EvenOdd => "evenOdd",
+        FillRule::NonZero => "nonZero",
+    };
+
+    let path = activation
+        .avm2()
+        .classes()
+        .graphicspath
+        .construct(activation, &[commands.into(), data.into(), winding.into()])?;
+    Ok(path.into())
+}
+
 fn process_commands<'gc>(
     activation: &mut Activation<'_, 'gc>,
     drawing: &mut Drawing,
@@ -1289,8 +1725,8 @@ fn handle_igraphics_data<'gc>(
         let style = handle_solid_fill(activation, obj)?;
         drawing.set_fill_style(Some(style));
     } else if class == activation.avm2().classes().graphicsshaderfill {
-        tracing::warn!("Graphics shader fill unimplemented {:?}", class);
-        drawing.set_fill_style(None);
+        let style = handle_shader_fill(activation, drawing, obj)?;
+        drawing.set_fill_style(style);
     } else if class == activation.avm2().classes().graphicsstroke {
         let thickness = obj
             .get_public_property("thickness", activation)?
@@ -1488,8 +1924,7 @@ fn handle_igraphics_fill<'gc>(
         let style = handle_solid_fill(activation, obj)?;
         Ok(Some(style))
     } else if class == activation.avm2().classes().graphicsshaderfill {
-        tracing::warn!("Graphics shader fill unimplemented {:?}", class);
-        Ok(None)
+        handle_shader_fill(activation, drawing, obj)
     } else {
         tracing::warn!("Unknown graphics fill class {:?}", class);
         Ok(None)
@@ -1657,3 +2092,30 @@ fn handle_bitmap_fill<'gc>(
 
     Ok(style)
 }
+
+fn handle_shader_fill<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    drawing: &mut Drawing,
+    obj: &Object<'gc>,
+) -> Result<Option<FillStyle>, Error<'gc>> {
+    let Some(shader) = obj.get_public_property("shader", activation)?.as_object() else {
+        return Ok(None);
+    };
+
+    let matrix = match obj.get_public_property("matrix", activation)?.as_object() {
+        Some(matrix) => Matrix::from(object_to_matrix(matrix, activation)?),
+        None => Matrix::IDENTITY,
+    };
+
+    let (shader_handle, arguments) = get_shader_args(shader, activation)?;
+    let scale_matrix = Matrix::scale(
+        (Twips::TWIPS_PER_PIXEL as i16).into(),
+        (Twips::TWIPS_PER_PIXEL as i16).into(),
+    );
+
+    Ok(Some(drawing.add_shader_fill(
+        shader_handle,
+        arguments,
+        matrix * scale_matrix,
+    )))
+}
@@ -11,11 +11,12 @@ use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
 use crate::avm2::ClassObject;
 use crate::avm2::Multiname;
-use crate::avm2::{Error, Object};
-use crate::avm2_stub_method;
+use crate::avm2::{Avm2, Domain, Error, EventObject, Object};
 use crate::backend::navigator::{NavigationMethod, Request};
+use crate::character::Character;
 use crate::display_object::LoaderDisplay;
 use crate::display_object::MovieClip;
+use crate::display_object::{DisplayObject, TDisplayObject, TDisplayObjectContainer};
 use crate::loader::MovieLoaderVMData;
 use crate::tag_utils::SwfMovie;
 use std::sync::Arc;
@@ -249,15 +250,41 @@ pub fn unload<'gc>(
     this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    // TODO: Broadcast an "unload" event on the LoaderInfo and reset LoaderInfo properties
-    avm2_stub_method!(activation, "flash.display.Loader", "unload");
-    let _ = crate::avm2::globals::flash::display::display_object_container::remove_child_at(
-        activation,
-        this,
-        &[0.into()],
-    );
+    unload_content(activation, this, false)?;
 
-    let loader_info = this
+    Ok(Value::Undefined)
+}
+
+pub fn unload_and_stop<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    unload_content(activation, this, true)?;
+
+    Ok(Value::Undefined)
+}
+
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let loader_info = content_loader_info(activation, this)?;
+
+    activation
+        .context
+        .load_manager
+        .cancel_avm2_movie_load(loader_info);
+
+    Ok(Value::Undefined)
+}
+
+fn content_loader_info<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+) -> Result<Object<'gc>, Error<'gc>> {
+    Ok(this
         .get_property(
             &Multiname::new(
                 activation.avm2().flash_display_internal,
@@ -266,11 +293,139 @@ pub fn unload<'gc>(
             activation,
         )?
         .as_object()
-        .unwrap();
+        .unwrap())
+}
 
-    let loader_info_object = loader_info.as_loader_info_object().unwrap();
+/// Detach the loaded content from this `Loader`, reset its `LoaderInfo`, and
+/// fire `Event.UNLOAD` if there was any content to remove.
+///
+/// If `stop` is set, everything the content left running (sounds, timelines,
+/// `NetStream`s, frame and activation listeners, timers and sockets) is
+/// stopped as well.
+fn unload_content<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    stop: bool,
+) -> Result<(), Error<'gc>> {
+    let loader_info = content_loader_info(activation, this)?;
+
+    let content = this
+        .as_display_object()
+        .and_then(|dobj| dobj.as_container())
+        .and_then(|ctr| ctr.child_by_index(0));
+
+    if let Some(content) = content {
+        if stop {
+            stop_display_object(activation, content)?;
+
+            // Sounds started from script aren't tied to a display object, so
+            // stop every instance of the content's own sounds too.
+            let sounds: Vec<_> = activation
+                .context
+                .library
+                .library_for_movie(content.movie())
+                .map(|library| {
+                    library
+                        .characters()
+                        .values()
+                        .filter_map(|character| match character {
+                            Character::Sound(sound) => Some(*sound),
+                            _ => None,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            for sound in sounds {
+                activation.context.stop_sounds_with_handle(sound);
+            }
 
-    loader_info_object.unload(activation);
+            if let Some(domain) = activation
+                .context
+                .library
+                .library_for_movie(content.movie())
+                .and_then(|library| library.try_avm2_domain())
+            {
+                // Content loaded into the loading SWF's own domain shares its
+                // sockets and timers, so only stop them if the content had a
+                // domain to itself.
+                let is_own_domain = activation
+                    .caller_domain()
+                    .is_some_and(|caller| Domain::ptr_eq(caller, domain));
+                if !is_own_domain {
+                    activation
+                        .context
+                        .sockets
+                        .close_avm2_sockets_in_domain(domain);
+                    activation.context.timers.remove_avm2_domain(domain);
+                }
+            }
+        }
 
-    Ok(Value::Undefined)
+        crate::avm2::globals::flash::display::display_object_container::remove_child_at(
+            activation,
+            this,
+            &[0.into()],
+        )?;
+
+        let unload_evt = EventObject::bare_default_event(&mut activation.context, "unload");
+        Avm2::dispatch_event(&mut activation.context, unload_evt, loader_info);
+    }
+
+    loader_info
+        .as_loader_info_object()
+        .unwrap()
+        .unload(activation);
+
+    Ok(())
+}
+
+/// Stop everything that `display_object` and its descendants are playing.
+fn stop_display_object<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    display_object: DisplayObject<'gc>,
+) -> Result<(), Error<'gc>> {
+    activation
+        .context
+        .stop_sounds_with_display_object(display_object);
+
+    if let Some(movie_clip) = display_object.as_movie_clip() {
+        movie_clip.stop(&mut activation.context);
+    }
+
+    if let Some(stream) = display_object
+        .as_video()
+        .and_then(|video| video.netstream())
+    {
+        stream.close(&mut activation.context);
+    }
+
+    if let Value::Object(object) = display_object.object2() {
+        let dispatch_list = object.get_property(
+            &Multiname::new(activation.avm2().flash_events_internal, "_dispatchList"),
+            activation,
+        )?;
+        if let Some(dispatch_list) = dispatch_list.as_object() {
+            if let Some(mut dispatch_list) =
+                dispatch_list.as_dispatch_mut(activation.context.gc_context)
+            {
+                for event in [
+                    "enterFrame",
+                    "frameConstructed",
+                    "exitFrame",
+                    "activate",
+                    "deactivate",
+                ] {
+                    dispatch_list.remove_all_event_listeners(event);
+                }
+            }
+        }
+    }
+
+    if let Some(container) = display_object.as_container() {
+        for child in container.iter_render_list() {
+            stop_display_object(activation, child)?;
+        }
+    }
+
+    Ok(())
 }
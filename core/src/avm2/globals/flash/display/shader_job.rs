@@ -6,9 +6,19 @@ use ruffle_render::{
     },
 };
 
+use gc_arena::{Collect, GcCell};
+
 use crate::{
-    avm2::{string::AvmString, Activation, Error, Object, TObject, Value},
+    avm2::{
+        parameters::ParametersExt, string::AvmString, Activation, Avm2, Error, Multiname, Object,
+        TObject, Value,
+    },
     avm2_stub_method,
+    bitmap::{
+        bitmap_data::{BitmapData, BitmapDataWrapper},
+        operations::copy_pixels,
+    },
+    context::UpdateContext,
     pixel_bender::PixelBenderTypeExt,
 };
 
@@ -54,10 +64,10 @@ pub fn get_shader_args<'gc>(
                     }
 
                     if name == OUT_COORD_NAME {
-                        // Pass in a dummy value - this will be ignored in favor of the actual pixel coordinate
+                        // The shader adds this offset to the actual pixel coordinate
                         return Some(PixelBenderShaderArgument::ValueInput {
                             index: index as u8,
-                            value: PixelBenderType::TFloat2(f32::NAN, f32::NAN),
+                            value: PixelBenderType::TFloat2(0.0, 0.0),
                         });
                     }
                     let shader_param = shader_data
@@ -130,18 +140,27 @@ pub fn get_shader_args<'gc>(
     Ok((shader_handle.clone(), args))
 }
 
+/// A `ShaderJob` that was started with `waitForCompletion = false`.
+///
+/// The shader renders into `output` in the background. On the next frame,
+/// the output is copied into `target` and `ShaderEvent.COMPLETE` is
+/// dispatched. `output` is `None` if the shader couldn't be run, in which
+/// case the target is left untouched.
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct PendingShaderJob<'gc> {
+    job: Object<'gc>,
+    target: BitmapDataWrapper<'gc>,
+    output: Option<BitmapDataWrapper<'gc>>,
+}
+
 /// Implements `ShaderJob.start`.
 pub fn start<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(
-        activation,
-        "flash.display.ShaderJob",
-        "start",
-        "async execution and non-BitmapData inputs"
-    );
+    let wait_for_completion = args.get_bool(0);
 
     let shader = this
         .get_public_property("shader", activation)?
@@ -155,33 +174,175 @@ pub fn start<'gc>(
         .as_object()
         .expect("ShaderJob.target is not an object");
 
-    let target_bitmap = target
-        .as_bitmap_data()
-        .expect("ShaderJob.target is not a BitmapData (FIXME - support other types)")
-        .sync();
-
-    // Perform both a GPU->CPU and CPU->GPU sync before writing to it.
-    // FIXME - are both necessary?
-    let mut target_bitmap_data = target_bitmap.write(activation.context.gc_context);
-    target_bitmap_data.update_dirty_texture(activation.context.renderer);
-
-    let target_handle = target_bitmap_data
-        .bitmap_handle(activation.context.renderer)
-        .expect("Missing handle");
-
-    let sync_handle = activation
-        .context
-        .renderer
-        .run_pixelbender_shader(shader_handle, &arguments, target_handle)
-        .expect("Failed to run shader");
-
-    let width = target_bitmap_data.width();
-    let height = target_bitmap_data.height();
-    target_bitmap_data.set_gpu_dirty(
-        activation.context.gc_context,
+    let Some(target) = target.as_bitmap_data() else {
+        avm2_stub_method!(
+            activation,
+            "flash.display.ShaderJob",
+            "start",
+            "non-BitmapData target"
+        );
+        return Ok(Value::Undefined);
+    };
+
+    // Restarting a job discards any run that hasn't completed yet.
+    cancel_pending(activation, this);
+
+    // A width or height of zero means "the whole target".
+    let ns = activation.avm2().flash_display_internal;
+    let width = this
+        .get_property(&Multiname::new(ns, "_width"), activation)?
+        .coerce_to_i32(activation)?;
+    let height = this
+        .get_property(&Multiname::new(ns, "_height"), activation)?
+        .coerce_to_i32(activation)?;
+    let width = match width {
+        1.. => (width as u32).min(target.width()),
+        _ => target.width(),
+    };
+    let height = match height {
+        1.. => (height as u32).min(target.height()),
+        _ => target.height(),
+    };
+
+    let output = run_shader(
+        &mut activation.context,
+        width,
+        height,
+        shader_handle,
+        &arguments,
+    );
+
+    if wait_for_completion {
+        copy_output(&mut activation.context, target, output);
+        this.set_property(&Multiname::new(ns, "_progress"), 1.into(), activation)?;
+    } else {
+        this.set_property(&Multiname::new(ns, "_progress"), 0.into(), activation)?;
+        activation
+            .avm2()
+            .pending_shader_jobs
+            .push(PendingShaderJob {
+                job: this,
+                target,
+                output,
+            });
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `ShaderJob.cancel`.
+pub fn cancel<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    cancel_pending(activation, this);
+    Ok(Value::Undefined)
+}
+
+fn cancel_pending<'gc>(activation: &mut Activation<'_, 'gc>, job: Object<'gc>) {
+    activation
+        .avm2()
+        .pending_shader_jobs
+        .retain(|pending| !Object::ptr_eq(pending.job, job));
+}
+
+/// Start running a shader into a new `width` by `height` bitmap.
+///
+/// The renderer works through the shader in the background; its result is
+/// only read back once the output is used.
+fn run_shader<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    width: u32,
+    height: u32,
+    shader_handle: PixelBenderShaderHandle,
+    arguments: &[PixelBenderShaderArgument<'static>],
+) -> Option<BitmapDataWrapper<'gc>> {
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let output = GcCell::new(context.gc_context, BitmapData::new(width, height, true, 0));
+    let mut output_data = output.write(context.gc_context);
+
+    let output_handle = output_data.bitmap_handle(context.renderer)?;
+
+    let sync_handle =
+        match context
+            .renderer
+            .run_pixelbender_shader(shader_handle, arguments, output_handle)
+        {
+            Ok(sync_handle) => sync_handle,
+            Err(e) => {
+                tracing::error!("Failed to run ShaderJob: {e}");
+                return None;
+            }
+        };
+
+    output_data.set_gpu_dirty(
+        context.gc_context,
         sync_handle,
         PixelRegion::for_whole_size(width, height),
     );
+    drop(output_data);
 
-    Ok(Value::Undefined)
+    Some(BitmapDataWrapper::new(output))
+}
+
+/// Write the result of a shader run into the job's target.
+fn copy_output<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    target: BitmapDataWrapper<'gc>,
+    output: Option<BitmapDataWrapper<'gc>>,
+) {
+    let Some(output) = output else {
+        return;
+    };
+    let (width, height) = (output.width() as i32, output.height() as i32);
+    copy_pixels(
+        context,
+        target,
+        output,
+        (0, 0, width, height),
+        (0, 0),
+        false,
+    );
+}
+
+/// Completes every `ShaderJob` that was started asynchronously before this
+/// frame, dispatching `ShaderEvent.COMPLETE` on each of them.
+///
+/// Jobs started by the completion handlers are completed on the next frame.
+pub fn run_pending_shader_jobs(context: &mut UpdateContext<'_, '_>) {
+    let completed = std::mem::take(&mut context.avm2.pending_shader_jobs);
+
+    let mut activation = Activation::from_nothing(context.reborrow());
+    let ns = activation.avm2().flash_display_internal;
+    for PendingShaderJob {
+        job,
+        target,
+        output,
+    } in completed
+    {
+        copy_output(&mut activation.context, target, output);
+
+        let result = job
+            .set_property(&Multiname::new(ns, "_progress"), 1.into(), &mut activation)
+            .and_then(|_| {
+                activation.avm2().classes().shaderevent.construct(
+                    &mut activation,
+                    &[
+                        "complete".into(),
+                        false.into(),
+                        false.into(),
+                        target.object2(),
+                    ],
+                )
+            });
+
+        match result {
+            Ok(event) => Avm2::dispatch_event(&mut activation.context, event, job),
+            Err(e) => tracing::error!("Error completing ShaderJob: {e:?}"),
+        }
+    }
 }
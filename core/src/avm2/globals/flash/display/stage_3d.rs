@@ -1,34 +1,51 @@
 use crate::avm2::object::Context3DObject;
 use crate::avm2::object::TObject;
 
+use crate::avm2::error::make_error_2008;
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::{Activation, Error, Object, Value};
 
+use ruffle_render::backend::Context3DProfile;
+
 pub use crate::avm2::object::stage_3d_allocator;
 
 pub fn request_context3d_internal<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     let this_stage3d = this.as_stage_3d().unwrap();
-    if this_stage3d.context3d().is_none() {
-        let context = activation.context.renderer.create_context3d()?;
-        let context3d_obj = Context3DObject::from_context(activation, context)?;
-        this_stage3d.set_context3d(context3d_obj, activation.context.gc_context);
+    let profiles = args.get_object(activation, 1, "profiles")?;
+    let profiles: Vec<Value<'gc>> = profiles
+        .as_vector_storage()
+        .map(|profiles| profiles.iter().collect())
+        .unwrap_or_default();
+    if profiles.is_empty() {
+        return Err(make_error_2008(activation, "profiles"));
+    }
 
-        let event = activation
-            .avm2()
-            .classes()
-            .event
-            .construct(activation, &["context3DCreate".into()])?;
+    let mut parsed_profiles = Vec::with_capacity(profiles.len());
+    for profile in profiles {
+        let profile = profile.coerce_to_string(activation)?;
+        let profile = Context3DProfile::from_wstr(&profile)
+            .ok_or_else(|| make_error_2008(activation, "profile"))?;
+        parsed_profiles.push(profile);
+    }
 
-        // FIXME - fire this at least one frame later,
-        // since some seems to expect this (e.g. the adobe triangle example)
-        this.call_public_property("dispatchEvent", &[event.into()], activation)?;
+    // Use the most capable of the requested profiles that the renderer supports.
+    parsed_profiles.sort_unstable_by(|a, b| b.cmp(a));
+    for profile in parsed_profiles {
+        match activation.context.renderer.create_context3d(profile) {
+            Ok(context) => {
+                let context3d_obj = Context3DObject::from_context(activation, context, profile)?;
+                this_stage3d.set_context3d(context3d_obj, activation.context.gc_context);
+                return Ok(true.into());
+            }
+            Err(e) => tracing::warn!("Failed to create Context3D with profile {profile:?}: {e}"),
+        }
     }
 
-    Ok(Value::Undefined)
+    Ok(false.into())
 }
 
 pub fn get_context_3d<'gc>(
@@ -6,10 +6,13 @@ package flash.display3D {
     import flash.display3D.textures.TextureBase;
     import flash.display3D.textures.RectangleTexture;
     import flash.display3D.textures.Texture;
-    import __ruffle__.stub_method;
-    import __ruffle__.stub_getter;
+    import flash.display3D.textures.VideoTexture;
 
     public final class Context3D extends EventDispatcher {
+        public static function get supportsVideoTexture():Boolean {
+            return true;
+        }
+
         public native function clear(red:Number = 0.0, green:Number = 0.0, blue:Number = 0.0, alpha:Number = 1.0, depth:Number = 1.0, stencil:uint = 0, mask:uint = 0xffffffff):void;
 
         public native function createIndexBuffer(numIndices:int, bufferUsage:String = "staticDraw"):IndexBuffer3D;
@@ -26,11 +29,9 @@ package flash.display3D {
         public native function createTexture(width:int, height:int, format:String, optimizeForRenderToTexture:Boolean, streamingLevels:int = 0):Texture;
         public native function createCubeTexture(size:int, format:String, optimizeForRenderToTexture:Boolean, streamingLevels:int = 0):CubeTexture;
         public native function createRectangleTexture(width:int, height:int, format:String, optimizeForRenderToTexture:Boolean):RectangleTexture;
+        public native function createVideoTexture():VideoTexture;
 
-        public function get driverInfo():String {
-            stub_getter("flash.display3D.Context3D", "driverInfo");
-            return "Dummy Ruffle driver";
-        }
+        public native function get driverInfo():String;
 
         public var enableErrorChecking:Boolean = true;
 
@@ -47,37 +48,22 @@ package flash.display3D {
 
         public native function setTextureAt(sampler:int, texture:TextureBase):void;
 
-        public function get profile():String {
-            stub_getter("flash.display3D.Context3D", "profile");
-            return "baseline";
-        }
-
-        public function get maxBackBufferWidth():int {
-            stub_getter("flash.display3D.Context3D", "maxBackBufferWidth");
-            return 2048;
-        }
+        public native function get profile():String;
+        public native function get maxBackBufferWidth():int;
+        public native function get maxBackBufferHeight():int;
 
-        public function get maxBackBufferHeight():int {
-            stub_getter("flash.display3D.Context3D", "maxBackBufferHeight");
-            return 2048;
-        }
-
-        public function setStencilReferenceValue(referenceValue:uint, readMask:uint = 255, writeMask:uint = 255):void {
-            stub_method("flash.display3D.Context3D", "setStencilReferenceValue");
-        }
+        public native function setStencilReferenceValue(referenceValue:uint, readMask:uint = 255, writeMask:uint = 255):void;
 
         public native function setSamplerStateAt(sampler:int, wrap:String, filter:String, mipfilter:String):void;
 
         public native function setRenderToTexture(texture:TextureBase, enableDepthAndStencil:Boolean = false, antiAlias:int = 0, surfaceSelector:int = 0, colorOutputIndex:int = 0):void;
 
-        public function setStencilActions(
+        public native function setStencilActions(
             triangleFace:String = "frontAndBack",
             compareMode:String = "always",
             actionOnBothPass:String = "keep",
             actionOnDepthFail:String = "keep",
             actionOnDepthPassStencilFail:String = "keep"
-        ):void {
-            stub_method("flash.display3D.Context3D", "setStencilActions");
-        }
+        ):void;
     }
 }
\ No newline at end of file
@@ -5,11 +5,12 @@ use crate::avm2::TObject;
 use crate::avm2::Value;
 use crate::avm2::{Error, Object};
 use crate::avm2_stub_method;
+use crate::string::AvmString;
 use ruffle_render::backend::Context3DTextureFilter;
 use ruffle_render::backend::Context3DWrapMode;
 use ruffle_render::backend::{
-    BufferUsage, Context3DBlendFactor, Context3DCompareMode, Context3DTextureFormat,
-    Context3DTriangleFace, Context3DVertexBufferFormat, ProgramType,
+    BufferUsage, Context3DBlendFactor, Context3DCompareMode, Context3DStencilAction,
+    Context3DTextureFormat, Context3DTriangleFace, Context3DVertexBufferFormat, ProgramType,
 };
 use swf::{Rectangle, Twips};
 
@@ -91,6 +92,15 @@ pub fn configure_back_buffer<'gc>(
             )?));
         }
 
+        let max_size = context.profile().max_back_buffer_size();
+        if width > max_size || height > max_size {
+            return Err(Error::AvmError(error(
+                activation,
+                "Error #3669: Bad input size.",
+                3669,
+            )?));
+        }
+
         let wants_best_resolution = args.get(4).unwrap_or(&Value::Undefined).coerce_to_boolean();
         let wants_best_resolution_on_browser_zoom =
             args.get(5).unwrap_or(&Value::Undefined).coerce_to_boolean();
@@ -237,18 +247,10 @@ pub fn set_culling<'gc>(
     if let Some(context) = this.as_context_3d() {
         let culling = args.get_string(activation, 0)?;
 
-        let culling = if &*culling == b"none" {
-            Context3DTriangleFace::None
-        } else if &*culling == b"back" {
-            Context3DTriangleFace::Back
-        } else if &*culling == b"front" {
-            Context3DTriangleFace::Front
-        } else if &*culling == b"frontAndBack" {
-            Context3DTriangleFace::FrontAndBack
-        } else {
+        let culling = Context3DTriangleFace::from_wstr(&culling).unwrap_or_else(|| {
             tracing::error!("Unknown culling {:?}", culling);
             Context3DTriangleFace::None
-        };
+        });
 
         context.set_culling(culling);
     }
@@ -447,6 +449,29 @@ pub fn create_rectangle_texture<'gc>(
     Ok(Value::Undefined)
 }
 
+pub fn create_video_texture<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(context) = this.as_context_3d() {
+        let class = activation.avm2().classes().videotexture;
+
+        // The real texture is created once the attached `NetStream` decodes
+        // its first frame, since only then do we know the video size.
+        return context.create_texture(
+            1,
+            1,
+            Context3DTextureFormat::Bgra,
+            false,
+            0,
+            class,
+            activation,
+        );
+    }
+    Ok(Value::Undefined)
+}
+
 pub fn create_cube_texture<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
@@ -543,6 +568,64 @@ pub fn set_depth_test<'gc>(
     Ok(Value::Undefined)
 }
 
+pub fn set_stencil_actions<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(context) = this.as_context_3d() {
+        let face = args.get_string(activation, 0)?;
+        let face = if let Some(face) = Context3DTriangleFace::from_wstr(&face) {
+            face
+        } else {
+            return Err(format!("Unsupported stencil triangle face: {:?}", face).into());
+        };
+
+        let compare_mode = args.get_string(activation, 1)?;
+        let compare_mode = if let Some(mode) = Context3DCompareMode::from_wstr(&compare_mode) {
+            mode
+        } else {
+            return Err(format!("Unsupported stencil compare mode: {:?}", compare_mode).into());
+        };
+
+        let mut actions = [Context3DStencilAction::Keep; 3];
+        for (i, action) in actions.iter_mut().enumerate() {
+            let name = args.get_string(activation, i + 2)?;
+            *action = if let Some(action) = Context3DStencilAction::from_wstr(&name) {
+                action
+            } else {
+                return Err(format!("Unsupported stencil action: {:?}", name).into());
+            };
+        }
+        let [action_on_both_pass, action_on_depth_fail, action_on_depth_pass_stencil_fail] =
+            actions;
+
+        context.set_stencil_actions(
+            face,
+            compare_mode,
+            action_on_both_pass,
+            action_on_depth_fail,
+            action_on_depth_pass_stencil_fail,
+        );
+    }
+    Ok(Value::Undefined)
+}
+
+pub fn set_stencil_reference_value<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(context) = this.as_context_3d() {
+        // The stencil buffer has 8 bits, so only the low byte of each value is used.
+        let reference_value = args.get_u32(activation, 0)? & 0xFF;
+        let read_mask = args.get_u32(activation, 1)? & 0xFF;
+        let write_mask = args.get_u32(activation, 2)? & 0xFF;
+        context.set_stencil_reference_value(reference_value, read_mask, write_mask);
+    }
+    Ok(Value::Undefined)
+}
+
 pub fn set_blend_factors<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
@@ -603,6 +686,10 @@ pub fn set_render_to_texture<'gc>(
         );
     }
 
+    if color_output_index >= context.profile().max_render_targets() {
+        return Err(make_error_2008(activation, "colorOutputIndex"));
+    }
+
     if color_output_index != 0 {
         avm2_stub_method!(
             activation,
@@ -711,3 +798,48 @@ pub fn set_scissor_rectangle<'gc>(
     context3d.set_scissor_rectangle(rectangle);
     Ok(Value::Undefined)
 }
+
+pub fn get_driver_info<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(context) = this.as_context_3d() {
+        let driver_info = context.driver_info();
+        return Ok(AvmString::new_utf8(activation.context.gc_context, driver_info).into());
+    }
+    Ok(Value::Undefined)
+}
+
+pub fn get_profile<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(context) = this.as_context_3d() {
+        return Ok(context.profile().to_str().into());
+    }
+    Ok(Value::Undefined)
+}
+
+pub fn get_max_back_buffer_width<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(context) = this.as_context_3d() {
+        return Ok(context.profile().max_back_buffer_size().into());
+    }
+    Ok(Value::Undefined)
+}
+
+pub fn get_max_back_buffer_height<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(context) = this.as_context_3d() {
+        return Ok(context.profile().max_back_buffer_size().into());
+    }
+    Ok(Value::Undefined)
+}
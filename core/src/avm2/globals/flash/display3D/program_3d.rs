@@ -1,9 +1,30 @@
+use crate::avm2::error::error;
 use crate::avm2::Activation;
 
 use crate::avm2::TObject;
 use crate::avm2::Value;
 use crate::avm2::{Error, Object};
 
+/// Checks that the AGAL version in a program's header is allowed by the context profile.
+fn check_agal_version<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    agal: &[u8],
+    max_version: u32,
+) -> Result<(), Error<'gc>> {
+    // The header is the magic byte 0xA0, followed by the version as a little-endian u32.
+    let version = agal
+        .get(1..5)
+        .map(|version| u32::from_le_bytes(version.try_into().unwrap()));
+    if version.map_or(false, |version| version > max_version) {
+        return Err(Error::AvmError(error(
+            activation,
+            "Error #3662: AGAL validation failed: Bad AGAL version for current profile.",
+            3662,
+        )?));
+    }
+    Ok(())
+}
+
 pub fn upload<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
@@ -28,6 +49,10 @@ pub fn upload<'gc>(
             .ok_or_else(|| Error::from("ArgumentError: Parameter must be a ByteArray"))?;
         let fragment_agal = fragment_agal.bytes().to_vec();
 
+        let max_agal_version = this.context3d().profile().max_agal_version();
+        check_agal_version(activation, &vertex_agal, max_agal_version)?;
+        check_agal_version(activation, &fragment_agal, max_agal_version)?;
+
         this.context3d()
             .upload_shaders(this, vertex_agal, fragment_agal);
     }
@@ -0,0 +1,31 @@
+package flash.display3D.textures {
+    import flash.events.VideoTextureEvent;
+    import flash.media.Camera;
+    import flash.media.VideoStatus;
+    import flash.net.NetStream;
+    import flash.utils.setTimeout;
+    import __ruffle__.stub_method;
+
+    public final class VideoTexture extends TextureBase {
+        public function attachNetStream(netStream:NetStream):void {
+            this.attachNetStream_internal(netStream);
+
+            if (netStream != null) {
+                // We always decode video frames in software.
+                var self = this;
+                setTimeout(function() {
+                    self.dispatchEvent(new VideoTextureEvent(VideoTextureEvent.RENDER_STATE, false, false, VideoStatus.SOFTWARE, "BT.601"));
+                }, 0);
+            }
+        }
+
+        private native function attachNetStream_internal(netStream:NetStream):void;
+
+        public function attachCamera(theCamera:Camera):void {
+            stub_method("flash.display3D.textures.VideoTexture", "attachCamera");
+        }
+
+        public native function get videoWidth():int;
+        public native function get videoHeight():int;
+    }
+}
@@ -0,0 +1,50 @@
+use crate::avm2::Activation;
+use crate::avm2::TObject;
+use crate::avm2::Value;
+use crate::avm2::{Error, Object};
+
+pub fn attach_net_stream_internal<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(texture) = this.as_texture() {
+        let stream = args
+            .get(0)
+            .and_then(|v| v.as_object())
+            .and_then(|o| o.as_netstream());
+
+        if let Some(stream) = stream {
+            stream.attach_video_texture(activation.context.gc_context, texture);
+        } else if let Some(old_stream) = texture.video_stream() {
+            old_stream.detach_video_texture(activation.context.gc_context, texture);
+        }
+    }
+    Ok(Value::Undefined)
+}
+
+pub fn get_video_width<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let width = this
+        .as_texture()
+        .and_then(|texture| texture.video_stream())
+        .and_then(|stream| stream.last_decoded_bitmap())
+        .map_or(0, |bitmap| bitmap.width);
+    Ok(width.into())
+}
+
+pub fn get_video_height<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let height = this
+        .as_texture()
+        .and_then(|texture| texture.video_stream())
+        .and_then(|stream| stream.last_decoded_bitmap())
+        .map_or(0, |bitmap| bitmap.height);
+    Ok(height.into())
+}
@@ -1,44 +1,38 @@
 package flash.geom {
-    import __ruffle__.stub_constructor;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_method;
-    import __ruffle__.stub_setter;
+    import flash.display.DisplayObject;
     import flash.geom.Matrix3D;
     import flash.geom.Point;
 
     public class PerspectiveProjection {
-        // Getters are stubbed with what seem to be Flash's default values
+        // The display object this projection was taken from, which is updated when it changes
+        internal var _displayObject:DisplayObject = null;
+
+        internal var _fieldOfView:Number;
+        internal var _centerX:Number;
+        internal var _centerY:Number;
+
         public function PerspectiveProjection() {
-            stub_constructor("flash.geom.PerspectiveProjection");
+            this.init();
         }
+        native function init():void;
 
-        public function get fieldOfView():Number {
-            stub_getter("flash.geom.PerspectiveProjection", "fieldOfView");
-            return 55;
-        }
-        public function set fieldOfView(value:Number) {
-            stub_setter("flash.geom.PerspectiveProjection", "fieldOfView");
-        }
+        public native function get fieldOfView():Number;
+        public native function set fieldOfView(value:Number):void;
 
-        public function get focalLength():Number {
-            stub_getter("flash.geom.PerspectiveProjection", "focalLength");
-            return 480.25;
-        }
-        public function set focalLength(value:Number) {
-            stub_setter("flash.geom.PerspectiveProjection", "focalLength");
-        }
+        public native function get focalLength():Number;
+        public native function set focalLength(value:Number):void;
 
-        public function get projectionCenter():Point {
-            stub_getter("flash.geom.PerspectiveProjection", "projectionCenter");
-            return new Point(250, 250);
-        }
-        public function set projectionCenter(value:Point) {
-            stub_setter("flash.geom.PerspectiveProjection", "projectionCenter");
-        }
+        public native function get projectionCenter():Point;
+        public native function set projectionCenter(value:Point):void;
 
         public function toMatrix3D():Matrix3D {
-            stub_method("flash.geom.PerspectiveProjection", "toMatrix3D");
-            return new Matrix3D();
+            var focalLength:Number = this.focalLength;
+            return new Matrix3D(new <Number>[
+                focalLength, 0, 0, 0,
+                0, focalLength, 0, 0,
+                0, 0, 1, 1,
+                0, 0, 0, 0
+            ]);
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,203 @@
+//! `flash.geom.PerspectiveProjection` native methods
+
+use crate::avm2::error::argument_error;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Multiname, Object, TObject, Value};
+use crate::display_object::{DisplayObject, PerspectiveProjection, TDisplayObject};
+use crate::prelude::{Point, Twips};
+
+fn get_internal<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    name: &'static str,
+) -> Result<Value<'gc>, Error<'gc>> {
+    this.get_property(
+        &Multiname::new(activation.avm2().flash_geom_internal, name),
+        activation,
+    )
+}
+
+fn set_internal<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    name: &'static str,
+    value: impl Into<Value<'gc>>,
+) -> Result<(), Error<'gc>> {
+    this.set_property(
+        &Multiname::new(activation.avm2().flash_geom_internal, name),
+        value.into(),
+        activation,
+    )
+}
+
+/// Copies the projection to the display object it was taken from, if any.
+fn sync<'gc>(activation: &mut Activation<'_, 'gc>, this: Object<'gc>) -> Result<(), Error<'gc>> {
+    let display_object = get_internal(activation, this, "_displayObject")?
+        .as_object()
+        .and_then(|object| object.as_display_object());
+    if let Some(display_object) = display_object {
+        let projection = object_to_perspective_projection(this, activation)?;
+        display_object.set_perspective_projection(activation.context.gc_context, Some(projection));
+    }
+    Ok(())
+}
+
+pub fn object_to_perspective_projection<'gc>(
+    object: Object<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<PerspectiveProjection, Error<'gc>> {
+    let field_of_view = get_internal(activation, object, "_fieldOfView")?;
+    let center_x = get_internal(activation, object, "_centerX")?;
+    let center_y = get_internal(activation, object, "_centerY")?;
+    Ok(PerspectiveProjection {
+        field_of_view: field_of_view.coerce_to_number(activation)?,
+        projection_center: Point::new(
+            Twips::from_pixels(center_x.coerce_to_number(activation)?),
+            Twips::from_pixels(center_y.coerce_to_number(activation)?),
+        ),
+    })
+}
+
+/// Creates a `PerspectiveProjection`, which updates `display_object` when it's modified.
+pub fn perspective_projection_to_object<'gc>(
+    projection: PerspectiveProjection,
+    display_object: Option<DisplayObject<'gc>>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let object = activation
+        .avm2()
+        .classes()
+        .perspectiveprojection
+        .construct(activation, &[])?;
+    set_internal(activation, object, "_fieldOfView", projection.field_of_view)?;
+    set_internal(
+        activation,
+        object,
+        "_centerX",
+        projection.projection_center.x.to_pixels(),
+    )?;
+    set_internal(
+        activation,
+        object,
+        "_centerY",
+        projection.projection_center.y.to_pixels(),
+    )?;
+    if let Some(display_object) = display_object {
+        let display_object = display_object.object2();
+        set_internal(activation, object, "_displayObject", display_object)?;
+    }
+    Ok(object.into())
+}
+
+pub fn init<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let (width, height) = activation.context.stage.movie_size();
+    let projection = PerspectiveProjection::for_stage(width, height);
+    set_internal(activation, this, "_fieldOfView", projection.field_of_view)?;
+    set_internal(
+        activation,
+        this,
+        "_centerX",
+        projection.projection_center.x.to_pixels(),
+    )?;
+    set_internal(
+        activation,
+        this,
+        "_centerY",
+        projection.projection_center.y.to_pixels(),
+    )?;
+    Ok(Value::Undefined)
+}
+
+pub fn get_field_of_view<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    get_internal(activation, this, "_fieldOfView")
+}
+
+pub fn set_field_of_view<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let field_of_view = args.get_f64(activation, 0)?;
+    if field_of_view <= 0.0 || field_of_view >= 180.0 {
+        return Err(Error::AvmError(argument_error(
+            activation,
+            "Error #2182: Invalid fieldOfView value.  The value must be greater than 0 and less than 180.",
+            2182,
+        )?));
+    }
+    set_internal(activation, this, "_fieldOfView", field_of_view)?;
+    sync(activation, this)?;
+    Ok(Value::Undefined)
+}
+
+pub fn get_focal_length<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let projection = object_to_perspective_projection(this, activation)?;
+    let (width, _) = activation.context.stage.movie_size();
+    Ok(projection.focal_length(width).into())
+}
+
+pub fn set_focal_length<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let focal_length = args.get_f64(activation, 0)?;
+    if focal_length <= 0.0 {
+        return Err(Error::AvmError(argument_error(
+            activation,
+            "Error #2186: Invalid focalLength.",
+            2186,
+        )?));
+    }
+    let mut projection = object_to_perspective_projection(this, activation)?;
+    let (width, _) = activation.context.stage.movie_size();
+    projection.set_focal_length(focal_length, width);
+    set_internal(activation, this, "_fieldOfView", projection.field_of_view)?;
+    sync(activation, this)?;
+    Ok(Value::Undefined)
+}
+
+pub fn get_projection_center<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let x = get_internal(activation, this, "_centerX")?;
+    let y = get_internal(activation, this, "_centerY")?;
+    let point = activation
+        .avm2()
+        .classes()
+        .point
+        .construct(activation, &[x, y])?;
+    Ok(point.into())
+}
+
+pub fn set_projection_center<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let point = args.get_object(activation, 0, "value")?;
+    let x = point
+        .get_public_property("x", activation)?
+        .coerce_to_number(activation)?;
+    let y = point
+        .get_public_property("y", activation)?
+        .coerce_to_number(activation)?;
+    set_internal(activation, this, "_centerX", x)?;
+    set_internal(activation, this, "_centerY", y)?;
+    sync(activation, this)?;
+    Ok(Value::Undefined)
+}
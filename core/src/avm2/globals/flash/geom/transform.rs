@@ -1,9 +1,15 @@
+use crate::avm2::globals::flash::geom::perspective_projection::{
+    object_to_perspective_projection, perspective_projection_to_object,
+};
+use crate::avm2::object::VectorObject;
 use crate::avm2::parameters::ParametersExt;
+use crate::avm2::vector::VectorStorage;
 use crate::avm2::Multiname;
 use crate::avm2::{Activation, Error, Object, TObject, Value};
 use crate::avm2_stub_getter;
-use crate::display_object::TDisplayObject;
+use crate::display_object::{PerspectiveProjection, TDisplayObject};
 use crate::prelude::{DisplayObject, Matrix, Twips};
+use ruffle_render::matrix3d::Matrix3D;
 use ruffle_render::quality::StageQuality;
 use swf::{ColorTransform, Fixed8, Rectangle};
 
@@ -64,7 +70,12 @@ pub fn get_matrix<'gc>(
     this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    let matrix = *get_display_object(this, activation)?.base().matrix();
+    let dobj = get_display_object(this, activation)?;
+    // Objects with a 3D transform only expose it through `matrix3D`.
+    if dobj.transform_3d().is_some() {
+        return Ok(Value::Null);
+    }
+    let matrix = *dobj.base().matrix();
     matrix_to_object(matrix, activation)
 }
 
@@ -76,6 +87,7 @@ pub fn set_matrix<'gc>(
     let matrix = object_to_matrix(args.get_object(activation, 0, "value")?, activation)?;
     let dobj = get_display_object(this, activation)?;
     dobj.set_matrix(activation.context.gc_context, matrix);
+    dobj.set_transform_3d(activation.context.gc_context, None);
     if let Some(parent) = dobj.parent() {
         // Self-transform changes are automatically handled,
         // we only want to inform ancestors to avoid unnecessary invalidations for tx/ty
@@ -136,6 +148,92 @@ pub fn get_concatenated_color_transform<'gc>(
     Ok(Value::Undefined)
 }
 
+pub fn get_matrix_3d<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let dobj = get_display_object(this, activation)?;
+    match dobj.matrix_3d(activation.context.gc_context) {
+        Some(matrix) => matrix_3d_to_object(matrix, activation),
+        None => Ok(Value::Null),
+    }
+}
+
+pub fn set_matrix_3d<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let dobj = get_display_object(this, activation)?;
+    match args.try_get_object(activation, 0) {
+        Some(matrix) => {
+            let matrix = object_to_matrix_3d(matrix, activation)?;
+            dobj.set_matrix_3d(activation.context.gc_context, &matrix);
+        }
+        // The 2D parts of the transform are kept.
+        None => dobj.set_transform_3d(activation.context.gc_context, None),
+    }
+    Ok(Value::Undefined)
+}
+
+pub fn get_perspective_projection<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let dobj = get_display_object(this, activation)?;
+    let projection = dobj.perspective_projection().or_else(|| {
+        // The root always has a projection, centered on the stage.
+        dobj.is_root().then(|| {
+            let (width, height) = activation.context.stage.movie_size();
+            PerspectiveProjection::for_stage(width, height)
+        })
+    });
+    match projection {
+        Some(projection) => perspective_projection_to_object(projection, Some(dobj), activation),
+        None => Ok(Value::Null),
+    }
+}
+
+pub fn set_perspective_projection<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let dobj = get_display_object(this, activation)?;
+    let projection = match args.try_get_object(activation, 0) {
+        Some(projection) => Some(object_to_perspective_projection(projection, activation)?),
+        None => None,
+    };
+    dobj.set_perspective_projection(activation.context.gc_context, projection);
+    Ok(Value::Undefined)
+}
+
+pub fn get_relative_matrix_3d<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let dobj = get_display_object(this, activation)?;
+    let relative_to = args
+        .get_object(activation, 0, "relativeTo")?
+        .as_display_object();
+    let Some(relative_to) = relative_to else {
+        return Ok(Value::Null);
+    };
+
+    let gc_context = activation.context.gc_context;
+    let global_to_relative = relative_to.local_to_global_matrix_3d(gc_context).inverse();
+    match global_to_relative {
+        Some(global_to_relative) => {
+            let matrix = global_to_relative * dobj.local_to_global_matrix_3d(gc_context);
+            matrix_3d_to_object(matrix, activation)
+        }
+        None => Ok(Value::Null),
+    }
+}
+
 // FIXME - handle clamping. We're throwing away precision here in converting to an integer:
 // is that what we should be doing?
 pub fn object_to_color_transform<'gc>(
@@ -247,6 +345,43 @@ pub fn object_to_matrix<'gc>(
     Ok(Matrix { a, b, c, d, tx, ty })
 }
 
+pub fn matrix_3d_to_object<'gc>(
+    matrix: Matrix3D,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let raw_data = VectorStorage::from_values(
+        matrix.raw_data.iter().map(|v| (*v).into()).collect(),
+        false,
+        Some(activation.avm2().classes().number),
+    );
+    let raw_data = VectorObject::from_vector(raw_data, activation)?;
+    let object = activation
+        .avm2()
+        .classes()
+        .matrix3d
+        .construct(activation, &[raw_data.into()])?;
+    Ok(object.into())
+}
+
+pub fn object_to_matrix_3d<'gc>(
+    object: Object<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Matrix3D, Error<'gc>> {
+    let raw_data = object
+        .get_public_property("rawData", activation)?
+        .coerce_to_object(activation)?;
+    let raw_data = raw_data
+        .as_vector_storage()
+        .map(|storage| storage.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let mut matrix = Matrix3D::IDENTITY;
+    for (element, value) in matrix.raw_data.iter_mut().zip(raw_data) {
+        *element = value.coerce_to_number(activation)?;
+    }
+    Ok(matrix)
+}
+
 pub fn get_pixel_bounds<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
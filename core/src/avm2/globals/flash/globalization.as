@@ -0,0 +1,7 @@
+package flash.globalization {
+    // Returns the defaults of the closest locale we have data for, along with the
+    // `lastOperationStatus` describing how that locale was chosen.
+    internal native function getLocaleData(requestedLocaleIDName:String):Object;
+
+    internal native function availableLocaleIDNames():Vector.<String>;
+}
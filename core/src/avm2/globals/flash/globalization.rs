@@ -0,0 +1,218 @@
+//! `flash.globalization` namespace
+
+use crate::avm2::object::{TObject, VectorObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::vector::VectorStorage;
+use crate::avm2::{Activation, Error, Object, Value};
+use crate::string::AvmString;
+use locale_data::{LocaleData, LOCALES};
+
+pub mod collator;
+pub mod currency_formatter;
+pub mod date_time_formatter;
+mod locale_data;
+pub mod number_formatter;
+pub mod string_tools;
+
+const NO_ERROR: &str = "noError";
+const USING_DEFAULT_WARNING: &str = "usingDefaultWarning";
+const USING_FALLBACK_WARNING: &str = "usingFallbackWarning";
+
+/// Finds the locale we have data for that is closest to `requested`.
+///
+/// The second value is true when the match was exact.
+fn find_locale(requested: &str) -> Option<(&'static LocaleData, bool)> {
+    let requested = requested.replace('_', "-");
+
+    if let Some(locale) = LOCALES
+        .iter()
+        .find(|locale| locale.name.eq_ignore_ascii_case(&requested))
+    {
+        return Some((locale, true));
+    }
+
+    let mut subtags = requested.split('-');
+    let language = subtags.next()?;
+
+    // Skip over any script or variant subtags, e.g. `zh-Hans-CN`.
+    let region = subtags.find(|subtag| {
+        subtag.len() == 2 || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+    });
+    if let Some(region) = region {
+        if let Some(locale) = LOCALES.iter().find(|locale| {
+            let mut parts = locale.name.split('-');
+            parts
+                .next()
+                .is_some_and(|l| l.eq_ignore_ascii_case(language))
+                && parts.next().is_some_and(|r| r.eq_ignore_ascii_case(region))
+        }) {
+            return Some((locale, false));
+        }
+    }
+
+    LOCALES
+        .iter()
+        .find(|locale| locale.language().eq_ignore_ascii_case(language))
+        .map(|locale| (locale, false))
+}
+
+/// Resolves a requested locale ID name, returning the locale to use and the
+/// `LastOperationStatus` describing how it was chosen.
+fn resolve_locale(
+    activation: &mut Activation<'_, '_>,
+    requested: &str,
+) -> (&'static LocaleData, &'static str) {
+    let ui_locale = find_locale(&activation.context.ui.language().to_string())
+        .map(|(locale, _)| locale)
+        .unwrap_or(&LOCALES[0]);
+
+    choose_locale(requested, ui_locale)
+}
+
+/// Picks the locale to use for `requested`, falling back to `ui_locale` when
+/// we have no data for its language.
+fn choose_locale(
+    requested: &str,
+    ui_locale: &'static LocaleData,
+) -> (&'static LocaleData, &'static str) {
+    if requested.eq_ignore_ascii_case("i-default") {
+        return (ui_locale, NO_ERROR);
+    }
+
+    match find_locale(requested) {
+        Some((locale, true)) => (locale, NO_ERROR),
+        Some((locale, false)) => (locale, USING_FALLBACK_WARNING),
+        None => (ui_locale, USING_DEFAULT_WARNING),
+    }
+}
+
+/// Returns the data for the locale that a `flash.globalization` object ended up using.
+fn actual_locale_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+) -> Result<&'static LocaleData, Error<'gc>> {
+    let name = this
+        .get_public_property("actualLocaleIDName", activation)?
+        .coerce_to_string(activation)?;
+    let name = name.to_utf8_lossy();
+
+    Ok(LOCALES
+        .iter()
+        .find(|locale| locale.name == name)
+        .unwrap_or(&LOCALES[0]))
+}
+
+/// Implements `flash.globalization.getLocaleData`, an internal helper used by
+/// the constructors of this package to pick up the defaults of a locale.
+pub fn get_locale_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let requested = args.get_string(activation, 0)?;
+    let (locale, status) = resolve_locale(activation, &requested.to_utf8_lossy());
+
+    let mc = activation.context.gc_context;
+    let string = |s: &str| Value::from(AvmString::new_utf8(mc, s));
+    let properties = [
+        ("actualLocaleIDName", locale.name.into()),
+        ("lastOperationStatus", status.into()),
+        ("decimalSeparator", string(locale.decimal_separator)),
+        ("groupingSeparator", string(locale.grouping_separator)),
+        ("groupingPattern", locale.grouping_pattern.into()),
+        ("negativeSymbol", string(locale.negative_symbol)),
+        ("negativeNumberFormat", locale.negative_number_format.into()),
+        ("currencyISOCode", locale.currency_iso_code.into()),
+        ("currencySymbol", string(locale.currency_symbol)),
+        (
+            "currencyFractionalDigits",
+            locale.currency_fractional_digits.into(),
+        ),
+        (
+            "positiveCurrencyFormat",
+            locale.positive_currency_format.into(),
+        ),
+        (
+            "negativeCurrencyFormat",
+            locale.negative_currency_format.into(),
+        ),
+        ("firstWeekday", locale.first_weekday.into()),
+    ];
+
+    let data = activation
+        .avm2()
+        .classes()
+        .object
+        .construct(activation, &[])?;
+    for (name, value) in properties {
+        data.set_public_property(name, value, activation)?;
+    }
+
+    Ok(data.into())
+}
+
+/// Implements `flash.globalization.availableLocaleIDNames`
+pub fn available_locale_id_names<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    name_vector(
+        activation,
+        LOCALES.iter().map(|locale| locale.name.to_string()),
+    )
+}
+
+/// Builds a `Vector.<String>` out of a list of names.
+fn name_vector<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    names: impl IntoIterator<Item = String>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mc = activation.context.gc_context;
+    let storage = VectorStorage::from_values(
+        names
+            .into_iter()
+            .map(|name| AvmString::new_utf8(mc, name).into())
+            .collect(),
+        false,
+        Some(activation.avm2().classes().string),
+    );
+
+    Ok(VectorObject::from_vector(storage, activation)?.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{choose_locale, LOCALES, NO_ERROR, USING_DEFAULT_WARNING, USING_FALLBACK_WARNING};
+
+    fn choose(requested: &str) -> (&'static str, &'static str) {
+        let ui_locale = LOCALES.iter().find(|l| l.name == "de-DE").unwrap();
+        let (locale, status) = choose_locale(requested, ui_locale);
+        (locale.name, status)
+    }
+
+    #[test]
+    fn exact_locales() {
+        assert_eq!(choose("en-US"), ("en-US", NO_ERROR));
+        assert_eq!(choose("en_gb"), ("en-GB", NO_ERROR));
+        assert_eq!(choose("ZH-tw"), ("zh-TW", NO_ERROR));
+        assert_eq!(choose("i-default"), ("de-DE", NO_ERROR));
+    }
+
+    #[test]
+    fn fallback_locales() {
+        assert_eq!(choose("fr"), ("fr-FR", USING_FALLBACK_WARNING));
+        assert_eq!(choose("fr-CA"), ("fr-FR", USING_FALLBACK_WARNING));
+        assert_eq!(choose("en-AU"), ("en-US", USING_FALLBACK_WARNING));
+        assert_eq!(choose("zh-Hant-TW"), ("zh-TW", USING_FALLBACK_WARNING));
+        assert_eq!(choose("zh_Hans_CN"), ("zh-CN", USING_FALLBACK_WARNING));
+        assert_eq!(choose("sv-SE-x-private"), ("sv-SE", USING_FALLBACK_WARNING));
+    }
+
+    #[test]
+    fn default_locales() {
+        assert_eq!(choose("xx-YY"), ("de-DE", USING_DEFAULT_WARNING));
+        assert_eq!(choose("tlh"), ("de-DE", USING_DEFAULT_WARNING));
+        assert_eq!(choose(""), ("de-DE", USING_DEFAULT_WARNING));
+    }
+}
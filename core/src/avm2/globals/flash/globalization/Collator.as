@@ -0,0 +1,120 @@
+package flash.globalization {
+    import flash.globalization.CollatorMode;
+    import flash.globalization.LastOperationStatus;
+
+    public final class Collator {
+        private var _actualLocaleIDName:String;
+        private var _ignoreCase:Boolean = false;
+        private var _ignoreCharacterWidth:Boolean = false;
+        private var _ignoreDiacritics:Boolean = false;
+        private var _ignoreKanaType:Boolean = false;
+        private var _ignoreSymbols:Boolean = false;
+        private var _lastOperationStatus:String;
+        private var _numericComparison:Boolean = false;
+        private var _requestedLocaleIDName:String;
+
+        public function Collator(requestedLocaleIDName:String, initialMode:String = "sorting") {
+            if (requestedLocaleIDName == null) {
+                throw new TypeError("Error #2007: Parameter requestedLocaleIDName must be non-null.", 2007);
+            }
+            if (initialMode == null) {
+                throw new TypeError("Error #2007: Parameter initialMode must be non-null.", 2007);
+            }
+            if (initialMode == CollatorMode.MATCHING) {
+                this._ignoreCase = true;
+                this._ignoreCharacterWidth = true;
+                this._ignoreDiacritics = true;
+                this._ignoreKanaType = true;
+            } else if (initialMode != CollatorMode.SORTING) {
+                throw new ArgumentError("Error #2008: Parameter initialMode must be one of the accepted values.", 2008);
+            }
+            this._requestedLocaleIDName = requestedLocaleIDName;
+
+            var data:Object = getLocaleData(requestedLocaleIDName);
+            this._actualLocaleIDName = data.actualLocaleIDName;
+            this._lastOperationStatus = data.lastOperationStatus;
+        }
+
+        private native function compareImpl(string1:String, string2:String):int;
+
+        public function get actualLocaleIDName():String {
+            return this._actualLocaleIDName;
+        }
+
+        public function get ignoreCase():Boolean {
+            return this._ignoreCase;
+        }
+        public function set ignoreCase(value:Boolean):void {
+            this._ignoreCase = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function get ignoreCharacterWidth():Boolean {
+            return this._ignoreCharacterWidth;
+        }
+        public function set ignoreCharacterWidth(value:Boolean):void {
+            this._ignoreCharacterWidth = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function get ignoreDiacritics():Boolean {
+            return this._ignoreDiacritics;
+        }
+        public function set ignoreDiacritics(value:Boolean):void {
+            this._ignoreDiacritics = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function get ignoreKanaType():Boolean {
+            return this._ignoreKanaType;
+        }
+        public function set ignoreKanaType(value:Boolean):void {
+            this._ignoreKanaType = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function get ignoreSymbols():Boolean {
+            return this._ignoreSymbols;
+        }
+        public function set ignoreSymbols(value:Boolean):void {
+            this._ignoreSymbols = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function get lastOperationStatus():String {
+            return this._lastOperationStatus;
+        }
+
+        public function get numericComparison():Boolean {
+            return this._numericComparison;
+        }
+        public function set numericComparison(value:Boolean):void {
+            this._numericComparison = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function get requestedLocaleIDName():String {
+            return this._requestedLocaleIDName;
+        }
+
+        public function compare(string1:String, string2:String):int {
+            if (string1 == null) {
+                throw new TypeError("Error #2007: Parameter string1 must be non-null.", 2007);
+            }
+            if (string2 == null) {
+                throw new TypeError("Error #2007: Parameter string2 must be non-null.", 2007);
+            }
+            var result:int = this.compareImpl(string1, string2);
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return result;
+        }
+
+        public function equals(string1:String, string2:String):Boolean {
+            return this.compare(string1, string2) == 0;
+        }
+
+        public static function getAvailableLocaleIDNames():Vector.<String> {
+            return availableLocaleIDNames();
+        }
+    }
+}
@@ -1,18 +1,212 @@
 package flash.globalization {
-    import __ruffle__.stub_constructor;
-    import __ruffle__.stub_method;
+    import flash.globalization.CurrencyParseResult;
+    import flash.globalization.LastOperationStatus;
+    import flash.globalization.NationalDigitsType;
 
     public final class CurrencyFormatter {
+        private var _actualLocaleIDName:String;
+        private var _currencyISOCode:String;
+        private var _currencySymbol:String;
+        private var _decimalSeparator:String;
+        private var _digitsType:uint = NationalDigitsType.EUROPEAN;
+        private var _fractionalDigits:int;
+        private var _groupingPattern:String;
+        private var _groupingSeparator:String;
+        private var _lastOperationStatus:String;
+        private var _leadingZero:Boolean = true;
+        private var _localeCurrencyISOCode:String;
+        private var _negativeCurrencyFormat:uint;
+        private var _negativeSymbol:String;
+        private var _positiveCurrencyFormat:uint;
+        private var _requestedLocaleIDName:String;
+        private var _trailingZeros:Boolean = true;
+        private var _useGrouping:Boolean = true;
 
-        public var fractionalDigits: int = 0;
+        public function CurrencyFormatter(requestedLocaleIDName:String) {
+            if (requestedLocaleIDName == null) {
+                throw new TypeError("Error #2007: Parameter requestedLocaleIDName must be non-null.", 2007);
+            }
+            this._requestedLocaleIDName = requestedLocaleIDName;
 
-        public function CurrencyFormatter(locale: String) {
-            stub_constructor("flash.globalization.CurrencyFormatter");
+            var data:Object = getLocaleData(requestedLocaleIDName);
+            this._actualLocaleIDName = data.actualLocaleIDName;
+            this._currencyISOCode = data.currencyISOCode;
+            this._localeCurrencyISOCode = data.currencyISOCode;
+            this._currencySymbol = data.currencySymbol;
+            this._decimalSeparator = data.decimalSeparator;
+            this._fractionalDigits = data.currencyFractionalDigits;
+            this._groupingPattern = data.groupingPattern;
+            this._groupingSeparator = data.groupingSeparator;
+            this._negativeCurrencyFormat = data.negativeCurrencyFormat;
+            this._negativeSymbol = data.negativeSymbol;
+            this._positiveCurrencyFormat = data.positiveCurrencyFormat;
+            this._lastOperationStatus = data.lastOperationStatus;
+        }
+
+        private native function formatImpl(value:Number, withCurrencySymbol:Boolean):String;
+
+        // Returns `[value, currencyString]`, or null if the input isn't a currency amount.
+        private native function parseImpl(inputString:String):Array;
+
+        private static function checkNonNull(value:String, name:String):void {
+            if (value == null) {
+                throw new TypeError("Error #2007: Parameter " + name + " must be non-null.", 2007);
+            }
+        }
+
+        public function get actualLocaleIDName():String {
+            return this._actualLocaleIDName;
+        }
+
+        public function get currencyISOCode():String {
+            return this._currencyISOCode;
+        }
+
+        public function get currencySymbol():String {
+            return this._currencySymbol;
+        }
+
+        public function get decimalSeparator():String {
+            return this._decimalSeparator;
+        }
+        public function set decimalSeparator(value:String):void {
+            checkNonNull(value, "decimalSeparator");
+            this._decimalSeparator = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function get digitsType():uint {
+            return this._digitsType;
+        }
+        public function set digitsType(value:uint):void {
+            this._digitsType = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function get fractionalDigits():int {
+            return this._fractionalDigits;
+        }
+        public function set fractionalDigits(value:int):void {
+            this._fractionalDigits = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function get groupingPattern():String {
+            return this._groupingPattern;
+        }
+        public function set groupingPattern(value:String):void {
+            checkNonNull(value, "groupingPattern");
+            this._groupingPattern = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function get groupingSeparator():String {
+            return this._groupingSeparator;
+        }
+        public function set groupingSeparator(value:String):void {
+            checkNonNull(value, "groupingSeparator");
+            this._groupingSeparator = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function get lastOperationStatus():String {
+            return this._lastOperationStatus;
+        }
+
+        public function get leadingZero():Boolean {
+            return this._leadingZero;
+        }
+        public function set leadingZero(value:Boolean):void {
+            this._leadingZero = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function get negativeCurrencyFormat():uint {
+            return this._negativeCurrencyFormat;
+        }
+        public function set negativeCurrencyFormat(value:uint):void {
+            if (value <= 15) {
+                this._negativeCurrencyFormat = value;
+                this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            } else {
+                this._lastOperationStatus = LastOperationStatus.ILLEGAL_ARGUMENT_ERROR;
+            }
+        }
+
+        public function get negativeSymbol():String {
+            return this._negativeSymbol;
+        }
+        public function set negativeSymbol(value:String):void {
+            checkNonNull(value, "negativeSymbol");
+            this._negativeSymbol = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function get positiveCurrencyFormat():uint {
+            return this._positiveCurrencyFormat;
+        }
+        public function set positiveCurrencyFormat(value:uint):void {
+            if (value <= 3) {
+                this._positiveCurrencyFormat = value;
+                this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            } else {
+                this._lastOperationStatus = LastOperationStatus.ILLEGAL_ARGUMENT_ERROR;
+            }
+        }
+
+        public function get requestedLocaleIDName():String {
+            return this._requestedLocaleIDName;
+        }
+
+        public function get trailingZeros():Boolean {
+            return this._trailingZeros;
+        }
+        public function set trailingZeros(value:Boolean):void {
+            this._trailingZeros = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function get useGrouping():Boolean {
+            return this._useGrouping;
+        }
+        public function set useGrouping(value:Boolean):void {
+            this._useGrouping = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
         }
 
         public function format(value:Number, withCurrencySymbol:Boolean = false):String {
-            stub_method("flash.globalization.CurrencyFormatter", "format");
-            return value.toString();
+            var result:String = this.formatImpl(value, withCurrencySymbol);
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return result;
+        }
+
+        public function formattingWithCurrencySymbolIsSafe(requestedISOCode:String):Boolean {
+            checkNonNull(requestedISOCode, "requestedISOCode");
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return requestedISOCode.toUpperCase() == this._localeCurrencyISOCode;
+        }
+
+        public static function getAvailableLocaleIDNames():Vector.<String> {
+            return availableLocaleIDNames();
+        }
+
+        public function parse(inputString:String):CurrencyParseResult {
+            checkNonNull(inputString, "inputString");
+            var result:Array = this.parseImpl(inputString);
+            if (result == null) {
+                this._lastOperationStatus = LastOperationStatus.PARSE_ERROR;
+                return new CurrencyParseResult();
+            }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return new CurrencyParseResult(result[0], result[1]);
+        }
+
+        public function setCurrency(currencyISOCode:String, currencySymbol:String):void {
+            checkNonNull(currencyISOCode, "currencyISOCode");
+            checkNonNull(currencySymbol, "currencySymbol");
+            this._currencyISOCode = currencyISOCode;
+            this._currencySymbol = currencySymbol;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
         }
     }
 }
@@ -0,0 +1,152 @@
+package flash.globalization {
+    import flash.globalization.DateTimeNameContext;
+    import flash.globalization.DateTimeNameStyle;
+    import flash.globalization.DateTimeStyle;
+    import flash.globalization.LastOperationStatus;
+
+    public final class DateTimeFormatter {
+        private var _actualLocaleIDName:String;
+        private var _dateStyle:String;
+        private var _firstWeekday:int;
+        private var _lastOperationStatus:String;
+        private var _pattern:String;
+        private var _requestedLocaleIDName:String;
+        private var _timeStyle:String;
+
+        public function DateTimeFormatter(requestedLocaleIDName:String, dateStyle:String = "long", timeStyle:String = "long") {
+            if (requestedLocaleIDName == null) {
+                throw new TypeError("Error #2007: Parameter requestedLocaleIDName must be non-null.", 2007);
+            }
+            this._requestedLocaleIDName = requestedLocaleIDName;
+
+            var data:Object = getLocaleData(requestedLocaleIDName);
+            this._actualLocaleIDName = data.actualLocaleIDName;
+            this._firstWeekday = data.firstWeekday;
+
+            this.setDateTimeStyles(dateStyle, timeStyle);
+            this._lastOperationStatus = data.lastOperationStatus;
+        }
+
+        private native function formatImpl(dateTime:Date, pattern:String, utc:Boolean):String;
+
+        // Returns null if either style isn't a valid `DateTimeStyle`.
+        private native function stylePattern(dateStyle:String, timeStyle:String):String;
+
+        private native function getNames(months:Boolean, nameStyle:String, context:String):Vector.<String>;
+
+        private static function checkNameArguments(nameStyle:String, context:String):void {
+            if (nameStyle == null) {
+                throw new TypeError("Error #2007: Parameter nameStyle must be non-null.", 2007);
+            }
+            if (context == null) {
+                throw new TypeError("Error #2007: Parameter context must be non-null.", 2007);
+            }
+            if (nameStyle != DateTimeNameStyle.FULL &&
+                nameStyle != DateTimeNameStyle.LONG_ABBREVIATION &&
+                nameStyle != DateTimeNameStyle.SHORT_ABBREVIATION) {
+                throw new ArgumentError("Error #2008: Parameter nameStyle must be one of the accepted values.", 2008);
+            }
+            if (context != DateTimeNameContext.FORMAT && context != DateTimeNameContext.STANDALONE) {
+                throw new ArgumentError("Error #2008: Parameter context must be one of the accepted values.", 2008);
+            }
+        }
+
+        public function get actualLocaleIDName():String {
+            return this._actualLocaleIDName;
+        }
+
+        public function get lastOperationStatus():String {
+            return this._lastOperationStatus;
+        }
+
+        public function get requestedLocaleIDName():String {
+            return this._requestedLocaleIDName;
+        }
+
+        public function format(dateTime:Date):String {
+            if (dateTime == null) {
+                throw new TypeError("Error #2007: Parameter dateTime must be non-null.", 2007);
+            }
+            var result:String = this.formatImpl(dateTime, this._pattern, false);
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return result;
+        }
+
+        public function formatUTC(dateTime:Date):String {
+            if (dateTime == null) {
+                throw new TypeError("Error #2007: Parameter dateTime must be non-null.", 2007);
+            }
+            var result:String = this.formatImpl(dateTime, this._pattern, true);
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return result;
+        }
+
+        public static function getAvailableLocaleIDNames():Vector.<String> {
+            return availableLocaleIDNames();
+        }
+
+        public function getDateStyle():String {
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return this._dateStyle;
+        }
+
+        public function getDateTimePattern():String {
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return this._pattern;
+        }
+
+        public function getFirstWeekday():int {
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return this._firstWeekday;
+        }
+
+        public function getMonthNames(nameStyle:String = "full", context:String = "standalone"):Vector.<String> {
+            checkNameArguments(nameStyle, context);
+            var result:Vector.<String> = this.getNames(true, nameStyle, context);
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return result;
+        }
+
+        public function getTimeStyle():String {
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return this._timeStyle;
+        }
+
+        public function getWeekdayNames(nameStyle:String = "full", context:String = "standalone"):Vector.<String> {
+            checkNameArguments(nameStyle, context);
+            var result:Vector.<String> = this.getNames(false, nameStyle, context);
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return result;
+        }
+
+        public function setDateTimePattern(pattern:String):void {
+            if (pattern == null) {
+                throw new TypeError("Error #2007: Parameter pattern must be non-null.", 2007);
+            }
+            this._pattern = pattern;
+            this._dateStyle = DateTimeStyle.CUSTOM;
+            this._timeStyle = DateTimeStyle.CUSTOM;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+
+        public function setDateTimeStyles(dateStyle:String, timeStyle:String):void {
+            if (dateStyle == null) {
+                throw new TypeError("Error #2007: Parameter dateStyle must be non-null.", 2007);
+            }
+            if (timeStyle == null) {
+                throw new TypeError("Error #2007: Parameter timeStyle must be non-null.", 2007);
+            }
+
+            var pattern:String = this.stylePattern(dateStyle, timeStyle);
+            if (pattern == null) {
+                var name:String = this.stylePattern(dateStyle, DateTimeStyle.NONE) == null ? "dateStyle" : "timeStyle";
+                throw new ArgumentError("Error #2008: Parameter " + name + " must be one of the accepted values.", 2008);
+            }
+
+            this._pattern = pattern;
+            this._dateStyle = dateStyle;
+            this._timeStyle = timeStyle;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+        }
+    }
+}
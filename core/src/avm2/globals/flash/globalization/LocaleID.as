@@ -1,61 +1,131 @@
 package flash.globalization {
-    import __ruffle__.stub_constructor;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_method;
     import flash.globalization.LastOperationStatus;
 
     public final class LocaleID {
         public static const DEFAULT:String = "i-default";
 
+        private static const RTL_SCRIPTS:Array = ["arab", "hebr", "nkoo", "syrc", "thaa"];
+        private static const RTL_LANGUAGES:Array = ["ar", "dv", "fa", "he", "iw", "ps", "sd", "ug", "ur", "yi"];
+
+        private var _keysAndValues:Object = {};
+        private var _language:String = "";
+        private var _lastOperationStatus:String = LastOperationStatus.NO_ERROR;
         private var _name:String;
+        private var _region:String = "";
+        private var _script:String = "";
+        private var _variant:String = "";
 
         public function LocaleID(name:String) {
-            stub_constructor("flash.globalization.LocaleID");
-            this._name = name;
+            if (name == null) {
+                throw new TypeError("Error #2007: Parameter name must be non-null.", 2007);
+            }
+            this._name = name.split("_").join("-");
+
+            // Keywords follow an `@`, as in `de-DE@collation=phonebook;currency=EUR`.
+            var subtags:Array = this._name.split("@");
+            if (subtags.length > 1) {
+                var keywords:Array = subtags[1].split(";");
+                for each (var keyword:String in keywords) {
+                    var parts:Array = keyword.split("=");
+                    if (parts.length == 2) {
+                        this._keysAndValues[parts[0]] = parts[1];
+                    }
+                }
+            }
+
+            subtags = subtags[0].split("-");
+            this._language = subtags.shift();
+            if (subtags.length > 0 && /^[A-Za-z]{4}$/.test(subtags[0])) {
+                this._script = subtags.shift();
+            }
+            if (subtags.length > 0 && /^([A-Za-z]{2}|[0-9]{3})$/.test(subtags[0])) {
+                this._region = subtags.shift();
+            }
+            this._variant = subtags.join("-");
+        }
+
+        private static function languageOf(name:String):String {
+            return name.split("_").join("-").split("-")[0].toLowerCase();
+        }
+
+        public static function determinePreferredLocales(want:Vector.<String>, have:Vector.<String>, keyword:String = "userinterface"):Vector.<String> {
+            if (want == null) {
+                throw new TypeError("Error #2007: Parameter want must be non-null.", 2007);
+            }
+            if (have == null) {
+                throw new TypeError("Error #2007: Parameter have must be non-null.", 2007);
+            }
+
+            var result:Vector.<String> = new <String>[];
+            var wanted:String;
+            var available:String;
+            for each (wanted in want) {
+                var normalized:String = wanted.split("_").join("-").toLowerCase();
+                var match:String = null;
+                for each (available in have) {
+                    if (available.split("_").join("-").toLowerCase() == normalized) {
+                        match = available;
+                        break;
+                    }
+                }
+                if (match == null) {
+                    for each (available in have) {
+                        if (languageOf(available) == languageOf(wanted)) {
+                            match = available;
+                            break;
+                        }
+                    }
+                }
+                if (match != null && result.indexOf(match) == -1) {
+                    result.push(match);
+                }
+            }
+            return result;
         }
 
         public function get lastOperationStatus():String {
-            stub_getter("flash.globalization.LocaleID", "lastOperationStatus");
-            return LastOperationStatus.NO_ERROR;
+            return this._lastOperationStatus;
         }
 
         public function get name():String {
             return this._name;
         }
 
-        public static function determinePreferredLocales(want:Vector.<String>, have:Vector.<String>, keyword:String = "userinterface"):Vector.<String> {
-            stub_method("flash.globalization.LocaleID", "determinePreferredLocales");
-            return null;
-        }
-
         public function getKeysAndValues():Object {
-            stub_method("flash.globalization.LocaleID", "getKeysAndValues");
-            return new Object();
+            var result:Object = {};
+            for (var key:String in this._keysAndValues) {
+                result[key] = this._keysAndValues[key];
+            }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return result;
         }
 
         public function getLanguage():String {
-            stub_method("flash.globalization.LocaleID", "getLanguage");
-            return this._name;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return this._language;
         }
 
         public function getRegion():String {
-            stub_method("flash.globalization.LocaleID", "getRegion");
-            return "";
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return this._region;
         }
 
         public function getScript():String {
-            stub_method("flash.globalization.LocaleID", "getScript");
-            return "";
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return this._script;
         }
 
         public function getVariant():String {
-            stub_method("flash.globalization.LocaleID", "getVariant");
-            return "";
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return this._variant;
         }
 
         public function isRightToLeft():Boolean {
-            stub_method("flash.globalization.LocaleID", "isRightToLeft");
-            return false;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            if (this._script != "") {
+                return RTL_SCRIPTS.indexOf(this._script.toLowerCase()) != -1;
+            }
+            return RTL_LANGUAGES.indexOf(this._language.toLowerCase()) != -1;
         }
     }
 }
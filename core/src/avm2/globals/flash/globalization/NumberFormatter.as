@@ -1,39 +1,61 @@
 package flash.globalization {
-    import __ruffle__.stub_constructor;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_method;
     import flash.globalization.LastOperationStatus;
     import flash.globalization.NationalDigitsType;
     import flash.globalization.NumberParseResult;
 
     public final class NumberFormatter {
-        private var _decimalSeparator = ".";
-        private var _digitsType = NationalDigitsType.EUROPEAN;
-        private var _fractionalDigits = 2;
-        private var _groupingPattern = "3;*";
-        private var _groupingSeparator = ",";
-        private var _leadingZero = true;
-        private var _localeIDName:String;
-        private var _negativeNumberFormat = 1;
-        private var _negativeSymbol = "-";
-        private var _trailingZeros = true;
-        private var _useGrouping = true;
+        private var _actualLocaleIDName:String;
+        private var _decimalSeparator:String;
+        private var _digitsType:uint = NationalDigitsType.EUROPEAN;
+        private var _fractionalDigits:int = 2;
+        private var _groupingPattern:String;
+        private var _groupingSeparator:String;
+        private var _lastOperationStatus:String;
+        private var _leadingZero:Boolean = true;
+        private var _negativeNumberFormat:uint;
+        private var _negativeSymbol:String;
+        private var _requestedLocaleIDName:String;
+        private var _trailingZeros:Boolean = true;
+        private var _useGrouping:Boolean = true;
 
         public function NumberFormatter(requestedLocaleIDName:String) {
-            stub_constructor("flash.globalization.NumberFormatter");
-            this._localeIDName = requestedLocaleIDName;
+            if (requestedLocaleIDName == null) {
+                throw new TypeError("Error #2007: Parameter requestedLocaleIDName must be non-null.", 2007);
+            }
+            this._requestedLocaleIDName = requestedLocaleIDName;
+
+            var data:Object = getLocaleData(requestedLocaleIDName);
+            this._actualLocaleIDName = data.actualLocaleIDName;
+            this._decimalSeparator = data.decimalSeparator;
+            this._groupingPattern = data.groupingPattern;
+            this._groupingSeparator = data.groupingSeparator;
+            this._negativeNumberFormat = data.negativeNumberFormat;
+            this._negativeSymbol = data.negativeSymbol;
+            this._lastOperationStatus = data.lastOperationStatus;
+        }
+
+        private native function formatImpl(value:Number, isInteger:Boolean):String;
+
+        // Returns `[value, startIndex, endIndex]`, or null if no number was found.
+        private native function parseImpl(parseString:String, wholeString:Boolean):Array;
+
+        private static function checkNonNull(value:String, name:String):void {
+            if (value == null) {
+                throw new TypeError("Error #2007: Parameter " + name + " must be non-null.", 2007);
+            }
         }
 
         public function get actualLocaleIDName():String {
-            stub_getter("flash.globalization.NumberFormatter", "actualLocaleIDName");
-            return this._localeIDName;
+            return this._actualLocaleIDName;
         }
 
         public function get decimalSeparator():String {
             return this._decimalSeparator;
         }
         public function set decimalSeparator(value:String):void {
+            checkNonNull(value, "decimalSeparator");
             this._decimalSeparator = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
         }
 
         public function get digitsType():uint {
@@ -41,6 +63,7 @@ package flash.globalization {
         }
         public function set digitsType(value:uint):void {
             this._digitsType = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
         }
 
         public function get fractionalDigits():int {
@@ -48,25 +71,29 @@ package flash.globalization {
         }
         public function set fractionalDigits(value:int):void {
             this._fractionalDigits = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
         }
 
         public function get groupingPattern():String {
             return this._groupingPattern;
         }
         public function set groupingPattern(value:String):void {
+            checkNonNull(value, "groupingPattern");
             this._groupingPattern = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
         }
 
         public function get groupingSeparator():String {
             return this._groupingSeparator;
         }
         public function set groupingSeparator(value:String):void {
+            checkNonNull(value, "groupingSeparator");
             this._groupingSeparator = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
         }
 
         public function get lastOperationStatus():String {
-            stub_getter("flash.globalization.NumberFormatter", "lastOperationStatus");
-            return LastOperationStatus.NO_ERROR;
+            return this._lastOperationStatus;
         }
 
         public function get leadingZero():Boolean {
@@ -74,6 +101,7 @@ package flash.globalization {
         }
         public function set leadingZero(value:Boolean):void {
             this._leadingZero = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
         }
 
         public function get negativeNumberFormat():uint {
@@ -82,6 +110,9 @@ package flash.globalization {
         public function set negativeNumberFormat(value:uint):void {
             if (value >= 0 && value <= 4) {
                 this._negativeNumberFormat = value;
+                this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            } else {
+                this._lastOperationStatus = LastOperationStatus.ILLEGAL_ARGUMENT_ERROR;
             }
         }
 
@@ -89,11 +120,13 @@ package flash.globalization {
             return this._negativeSymbol;
         }
         public function set negativeSymbol(value:String):void {
+            checkNonNull(value, "negativeSymbol");
             this._negativeSymbol = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
         }
 
         public function get requestedLocaleIDName():String {
-            return this._localeIDName;
+            return this._requestedLocaleIDName;
         }
 
         public function get trailingZeros():Boolean {
@@ -101,6 +134,7 @@ package flash.globalization {
         }
         public function set trailingZeros(value:Boolean):void {
             this._trailingZeros = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
         }
 
         public function get useGrouping():Boolean {
@@ -108,36 +142,51 @@ package flash.globalization {
         }
         public function set useGrouping(value:Boolean):void {
             this._useGrouping = value;
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
         }
 
         public function formatInt(value:int):String {
-            stub_method("flash.globalization.NumberFormatter", "formatInt");
-            return value.toString();
+            var result:String = this.formatImpl(value, true);
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return result;
         }
 
         public function formatNumber(value:Number):String {
-            stub_method("flash.globalization.NumberFormatter", "formatNumber");
-            return value.toString();
+            var result:String = this.formatImpl(value, false);
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return result;
         }
 
         public function formatUint(value:uint):String {
-            stub_method("flash.globalization.NumberFormatter", "formatUint");
-            return value.toString();
+            var result:String = this.formatImpl(value, true);
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return result;
         }
 
         public static function getAvailableLocaleIDNames():Vector.<String> {
-            stub_method("flash.globalization.NumberFormatter", "getAvailableLocaleIDNames");
-            return new <String>["en-US"];
+            return availableLocaleIDNames();
         }
 
         public function parse(parseString:String):NumberParseResult {
-            stub_method("flash.globalization.NumberFormatter", "parse");
-            return new NumberParseResult();
+            checkNonNull(parseString, "parseString");
+            var result:Array = this.parseImpl(parseString, false);
+            if (result == null) {
+                this._lastOperationStatus = LastOperationStatus.PARSE_ERROR;
+                return new NumberParseResult();
+            }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return new NumberParseResult(result[0], result[1], result[2]);
         }
 
         public function parseNumber(parseString:String):Number {
-            stub_method("flash.globalization.NumberFormatter", "parseNumber");
-            return Number(parseString);
+            checkNonNull(parseString, "parseString");
+            var result:Array = this.parseImpl(parseString, true);
+            if (result == null) {
+                this._lastOperationStatus = LastOperationStatus.PARSE_ERROR;
+                return NaN;
+            }
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return result[0];
         }
     }
 }
@@ -0,0 +1,58 @@
+package flash.globalization {
+    import flash.globalization.LastOperationStatus;
+
+    public final class StringTools {
+        private var _actualLocaleIDName:String;
+        private var _lastOperationStatus:String;
+        private var _requestedLocaleIDName:String;
+
+        public function StringTools(requestedLocaleIDName:String) {
+            if (requestedLocaleIDName == null) {
+                throw new TypeError("Error #2007: Parameter requestedLocaleIDName must be non-null.", 2007);
+            }
+            this._requestedLocaleIDName = requestedLocaleIDName;
+
+            var data:Object = getLocaleData(requestedLocaleIDName);
+            this._actualLocaleIDName = data.actualLocaleIDName;
+            this._lastOperationStatus = data.lastOperationStatus;
+        }
+
+        private native function toLowerCaseImpl(s:String):String;
+
+        private native function toUpperCaseImpl(s:String):String;
+
+        public function get actualLocaleIDName():String {
+            return this._actualLocaleIDName;
+        }
+
+        public function get lastOperationStatus():String {
+            return this._lastOperationStatus;
+        }
+
+        public function get requestedLocaleIDName():String {
+            return this._requestedLocaleIDName;
+        }
+
+        public static function getAvailableLocaleIDNames():Vector.<String> {
+            return availableLocaleIDNames();
+        }
+
+        public function toLowerCase(s:String):String {
+            if (s == null) {
+                throw new TypeError("Error #2007: Parameter s must be non-null.", 2007);
+            }
+            var result:String = this.toLowerCaseImpl(s);
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return result;
+        }
+
+        public function toUpperCase(s:String):String {
+            if (s == null) {
+                throw new TypeError("Error #2007: Parameter s must be non-null.", 2007);
+            }
+            var result:String = this.toUpperCaseImpl(s);
+            this._lastOperationStatus = LastOperationStatus.NO_ERROR;
+            return result;
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! `flash.globalization.Collator` native methods
+
+use crate::avm2::globals::flash::globalization::actual_locale_data;
+use crate::avm2::object::TObject;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Object, Value};
+use std::cmp::Ordering;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// The `ignore*` and `numericComparison` properties of a `Collator`.
+#[derive(Default)]
+pub struct CollatorOptions {
+    pub ignore_case: bool,
+    pub ignore_character_width: bool,
+    pub ignore_diacritics: bool,
+    pub ignore_kana_type: bool,
+    pub ignore_symbols: bool,
+    pub numeric_comparison: bool,
+}
+
+const VARIANT_UPPERCASE: u8 = 1 << 2;
+const VARIANT_WIDE: u8 = 1 << 1;
+const VARIANT_KATAKANA: u8 = 1;
+
+/// The base weight of a character, ignoring diacritics, case, width and kana type.
+///
+/// Symbols sort before digits, which sort before letters.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Primary {
+    Symbol(char),
+    Digit(u32),
+
+    /// A run of digits, compared by value. Holds the number of significant
+    /// digits, followed by the digits themselves.
+    Number(usize, String),
+
+    /// A letter's lowercase code point, doubled to leave room for tailorings.
+    Letter(u32),
+}
+
+struct CollationElement {
+    primary: Primary,
+    diacritics: Vec<char>,
+    variant: u8,
+}
+
+/// Returns the weight of letters that a language sorts differently from the
+/// root collation, e.g. `ñ` coming between `n` and `o` in Spanish.
+fn tailored_letter(language: &str, c: char) -> Option<u32> {
+    let after_z = 'z' as u32 * 2;
+    match (language, c) {
+        ("es", 'ñ') => Some('n' as u32 * 2 + 1),
+        ("sv" | "fi", 'å') => Some(after_z + 1),
+        ("sv" | "fi", 'ä' | 'æ') => Some(after_z + 2),
+        ("sv" | "fi", 'ö' | 'ø') => Some(after_z + 3),
+        ("da" | "nb" | "nn" | "no", 'æ' | 'ä') => Some(after_z + 1),
+        ("da" | "nb" | "nn" | "no", 'ø' | 'ö') => Some(after_z + 2),
+        ("da" | "nb" | "nn" | "no", 'å') => Some(after_z + 3),
+        _ => None,
+    }
+}
+
+fn collation_elements(
+    string: &str,
+    language: &str,
+    options: &CollatorOptions,
+) -> Vec<CollationElement> {
+    let mut elements: Vec<CollationElement> = Vec::new();
+    let mut in_number = false;
+
+    for c in string.chars() {
+        let mut variant = 0;
+
+        let c = if ('\u{FF01}'..='\u{FFEE}').contains(&c) {
+            variant |= VARIANT_WIDE;
+            c.to_string().nfkc().next().unwrap_or(c)
+        } else {
+            c
+        };
+
+        if c.is_uppercase() {
+            variant |= VARIANT_UPPERCASE;
+        }
+        let lower = c.to_lowercase().next().unwrap_or(c);
+        if let Some(weight) = tailored_letter(language, lower) {
+            elements.push(CollationElement {
+                primary: Primary::Letter(weight),
+                diacritics: Vec::new(),
+                variant,
+            });
+            in_number = false;
+            continue;
+        }
+
+        let mut decomposed = c.to_string().nfd().collect::<Vec<_>>().into_iter();
+        let Some(mut base) = decomposed.next() else {
+            continue;
+        };
+        let diacritics: Vec<char> = decomposed.collect();
+
+        if is_combining_mark(base) {
+            if let Some(previous) = elements.last_mut() {
+                previous.diacritics.push(base);
+                previous.diacritics.extend(diacritics);
+            }
+            continue;
+        }
+
+        if ('\u{30A1}'..='\u{30F6}').contains(&base) {
+            variant |= VARIANT_KATAKANA;
+            base = char::from_u32(base as u32 - 0x60).unwrap_or(base);
+        }
+
+        let primary = if let Some(digit) = base.to_digit(10).filter(|_| base.is_ascii_digit()) {
+            if options.numeric_comparison {
+                if in_number {
+                    if let Some(Primary::Number(len, digits)) =
+                        elements.last_mut().map(|e| &mut e.primary)
+                    {
+                        if !(digits.is_empty() && digit == 0) {
+                            digits.push(base);
+                            *len = digits.len();
+                        }
+                        continue;
+                    }
+                }
+                in_number = true;
+                if digit == 0 {
+                    Primary::Number(0, String::new())
+                } else {
+                    Primary::Number(1, base.to_string())
+                }
+            } else {
+                Primary::Digit(digit)
+            }
+        } else if base.is_alphabetic() {
+            in_number = false;
+            Primary::Letter(base.to_lowercase().next().unwrap_or(base) as u32 * 2)
+        } else {
+            in_number = false;
+            if options.ignore_symbols {
+                continue;
+            }
+            Primary::Symbol(base)
+        };
+
+        elements.push(CollationElement {
+            primary,
+            diacritics,
+            variant,
+        });
+    }
+
+    elements
+}
+
+/// Compares two strings the way a `Collator` for `language` would.
+pub fn compare(a: &str, b: &str, language: &str, options: &CollatorOptions) -> Ordering {
+    let a = collation_elements(a, language, options);
+    let b = collation_elements(b, language, options);
+
+    let mut variant_mask = 0;
+    if !options.ignore_case {
+        variant_mask |= VARIANT_UPPERCASE;
+    }
+    if !options.ignore_character_width {
+        variant_mask |= VARIANT_WIDE;
+    }
+    if !options.ignore_kana_type {
+        variant_mask |= VARIANT_KATAKANA;
+    }
+
+    a.iter()
+        .map(|e| &e.primary)
+        .cmp(b.iter().map(|e| &e.primary))
+        .then_with(|| {
+            if options.ignore_diacritics {
+                Ordering::Equal
+            } else {
+                a.iter()
+                    .map(|e| &e.diacritics)
+                    .cmp(b.iter().map(|e| &e.diacritics))
+            }
+        })
+        .then_with(|| {
+            a.iter()
+                .map(|e| e.variant & variant_mask)
+                .cmp(b.iter().map(|e| e.variant & variant_mask))
+        })
+}
+
+/// Implements `Collator.compareImpl`
+pub fn compare_impl<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let string1 = args.get_string(activation, 0)?;
+    let string2 = args.get_string(activation, 1)?;
+
+    let locale = actual_locale_data(activation, this)?;
+    let mut get_bool = |name| -> Result<bool, Error<'gc>> {
+        Ok(this
+            .get_public_property(name, activation)?
+            .coerce_to_boolean())
+    };
+    let options = CollatorOptions {
+        ignore_case: get_bool("ignoreCase")?,
+        ignore_character_width: get_bool("ignoreCharacterWidth")?,
+        ignore_diacritics: get_bool("ignoreDiacritics")?,
+        ignore_kana_type: get_bool("ignoreKanaType")?,
+        ignore_symbols: get_bool("ignoreSymbols")?,
+        numeric_comparison: get_bool("numericComparison")?,
+    };
+
+    let ordering = compare(
+        &string1.to_utf8_lossy(),
+        &string2.to_utf8_lossy(),
+        locale.language(),
+        &options,
+    );
+    Ok((ordering as i32).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare, CollatorOptions};
+    use std::cmp::Ordering;
+
+    #[test]
+    fn compares_strings() {
+        let sorting = CollatorOptions::default();
+        assert_eq!(compare("apple", "Apple", "en", &sorting), Ordering::Less);
+        assert_eq!(compare("Apple", "banana", "en", &sorting), Ordering::Less);
+        assert_eq!(compare("resume", "résumé", "en", &sorting), Ordering::Less);
+        assert_eq!(compare("résumé", "resumes", "en", &sorting), Ordering::Less);
+        assert_eq!(compare("file10", "file9", "en", &sorting), Ordering::Less);
+        assert_eq!(compare("ö", "z", "de", &sorting), Ordering::Less);
+        assert_eq!(compare("ö", "z", "sv", &sorting), Ordering::Greater);
+        assert_eq!(compare("ñ", "o", "es", &sorting), Ordering::Less);
+        assert_eq!(compare("ñ", "nz", "es", &sorting), Ordering::Greater);
+
+        let matching = CollatorOptions {
+            ignore_case: true,
+            ignore_character_width: true,
+            ignore_diacritics: true,
+            ignore_kana_type: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            compare("Résumé", "resume", "en", &matching),
+            Ordering::Equal
+        );
+        assert_eq!(compare("ＡＢＣ", "abc", "en", &matching), Ordering::Equal);
+        assert_eq!(
+            compare("カタカナ", "かたかな", "ja", &matching),
+            Ordering::Equal
+        );
+
+        let numeric = CollatorOptions {
+            numeric_comparison: true,
+            ignore_symbols: true,
+            ..Default::default()
+        };
+        assert_eq!(compare("file9", "file10", "en", &numeric), Ordering::Less);
+        assert_eq!(
+            compare("file010", "file10", "en", &numeric),
+            Ordering::Equal
+        );
+        assert_eq!(compare("a-b", "ab", "en", &numeric), Ordering::Equal);
+    }
+}
@@ -0,0 +1,139 @@
+//! `flash.globalization.CurrencyFormatter` native methods
+
+use crate::avm2::globals::flash::globalization::number_formatter::NumberFormat;
+use crate::avm2::object::{ArrayObject, TObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, ArrayStorage, Error, Object, Value};
+use crate::string::AvmString;
+
+/// Layouts for `positiveCurrencyFormat`, where `¤` is the currency and `n` the number.
+const POSITIVE_FORMATS: [&str; 4] = ["¤n", "n¤", "¤ n", "n ¤"];
+
+/// Layouts for `negativeCurrencyFormat`, where `-` is the negative symbol.
+const NEGATIVE_FORMATS: [&str; 16] = [
+    "(¤n)", "-¤n", "¤-n", "¤n-", "(n¤)", "-n¤", "n-¤", "n¤-", "-n ¤", "-¤ n", "n ¤-", "¤ n-",
+    "¤ -n", "n- ¤", "(¤ n)", "(n ¤)",
+];
+
+/// Lays out a formatted amount according to a currency format.
+///
+/// ISO codes are always kept apart from the number by a space, as they would
+/// otherwise run into it.
+fn apply_format(
+    layout: &str,
+    amount: &str,
+    currency: &str,
+    negative_symbol: &str,
+    is_iso_code: bool,
+) -> String {
+    let mut result = String::new();
+    let mut chars = layout.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '¤' => {
+                result.push_str(currency);
+                if is_iso_code && chars.peek() == Some(&'n') {
+                    result.push(' ');
+                }
+            }
+            'n' => {
+                result.push_str(amount);
+                if is_iso_code && chars.peek() == Some(&'¤') {
+                    result.push(' ');
+                }
+            }
+            '-' => result.push_str(negative_symbol),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// Implements `CurrencyFormatter.formatImpl`
+pub fn format_impl<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let value = args.get_f64(activation, 0)?;
+    let with_currency_symbol = args.get_bool(1);
+
+    let format = NumberFormat::from_formatter(activation, this)?;
+    let currency = if with_currency_symbol {
+        this.get_public_property("currencySymbol", activation)?
+    } else {
+        this.get_public_property("currencyISOCode", activation)?
+    }
+    .coerce_to_string(activation)?;
+    let positive_format = this
+        .get_public_property("positiveCurrencyFormat", activation)?
+        .coerce_to_u32(activation)?;
+    let negative_format = this
+        .get_public_property("negativeCurrencyFormat", activation)?
+        .coerce_to_u32(activation)?;
+
+    let (amount, non_zero) = format.format_magnitude(value, format.fractional_digits);
+    let layout = if value < 0.0 && non_zero {
+        NEGATIVE_FORMATS.get(negative_format as usize)
+    } else {
+        POSITIVE_FORMATS.get(positive_format as usize)
+    };
+    let formatted = apply_format(
+        layout.unwrap_or(&"¤n"),
+        &amount,
+        &currency.to_utf8_lossy(),
+        &format.negative_symbol,
+        !with_currency_symbol,
+    );
+
+    Ok(AvmString::new_utf8(activation.context.gc_context, formatted).into())
+}
+
+/// Implements `CurrencyFormatter.parseImpl`
+///
+/// Returns `[value, currencyString]`, or null if the input isn't a currency amount.
+pub fn parse_impl<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let input = args.get_string(activation, 0)?;
+
+    let format = NumberFormat::from_formatter(activation, this)?;
+    let input: Vec<char> = input.to_utf8_lossy().chars().collect();
+
+    // The currency string is the first run of characters that can't be part
+    // of the number itself.
+    let is_number_char = |c: &char| {
+        c.is_whitespace()
+            || c.is_ascii_digit()
+            || matches!(c, '(' | ')')
+            || format.decimal_separator.contains(*c)
+            || format.grouping_separator.contains(*c)
+            || format.negative_symbol.contains(*c)
+    };
+    let currency_start = input
+        .iter()
+        .position(|c| !is_number_char(c))
+        .unwrap_or(input.len());
+    let currency_end = input[currency_start..]
+        .iter()
+        .position(is_number_char)
+        .map_or(input.len(), |len| currency_start + len);
+
+    let mut amount = input[..currency_start].to_vec();
+    amount.extend_from_slice(&input[currency_end..]);
+    let Some((value, _, end)) = format.parse(&amount) else {
+        return Ok(Value::Null);
+    };
+    if !amount[end..].iter().all(|c| c.is_whitespace()) {
+        return Ok(Value::Null);
+    }
+
+    let currency_string: String = input[currency_start..currency_end].iter().collect();
+    let storage = ArrayStorage::from_args(&[
+        value.into(),
+        AvmString::new_utf8(activation.context.gc_context, currency_string).into(),
+    ]);
+    Ok(ArrayObject::from_storage(activation, storage)?.into())
+}
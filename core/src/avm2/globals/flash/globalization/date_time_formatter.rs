@@ -0,0 +1,210 @@
+//! `flash.globalization.DateTimeFormatter` native methods
+
+use crate::avm2::globals::flash::globalization::locale_data::LocaleData;
+use crate::avm2::globals::flash::globalization::{actual_locale_data, name_vector};
+use crate::avm2::object::TObject;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Object, Value};
+use crate::locale::get_timezone;
+use crate::string::AvmString;
+use chrono::{DateTime, Datelike, FixedOffset, Timelike};
+
+/// Returns the pattern for a `DateTimeStyle`, or `None` for an unknown style.
+fn pattern_for_style(patterns: &[&'static str; 3], style: &str) -> Option<&'static str> {
+    match style {
+        "long" => Some(patterns[0]),
+        "medium" => Some(patterns[1]),
+        "short" => Some(patterns[2]),
+        "none" => Some(""),
+        _ => None,
+    }
+}
+
+/// The narrow form of a name, e.g. `J` for January.
+fn narrow_name(name: &str) -> String {
+    let digits: String = name.chars().take_while(|c| c.is_ascii_digit()).collect();
+    if !digits.is_empty() {
+        return digits;
+    }
+    name.chars()
+        .next()
+        .map(|c| c.to_uppercase().collect())
+        .unwrap_or_default()
+}
+
+/// Formats a date according to a TR35 pattern, such as `yyyy-MM-dd HH:mm`.
+///
+/// Text in single quotes is copied as-is, and `''` produces a single quote.
+fn format_date(locale: &LocaleData, pattern: &str, date: DateTime<FixedOffset>) -> String {
+    let mut result = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            if chars.peek() == Some(&'\'') {
+                chars.next();
+                result.push('\'');
+                continue;
+            }
+            for c in chars.by_ref() {
+                if c == '\'' {
+                    break;
+                }
+                result.push(c);
+            }
+            continue;
+        }
+
+        if !c.is_ascii_alphabetic() {
+            result.push(c);
+            continue;
+        }
+
+        let mut count = 1;
+        while chars.peek() == Some(&c) {
+            chars.next();
+            count += 1;
+        }
+
+        let number = |value: u32| format!("{value:0count$}");
+        let month = date.month0() as usize;
+        let weekday = date.weekday().num_days_from_sunday() as usize;
+        match c {
+            'G' => result.push_str(locale.eras[usize::from(date.year() > 0)]),
+            'y' if count == 2 => result.push_str(&number(date.year().rem_euclid(100) as u32)),
+            'y' => result.push_str(&format!("{:0count$}", date.year())),
+            'M' | 'L' => {
+                let names = if c == 'M' {
+                    &locale.months
+                } else {
+                    &locale.standalone_months
+                };
+                match count {
+                    1 | 2 => result.push_str(&number(date.month())),
+                    3 => result.push_str(locale.short_months[month]),
+                    4 => result.push_str(names[month]),
+                    _ => result.push_str(&narrow_name(names[month])),
+                }
+            }
+            'd' => result.push_str(&number(date.day())),
+            'D' => result.push_str(&number(date.ordinal())),
+            'E' => match count {
+                1..=3 => result.push_str(locale.short_weekdays[weekday]),
+                4 => result.push_str(locale.weekdays[weekday]),
+                _ => result.push_str(&narrow_name(locale.weekdays[weekday])),
+            },
+            'Q' => result.push_str(&number(date.month0() / 3 + 1)),
+            'a' => result.push_str(locale.am_pm[usize::from(date.hour() >= 12)]),
+            'h' => result.push_str(&number(date.hour12().1)),
+            'H' => result.push_str(&number(date.hour())),
+            'K' => result.push_str(&number(date.hour() % 12)),
+            'k' => result.push_str(&number(if date.hour() == 0 { 24 } else { date.hour() })),
+            'm' => result.push_str(&number(date.minute())),
+            's' => result.push_str(&number(date.second())),
+            'S' => {
+                let millis = format!("{:03}", date.timestamp_subsec_millis());
+                result.push_str(&format!("{millis:0<count$.count$}"));
+            }
+            'z' | 'Z' | 'v' => {
+                let offset = date.offset().local_minus_utc();
+                let sign = if offset < 0 { '-' } else { '+' };
+                let (hours, minutes) = (offset.abs() / 3600, offset.abs() / 60 % 60);
+                if c == 'Z' {
+                    result.push_str(&format!("{sign}{hours:02}{minutes:02}"));
+                } else if offset == 0 {
+                    result.push_str("GMT");
+                } else {
+                    result.push_str(&format!("GMT{sign}{hours:02}:{minutes:02}"));
+                }
+            }
+            c => (0..count).for_each(|_| result.push(c)),
+        }
+    }
+    result
+}
+
+/// Implements `DateTimeFormatter.formatImpl`
+pub fn format_impl<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let date = args.get_object(activation, 0, "dateTime")?;
+    let pattern = args.get_string(activation, 1)?;
+    let utc = args.get_bool(2);
+
+    let locale = actual_locale_data(activation, this)?;
+    let Some(date_time) = date.as_date_object().and_then(|date| date.date_time()) else {
+        return Ok("".into());
+    };
+    let offset = if utc {
+        FixedOffset::east_opt(0).expect("UTC is a valid offset")
+    } else {
+        get_timezone()
+    };
+
+    let formatted = format_date(
+        locale,
+        &pattern.to_utf8_lossy(),
+        date_time.with_timezone(&offset),
+    );
+    Ok(AvmString::new_utf8(activation.context.gc_context, formatted).into())
+}
+
+/// Implements `DateTimeFormatter.stylePattern`
+///
+/// Returns the pattern for a combination of date and time styles, or null if
+/// either style isn't a valid `DateTimeStyle`.
+pub fn style_pattern<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let date_style = args.get_string(activation, 0)?.to_utf8_lossy().into_owned();
+    let time_style = args.get_string(activation, 1)?.to_utf8_lossy().into_owned();
+
+    let locale = actual_locale_data(activation, this)?;
+    let (Some(date), Some(time)) = (
+        pattern_for_style(&locale.date_patterns, &date_style),
+        pattern_for_style(&locale.time_patterns, &time_style),
+    ) else {
+        return Ok(Value::Null);
+    };
+
+    let pattern = match (date.is_empty(), time.is_empty()) {
+        (false, false) => format!("{date} {time}"),
+        _ => format!("{date}{time}"),
+    };
+    Ok(AvmString::new_utf8(activation.context.gc_context, pattern).into())
+}
+
+/// Implements `DateTimeFormatter.getNames`
+///
+/// The arguments have already been checked to be a valid `DateTimeNameStyle`
+/// and `DateTimeNameContext`.
+pub fn get_names<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let months = args.get_bool(0);
+    let name_style = args.get_string(activation, 1)?;
+    let context = args.get_string(activation, 2)?;
+
+    let locale = actual_locale_data(activation, this)?;
+    let standalone = &context == b"standalone";
+    let (full, abbreviated): (&[&str], &[&str]) = match (months, standalone) {
+        (true, true) => (&locale.standalone_months, &locale.short_months),
+        (true, false) => (&locale.months, &locale.short_months),
+        (false, _) => (&locale.weekdays, &locale.short_weekdays),
+    };
+
+    let names: Vec<String> = if &name_style == b"full" {
+        full.iter().map(|name| name.to_string()).collect()
+    } else if &name_style == b"longAbbreviation" {
+        abbreviated.iter().map(|name| name.to_string()).collect()
+    } else {
+        full.iter().map(|name| narrow_name(name)).collect()
+    };
+
+    name_vector(activation, names)
+}
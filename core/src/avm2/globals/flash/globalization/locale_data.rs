@@ -0,0 +1,788 @@
+//! Embedded locale data, extracted from the Unicode CLDR.
+//!
+//! Flash Player asks the operating system for this information, so the exact strings
+//! (particularly date and time patterns) can vary between platforms. We use CLDR's
+//! values for the locales we support, which is what most modern platforms agree on.
+
+pub struct LocaleData {
+    /// The normalized locale ID name, e.g. `en-US`.
+    pub name: &'static str,
+
+    pub decimal_separator: &'static str,
+    pub grouping_separator: &'static str,
+    pub grouping_pattern: &'static str,
+    pub negative_symbol: &'static str,
+    pub negative_number_format: u32,
+
+    pub currency_iso_code: &'static str,
+    pub currency_symbol: &'static str,
+    pub currency_fractional_digits: i32,
+    pub positive_currency_format: u32,
+    pub negative_currency_format: u32,
+
+    /// Abbreviated era names, BC first.
+    pub eras: [&'static str; 2],
+
+    /// Month names as used inside a formatted date.
+    pub months: [&'static str; 12],
+
+    /// Month names as used on their own, e.g. in a calendar header.
+    pub standalone_months: [&'static str; 12],
+
+    pub short_months: [&'static str; 12],
+
+    /// Weekday names, starting with Sunday.
+    pub weekdays: [&'static str; 7],
+
+    pub short_weekdays: [&'static str; 7],
+    pub am_pm: [&'static str; 2],
+
+    /// Long, medium and short date patterns.
+    pub date_patterns: [&'static str; 3],
+
+    /// Long, medium and short time patterns.
+    pub time_patterns: [&'static str; 3],
+
+    /// The first day of the week, where Sunday is 0.
+    pub first_weekday: u32,
+}
+
+impl LocaleData {
+    pub fn language(&self) -> &'static str {
+        self.name.split('-').next().unwrap_or(self.name)
+    }
+}
+
+const EN_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const EN_SHORT_MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+const EN_WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+const EN_SHORT_WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+const FR_MONTHS: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+
+const DE_MONTHS: [&str; 12] = [
+    "Januar",
+    "Februar",
+    "März",
+    "April",
+    "Mai",
+    "Juni",
+    "Juli",
+    "August",
+    "September",
+    "Oktober",
+    "November",
+    "Dezember",
+];
+
+const ES_MONTHS: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+
+const IT_MONTHS: [&str; 12] = [
+    "gennaio",
+    "febbraio",
+    "marzo",
+    "aprile",
+    "maggio",
+    "giugno",
+    "luglio",
+    "agosto",
+    "settembre",
+    "ottobre",
+    "novembre",
+    "dicembre",
+];
+
+const PT_MONTHS: [&str; 12] = [
+    "janeiro",
+    "fevereiro",
+    "março",
+    "abril",
+    "maio",
+    "junho",
+    "julho",
+    "agosto",
+    "setembro",
+    "outubro",
+    "novembro",
+    "dezembro",
+];
+
+const NL_MONTHS: [&str; 12] = [
+    "januari",
+    "februari",
+    "maart",
+    "april",
+    "mei",
+    "juni",
+    "juli",
+    "augustus",
+    "september",
+    "oktober",
+    "november",
+    "december",
+];
+
+const SV_MONTHS: [&str; 12] = [
+    "januari",
+    "februari",
+    "mars",
+    "april",
+    "maj",
+    "juni",
+    "juli",
+    "augusti",
+    "september",
+    "oktober",
+    "november",
+    "december",
+];
+
+const RU_MONTHS: [&str; 12] = [
+    "января",
+    "февраля",
+    "марта",
+    "апреля",
+    "мая",
+    "июня",
+    "июля",
+    "августа",
+    "сентября",
+    "октября",
+    "ноября",
+    "декабря",
+];
+
+const RU_STANDALONE_MONTHS: [&str; 12] = [
+    "январь",
+    "февраль",
+    "март",
+    "апрель",
+    "май",
+    "июнь",
+    "июль",
+    "август",
+    "сентябрь",
+    "октябрь",
+    "ноябрь",
+    "декабрь",
+];
+
+const PL_MONTHS: [&str; 12] = [
+    "stycznia",
+    "lutego",
+    "marca",
+    "kwietnia",
+    "maja",
+    "czerwca",
+    "lipca",
+    "sierpnia",
+    "września",
+    "października",
+    "listopada",
+    "grudnia",
+];
+
+const PL_STANDALONE_MONTHS: [&str; 12] = [
+    "styczeń",
+    "luty",
+    "marzec",
+    "kwiecień",
+    "maj",
+    "czerwiec",
+    "lipiec",
+    "sierpień",
+    "wrzesień",
+    "październik",
+    "listopad",
+    "grudzień",
+];
+
+const TR_MONTHS: [&str; 12] = [
+    "Ocak", "Şubat", "Mart", "Nisan", "Mayıs", "Haziran", "Temmuz", "Ağustos", "Eylül", "Ekim",
+    "Kasım", "Aralık",
+];
+
+const JA_MONTHS: [&str; 12] = [
+    "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+];
+
+const ZH_MONTHS: [&str; 12] = [
+    "一月",
+    "二月",
+    "三月",
+    "四月",
+    "五月",
+    "六月",
+    "七月",
+    "八月",
+    "九月",
+    "十月",
+    "十一月",
+    "十二月",
+];
+
+const ZH_WEEKDAYS: [&str; 7] = [
+    "星期日",
+    "星期一",
+    "星期二",
+    "星期三",
+    "星期四",
+    "星期五",
+    "星期六",
+];
+
+const KO_MONTHS: [&str; 12] = [
+    "1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월",
+];
+
+const EURO_TIME_PATTERNS: [&str; 3] = ["HH:mm:ss", "HH:mm:ss", "HH:mm"];
+
+/// Every locale we have data for.
+///
+/// When only a language is requested, the first locale for that language wins,
+/// so the most widely used region of each language comes first.
+pub static LOCALES: &[LocaleData] = &[
+    LocaleData {
+        name: "en-US",
+        decimal_separator: ".",
+        grouping_separator: ",",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "USD",
+        currency_symbol: "$",
+        currency_fractional_digits: 2,
+        positive_currency_format: 0,
+        negative_currency_format: 1,
+        eras: ["BC", "AD"],
+        months: EN_MONTHS,
+        standalone_months: EN_MONTHS,
+        short_months: EN_SHORT_MONTHS,
+        weekdays: EN_WEEKDAYS,
+        short_weekdays: EN_SHORT_WEEKDAYS,
+        am_pm: ["AM", "PM"],
+        date_patterns: ["EEEE, MMMM d, yyyy", "MMM d, yyyy", "M/d/yy"],
+        time_patterns: ["h:mm:ss a", "h:mm:ss a", "h:mm a"],
+        first_weekday: 0,
+    },
+    LocaleData {
+        name: "en-GB",
+        decimal_separator: ".",
+        grouping_separator: ",",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "GBP",
+        currency_symbol: "£",
+        currency_fractional_digits: 2,
+        positive_currency_format: 0,
+        negative_currency_format: 1,
+        eras: ["BC", "AD"],
+        months: EN_MONTHS,
+        standalone_months: EN_MONTHS,
+        short_months: EN_SHORT_MONTHS,
+        weekdays: EN_WEEKDAYS,
+        short_weekdays: EN_SHORT_WEEKDAYS,
+        am_pm: ["am", "pm"],
+        date_patterns: ["EEEE, d MMMM yyyy", "d MMM yyyy", "dd/MM/yyyy"],
+        time_patterns: EURO_TIME_PATTERNS,
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "fr-FR",
+        decimal_separator: ",",
+        grouping_separator: "\u{A0}",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "EUR",
+        currency_symbol: "€",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        eras: ["av. J.-C.", "ap. J.-C."],
+        months: FR_MONTHS,
+        standalone_months: FR_MONTHS,
+        short_months: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        weekdays: [
+            "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+        ],
+        short_weekdays: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+        am_pm: ["AM", "PM"],
+        date_patterns: ["EEEE d MMMM yyyy", "d MMM yyyy", "dd/MM/yyyy"],
+        time_patterns: EURO_TIME_PATTERNS,
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "de-DE",
+        decimal_separator: ",",
+        grouping_separator: ".",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "EUR",
+        currency_symbol: "€",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        eras: ["v. Chr.", "n. Chr."],
+        months: DE_MONTHS,
+        standalone_months: DE_MONTHS,
+        short_months: [
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+            "Dez.",
+        ],
+        weekdays: [
+            "Sonntag",
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+        ],
+        short_weekdays: ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+        am_pm: ["AM", "PM"],
+        date_patterns: ["EEEE, d. MMMM yyyy", "dd.MM.yyyy", "dd.MM.yy"],
+        time_patterns: EURO_TIME_PATTERNS,
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "es-ES",
+        decimal_separator: ",",
+        grouping_separator: ".",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "EUR",
+        currency_symbol: "€",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        eras: ["a. C.", "d. C."],
+        months: ES_MONTHS,
+        standalone_months: ES_MONTHS,
+        short_months: [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ],
+        weekdays: [
+            "domingo",
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+        ],
+        short_weekdays: ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+        am_pm: ["a. m.", "p. m."],
+        date_patterns: ["EEEE, d 'de' MMMM 'de' yyyy", "d MMM yyyy", "d/M/yy"],
+        time_patterns: ["H:mm:ss", "H:mm:ss", "H:mm"],
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "it-IT",
+        decimal_separator: ",",
+        grouping_separator: ".",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "EUR",
+        currency_symbol: "€",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        eras: ["a.C.", "d.C."],
+        months: IT_MONTHS,
+        standalone_months: IT_MONTHS,
+        short_months: [
+            "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+        ],
+        weekdays: [
+            "domenica",
+            "lunedì",
+            "martedì",
+            "mercoledì",
+            "giovedì",
+            "venerdì",
+            "sabato",
+        ],
+        short_weekdays: ["dom", "lun", "mar", "mer", "gio", "ven", "sab"],
+        am_pm: ["AM", "PM"],
+        date_patterns: ["EEEE d MMMM yyyy", "d MMM yyyy", "dd/MM/yy"],
+        time_patterns: EURO_TIME_PATTERNS,
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "pt-BR",
+        decimal_separator: ",",
+        grouping_separator: ".",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "BRL",
+        currency_symbol: "R$",
+        currency_fractional_digits: 2,
+        positive_currency_format: 2,
+        negative_currency_format: 9,
+        eras: ["a.C.", "d.C."],
+        months: PT_MONTHS,
+        standalone_months: PT_MONTHS,
+        short_months: [
+            "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.",
+            "dez.",
+        ],
+        weekdays: [
+            "domingo",
+            "segunda-feira",
+            "terça-feira",
+            "quarta-feira",
+            "quinta-feira",
+            "sexta-feira",
+            "sábado",
+        ],
+        short_weekdays: ["dom.", "seg.", "ter.", "qua.", "qui.", "sex.", "sáb."],
+        am_pm: ["AM", "PM"],
+        date_patterns: [
+            "EEEE, d 'de' MMMM 'de' yyyy",
+            "d 'de' MMM 'de' yyyy",
+            "dd/MM/yyyy",
+        ],
+        time_patterns: EURO_TIME_PATTERNS,
+        first_weekday: 0,
+    },
+    LocaleData {
+        name: "nl-NL",
+        decimal_separator: ",",
+        grouping_separator: ".",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "EUR",
+        currency_symbol: "€",
+        currency_fractional_digits: 2,
+        positive_currency_format: 2,
+        negative_currency_format: 12,
+        eras: ["v.Chr.", "n.Chr."],
+        months: NL_MONTHS,
+        standalone_months: NL_MONTHS,
+        short_months: [
+            "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+        ],
+        weekdays: [
+            "zondag",
+            "maandag",
+            "dinsdag",
+            "woensdag",
+            "donderdag",
+            "vrijdag",
+            "zaterdag",
+        ],
+        short_weekdays: ["zo", "ma", "di", "wo", "do", "vr", "za"],
+        am_pm: ["a.m.", "p.m."],
+        date_patterns: ["EEEE d MMMM yyyy", "d MMM yyyy", "dd-MM-yyyy"],
+        time_patterns: EURO_TIME_PATTERNS,
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "sv-SE",
+        decimal_separator: ",",
+        grouping_separator: "\u{A0}",
+        grouping_pattern: "3;*",
+        negative_symbol: "\u{2212}",
+        negative_number_format: 1,
+        currency_iso_code: "SEK",
+        currency_symbol: "kr",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        eras: ["f.Kr.", "e.Kr."],
+        months: SV_MONTHS,
+        standalone_months: SV_MONTHS,
+        short_months: [
+            "jan.", "feb.", "mars", "apr.", "maj", "juni", "juli", "aug.", "sep.", "okt.", "nov.",
+            "dec.",
+        ],
+        weekdays: [
+            "söndag", "måndag", "tisdag", "onsdag", "torsdag", "fredag", "lördag",
+        ],
+        short_weekdays: ["sön", "mån", "tis", "ons", "tors", "fre", "lör"],
+        am_pm: ["fm", "em"],
+        date_patterns: ["EEEE d MMMM yyyy", "d MMM yyyy", "yyyy-MM-dd"],
+        time_patterns: EURO_TIME_PATTERNS,
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "ru-RU",
+        decimal_separator: ",",
+        grouping_separator: "\u{A0}",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "RUB",
+        currency_symbol: "₽",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        eras: ["до н. э.", "н. э."],
+        months: RU_MONTHS,
+        standalone_months: RU_STANDALONE_MONTHS,
+        short_months: [
+            "янв.",
+            "февр.",
+            "мар.",
+            "апр.",
+            "мая",
+            "июн.",
+            "июл.",
+            "авг.",
+            "сент.",
+            "окт.",
+            "нояб.",
+            "дек.",
+        ],
+        weekdays: [
+            "воскресенье",
+            "понедельник",
+            "вторник",
+            "среда",
+            "четверг",
+            "пятница",
+            "суббота",
+        ],
+        short_weekdays: ["вс", "пн", "вт", "ср", "чт", "пт", "сб"],
+        am_pm: ["AM", "PM"],
+        date_patterns: ["EEEE, d MMMM yyyy 'г'.", "d MMM yyyy 'г'.", "dd.MM.yyyy"],
+        time_patterns: EURO_TIME_PATTERNS,
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "pl-PL",
+        decimal_separator: ",",
+        grouping_separator: "\u{A0}",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "PLN",
+        currency_symbol: "zł",
+        currency_fractional_digits: 2,
+        positive_currency_format: 3,
+        negative_currency_format: 8,
+        eras: ["p.n.e.", "n.e."],
+        months: PL_MONTHS,
+        standalone_months: PL_STANDALONE_MONTHS,
+        short_months: [
+            "sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru",
+        ],
+        weekdays: [
+            "niedziela",
+            "poniedziałek",
+            "wtorek",
+            "środa",
+            "czwartek",
+            "piątek",
+            "sobota",
+        ],
+        short_weekdays: ["niedz.", "pon.", "wt.", "śr.", "czw.", "pt.", "sob."],
+        am_pm: ["AM", "PM"],
+        date_patterns: ["EEEE, d MMMM yyyy", "d MMM yyyy", "dd.MM.yyyy"],
+        time_patterns: EURO_TIME_PATTERNS,
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "tr-TR",
+        decimal_separator: ",",
+        grouping_separator: ".",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "TRY",
+        currency_symbol: "₺",
+        currency_fractional_digits: 2,
+        positive_currency_format: 0,
+        negative_currency_format: 1,
+        eras: ["MÖ", "MS"],
+        months: TR_MONTHS,
+        standalone_months: TR_MONTHS,
+        short_months: [
+            "Oca", "Şub", "Mar", "Nis", "May", "Haz", "Tem", "Ağu", "Eyl", "Eki", "Kas", "Ara",
+        ],
+        weekdays: [
+            "Pazar",
+            "Pazartesi",
+            "Salı",
+            "Çarşamba",
+            "Perşembe",
+            "Cuma",
+            "Cumartesi",
+        ],
+        short_weekdays: ["Paz", "Pzt", "Sal", "Çar", "Per", "Cum", "Cmt"],
+        am_pm: ["ÖÖ", "ÖS"],
+        date_patterns: ["d MMMM yyyy EEEE", "d MMM yyyy", "d.MM.yyyy"],
+        time_patterns: EURO_TIME_PATTERNS,
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "ja-JP",
+        decimal_separator: ".",
+        grouping_separator: ",",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "JPY",
+        currency_symbol: "￥",
+        currency_fractional_digits: 0,
+        positive_currency_format: 0,
+        negative_currency_format: 1,
+        eras: ["紀元前", "西暦"],
+        months: JA_MONTHS,
+        standalone_months: JA_MONTHS,
+        short_months: JA_MONTHS,
+        weekdays: [
+            "日曜日",
+            "月曜日",
+            "火曜日",
+            "水曜日",
+            "木曜日",
+            "金曜日",
+            "土曜日",
+        ],
+        short_weekdays: ["日", "月", "火", "水", "木", "金", "土"],
+        am_pm: ["午前", "午後"],
+        date_patterns: ["yyyy年M月d日EEEE", "yyyy/MM/dd", "yyyy/MM/dd"],
+        time_patterns: ["H:mm:ss", "H:mm:ss", "H:mm"],
+        first_weekday: 0,
+    },
+    LocaleData {
+        name: "zh-CN",
+        decimal_separator: ".",
+        grouping_separator: ",",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "CNY",
+        currency_symbol: "¥",
+        currency_fractional_digits: 2,
+        positive_currency_format: 0,
+        negative_currency_format: 1,
+        eras: ["公元前", "公元"],
+        months: ZH_MONTHS,
+        standalone_months: ZH_MONTHS,
+        short_months: JA_MONTHS,
+        weekdays: ZH_WEEKDAYS,
+        short_weekdays: ["周日", "周一", "周二", "周三", "周四", "周五", "周六"],
+        am_pm: ["上午", "下午"],
+        date_patterns: ["yyyy年M月d日EEEE", "yyyy年M月d日", "yyyy/M/d"],
+        time_patterns: EURO_TIME_PATTERNS,
+        first_weekday: 1,
+    },
+    LocaleData {
+        name: "zh-TW",
+        decimal_separator: ".",
+        grouping_separator: ",",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "TWD",
+        currency_symbol: "$",
+        currency_fractional_digits: 2,
+        positive_currency_format: 0,
+        negative_currency_format: 1,
+        eras: ["西元前", "西元"],
+        months: JA_MONTHS,
+        standalone_months: JA_MONTHS,
+        short_months: JA_MONTHS,
+        weekdays: ZH_WEEKDAYS,
+        short_weekdays: ["週日", "週一", "週二", "週三", "週四", "週五", "週六"],
+        am_pm: ["上午", "下午"],
+        date_patterns: ["yyyy年M月d日 EEEE", "yyyy年M月d日", "yyyy/M/d"],
+        time_patterns: ["ah:mm:ss", "ah:mm:ss", "ah:mm"],
+        first_weekday: 0,
+    },
+    LocaleData {
+        name: "ko-KR",
+        decimal_separator: ".",
+        grouping_separator: ",",
+        grouping_pattern: "3;*",
+        negative_symbol: "-",
+        negative_number_format: 1,
+        currency_iso_code: "KRW",
+        currency_symbol: "₩",
+        currency_fractional_digits: 0,
+        positive_currency_format: 0,
+        negative_currency_format: 1,
+        eras: ["기원전", "서기"],
+        months: KO_MONTHS,
+        standalone_months: KO_MONTHS,
+        short_months: KO_MONTHS,
+        weekdays: [
+            "일요일",
+            "월요일",
+            "화요일",
+            "수요일",
+            "목요일",
+            "금요일",
+            "토요일",
+        ],
+        short_weekdays: ["일", "월", "화", "수", "목", "금", "토"],
+        am_pm: ["오전", "오후"],
+        date_patterns: ["yyyy년 M월 d일 EEEE", "yyyy. M. d.", "yy. M. d."],
+        time_patterns: ["a h:mm:ss", "a h:mm:ss", "a h:mm"],
+        first_weekday: 0,
+    },
+];
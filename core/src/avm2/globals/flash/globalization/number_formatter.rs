@@ -0,0 +1,484 @@
+//! `flash.globalization.NumberFormatter` native methods
+
+use crate::avm2::object::{ArrayObject, TObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, ArrayStorage, Error, Object, Value};
+use crate::string::AvmString;
+
+/// The formatting settings shared by `NumberFormatter` and `CurrencyFormatter`.
+pub struct NumberFormat {
+    pub decimal_separator: String,
+    pub digits_type: u32,
+    pub fractional_digits: usize,
+    pub grouping_pattern: String,
+    pub grouping_separator: String,
+    pub leading_zero: bool,
+    pub negative_symbol: String,
+    pub trailing_zeros: bool,
+    pub use_grouping: bool,
+}
+
+impl NumberFormat {
+    /// Reads the current settings of a `NumberFormatter` or `CurrencyFormatter`.
+    pub fn from_formatter<'gc>(
+        activation: &mut Activation<'_, 'gc>,
+        formatter: Object<'gc>,
+    ) -> Result<Self, Error<'gc>> {
+        let mut get_string = |name| -> Result<String, Error<'gc>> {
+            Ok(formatter
+                .get_public_property(name, activation)?
+                .coerce_to_string(activation)?
+                .to_utf8_lossy()
+                .into_owned())
+        };
+        let decimal_separator = get_string("decimalSeparator")?;
+        let grouping_pattern = get_string("groupingPattern")?;
+        let grouping_separator = get_string("groupingSeparator")?;
+        let negative_symbol = get_string("negativeSymbol")?;
+
+        Ok(Self {
+            decimal_separator,
+            digits_type: formatter
+                .get_public_property("digitsType", activation)?
+                .coerce_to_u32(activation)?,
+            fractional_digits: formatter
+                .get_public_property("fractionalDigits", activation)?
+                .coerce_to_i32(activation)?
+                .clamp(0, 20) as usize,
+            grouping_pattern,
+            grouping_separator,
+            leading_zero: formatter
+                .get_public_property("leadingZero", activation)?
+                .coerce_to_boolean(),
+            negative_symbol,
+            trailing_zeros: formatter
+                .get_public_property("trailingZeros", activation)?
+                .coerce_to_boolean(),
+            use_grouping: formatter
+                .get_public_property("useGrouping", activation)?
+                .coerce_to_boolean(),
+        })
+    }
+
+    /// Parses the grouping pattern into group sizes, ordered from the decimal
+    /// separator outwards, and whether the last size repeats.
+    ///
+    /// Returns `None` if the pattern is invalid.
+    fn grouping_sizes(&self) -> Option<(Vec<usize>, bool)> {
+        let mut sizes = Vec::new();
+        let mut repeat = false;
+        for part in self.grouping_pattern.split(';') {
+            if repeat {
+                return None;
+            }
+            if part == "*" {
+                repeat = true;
+            } else {
+                match part.parse::<usize>() {
+                    Ok(size @ 1..=9) => sizes.push(size),
+                    _ => return None,
+                }
+            }
+        }
+
+        if sizes.is_empty() {
+            return None;
+        }
+        Some((sizes, repeat))
+    }
+
+    /// Inserts grouping separators into a string of integer digits.
+    fn group(&self, digits: &str) -> String {
+        let Some((sizes, repeat)) = self.grouping_sizes().filter(|_| self.use_grouping) else {
+            return digits.to_string();
+        };
+
+        let mut groups = Vec::new();
+        let mut rest = digits;
+        let mut sizes = sizes.iter().copied();
+        let mut size = sizes.next();
+        while let Some(current) = size {
+            if rest.len() <= current {
+                break;
+            }
+            let (head, tail) = rest.split_at(rest.len() - current);
+            groups.push(tail);
+            rest = head;
+            size = sizes.next().or(if repeat { Some(current) } else { None });
+        }
+        groups.push(rest);
+        groups.reverse();
+
+        groups.join(&self.grouping_separator)
+    }
+
+    /// Maps European digits onto the digit set selected by `digitsType`.
+    fn localize_digits(&self, formatted: String) -> String {
+        if self.digits_type == '0' as u32 {
+            return formatted;
+        }
+
+        formatted
+            .chars()
+            .map(|c| match c.to_digit(10) {
+                Some(digit) if c.is_ascii_digit() => {
+                    char::from_u32(self.digits_type + digit).unwrap_or(c)
+                }
+                _ => c,
+            })
+            .collect()
+    }
+
+    /// Formats the magnitude of a number, without any negative sign.
+    ///
+    /// The second value is true if the formatted number is not zero.
+    pub fn format_magnitude(&self, value: f64, fractional_digits: usize) -> (String, bool) {
+        if value.is_nan() {
+            return ("NaN".to_string(), true);
+        }
+        if value.is_infinite() {
+            return ("∞".to_string(), true);
+        }
+
+        // Round halves away from zero, rather than to even as Rust does.
+        let scale = 10f64.powi(fractional_digits as i32);
+        let magnitude = if value.abs() * scale < 2f64.powi(53) {
+            (value.abs() * scale).round() / scale
+        } else {
+            value.abs()
+        };
+
+        let formatted = format!("{:.*}", fractional_digits, magnitude);
+        let (integer, fraction) = formatted
+            .split_once('.')
+            .unwrap_or((formatted.as_str(), ""));
+        let non_zero = formatted.bytes().any(|b| matches!(b, b'1'..=b'9'));
+
+        let fraction = if self.trailing_zeros {
+            fraction
+        } else {
+            fraction.trim_end_matches('0')
+        };
+        let integer = if integer == "0" && !self.leading_zero && !fraction.is_empty() {
+            String::new()
+        } else {
+            self.group(integer)
+        };
+
+        let mut result = integer;
+        if !fraction.is_empty() {
+            result.push_str(&self.decimal_separator);
+            result.push_str(fraction);
+        }
+
+        (self.localize_digits(result), non_zero)
+    }
+
+    /// Formats a number, laying out negative numbers according to `negativeNumberFormat`.
+    pub fn format(
+        &self,
+        value: f64,
+        fractional_digits: usize,
+        negative_number_format: u32,
+    ) -> String {
+        let (magnitude, non_zero) = self.format_magnitude(value, fractional_digits);
+        if !(value < 0.0 && non_zero) {
+            return magnitude;
+        }
+
+        let negative = &self.negative_symbol;
+        match negative_number_format {
+            0 => format!("({magnitude})"),
+            2 => format!("{negative} {magnitude}"),
+            3 => format!("{magnitude}{negative}"),
+            4 => format!("{magnitude} {negative}"),
+            _ => format!("{negative}{magnitude}"),
+        }
+    }
+
+    /// Returns the value of `c` if it is a digit in either the European
+    /// digit set or the one selected by `digitsType`.
+    fn digit_value(&self, c: char) -> Option<u32> {
+        if c.is_ascii_digit() {
+            return c.to_digit(10);
+        }
+        (c as u32)
+            .checked_sub(self.digits_type)
+            .filter(|digit| *digit < 10)
+    }
+
+    /// Reads the digits of a number starting at `pos`, returning them as a
+    /// string that Rust can parse, along with the position after the number.
+    fn parse_digits(&self, input: &[char], mut pos: usize) -> Option<(String, usize)> {
+        let decimal: Vec<char> = self.decimal_separator.chars().collect();
+        let grouping: Vec<char> = self.grouping_separator.chars().collect();
+        let is_space_grouping = !grouping.is_empty() && grouping.iter().all(|c| c.is_whitespace());
+
+        let mut digits = String::new();
+        let mut seen_decimal = false;
+        let mut seen_digit = false;
+        while pos < input.len() {
+            if let Some(digit) = self.digit_value(input[pos]) {
+                digits.push(char::from_digit(digit, 10).unwrap_or('0'));
+                seen_digit = true;
+                pos += 1;
+            } else if !seen_decimal && !decimal.is_empty() && input[pos..].starts_with(&decimal) {
+                digits.push('.');
+                seen_decimal = true;
+                pos += decimal.len();
+            } else if seen_digit && !seen_decimal {
+                // Grouping separators are only allowed between digits.
+                let separator_len = if !grouping.is_empty() && input[pos..].starts_with(&grouping) {
+                    grouping.len()
+                } else if is_space_grouping && input[pos].is_whitespace() {
+                    1
+                } else {
+                    break;
+                };
+                match input.get(pos + separator_len) {
+                    Some(c) if self.digit_value(*c).is_some() => pos += separator_len,
+                    _ => break,
+                }
+            } else {
+                break;
+            }
+        }
+
+        if !seen_digit {
+            return None;
+        }
+        Some((digits, pos))
+    }
+
+    /// Parses a number at the start of `input`, after any leading whitespace.
+    ///
+    /// Returns the value and the range of characters that made up the number.
+    pub fn parse(&self, input: &[char]) -> Option<(f64, usize, usize)> {
+        let negative: Vec<char> = self.negative_symbol.chars().collect();
+        let skip_whitespace = |mut pos: usize| {
+            while input.get(pos).is_some_and(|c| c.is_whitespace()) {
+                pos += 1;
+            }
+            pos
+        };
+
+        let start = skip_whitespace(0);
+        let mut pos = start;
+        let mut parenthesized = false;
+        let mut is_negative = false;
+        if input.get(pos) == Some(&'(') {
+            parenthesized = true;
+            pos = skip_whitespace(pos + 1);
+        } else if !negative.is_empty() && input[pos..].starts_with(&negative) {
+            is_negative = true;
+            pos = skip_whitespace(pos + negative.len());
+        }
+
+        let (digits, mut end) = self.parse_digits(input, pos)?;
+
+        if parenthesized {
+            let close = skip_whitespace(end);
+            if input.get(close) != Some(&')') {
+                return None;
+            }
+            is_negative = true;
+            end = close + 1;
+        } else if !is_negative && !negative.is_empty() {
+            let suffix = skip_whitespace(end);
+            if input[suffix..].starts_with(&negative) {
+                is_negative = true;
+                end = suffix + negative.len();
+            }
+        }
+
+        let value = digits.parse::<f64>().ok()?;
+        Some((if is_negative { -value } else { value }, start, end))
+    }
+}
+
+/// Converts an index into a list of chars into a UTF-16 index.
+pub fn utf16_index(input: &[char], index: usize) -> usize {
+    input[..index].iter().map(|c| c.len_utf16()).sum()
+}
+
+/// Implements `NumberFormatter.formatImpl`
+pub fn format_impl<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let value = args.get_f64(activation, 0)?;
+    let is_integer = args.get_bool(1);
+
+    let format = NumberFormat::from_formatter(activation, this)?;
+    let negative_number_format = this
+        .get_public_property("negativeNumberFormat", activation)?
+        .coerce_to_u32(activation)?;
+    let fractional_digits = if is_integer {
+        0
+    } else {
+        format.fractional_digits
+    };
+
+    let formatted = format.format(value, fractional_digits, negative_number_format);
+    Ok(AvmString::new_utf8(activation.context.gc_context, formatted).into())
+}
+
+/// Implements `NumberFormatter.parseImpl`
+///
+/// Returns `[value, startIndex, endIndex]`, or null if no number could be found.
+/// When `wholeString` is true, nothing but whitespace may follow the number.
+pub fn parse_impl<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let input = args.get_string(activation, 0)?;
+    let whole_string = args.get_bool(1);
+
+    let format = NumberFormat::from_formatter(activation, this)?;
+    let input: Vec<char> = input.to_utf8_lossy().chars().collect();
+    let Some((value, start, end)) = format.parse(&input) else {
+        return Ok(Value::Null);
+    };
+    if whole_string && !input[end..].iter().all(|c| c.is_whitespace()) {
+        return Ok(Value::Null);
+    }
+
+    let storage = ArrayStorage::from_args(&[
+        value.into(),
+        utf16_index(&input, start).into(),
+        utf16_index(&input, end).into(),
+    ]);
+    Ok(ArrayObject::from_storage(activation, storage)?.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NumberFormat;
+
+    fn format(decimal: &str, grouping: &str) -> NumberFormat {
+        NumberFormat {
+            decimal_separator: decimal.to_string(),
+            digits_type: '0' as u32,
+            fractional_digits: 2,
+            grouping_pattern: "3;*".to_string(),
+            grouping_separator: grouping.to_string(),
+            leading_zero: true,
+            negative_symbol: "-".to_string(),
+            trailing_zeros: true,
+            use_grouping: true,
+        }
+    }
+
+    #[test]
+    fn formats_numbers() {
+        let mut en = format(".", ",");
+        assert_eq!(en.format(1234567.891, 2, 1), "1,234,567.89");
+        assert_eq!(en.format(-1234.5, 2, 1), "-1,234.50");
+        assert_eq!(en.format(-0.001, 2, 1), "0.00");
+        assert_eq!(en.format(123.0, 0, 1), "123");
+        assert_eq!(en.format(1234.5, 0, 1), "1,235");
+
+        en.trailing_zeros = false;
+        en.leading_zero = false;
+        assert_eq!(en.format(-0.5, 2, 0), "(.5)");
+        assert_eq!(en.format(-1.0, 0, 4), "1 -");
+
+        en.grouping_pattern = "3;2;*".to_string();
+        assert_eq!(en.format(123456789.0, 0, 1), "12,34,56,789");
+
+        en.grouping_pattern = "3".to_string();
+        assert_eq!(en.format(123456789.0, 0, 1), "123456,789");
+
+        en.digits_type = 0x0660;
+        assert_eq!(en.format(1204.0, 0, 1), "١,٢٠٤");
+
+        let de = format(",", ".");
+        assert_eq!(de.format(1234.5, 2, 1), "1.234,50");
+    }
+
+    #[test]
+    fn groups_digits() {
+        let mut en = format(".", ",");
+        let mut group = |pattern: &str, digits: &str| {
+            en.grouping_pattern = pattern.to_string();
+            en.group(digits)
+        };
+
+        assert_eq!(group("3;*", "1"), "1");
+        assert_eq!(group("3;*", "123"), "123");
+        assert_eq!(group("3;*", "1234"), "1,234");
+        assert_eq!(group("3;*", "1234567"), "1,234,567");
+        assert_eq!(group("4;*", "123456789"), "1,2345,6789");
+        assert_eq!(group("3;2;*", "1234567"), "12,34,567");
+        assert_eq!(group("3;2", "123456789"), "1234,56,789");
+        assert_eq!(group("1;2;3", "1234567890"), "1234,567,89,0");
+        assert_eq!(group("9", "1234567890"), "1,234567890");
+
+        // Invalid patterns disable grouping.
+        assert_eq!(group("", "1234567"), "1234567");
+        assert_eq!(group("*", "1234567"), "1234567");
+        assert_eq!(group("0;*", "1234567"), "1234567");
+        assert_eq!(group("10;*", "1234567"), "1234567");
+        assert_eq!(group("3;*;2", "1234567"), "1234567");
+        assert_eq!(group("3;x", "1234567"), "1234567");
+        assert_eq!(group("3,*", "1234567"), "1234567");
+
+        en.grouping_pattern = "3;*".to_string();
+        en.use_grouping = false;
+        assert_eq!(en.group("1234567"), "1234567");
+    }
+
+    #[test]
+    fn round_trips_numbers() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+
+        let mut arabic = format("\u{66B}", "\u{66C}");
+        arabic.digits_type = 0x0660;
+        let mut indian = format(".", ",");
+        indian.grouping_pattern = "3;2;*".to_string();
+        let mut suffix = format(",", "\u{A0}");
+        suffix.trailing_zeros = false;
+
+        let formats = [
+            (format(".", ","), 1),
+            (format(",", "."), 1),
+            (format(",", "\u{A0}"), 1),
+            (format(".", "'"), 2),
+            (format(".", " "), 0),
+            (arabic, 1),
+            (indian, 1),
+            (suffix, 3),
+        ];
+        for (format, negative_number_format) in &formats {
+            for value in [0.0, 7.0, -7.25, 1234.5, -98765.43, 123456789.01] {
+                let formatted = format.format(value, 2, *negative_number_format);
+                let input = chars(&formatted);
+                assert_eq!(
+                    format.parse(&input),
+                    Some((value, 0, input.len())),
+                    "{formatted:?} should parse back to {value}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parses_numbers() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+
+        let en = format(".", ",");
+        assert_eq!(en.parse(&chars(" 1,234.5 ")), Some((1234.5, 1, 8)));
+        assert_eq!(en.parse(&chars("-12")), Some((-12.0, 0, 3)));
+        assert_eq!(en.parse(&chars("(12.5)")), Some((-12.5, 0, 6)));
+        assert_eq!(en.parse(&chars("12 -")), Some((-12.0, 0, 4)));
+        assert_eq!(en.parse(&chars("abc")), None);
+
+        let fr = format(",", "\u{A0}");
+        assert_eq!(
+            fr.parse(&chars("123 567,89,0.254")),
+            Some((123567.89, 0, 10))
+        );
+    }
+}
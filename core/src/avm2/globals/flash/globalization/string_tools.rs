@@ -0,0 +1,45 @@
+//! `flash.globalization.StringTools` native methods
+
+use crate::avm2::globals::flash::globalization::actual_locale_data;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Object, Value};
+use crate::string::AvmString;
+
+/// Whether a language distinguishes dotted and dotless `i` when changing case.
+fn has_dotless_i(language: &str) -> bool {
+    matches!(language, "tr" | "az")
+}
+
+/// Implements `StringTools.toLowerCaseImpl`
+pub fn to_lower_case_impl<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let string = args.get_string(activation, 0)?;
+
+    let locale = actual_locale_data(activation, this)?;
+    let mut string = string.to_utf8_lossy().into_owned();
+    if has_dotless_i(locale.language()) {
+        string = string.replace('I', "ı").replace('İ', "i");
+    }
+
+    Ok(AvmString::new_utf8(activation.context.gc_context, string.to_lowercase()).into())
+}
+
+/// Implements `StringTools.toUpperCaseImpl`
+pub fn to_upper_case_impl<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let string = args.get_string(activation, 0)?;
+
+    let locale = actual_locale_data(activation, this)?;
+    let mut string = string.to_utf8_lossy().into_owned();
+    if has_dotless_i(locale.language()) {
+        string = string.replace('i', "İ");
+    }
+
+    Ok(AvmString::new_utf8(activation.context.gc_context, string.to_uppercase()).into())
+}
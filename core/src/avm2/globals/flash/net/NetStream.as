@@ -41,9 +41,7 @@ package flash.net {
             stub_method("flash.net.NetStream", "attachCamera");
         }
 
-        public function close() {
-            stub_method("flash.net.NetStream", "close");
-        }
+        public native function close();
 
         public function dispose() {
             stub_method("flash.net.NetStream", "dispose");
@@ -59,6 +59,18 @@ pub fn pause<'gc>(
     Ok(Value::Undefined)
 }
 
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(ns) = this.as_netstream() {
+        ns.close(&mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
 pub fn resume<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
@@ -41,11 +41,18 @@ pub fn connect<'gc>(
         .try_into()
         .map_err(|_| invalid_port_number(activation))?;
 
+    let domain = activation.caller_domain();
     let UpdateContext {
         sockets, navigator, ..
     } = &mut activation.context;
 
-    sockets.connect_avm2(*navigator, socket, host.to_utf8_lossy().into_owned(), port);
+    sockets.connect_avm2(
+        *navigator,
+        socket,
+        domain,
+        host.to_utf8_lossy().into_owned(),
+        port,
+    );
 
     Ok(Value::Undefined)
 }
@@ -1,6 +1,76 @@
 package flash.printing {
+    import flash.display.Sprite;
     import flash.events.EventDispatcher;
+    import flash.geom.Rectangle;
 
     public class PrintJob extends EventDispatcher {
+        private var _started:Boolean = false;
+        private var _paperWidth:int = 0;
+        private var _paperHeight:int = 0;
+        private var _pageWidth:int = 0;
+        private var _pageHeight:int = 0;
+        private var _orientation:String = PrintJobOrientation.PORTRAIT;
+
+        public function PrintJob() {
+        }
+
+        public static function get isSupported():Boolean {
+            return true;
+        }
+
+        public function get paperWidth():int {
+            return this._paperWidth;
+        }
+
+        public function get paperHeight():int {
+            return this._paperHeight;
+        }
+
+        public function get pageWidth():int {
+            return this._pageWidth;
+        }
+
+        public function get pageHeight():int {
+            return this._pageHeight;
+        }
+
+        public function get orientation():String {
+            return this._orientation;
+        }
+
+        public function start():Boolean {
+            // Returns [paperWidth, paperHeight, pageWidth, pageHeight], or null if the job was cancelled.
+            var paper:Array = this.startInternal();
+            if (paper == null) {
+                this._started = false;
+                return false;
+            }
+
+            this._paperWidth = paper[0];
+            this._paperHeight = paper[1];
+            this._pageWidth = paper[2];
+            this._pageHeight = paper[3];
+            this._orientation = this._paperWidth > this._paperHeight ? PrintJobOrientation.LANDSCAPE : PrintJobOrientation.PORTRAIT;
+            this._started = true;
+            return true;
+        }
+
+        public function addPage(sprite:Sprite, printArea:Rectangle = null, options:PrintJobOptions = null, frameNum:int = 0):void {
+            // Every page is rasterised, so `options.printAsBitmap` makes no difference.
+            if (!this._started || !this.addPageInternal(sprite, printArea, frameNum)) {
+                throw new Error("Error #2057: The page could not be added to the print job.", 2057);
+            }
+        }
+
+        public function send():void {
+            if (this._started) {
+                this._started = false;
+                this.sendInternal();
+            }
+        }
+
+        private native function startInternal():Array;
+        private native function addPageInternal(sprite:Sprite, printArea:Rectangle, frameNum:int):Boolean;
+        private native function sendInternal():void;
     }
-}
\ No newline at end of file
+}
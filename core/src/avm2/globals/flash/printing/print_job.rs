@@ -0,0 +1,60 @@
+//! `flash.printing.PrintJob` native methods
+
+use crate::avm2::array::ArrayStorage;
+use crate::avm2::globals::flash::display::display_object::object_to_rectangle;
+use crate::avm2::object::ArrayObject;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Object, TObject, Value};
+use crate::printing::PrintJob;
+
+/// Implements `PrintJob.startInternal`
+pub fn start_internal<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(paper) = PrintJob::start(&mut activation.context) else {
+        return Ok(Value::Null);
+    };
+
+    let storage = ArrayStorage::from_args(&[
+        paper.paper_width.into(),
+        paper.paper_height.into(),
+        paper.page_width.into(),
+        paper.page_height.into(),
+    ]);
+    Ok(ArrayObject::from_storage(activation, storage)?.into())
+}
+
+/// Implements `PrintJob.addPageInternal`
+pub fn add_page_internal<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let sprite = args.get_object(activation, 0, "sprite")?;
+    let Some(sprite) = sprite.as_display_object() else {
+        return Ok(false.into());
+    };
+
+    let area = match args.try_get_object(activation, 1) {
+        Some(area) => Some(object_to_rectangle(activation, area)?),
+        None => None,
+    };
+
+    // A frame number of 0 prints the current frame.
+    let frame = args.get_i32(activation, 2)?;
+    let frame = (frame > 0).then(|| frame.min(u16::MAX.into()) as u16);
+
+    Ok(PrintJob::add_page(&mut activation.context, sprite, area, frame).into())
+}
+
+/// Implements `PrintJob.sendInternal`
+pub fn send_internal<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    PrintJob::send(&mut activation.context);
+    Ok(Value::Undefined)
+}
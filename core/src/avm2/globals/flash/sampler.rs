@@ -0,0 +1,140 @@
+//! `flash.sampler` namespace
+
+use crate::avm2::array::ArrayStorage;
+use crate::avm2::object::{ArrayObject, TObject};
+use crate::avm2::sampler::{estimate_retained_size, SampleKind, Sampler};
+use crate::avm2::{Activation, Error, Object, Value};
+use crate::string::AvmString;
+
+pub mod new_object_sample;
+
+/// Implements `flash.sampler.getSize`
+pub fn get_size<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let value = args.get(0).copied().unwrap_or(Value::Undefined);
+    Ok((estimate_retained_size(value) as f64).into())
+}
+
+/// Implements `flash.sampler.startSampling`
+pub fn start_sampling<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    activation.avm2().sampler.start();
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.sampler.stopSampling`
+pub fn stop_sampling<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    activation.avm2().sampler.stop();
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.sampler.pauseSampling`
+pub fn pause_sampling<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    activation.avm2().sampler.pause();
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.sampler.clearSamples`
+pub fn clear_samples<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    activation.avm2().sampler.clear();
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.sampler.getSamples`
+pub fn get_samples<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Sampler::record_deleted_objects(activation);
+
+    // Don't sample the objects we're about to create to describe the samples.
+    let was_sampling = activation.avm2().sampler.is_sampling();
+    activation.avm2().sampler.pause();
+    let result = build_samples(activation);
+    if was_sampling {
+        activation.avm2().sampler.start();
+    }
+
+    Ok(ArrayObject::from_storage(activation, ArrayStorage::from_storage(result?))?.into())
+}
+
+fn build_samples<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Vec<Option<Value<'gc>>>, Error<'gc>> {
+    let samples: Vec<_> = activation
+        .avm2()
+        .sampler
+        .samples()
+        .iter()
+        .map(|sample| (sample.time, sample.stack.clone(), sample.kind.clone()))
+        .collect();
+
+    let mut result = Vec::with_capacity(samples.len());
+    for (time, stack, kind) in samples {
+        let stack = build_stack(activation, &stack)?;
+        let sample = match kind {
+            SampleKind::NewObject {
+                id, class, size, ..
+            } => activation.avm2().classes().newobjectsample.construct(
+                activation,
+                &[
+                    time.into(),
+                    stack,
+                    (id as f64).into(),
+                    class.into(),
+                    (size as f64).into(),
+                ],
+            )?,
+            SampleKind::DeleteObject { id, size } => {
+                activation.avm2().classes().deleteobjectsample.construct(
+                    activation,
+                    &[time.into(), stack, (id as f64).into(), (size as f64).into()],
+                )?
+            }
+        };
+        result.push(Some(sample.into()));
+    }
+    Ok(result)
+}
+
+fn build_stack<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    stack: &[String],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if stack.is_empty() {
+        return Ok(Value::Null);
+    }
+
+    let mut frames = Vec::with_capacity(stack.len());
+    for name in stack {
+        // `StackFrame.toString` adds its own parentheses.
+        let name = name.strip_suffix("()").unwrap_or(name);
+        let name = AvmString::new_utf8(activation.context.gc_context, name);
+        let frame = activation
+            .avm2()
+            .classes()
+            .stackframe
+            .construct(activation, &[name.into()])?;
+        frames.push(Some(frame.into()));
+    }
+    Ok(ArrayObject::from_storage(activation, ArrayStorage::from_storage(frames))?.into())
+}
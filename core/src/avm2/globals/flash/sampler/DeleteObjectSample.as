@@ -3,6 +3,11 @@ package flash.sampler {
         public const id:Number;
      
         public const size:Number;
+
+        public function DeleteObjectSample(time:Number = 0, stack:Array = null, id:Number = 0, size:Number = 0) {
+            super(time, stack);
+            this.id = id;
+            this.size = size;
+        }
     }
 }
-
@@ -3,6 +3,10 @@ package flash.sampler {
         public const time:Number;
       
         public const stack:Array;
+
+        public function Sample(time:Number = 0, stack:Array = null) {
+            this.time = time;
+            this.stack = stack;
+        }
     }
 }
-
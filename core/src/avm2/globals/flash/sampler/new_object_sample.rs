@@ -0,0 +1,22 @@
+//! `flash.sampler.NewObjectSample` native methods
+
+use crate::avm2::object::TObject;
+use crate::avm2::{Activation, Error, Object, Value};
+
+/// Implements `NewObjectSample.object`'s getter
+pub fn get_object<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = this
+        .get_public_property("id", activation)?
+        .coerce_to_number(activation)?;
+
+    let mc = activation.context.gc_context;
+    Ok(activation
+        .avm2()
+        .sampler
+        .live_object(id as u64, mc)
+        .map_or(Value::Undefined, Value::from))
+}
@@ -0,0 +1,123 @@
+package flash.system {
+    import flash.concurrent.Condition;
+    import flash.concurrent.Mutex;
+    import flash.events.Event;
+    import flash.events.EventDispatcher;
+    import flash.utils.ByteArray;
+
+    public final class MessageChannel extends EventDispatcher {
+        private static var _constructing:Boolean = false;
+
+        private var _sender:Worker;
+        private var _receiver:Worker;
+        private var _state:String;
+        private var _queue:Array = [];
+
+        public function MessageChannel() {
+            if (!_constructing) {
+                throw new ArgumentError("Error #2012: MessageChannel$ class cannot be instantiated.", 2012);
+            }
+            _constructing = false;
+        }
+
+        internal static function createChannel(sender:Worker, receiver:Worker):MessageChannel {
+            _constructing = true;
+            var channel:MessageChannel = new MessageChannel();
+            channel._sender = sender;
+            channel._receiver = receiver;
+            channel._state = MessageChannelState.OPEN;
+            return channel;
+        }
+
+        // Values crossing a worker boundary are copied through AMF3, except for
+        // the types that Flash Player shares between workers by reference.
+        internal static function copyValue(value:*):* {
+            // Primitives are immutable, so there's no need to copy them.
+            if (value == null || value is String || value is Number || value is Boolean) {
+                return value;
+            }
+            if (value is MessageChannel || value is Worker || value is Mutex || value is Condition) {
+                return value;
+            }
+            if (value is ByteArray && ByteArray(value).shareable) {
+                return value;
+            }
+
+            var bytes:ByteArray = new ByteArray();
+            bytes.writeObject(value);
+            bytes.position = 0;
+            return bytes.readObject();
+        }
+
+        public function get messageAvailable():Boolean {
+            return this._queue.length > 0;
+        }
+
+        public function get state():String {
+            return this._state;
+        }
+
+        public function send(arg:*, queueLimit:int = -1):void {
+            if (this._state != MessageChannelState.OPEN) {
+                return;
+            }
+            if (queueLimit >= 0 && this._queue.length >= queueLimit) {
+                // Block until the receiver catches up.
+                var self:MessageChannel = this;
+                var hasRoom:Function = function():Boolean {
+                    return self._queue.length < queueLimit || self._state != MessageChannelState.OPEN;
+                };
+                if (!Worker.ruffle::park(hasRoom, -1)) {
+                    throw new Error("There is no worker left that could receive messages from this channel.");
+                }
+                if (this._state != MessageChannelState.OPEN) {
+                    return;
+                }
+            }
+
+            this._queue.push(copyValue(arg));
+            this.dispatchLater(Event.CHANNEL_MESSAGE);
+        }
+
+        public function receive(blockUntilReceived:Boolean = false):* {
+            if (this._queue.length == 0 && blockUntilReceived) {
+                var self:MessageChannel = this;
+                var hasMessage:Function = function():Boolean {
+                    return self._queue.length > 0 || self._state != MessageChannelState.OPEN;
+                };
+                if (!Worker.ruffle::park(hasMessage, -1)) {
+                    throw new Error("There is no worker left that could send messages to this channel.");
+                }
+            }
+            if (this._queue.length == 0) {
+                return null;
+            }
+
+            var message:* = this._queue.shift();
+            if (this._state == MessageChannelState.CLOSING && this._queue.length == 0) {
+                this.setState(MessageChannelState.CLOSED);
+            }
+            return message;
+        }
+
+        public function close():void {
+            if (this._state != MessageChannelState.OPEN) {
+                return;
+            }
+            if (this._queue.length == 0) {
+                this.setState(MessageChannelState.CLOSED);
+            } else {
+                this.setState(MessageChannelState.CLOSING);
+            }
+        }
+
+        private function setState(state:String):void {
+            this._state = state;
+            this.dispatchLater(Event.CHANNEL_STATE);
+        }
+
+        private function dispatchLater(type:String):void {
+            Worker.queueEvent(this._receiver, this, new Event(type));
+        }
+    }
+}
@@ -1,10 +1,183 @@
 package flash.system {
-    public final class Worker {
+    import flash.display.Loader;
+    import flash.events.Event;
+    import flash.events.EventDispatcher;
+    import flash.utils.ByteArray;
+    import flash.utils.getTimer;
+    import flash.utils.setTimeout;
+
+    namespace ruffle = "__ruffle__";
+
+    // Unlike Flash Player, which gives each worker its own AVM2 instance, every
+    // worker shares the AVM2 of the primordial worker. A worker only gets its
+    // own application domain (see `createDomain_internal`), so static
+    // playerglobal state such as `XML.settings()`,
+    // `ByteArray.defaultObjectEncoding` or `SoundMixer` is shared by all of them.
+    public final class Worker extends EventDispatcher {
+        private static var _primordial:Worker;
+        private static var _constructing:Boolean = false;
+
+        // Every worker runs on the player thread, so events meant for a worker
+        // are queued here instead of being dispatched by their own timers.
+        // That lets a worker that's blocked in `Mutex.lock` or `Condition.wait`
+        // hand over to the other workers until it can continue.
+        private static var _pendingEvents:Array = [];
+        private static var _flushScheduled:Boolean = false;
+        private static var _parkedWorkers:Vector.<Worker> = new Vector.<Worker>();
+
+        private var _isPrimordial:Boolean;
+        private var _state:String;
+        private var _swf:ByteArray;
+        private var _loader:Loader;
+        private var _sharedProperties:Object = {};
+
         public function Worker() {
-            throw new ArgumentError("Error #2012: Worker$ class cannot be instantiated.", 2012);
+            if (!_constructing) {
+                throw new ArgumentError("Error #2012: Worker$ class cannot be instantiated.", 2012);
+            }
+            _constructing = false;
+        }
+
+        internal static function createWorker(swf:ByteArray, isPrimordial:Boolean):Worker {
+            _constructing = true;
+            var worker:Worker = new Worker();
+            worker._isPrimordial = isPrimordial;
+            worker._state = isPrimordial ? WorkerState.RUNNING : WorkerState.NEW;
+            worker._swf = swf;
+            return worker;
         }
 
         public static function get isSupported():Boolean {
+            return true;
+        }
+
+        // Returns the worker whose SWF the calling code was loaded from,
+        // falling back to the primordial worker.
+        public static native function get current():Worker;
+
+        internal static function get primordial():Worker {
+            if (_primordial == null) {
+                _primordial = createWorker(null, true);
+            }
+            return _primordial;
+        }
+
+        // Creates the application domain this worker's SWF will be loaded into.
+        // It is a sibling of the stage domain, so the worker gets its own copy
+        // of every class from its SWF (and its own domain memory), even when the
+        // primordial worker was loaded from the same SWF. Playerglobal classes,
+        // and their static state, are still shared between all workers.
+        private native function createDomain_internal():ApplicationDomain;
+
+        public function get isPrimordial():Boolean {
+            return this._isPrimordial;
+        }
+
+        public function get state():String {
+            return this._state;
+        }
+
+        public function start():void {
+            if (this._state != WorkerState.NEW) {
+                return;
+            }
+
+            var context:LoaderContext = new LoaderContext(false, this.createDomain_internal());
+            this._loader = new Loader();
+            this._loader.loadBytes(this._swf, context);
+            this.setState(WorkerState.RUNNING);
+        }
+
+        public function terminate():Boolean {
+            if (this._isPrimordial || this._state != WorkerState.RUNNING) {
+                return false;
+            }
+
+            this.terminate_internal();
+            this._loader.unloadAndStop();
+            this._loader = null;
+
+            var remaining:Array = [];
+            for each (var pending:Object in _pendingEvents) {
+                if (pending.worker != this) {
+                    remaining.push(pending);
+                }
+            }
+            _pendingEvents = remaining;
+
+            this.setState(WorkerState.TERMINATED);
+            return true;
+        }
+
+        // Forgets this worker's domain and stops every timer its code started.
+        private native function terminate_internal():void;
+
+        public function setSharedProperty(key:String, value:*):void {
+            this._sharedProperties[key] = MessageChannel.copyValue(value);
+        }
+
+        public function getSharedProperty(key:String):* {
+            return MessageChannel.copyValue(this._sharedProperties[key]);
+        }
+
+        public function createMessageChannel(receiver:Worker):MessageChannel {
+            if (receiver == null) {
+                throw new TypeError("Error #2007: Parameter receiver must be non-null.", 2007);
+            }
+            return MessageChannel.createChannel(this, receiver);
+        }
+
+        private function setState(state:String):void {
+            this._state = state;
+            queueEvent(current, this, new Event(Event.WORKER_STATE));
+        }
+
+        // Dispatches `event` on `target` as soon as `worker` is free to run.
+        internal static function queueEvent(worker:Worker, target:EventDispatcher, event:Event):void {
+            _pendingEvents.push({worker: worker, target: target, event: event});
+
+            if (!_flushScheduled) {
+                _flushScheduled = true;
+                setTimeout(function():void {
+                    _flushScheduled = false;
+                    while (runPendingEvent()) {}
+                }, 0);
+            }
+        }
+
+        // Dispatches the oldest queued event whose worker isn't parked.
+        // Returns false if there was no such event.
+        private static function runPendingEvent():Boolean {
+            for (var i:int = 0; i < _pendingEvents.length; i++) {
+                var pending:Object = _pendingEvents[i];
+                if (_parkedWorkers.indexOf(pending.worker) == -1) {
+                    _pendingEvents.splice(i, 1);
+                    pending.target.dispatchEvent(pending.event);
+                    return true;
+                }
+            }
+            return false;
+        }
+
+        // Blocks the current worker until `isWoken` returns true, running the
+        // other workers in the meantime. Gives up once `timeout` milliseconds
+        // have passed (if it isn't negative), or when no other worker has
+        // anything left to do, as nothing could wake us up anymore.
+        ruffle static function park(isWoken:Function, timeout:Number):Boolean {
+            var worker:Worker = current;
+            var deadline:Number = timeout < 0 ? Infinity : getTimer() + timeout;
+
+            _parkedWorkers.push(worker);
+            try {
+                while (!isWoken()) {
+                    if (getTimer() > deadline || !runPendingEvent()) {
+                        return false;
+                    }
+                }
+                return true;
+            } finally {
+                _parkedWorkers.splice(_parkedWorkers.indexOf(worker), 1);
+            }
             return false;
         }
     }
@@ -1,9 +1,47 @@
 package flash.system {
+    import flash.utils.ByteArray;
+
     public final class WorkerDomain {
-        public static const isSupported: Boolean = false;
+        public static const isSupported: Boolean = true;
+
+        private static var _current:WorkerDomain;
+        private static var _constructing:Boolean = false;
+
+        private var _workers:Vector.<Worker> = new Vector.<Worker>();
 
         public function WorkerDomain() {
-            throw new ArgumentError("Error #2012: WorkerDomain$ class cannot be instantiated.", 2012)
+            if (!_constructing) {
+                throw new ArgumentError("Error #2012: WorkerDomain$ class cannot be instantiated.", 2012);
+            }
+            _constructing = false;
+        }
+
+        public static function get current():WorkerDomain {
+            if (_current == null) {
+                _constructing = true;
+                _current = new WorkerDomain();
+            }
+            return _current;
+        }
+
+        public function createWorker(swf:ByteArray, giveAppPrivileges:Boolean = false):Worker {
+            if (swf == null) {
+                throw new TypeError("Error #2007: Parameter swf must be non-null.", 2007);
+            }
+            var worker:Worker = Worker.createWorker(swf, false);
+            this._workers.push(worker);
+            return worker;
+        }
+
+        public function listWorkers():Vector.<Worker> {
+            var workers:Vector.<Worker> = new Vector.<Worker>();
+            workers.push(Worker.primordial);
+            for each (var worker:Worker in this._workers) {
+                if (worker.state == WorkerState.RUNNING) {
+                    workers.push(worker);
+                }
+            }
+            return workers;
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,74 @@
+//! `flash.system.Worker` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::object::{DomainObject, Object, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::{Domain, Error, Multiname};
+
+/// Implements `Worker.current`
+pub fn get_current<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    // Playerglobal code (such as `Mutex`) runs on behalf of whichever worker
+    // called into it, so look for the innermost code that isn't ours.
+    let call_stack = activation.avm2().call_stack();
+    let domains: Vec<_> = call_stack
+        .read()
+        .domains()
+        .chain(activation.caller_domain())
+        .collect();
+    let avm2 = activation.avm2();
+    let caller_domain = domains
+        .into_iter()
+        .find(|domain| !domain.is_playerglobals_domain(avm2));
+
+    let mut domain = caller_domain;
+    while let Some(current) = domain {
+        if let Some((_, worker)) = activation
+            .avm2()
+            .worker_domains
+            .iter()
+            .find(|(worker_domain, _)| *worker_domain == current)
+        {
+            return Ok((*worker).into());
+        }
+        domain = current.parent_domain();
+    }
+
+    let primordial = Multiname::new(activation.avm2().flash_system_internal, "primordial");
+    this.get_property(&primordial, activation)
+}
+
+/// Implements `Worker.createDomain_internal`
+pub fn create_domain_internal<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let playerglobals_domain = activation.avm2().playerglobals_domain();
+    let domain = Domain::movie_domain(activation, playerglobals_domain);
+    activation.avm2().worker_domains.push((domain, this));
+
+    Ok(DomainObject::from_domain(activation, domain)?.into())
+}
+
+/// Implements `Worker.terminate_internal`
+pub fn terminate_internal<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let worker_domains = &mut activation.avm2().worker_domains;
+    let domain = worker_domains
+        .iter()
+        .position(|(_, worker)| Object::ptr_eq(*worker, this))
+        .map(|index| worker_domains.remove(index).0);
+
+    if let Some(domain) = domain {
+        activation.context.timers.remove_avm2_domain(domain);
+    }
+
+    Ok(Value::Undefined)
+}
@@ -5,3 +5,4 @@ pub mod font;
 pub mod static_text;
 pub mod text_field;
 pub mod text_format;
+pub mod text_snapshot;
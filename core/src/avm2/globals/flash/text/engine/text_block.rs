@@ -67,6 +67,13 @@ pub fn create_text_line<'gc>(
     let display_object: EditText =
         EditText::new_tlf(&mut activation.context, movie, 0.0, 0.0, width, 15.0);
 
+    // Even levels lay paragraphs out left-to-right, odd levels right-to-left.
+    let bidi_level = this
+        .get_public_property("bidiLevel", activation)?
+        .coerce_to_i32(activation)?;
+    display_object.set_bidi_level(activation.context.gc_context, u8::try_from(bidi_level).ok());
+
+    // This lays the text out, using the bidi level set above.
     display_object.set_text(text.as_wstr(), &mut activation.context);
 
     // FIXME: This needs to use `intrinsic_bounds` to measure the width
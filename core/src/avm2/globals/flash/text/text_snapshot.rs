@@ -0,0 +1,197 @@
+//! `flash.text.TextSnapshot` native methods
+
+use crate::avm2::array::ArrayStorage;
+use crate::avm2::object::ArrayObject;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Multiname, Object, TObject, Value};
+use crate::display_object::TDisplayObject;
+use crate::string::AvmString;
+use crate::text_snapshot::TextSnapshot;
+use swf::{Color, Point, Twips};
+
+/// Takes a snapshot of the static text of the container this `TextSnapshot` was created for.
+fn snapshot<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+) -> Result<Option<TextSnapshot<'gc>>, Error<'gc>> {
+    let container = this
+        .get_property(
+            &Multiname::new(activation.avm2().flash_text_internal, "_container"),
+            activation,
+        )?
+        .as_object()
+        .and_then(|container| container.as_display_object())
+        .and_then(|container| container.as_container());
+    Ok(container.map(|container| TextSnapshot::new(&activation.context, container)))
+}
+
+/// Converts a pair of character indices to a range, treating negative indices as 0.
+fn index_range<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    args: &[Value<'gc>],
+) -> Result<std::ops::Range<usize>, Error<'gc>> {
+    let begin = args.get_i32(activation, 0)?.max(0) as usize;
+    let end = args.get_i32(activation, 1)?.max(0) as usize;
+    Ok(begin..end)
+}
+
+/// Implements `TextSnapshot.charCount`
+pub fn get_char_count<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let count = snapshot(activation, this)?.map_or(0, |snapshot| snapshot.char_count());
+    Ok(count.into())
+}
+
+/// Implements `TextSnapshot.findText`
+pub fn find_text<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let begin = args.get_i32(activation, 0)?.max(0) as usize;
+    let text = args.get_string(activation, 1)?;
+    let case_sensitive = args.get_bool(2);
+
+    let index = snapshot(activation, this)?
+        .and_then(|snapshot| snapshot.find_text(begin, &text, case_sensitive));
+    Ok(index.map_or(-1, |index| index as i32).into())
+}
+
+/// Implements `TextSnapshot.getSelected`
+pub fn get_selected<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let range = index_range(activation, args)?;
+    let selected =
+        snapshot(activation, this)?.map_or(false, |snapshot| snapshot.is_selected(range));
+    Ok(selected.into())
+}
+
+/// Implements `TextSnapshot.getSelectedText`
+pub fn get_selected_text<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let include_line_endings = args.get_bool(0);
+    let Some(snapshot) = snapshot(activation, this)? else {
+        return Ok("".into());
+    };
+    let text = snapshot.selected_text(include_line_endings);
+    Ok(AvmString::new(activation.context.gc_context, text).into())
+}
+
+/// Implements `TextSnapshot.getText`
+pub fn get_text<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let range = index_range(activation, args)?;
+    let include_line_endings = args.get_bool(2);
+    let Some(snapshot) = snapshot(activation, this)? else {
+        return Ok("".into());
+    };
+    let text = snapshot.text(range, include_line_endings);
+    Ok(AvmString::new(activation.context.gc_context, text).into())
+}
+
+/// Implements `TextSnapshot.getTextRunInfo`
+pub fn get_text_run_info<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let range = index_range(activation, args)?;
+    let runs = snapshot(activation, this)?
+        .map(|snapshot| snapshot.text_run_info(range))
+        .unwrap_or_default();
+
+    let mut values = Vec::with_capacity(runs.len());
+    for run in runs {
+        let info = activation
+            .avm2()
+            .classes()
+            .object
+            .construct(activation, &[])?;
+        let font_name = AvmString::new_utf8(activation.context.gc_context, run.font_name);
+        let matrix = run.matrix;
+        let [c0, c1, c2, c3] = run.corners;
+        let properties: [(&'static str, Value<'gc>); 19] = [
+            ("indexInRun", run.index_in_run.into()),
+            ("selected", run.selected.into()),
+            ("font", font_name.into()),
+            ("color", run.color.to_rgb().into()),
+            ("height", run.height.to_pixels().into()),
+            ("matrix_a", matrix.a.into()),
+            ("matrix_b", matrix.b.into()),
+            ("matrix_c", matrix.c.into()),
+            ("matrix_d", matrix.d.into()),
+            ("matrix_tx", matrix.tx.to_pixels().into()),
+            ("matrix_ty", matrix.ty.to_pixels().into()),
+            ("corner0x", c0.x.to_pixels().into()),
+            ("corner0y", c0.y.to_pixels().into()),
+            ("corner1x", c1.x.to_pixels().into()),
+            ("corner1y", c1.y.to_pixels().into()),
+            ("corner2x", c2.x.to_pixels().into()),
+            ("corner2y", c2.y.to_pixels().into()),
+            ("corner3x", c3.x.to_pixels().into()),
+            ("corner3y", c3.y.to_pixels().into()),
+        ];
+        for (name, value) in properties {
+            info.set_public_property(name, value, activation)?;
+        }
+        values.push(info.into());
+    }
+
+    Ok(ArrayObject::from_storage(activation, ArrayStorage::from_args(&values))?.into())
+}
+
+/// Implements `TextSnapshot.hitTestTextNearPos`
+pub fn hit_test_text_near_pos<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let point = Point::new(
+        Twips::from_pixels(args.get_f64(activation, 0)?),
+        Twips::from_pixels(args.get_f64(activation, 1)?),
+    );
+    let max_distance = Twips::from_pixels(args.get_f64(activation, 2)?);
+
+    let index = snapshot(activation, this)?
+        .and_then(|snapshot| snapshot.hit_test_text_near_pos(point, max_distance));
+    Ok(index.map_or(-1.0, |index| index as f64).into())
+}
+
+/// Implements `TextSnapshot.setSelectColor`
+pub fn set_select_color<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let color = Color::from_rgb(args.get_u32(activation, 0)?, 255);
+    if let Some(snapshot) = snapshot(activation, this)? {
+        snapshot.set_selection_color(activation.context.gc_context, color);
+    }
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextSnapshot.setSelected`
+pub fn set_selected<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let range = index_range(activation, args)?;
+    let selected = args.get_bool(2);
+    if let Some(snapshot) = snapshot(activation, this)? {
+        snapshot.set_selected(activation.context.gc_context, range, selected);
+    }
+    Ok(Value::Undefined)
+}
@@ -1,9 +1,13 @@
 //! `flash.ui.Mouse` builtin
 
 use crate::avm2::activation::Activation;
-use crate::avm2::object::Object;
+use crate::avm2::error::{argument_error, make_error_2008};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
+use crate::backend::ui::{CursorImage, CustomMouseCursor};
+use crate::string::AvmString;
 
 pub fn hide<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -22,3 +26,152 @@ pub fn show<'gc>(
     activation.context.ui.set_mouse_visible(true);
     Ok(Value::Undefined)
 }
+
+/// Implements `Mouse.supportsNativeCursor`'s getter.
+pub fn get_supports_native_cursor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation.context.ui.supports_custom_mouse_cursor().into())
+}
+
+/// Implements `Mouse.cursor`'s getter.
+pub fn get_cursor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let name = activation.context.mouse_cursors.selection().name();
+    Ok(AvmString::new_utf8(activation.context.gc_context, name).into())
+}
+
+/// Implements `Mouse.cursor`'s setter.
+pub fn set_cursor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let name = args.get_string_non_null(activation, 0, "cursor")?;
+    let selection = activation
+        .context
+        .mouse_cursors
+        .selection_by_name(&name.to_utf8_lossy());
+
+    match selection {
+        Some(selection) => {
+            let context = &mut activation.context;
+            context.mouse_cursors.select(selection, context.ui);
+            Ok(Value::Undefined)
+        }
+        None => Err(make_error_2008(activation, "cursor")),
+    }
+}
+
+/// Implements `Mouse.registerCursor`.
+pub fn register_cursor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let name = args.get_string(activation, 0)?.to_string();
+    let cursor_data = args.get_object(activation, 1, "cursor")?;
+    let cursor = custom_cursor_from_data(activation, cursor_data)?;
+
+    let context = &mut activation.context;
+    context
+        .mouse_cursors
+        .register_cursor(name, cursor, context.ui);
+    Ok(Value::Undefined)
+}
+
+/// Implements `Mouse.unregisterCursor`.
+pub fn unregister_cursor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let name = args.get_string(activation, 0)?.to_string();
+
+    let context = &mut activation.context;
+    context.mouse_cursors.unregister_cursor(&name, context.ui);
+    Ok(Value::Undefined)
+}
+
+/// Copies the images, frame rate and hot spot out of a `MouseCursorData`.
+fn custom_cursor_from_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    cursor_data: Object<'gc>,
+) -> Result<CustomMouseCursor, Error<'gc>> {
+    let bitmaps: Vec<Value<'gc>> = cursor_data
+        .get_public_property("data", activation)?
+        .as_object()
+        .and_then(|data| data.as_vector_storage().map(|data| data.iter().collect()))
+        .unwrap_or_default();
+
+    let mut frames = Vec::with_capacity(bitmaps.len());
+    for bitmap in bitmaps {
+        let Some(bitmap_data) = bitmap.as_object().and_then(|o| o.as_bitmap_data()) else {
+            continue;
+        };
+        bitmap_data.check_valid(activation)?;
+
+        let bitmap_data = bitmap_data.sync();
+        let bitmap_data = bitmap_data.read();
+        let rgba = bitmap_data
+            .pixels()
+            .iter()
+            .flat_map(|p| {
+                let p = p.to_un_multiplied_alpha();
+                [p.red(), p.green(), p.blue(), p.alpha()]
+            })
+            .collect();
+        frames.push(CursorImage {
+            width: bitmap_data.width(),
+            height: bitmap_data.height(),
+            rgba,
+        });
+    }
+
+    let frame_rate = cursor_data
+        .get_public_property("frameRate", activation)?
+        .coerce_to_number(activation)?;
+
+    let hot_spot = match cursor_data
+        .get_public_property("hotSpot", activation)?
+        .as_object()
+    {
+        Some(point) => {
+            let x = point
+                .get_public_property("x", activation)?
+                .coerce_to_number(activation)?;
+            let y = point
+                .get_public_property("y", activation)?
+                .coerce_to_number(activation)?;
+
+            // The hot spot has to lie on every frame of the cursor.
+            let (width, height) = frames.iter().fold((u32::MAX, u32::MAX), |size, frame| {
+                (size.0.min(frame.width), size.1.min(frame.height))
+            });
+            if !(x >= 0.0 && y >= 0.0 && x < f64::from(width) && y < f64::from(height)) {
+                return Err(Error::AvmError(argument_error(
+                    activation,
+                    "Error #2004: One of the parameters is invalid.",
+                    2004,
+                )?));
+            }
+            (x as u32, y as u32)
+        }
+        None => (0, 0),
+    };
+
+    Ok(CustomMouseCursor {
+        frames,
+        frame_rate: if frame_rate.is_finite() && frame_rate > 0.0 {
+            frame_rate
+        } else {
+            0.0
+        },
+        hot_spot,
+    })
+}
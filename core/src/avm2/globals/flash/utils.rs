@@ -41,6 +41,7 @@ pub fn set_interval<'gc>(
             .as_object()
             .ok_or("setInterval: argument 0 is not an object")?,
         params: params.to_vec(),
+        domain: activation.caller_domain(),
     };
     let interval = args
         .get(1)
@@ -84,6 +85,7 @@ pub fn set_timeout<'gc>(
             .as_object()
             .ok_or("setTimeout: argument 0 is not an object")?,
         params: params.to_vec(),
+        domain: activation.caller_domain(),
     };
     let interval = args
         .get(1)
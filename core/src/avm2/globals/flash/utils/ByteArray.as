@@ -24,6 +24,19 @@ package flash.utils {
 		public native function get position():uint;
 		public native function set position(value:uint):void;
 
+		private var _shareable:Boolean;
+
+		public function get shareable():Boolean {
+			return this._shareable;
+		}
+
+		public function set shareable(value:Boolean):void {
+			// Once shared between workers, a ByteArray stays shareable.
+			if (value) {
+				this._shareable = true;
+			}
+		}
+
 		public function ByteArray() {
 			this.objectEncoding = _defaultObjectEncoding;
 		}
@@ -36,6 +49,27 @@ package flash.utils {
 
 		public native function compress(algorithm: String = CompressionAlgorithm.ZLIB): void;
 
+		// Workers never run concurrently, so these are trivially atomic.
+		public function atomicCompareAndSwapIntAt(byteIndex:int, expectedValue:int, newValue:int):int {
+			var oldPosition:uint = this.position;
+			this.position = byteIndex;
+			var value:int = this.readInt();
+			if (value == expectedValue) {
+				this.position = byteIndex;
+				this.writeInt(newValue);
+			}
+			this.position = oldPosition;
+			return value;
+		}
+
+		public function atomicCompareAndSwapLength(expectedLength:int, newLength:int):int {
+			var length:int = this.length;
+			if (length == expectedLength) {
+				this.length = newLength;
+			}
+			return length;
+		}
+
 		public function inflate(): void {
 			this.uncompress("deflate");
 		}
@@ -1 +1,21 @@
+//! `flash.utils.Dictionary` native methods
+
+use crate::avm2::activation::Activation;
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+
 pub use crate::avm2::object::dictionary_allocator;
+
+/// Implements `Dictionary.setWeakKeys`
+pub fn set_weak_keys<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(dictionary) = this.as_dictionary_object() {
+        dictionary.set_weak_keys(activation);
+    }
+
+    Ok(Value::Undefined)
+}
@@ -128,6 +128,7 @@ include "flash/display3D/textures/TextureBase.as"
 include "flash/display3D/textures/CubeTexture.as"
 include "flash/display3D/textures/Texture.as"
 include "flash/display3D/textures/RectangleTexture.as"
+include "flash/display3D/textures/VideoTexture.as"
 include "flash/display3D/VertexBuffer3D.as"
 
 include "flash/display/MovieClip.as"
@@ -216,9 +217,12 @@ include "flash/geom/Rectangle.as"
 include "flash/geom/Transform.as"
 include "flash/geom/Utils3D.as"
 include "flash/geom/Vector3D.as"
+include "flash/globalization.as"
+include "flash/globalization/Collator.as"
 include "flash/globalization/CollatorMode.as"
 include "flash/globalization/CurrencyParseResult.as"
 include "flash/globalization/CurrencyFormatter.as"
+include "flash/globalization/DateTimeFormatter.as"
 include "flash/globalization/DateTimeNameContext.as"
 include "flash/globalization/DateTimeNameStyle.as"
 include "flash/globalization/DateTimeStyle.as"
@@ -227,6 +231,7 @@ include "flash/globalization/LocaleID.as"
 include "flash/globalization/NationalDigitsType.as"
 include "flash/globalization/NumberFormatter.as"
 include "flash/globalization/NumberParseResult.as"
+include "flash/globalization/StringTools.as"
 
 include "flash/media/AudioDecoder.as"
 include "flash/media/AudioOutputChangeReason.as"
@@ -309,6 +314,7 @@ include "flash/system/IMEConversionMode.as"
 include "flash/system/ImageDecodingPolicy.as"
 include "flash/system/LoaderContext.as"
 include "flash/system/JPEGLoaderContext.as"
+include "flash/system/MessageChannel.as"
 include "flash/system/MessageChannelState.as"
 include "flash/system/Security.as"
 include "flash/system/SecurityDomain.as"
@@ -340,6 +346,7 @@ include "flash/text/TextFormatDisplay.as"
 include "flash/text/TextInteractionMode.as"
 include "flash/text/TextLineMetrics.as"
 include "flash/text/TextRenderer.as"
+include "flash/text/TextSnapshot.as"
 
 include "flash/text/engine/BreakOpportunity.as"
 include "flash/text/engine/CFFHinting.as"
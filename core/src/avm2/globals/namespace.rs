@@ -2,14 +2,17 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::Class;
+use crate::avm2::e4x::is_xml_name;
+use crate::avm2::error::type_error;
 use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::object::{namespace_allocator, Object, TObject};
+use crate::avm2::string::AvmString;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::avm2::Multiname;
 use crate::avm2::Namespace;
 use crate::avm2::QName;
-use crate::{avm2_stub_constructor, avm2_stub_getter};
+use crate::avm2_stub_constructor;
 use gc_arena::GcCell;
 
 /// Implements `Namespace`'s instance initializer.
@@ -19,31 +22,68 @@ pub fn instance_init<'gc>(
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(this) = this.as_namespace_object() {
-        let uri_value = match args {
-            [_prefix, uri] => {
-                avm2_stub_constructor!(activation, "Namespace", "Namespace prefix not supported");
-                Some(*uri)
-            }
-            [uri] => Some(*uri),
-            _ => None,
+        let (prefix_value, uri_value) = match args {
+            [prefix, uri] => (Some(*prefix), Some(*uri)),
+            [uri] => (None, Some(*uri)),
+            _ => (None, None),
         };
 
         let api_version = activation.avm2().root_api_version;
 
-        let namespace = match uri_value {
-            Some(Value::Object(Object::QNameObject(qname))) => qname
-                .uri()
-                .map(|uri| Namespace::package(uri, api_version, &mut activation.borrow_gc()))
-                .unwrap_or_else(|| Namespace::any(activation.context.gc_context)),
-            Some(val) => Namespace::package(
-                val.coerce_to_string(activation)?,
-                api_version,
-                &mut activation.borrow_gc(),
+        let (namespace, default_prefix) = match uri_value {
+            Some(Value::Object(Object::NamespaceObject(ns))) if prefix_value.is_none() => {
+                (ns.namespace(), ns.prefix())
+            }
+            Some(Value::Object(Object::QNameObject(qname))) => (
+                qname
+                    .uri()
+                    .map(|uri| Namespace::package(uri, api_version, &mut activation.borrow_gc()))
+                    .unwrap_or_else(|| Namespace::any(activation.context.gc_context)),
+                None,
+            ),
+            Some(val) => {
+                let uri = val.coerce_to_string(activation)?;
+                let prefix = uri.is_empty().then(AvmString::default);
+                (
+                    Namespace::package(uri, api_version, &mut activation.borrow_gc()),
+                    prefix,
+                )
+            }
+            None => (
+                activation.avm2().public_namespace_base_version,
+                Some(AvmString::default()),
             ),
-            None => activation.avm2().public_namespace_base_version,
+        };
+
+        // ECMA-357 13.2.2 The Namespace Constructor, steps 4.c - 4.e
+        let prefix = match prefix_value {
+            None => default_prefix,
+            Some(prefix_value) if namespace.as_uri().is_empty() => {
+                let prefix = if prefix_value == Value::Undefined {
+                    AvmString::default()
+                } else {
+                    prefix_value.coerce_to_string(activation)?
+                };
+
+                if !prefix.is_empty() {
+                    return Err(Error::AvmError(type_error(
+                        activation,
+                        &format!("Error #1098: Illegal prefix {prefix} for no namespace."),
+                        1098,
+                    )?));
+                }
+
+                Some(prefix)
+            }
+            Some(Value::Undefined) => None,
+            Some(prefix_value) => {
+                let prefix = prefix_value.coerce_to_string(activation)?;
+                is_xml_name(prefix).then_some(prefix)
+            }
         };
 
         this.init_namespace(activation.context.gc_context, namespace);
+        this.init_prefix(activation.context.gc_context, prefix);
     }
     Ok(Value::Undefined)
 }
@@ -79,13 +119,12 @@ pub fn class_init<'gc>(
 
 /// Implements `Namespace.prefix`'s getter
 pub fn prefix<'gc>(
-    activation: &mut Activation<'_, 'gc>,
+    _activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    if this.as_namespace_object().is_some() {
-        avm2_stub_getter!(activation, "Namespace", "prefix");
-        return Ok("".into());
+    if let Some(o) = this.as_namespace_object() {
+        return Ok(o.prefix().map_or(Value::Undefined, Value::String));
     }
 
     Ok(Value::Undefined)
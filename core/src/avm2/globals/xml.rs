@@ -1,17 +1,13 @@
 //! XML builtin and prototype
 
-use crate::avm2::api_version::ApiVersion;
-use crate::avm2::e4x::{name_to_multiname, E4XNode, E4XNodeKind};
+use crate::avm2::array::ArrayStorage;
+use crate::avm2::e4x::{name_to_multiname, E4XNamespace, E4XNode, E4XNodeKind};
 use crate::avm2::error::type_error;
 pub use crate::avm2::object::xml_allocator;
-use crate::avm2::object::{
-    E4XOrXml, NamespaceObject, QNameObject, TObject, XmlListObject, XmlObject,
-};
+use crate::avm2::object::{ArrayObject, E4XOrXml, QNameObject, TObject, XmlListObject, XmlObject};
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::string::AvmString;
-use crate::avm2::Namespace;
 use crate::avm2::{Activation, Error, Multiname, Object, Value};
-use crate::avm2_stub_method;
 
 fn ill_formed_markup_err<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -81,14 +77,16 @@ pub fn name<'gc>(
     }
 }
 
+// ECMA-357 13.4.4.35 XML.prototype.setName ( name )
 pub fn set_name<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     let xml = this.as_xml_object().unwrap();
-    let node = xml.node();
+    let node = *xml.node();
 
+    // 1. If x.[[Class]] ∈ {"text", "comment"}, return
     let is_attribute_or_element = matches!(
         &*node.kind(),
         E4XNodeKind::Attribute(_)
@@ -102,17 +100,19 @@ pub fn set_name<'gc>(
 
     let new_name = args.get_value(0);
 
-    let new_name = if let Some(qname) = new_name.as_object().and_then(|q| q.as_qname_object()) {
-        let has_no_ns = qname.name().is_any_namespace()
-            || (qname.name().namespace_set().len() == 1
-                && qname.name().namespace_set()[0].is_public());
-        if !has_no_ns {
-            avm2_stub_method!(activation, "XML", "setName", "with QName namespaces");
-        }
-        qname.local_name()
-    } else {
-        new_name.coerce_to_string(activation)?
-    };
+    // 2. If (Type(name) is Object) and (name.[[Class]] == "QName") and (name.uri == null)
+    // 2.a. Let name = name.localName
+    // 3. Let n be a new QName created as if by calling the constructor new QName(name)
+    let (namespace, new_name) =
+        if let Some(qname) = new_name.as_object().and_then(|q| q.as_qname_object()) {
+            let namespace = qname
+                .uri()
+                .filter(|uri| !uri.is_empty())
+                .map(E4XNamespace::new_uri);
+            (namespace, qname.local_name())
+        } else {
+            (None, new_name.coerce_to_string(activation)?)
+        };
 
     let is_name_valid = crate::avm2::e4x::is_xml_name(new_name);
     if !is_name_valid {
@@ -123,28 +123,52 @@ pub fn set_name<'gc>(
         )?));
     }
 
-    node.set_local_name(new_name, activation.context.gc_context);
+    // 4. If x.[[Class]] == "processing-instruction", let n.uri be the empty string
+    let namespace =
+        namespace.filter(|_| !matches!(&*node.kind(), E4XNodeKind::ProcessingInstruction(_)));
+
+    let old_name = name(activation, this, &[])?;
+
+    // 5. Let x.[[Name]] = n
+    node.set_local_name(new_name, activation.gc());
+    node.set_namespace(namespace, activation.gc());
+
+    // 6. Let ns be the result of calling [[GetNamespace]] on n with no arguments
+    let ns = node.get_namespace(&[]);
+
+    // 7. If x.[[Class]] == "attribute"
+    if node.is_attribute() {
+        // 7.a. If x.[[Parent]] == null, return
+        // 7.b. Call x.[[Parent]].[[AddInScopeNamespace]](ns)
+        if let Some(parent) = node.parent() {
+            parent.add_in_scope_namespace(activation.gc(), ns);
+        }
+    // 8. If x.[[Class]] == "element"
+    } else if node.is_element() {
+        // 8.a. Call x.[[AddInScopeNamespace]](ns)
+        node.add_in_scope_namespace(activation.gc(), ns);
+    }
+
+    let new_name = name(activation, this, &[])?;
+    node.notify(activation, "nameSet", new_name, old_name)?;
 
     Ok(Value::Undefined)
 }
 
+// ECMA-357 13.4.4.23 XML.prototype.namespace ( [ prefix ] )
 // namespace_internal_impl(hasPrefix:Boolean, prefix:String = null):*
 pub fn namespace_internal_impl<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "XML", "namespace");
+    let xml = this.as_xml_object().unwrap();
+    let node = *xml.node();
 
-    // FIXME:
     // 1. Let y = x
     // 2. Let inScopeNS = { }
     // 3. While (y is not null)
-    //     a. For each ns in y.[[InScopeNamespaces]]
-    //     ....
-
-    let xml = this.as_xml_object().unwrap();
-    let node = xml.node();
+    let in_scope = node.in_scope_namespaces();
 
     // 4. If prefix was not specified
     if args[0] == Value::Bool(false) {
@@ -160,29 +184,187 @@ pub fn namespace_internal_impl<'gc>(
         }
 
         // b. Return the result of calling the [[GetNamespace]] method of x.[[Name]] with argument inScopeNS
-        // FIXME: Use inScopeNS
-        let namespace = xml.namespace(activation);
-        Ok(NamespaceObject::from_namespace(activation, namespace)?.into())
+        node.get_namespace(&in_scope)
+            .as_namespace_object(activation)
+            .map(Into::into)
     } else {
         // a. Let prefix = ToString(prefix)
         let prefix = args.get_string(activation, 1)?;
 
         // b. Find a Namespace ns ∈ inScopeNS, such that ns.prefix = prefix. If no such ns exists, let ns = undefined.
         // c. Return ns
+        match in_scope.iter().find(|ns| ns.prefix == Some(prefix)) {
+            Some(ns) => ns.as_namespace_object(activation).map(Into::into),
+            None => Ok(Value::Undefined),
+        }
+    }
+}
 
-        // FIXME: Nodes currently either have zero or one namespace, which has the prefix "" (empty string)
-        Ok(match node.namespace() {
-            Some(ns) if prefix.is_empty() => {
-                let namespace = Namespace::package(
-                    ns,
-                    ApiVersion::AllVersions,
-                    &mut activation.context.borrow_gc(),
-                );
-                NamespaceObject::from_namespace(activation, namespace)?.into()
-            }
-            _ => Value::Undefined,
-        })
+// ECMA-357 13.4.4.17 XML.prototype.inScopeNamespaces ( )
+pub fn in_scope_namespaces<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let xml = this.as_xml_object().unwrap();
+    let in_scope = xml.node().in_scope_namespaces();
+
+    let mut namespaces = Vec::with_capacity(in_scope.len());
+    for ns in in_scope {
+        namespaces.push(ns.as_namespace_object(activation)?.into());
+    }
+
+    Ok(ArrayObject::from_storage(activation, ArrayStorage::from_args(&namespaces))?.into())
+}
+
+// ECMA-357 13.4.4.24 XML.prototype.namespaceDeclarations ( )
+pub fn namespace_declarations<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let xml = this.as_xml_object().unwrap();
+    let node = *xml.node();
+
+    let mut declarations = Vec::new();
+
+    // 2. If x.[[Class]] ∈ {"text", "comment", "processing-instruction", "attribute"}, return a
+    if node.is_element() {
+        // 3. Let y = x.[[Parent]]
+        // 4. Let ancestorNS = { }
+        // 5. While (y is not null)
+        let ancestors = node
+            .parent()
+            .map(|parent| parent.in_scope_namespaces())
+            .unwrap_or_default();
+
+        // 6. Let declaredNS = { }
+        // 7. For each ns in x.[[InScopeNamespaces]]
+        // 7.a. If there exists no n ∈ ancestorNS, such that n.prefix == ns.prefix and n.uri == ns.uri
+        // 7.a.i. Let declaredNS = declaredNS ∪ { ns }
+        let declared: Vec<_> = node
+            .namespaces()
+            .iter()
+            .filter(|ns| {
+                !ancestors
+                    .iter()
+                    .any(|other| other.prefix == ns.prefix && other.uri == ns.uri)
+            })
+            .copied()
+            .collect();
+
+        for ns in declared {
+            declarations.push(ns.as_namespace_object(activation)?.into());
+        }
+    }
+
+    // 8. Let i = 0
+    // 9. For each ns in declaredNS
+    // 10. Return a
+    Ok(ArrayObject::from_storage(activation, ArrayStorage::from_args(&declarations))?.into())
+}
+
+// ECMA-357 13.4.4.2 XML.prototype.addNamespace ( namespace )
+pub fn add_namespace<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let xml = this.as_xml_object().unwrap();
+    let node = *xml.node();
+
+    // 1. Let ns a Namespace constructed as if by calling the function Namespace(namespace)
+    let value = args.get_value(0);
+    let ns = E4XNamespace::from_value(activation, value)?;
+
+    // 2. Call the [[AddInScopeNamespace]] method of x with parameter ns
+    node.add_in_scope_namespace(activation.gc(), ns);
+
+    let ns = ns.as_namespace_object(activation)?;
+    node.notify(activation, "namespaceAdded", ns.into(), Value::Null)?;
+
+    // 3. Return x
+    Ok(xml.into())
+}
+
+// ECMA-357 13.4.4.31 XML.prototype.removeNamespace ( namespace )
+pub fn remove_namespace<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let xml = this.as_xml_object().unwrap();
+    let node = *xml.node();
+
+    // 1. If x.[[Class]] ∈ {"text", "comment", "processing-instruction", "attribute"}, return x
+    if !node.is_element() {
+        return Ok(xml.into());
+    }
+
+    // 2. Let ns be a Namespace object created as if by calling the function Namespace( namespace )
+    let value = args.get_value(0);
+    let ns = E4XNamespace::from_value(activation, value)?;
+
+    node.remove_namespace(activation.gc(), ns);
+
+    let ns = ns.as_namespace_object(activation)?;
+    node.notify(activation, "namespaceRemoved", ns.into(), Value::Null)?;
+
+    // 9. Return x
+    Ok(xml.into())
+}
+
+// ECMA-357 13.4.4.36 XML.prototype.setNamespace ( ns )
+pub fn set_namespace<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let xml = this.as_xml_object().unwrap();
+    let node = *xml.node();
+
+    // 1. If x.[[Class]] ∈ {"text", "comment", "processing-instruction"}, return
+    if !node.is_element() && !node.is_attribute() {
+        return Ok(Value::Undefined);
     }
+
+    // 2. Let ns2 be a new Namespace created as if by calling the constructor new Namespace(ns)
+    let value = args.get_value(0);
+    let ns = E4XNamespace::from_value(activation, value)?;
+
+    // 3. Let x.[[Name]] be a new QName created as if by calling the constructor new QName(ns2, x.[[Name]])
+    node.set_namespace(Some(ns), activation.gc());
+
+    // 4. If x.[[Class]] == "attribute"
+    if node.is_attribute() {
+        // 4.a. If x.[[Parent]] == null, return
+        // 4.b. Call x.[[Parent]].[[AddInScopeNamespace]](ns2)
+        if let Some(parent) = node.parent() {
+            parent.add_in_scope_namespace(activation.gc(), ns);
+        }
+    // 5. If x.[[Class]] == "element"
+    } else {
+        // 5.a. Call x.[[AddInScopeNamespace]](ns2)
+        node.add_in_scope_namespace(activation.gc(), ns);
+    }
+
+    let ns = ns.as_namespace_object(activation)?;
+    node.notify(activation, "namespaceSet", ns.into(), Value::Null)?;
+
+    Ok(Value::Undefined)
+}
+
+// ECMA-357 13.4.4.26 XML.prototype.normalize ( )
+pub fn normalize<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let xml = this.as_xml_object().unwrap();
+    xml.node().normalize(activation.gc());
+
+    // 3. Return x
+    Ok(xml.into())
 }
 
 pub fn local_name<'gc>(
@@ -432,6 +614,9 @@ pub fn append_child<'gc>(
     );
     xml_list.set_property_local(&name, child, activation)?;
 
+    xml.node()
+        .notify(activation, "nodeAdded", child, Value::Null)?;
+
     // 3. Return x
     Ok(this.into())
 }
@@ -449,6 +634,9 @@ pub fn prepend_child<'gc>(
     // 1. Call the [[Insert]] method of this object with arguments "0" and value
     xml.node().insert(0, child, activation)?;
 
+    xml.node()
+        .notify(activation, "nodeAdded", child, Value::Null)?;
+
     // 2. Return x
     Ok(xml.into())
 }
@@ -628,6 +816,8 @@ pub fn insert_child_after<'gc>(
         if let Some(index) = index {
             // 3.a.i.1. Call the [[Insert]] method of x with arguments ToString(i + 1) and child2
             xml.node().insert(index + 1, child2, activation)?;
+            xml.node()
+                .notify(activation, "nodeAdded", child2, Value::Null)?;
             // 3.a.i.2. Return x
             return Ok(xml.into());
         }
@@ -635,6 +825,8 @@ pub fn insert_child_after<'gc>(
     } else if matches!(child1, Value::Null) {
         // 2.a. Call the [[Insert]] method of x with arguments "0" and child2
         xml.node().insert(0, child2, activation)?;
+        xml.node()
+            .notify(activation, "nodeAdded", child2, Value::Null)?;
         // 2.b. Return x
         return Ok(xml.into());
     }
@@ -684,6 +876,8 @@ pub fn insert_child_before<'gc>(
         if let Some(index) = index {
             // 3.a.i.1. Call the [[Insert]] method of x with arguments ToString(i) and child2
             xml.node().insert(index, child2, activation)?;
+            xml.node()
+                .notify(activation, "nodeAdded", child2, Value::Null)?;
             // 3.a.i.2. Return x
             return Ok(xml.into());
         }
@@ -697,6 +891,8 @@ pub fn insert_child_before<'gc>(
 
         // 2.a. Call the [[Insert]] method of x with arguments ToString(x.[[Length]]) and child2
         xml.node().insert(length, child2, activation)?;
+        xml.node()
+            .notify(activation, "nodeAdded", child2, Value::Null)?;
         // 2.b. Return x
         return Ok(xml.into());
     }
@@ -785,7 +981,6 @@ pub fn set_notification<'gc>(
     this: Object<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "XML", "setNotification");
     let xml = this.as_xml_object().unwrap();
     let node = xml.node();
     let fun = args.try_get_object(activation, 0);
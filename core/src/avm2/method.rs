@@ -2,6 +2,7 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::object::{ClassObject, Object};
+use crate::avm2::optimize::OptimizedCode;
 use crate::avm2::script::TranslationUnit;
 use crate::avm2::value::{abc_default_value, Value};
 use crate::avm2::Error;
@@ -11,6 +12,7 @@ use crate::tag_utils::SwfMovie;
 use gc_arena::barrier::unlock;
 use gc_arena::lock::Lock;
 use gc_arena::{Collect, Gc, Mutation};
+use std::cell::Cell;
 use std::fmt;
 use std::ops::Deref;
 use std::rc::Rc;
@@ -133,6 +135,14 @@ pub struct BytecodeMethod<'gc> {
     /// A free-standing function corresponds to the `Function` trait type, and
     /// is instantiated with the `newfunction` opcode.
     pub is_function: bool,
+
+    /// Whether this method's body has already passed verification.
+    #[collect(require_static)]
+    verified: Cell<bool>,
+
+    /// The optimized form of this method's body. Initialized lazily, the
+    /// first time the method is run.
+    optimized_code: Lock<Option<Gc<'gc, OptimizedCode<'gc>>>>,
 }
 
 impl<'gc> BytecodeMethod<'gc> {
@@ -168,6 +178,8 @@ impl<'gc> BytecodeMethod<'gc> {
                         return_type,
                         is_function,
                         activation_class: Lock::new(None),
+                        verified: Cell::new(false),
+                        optimized_code: Lock::new(None),
                     });
                 }
             }
@@ -182,6 +194,8 @@ impl<'gc> BytecodeMethod<'gc> {
             return_type: Multiname::any(activation.gc()),
             is_function,
             activation_class: Lock::new(None),
+            verified: Cell::new(false),
+            optimized_code: Lock::new(None),
         })
     }
 
@@ -216,6 +230,17 @@ impl<'gc> BytecodeMethod<'gc> {
         }
     }
 
+    /// Whether this method's body has been verified yet.
+    pub fn is_verified(&self) -> bool {
+        self.verified.get()
+    }
+
+    /// Record that this method's body passed verification, so that it won't
+    /// be checked again.
+    pub fn set_verified(&self) {
+        self.verified.set(true);
+    }
+
     /// Get the list of method params for this method.
     pub fn signature(&self) -> &[ParamConfig<'gc>] {
         &self.signature
@@ -286,6 +311,21 @@ impl<'gc> BytecodeMethod<'gc> {
             None
         })
     }
+
+    /// Initialize and return the optimized form of this method's body.
+    pub fn get_or_init_optimized_code(
+        this: Gc<'gc, Self>,
+        mc: &Mutation<'gc>,
+        init: impl FnOnce() -> Result<OptimizedCode<'gc>, Error<'gc>>,
+    ) -> Result<Gc<'gc, OptimizedCode<'gc>>, Error<'gc>> {
+        if let Some(cached) = this.optimized_code.get() {
+            return Ok(cached);
+        }
+
+        let code = Gc::new(mc, init()?);
+        unlock!(Gc::write(mc, this), Self, optimized_code).set(Some(code));
+        Ok(code)
+    }
 }
 
 /// An uninstantiated method
@@ -23,7 +23,7 @@ use crate::display_object::DisplayObject;
 use crate::html::TextFormat;
 use crate::streams::NetStream;
 use crate::string::AvmString;
-use gc_arena::{Collect, Gc, GcCell, Mutation};
+use gc_arena::{Collect, Finalization, Gc, GcCell, Mutation};
 use ruffle_macros::enum_trait_object;
 use std::cell::{Ref, RefMut};
 use std::fmt::Debug;
@@ -217,13 +217,27 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
     /// This corresponds directly to the AVM2 operation `getproperty`, with the
     /// exception that it does not special-case object lookups on dictionary
     /// structured objects.
-    #[allow(unused_mut)] //Not unused.
     fn get_property(
+        self,
+        multiname: &Multiname<'gc>,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Result<Value<'gc>, Error<'gc>> {
+        let property = self.vtable().and_then(|vtable| vtable.get_trait(multiname));
+        self.get_resolved_property(property, multiname, activation)
+    }
+
+    /// Retrieve a property whose trait has already been looked up.
+    ///
+    /// `property` must be what this object's vtable holds for `multiname`.
+    /// This allows the interpreter to cache trait lookups.
+    #[allow(unused_mut)] //Not unused.
+    fn get_resolved_property(
         mut self,
+        property: Option<Property>,
         multiname: &Multiname<'gc>,
         activation: &mut Activation<'_, 'gc>,
     ) -> Result<Value<'gc>, Error<'gc>> {
-        match self.vtable().and_then(|vtable| vtable.get_trait(multiname)) {
+        match property {
             Some(Property::Slot { slot_id }) | Some(Property::ConstSlot { slot_id }) => {
                 self.base().get_slot(slot_id)
             }
@@ -318,7 +332,21 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
         value: Value<'gc>,
         activation: &mut Activation<'_, 'gc>,
     ) -> Result<(), Error<'gc>> {
-        match self.vtable().and_then(|vtable| vtable.get_trait(multiname)) {
+        let property = self.vtable().and_then(|vtable| vtable.get_trait(multiname));
+        self.set_resolved_property(property, multiname, value, activation)
+    }
+
+    /// Set a property whose trait has already been looked up.
+    ///
+    /// `property` must be what this object's vtable holds for `multiname`.
+    fn set_resolved_property(
+        &self,
+        property: Option<Property>,
+        multiname: &Multiname<'gc>,
+        value: Value<'gc>,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Result<(), Error<'gc>> {
+        match property {
             Some(Property::Slot { slot_id }) => {
                 let value = self
                     .vtable()
@@ -403,7 +431,21 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
         value: Value<'gc>,
         activation: &mut Activation<'_, 'gc>,
     ) -> Result<(), Error<'gc>> {
-        match self.vtable().and_then(|vtable| vtable.get_trait(multiname)) {
+        let property = self.vtable().and_then(|vtable| vtable.get_trait(multiname));
+        self.init_resolved_property(property, multiname, value, activation)
+    }
+
+    /// Initialize a property whose trait has already been looked up.
+    ///
+    /// `property` must be what this object's vtable holds for `multiname`.
+    fn init_resolved_property(
+        &self,
+        property: Option<Property>,
+        multiname: &Multiname<'gc>,
+        value: Value<'gc>,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Result<(), Error<'gc>> {
+        match property {
             Some(Property::Slot { slot_id }) | Some(Property::ConstSlot { slot_id }) => {
                 let value = self
                     .vtable()
@@ -467,14 +509,28 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
     /// This method should not be overridden.
     ///
     /// This corresponds directly to the `callproperty` operation in AVM2.
-    #[allow(unused_mut)]
     fn call_property(
+        self,
+        multiname: &Multiname<'gc>,
+        arguments: &[Value<'gc>],
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Result<Value<'gc>, Error<'gc>> {
+        let property = self.vtable().and_then(|vtable| vtable.get_trait(multiname));
+        self.call_resolved_property(property, multiname, arguments, activation)
+    }
+
+    /// Call a property whose trait has already been looked up.
+    ///
+    /// `property` must be what this object's vtable holds for `multiname`.
+    #[allow(unused_mut)]
+    fn call_resolved_property(
         mut self,
+        property: Option<Property>,
         multiname: &Multiname<'gc>,
         arguments: &[Value<'gc>],
         activation: &mut Activation<'_, 'gc>,
     ) -> Result<Value<'gc>, Error<'gc>> {
-        match self.vtable().and_then(|vtable| vtable.get_trait(multiname)) {
+        match property {
             Some(Property::Slot { slot_id }) | Some(Property::ConstSlot { slot_id }) => {
                 let obj = self.base().get_slot(slot_id)?.as_callable(
                     activation,
@@ -1557,4 +1613,47 @@ impl<'gc> WeakObject<'gc> {
             Self::FontObject(o) => FontObject(o.0.upgrade(mc)?).into(),
         })
     }
+
+    /// Whether the referenced object is about to be collected, unless it's resurrected.
+    pub fn is_dead(self, fc: &Finalization<'gc>) -> bool {
+        match self {
+            Self::ScriptObject(o) => o.0.is_dead(fc),
+            Self::FunctionObject(o) => o.0.is_dead(fc),
+            Self::PrimitiveObject(o) => o.0.is_dead(fc),
+            Self::NamespaceObject(o) => o.0.is_dead(fc),
+            Self::ArrayObject(o) => o.0.is_dead(fc),
+            Self::StageObject(o) => o.0.is_dead(fc),
+            Self::DomainObject(o) => o.0.is_dead(fc),
+            Self::EventObject(o) => o.0.is_dead(fc),
+            Self::DispatchObject(o) => o.0.is_dead(fc),
+            Self::XmlObject(o) => o.0.is_dead(fc),
+            Self::XmlListObject(o) => o.0.is_dead(fc),
+            Self::RegExpObject(o) => o.0.is_dead(fc),
+            Self::ByteArrayObject(o) => o.0.is_dead(fc),
+            Self::LoaderInfoObject(o) => o.0.is_dead(fc),
+            Self::ClassObject(o) => o.0.is_dead(fc),
+            Self::VectorObject(o) => o.0.is_dead(fc),
+            Self::SoundObject(o) => o.0.is_dead(fc),
+            Self::SoundChannelObject(o) => o.0.is_dead(fc),
+            Self::BitmapDataObject(o) => o.0.is_dead(fc),
+            Self::DateObject(o) => o.0.is_dead(fc),
+            Self::DictionaryObject(o) => o.0.is_dead(fc),
+            Self::QNameObject(o) => o.0.is_dead(fc),
+            Self::TextFormatObject(o) => o.0.is_dead(fc),
+            Self::ProxyObject(o) => o.0.is_dead(fc),
+            Self::ErrorObject(o) => o.0.is_dead(fc),
+            Self::Stage3DObject(o) => o.0.is_dead(fc),
+            Self::Context3DObject(o) => o.0.is_dead(fc),
+            Self::IndexBuffer3DObject(o) => o.0.is_dead(fc),
+            Self::VertexBuffer3DObject(o) => o.0.is_dead(fc),
+            Self::TextureObject(o) => o.0.is_dead(fc),
+            Self::Program3DObject(o) => o.0.is_dead(fc),
+            Self::NetStreamObject(o) => o.0.is_dead(fc),
+            Self::NetConnectionObject(o) => o.0.is_dead(fc),
+            Self::ResponderObject(o) => o.0.is_dead(fc),
+            Self::ShaderDataObject(o) => o.0.is_dead(fc),
+            Self::SocketObject(o) => o.0.is_dead(fc),
+            Self::FontObject(o) => o.0.is_dead(fc),
+        }
+    }
 }
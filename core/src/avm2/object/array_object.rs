@@ -4,6 +4,7 @@ use crate::avm2::activation::Activation;
 use crate::avm2::array::ArrayStorage;
 use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject};
+use crate::avm2::sampler::Sampler;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::avm2::Multiname;
@@ -79,6 +80,8 @@ impl<'gc> ArrayObject<'gc> {
         .into();
         instance.install_instance_slots(activation.context.gc_context);
 
+        Sampler::record_new_object(activation, instance);
+
         class.call_native_init(instance.into(), &[], activation)?;
 
         Ok(instance)
@@ -9,6 +9,7 @@ use crate::avm2::object::function_object::FunctionObject;
 use crate::avm2::object::script_object::{scriptobject_allocator, ScriptObjectData};
 use crate::avm2::object::{Object, ObjectPtr, TObject};
 use crate::avm2::property::Property;
+use crate::avm2::sampler::Sampler;
 use crate::avm2::scope::{Scope, ScopeChain};
 use crate::avm2::value::Value;
 use crate::avm2::vtable::{ClassBoundMethod, VTable};
@@ -155,7 +156,11 @@ impl<'gc> ClassObject<'gc> {
             class_class,
         );
         class_object.init_instance_vtable(activation)?;
-        class_object.into_finished_class(activation)
+        let class_object = class_object.into_finished_class(activation)?;
+
+        Sampler::record_new_object(activation, class_object.into());
+
+        Ok(class_object)
     }
 
     /// Allocate a class but do not properly construct it.
@@ -892,6 +897,8 @@ impl<'gc> TObject<'gc> for ClassObject<'gc> {
 
         instance.install_instance_slots(activation.context.gc_context);
 
+        Sampler::record_new_object(activation, instance);
+
         self.call_init(instance.into(), arguments, activation)?;
 
         Ok(instance)
@@ -1,6 +1,7 @@
 //! Object representation for Context3D objects
 
 use crate::avm2::activation::Activation;
+use crate::avm2::error::{argument_error, make_error_2008};
 use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{Object, ObjectPtr, TObject};
 use crate::avm2::value::Value;
@@ -13,10 +14,12 @@ use gc_arena::lock::RefLock;
 use gc_arena::{Collect, Gc, GcCell, GcWeak, Mutation};
 use ruffle_render::backend::{
     BufferUsage, Context3D, Context3DBlendFactor, Context3DCommand, Context3DCompareMode,
-    Context3DTextureFormat, Context3DTriangleFace, Context3DVertexBufferFormat, ProgramType,
-    Texture,
+    Context3DProfile, Context3DStencilAction, Context3DTextureFormat, Context3DTriangleFace,
+    Context3DVertexBufferFormat, ProgramType, Texture,
 };
+use ruffle_render::bitmap::BitmapInfo;
 use ruffle_render::commands::CommandHandler;
+use ruffle_render::error::Error as RenderError;
 use std::cell::{Cell, Ref, RefMut};
 use std::rc::Rc;
 use swf::{Rectangle, Twips};
@@ -37,6 +40,7 @@ impl<'gc> Context3DObject<'gc> {
     pub fn from_context(
         activation: &mut Activation<'_, 'gc>,
         context: Box<dyn Context3D>,
+        profile: Context3DProfile,
     ) -> Result<Object<'gc>, Error<'gc>> {
         let class = activation.avm2().classes().context3d;
 
@@ -45,6 +49,7 @@ impl<'gc> Context3DObject<'gc> {
             Context3DData {
                 base: RefLock::new(ScriptObjectData::new(class)),
                 render_context: Cell::new(Some(context)),
+                profile,
             },
         ))
         .into();
@@ -55,6 +60,39 @@ impl<'gc> Context3DObject<'gc> {
         Ok(this)
     }
 
+    pub fn profile(&self) -> Context3DProfile {
+        self.0.profile
+    }
+
+    pub fn driver_info(&self) -> String {
+        self.with_context_3d(|ctx| ctx.driver_info())
+    }
+
+    /// Checks that our profile allows creating a texture with this size and format.
+    fn check_texture_limits(
+        &self,
+        activation: &mut Activation<'_, 'gc>,
+        width: u32,
+        height: u32,
+        format: Context3DTextureFormat,
+    ) -> Result<(), Error<'gc>> {
+        if matches!(format, Context3DTextureFormat::RgbaHalfFloat)
+            && !self.profile().supports_float_textures()
+        {
+            return Err(make_error_2008(activation, "format"));
+        }
+
+        let max_size = self.profile().max_texture_size();
+        if width > max_size || height > max_size {
+            return Err(Error::AvmError(argument_error(
+                activation,
+                &format!("Error #3683: Texture too big (max is {max_size}x{max_size})."),
+                3683,
+            )?));
+        }
+        Ok(())
+    }
+
     fn with_context_3d<R>(&self, f: impl FnOnce(&mut dyn Context3D) -> R) -> R {
         // Temporarily take ownership of the Context3D instance.
         let cell = &self.0.render_context;
@@ -111,6 +149,7 @@ impl<'gc> Context3DObject<'gc> {
         class: ClassObject<'gc>,
         activation: &mut Activation<'_, 'gc>,
     ) -> Result<Value<'gc>, Error<'gc>> {
+        self.check_texture_limits(activation, width, height, format)?;
         check_texture_stub(activation, format);
         let texture = self.with_context_3d(|ctx| {
             ctx.create_texture(
@@ -359,6 +398,32 @@ impl<'gc> Context3DObject<'gc> {
         });
     }
 
+    /// Copy a decoded video frame into a `VideoTexture`, recreating the
+    /// texture first if the frame size has changed.
+    pub(crate) fn copy_video_frame_to_texture(
+        &self,
+        source: &BitmapInfo,
+        dest: TextureObject<'gc>,
+    ) -> Result<(), RenderError> {
+        let width = source.width as u32;
+        let height = source.height as u32;
+
+        self.with_context_3d(|ctx| {
+            let mut handle = dest.handle();
+            if handle.width() != width || handle.height() != height {
+                handle =
+                    ctx.create_texture(width, height, Context3DTextureFormat::Bgra, false, 0)?;
+                dest.set_handle(handle.clone());
+            }
+
+            ctx.process_command(Context3DCommand::CopyBitmapHandleToTexture {
+                source: source.handle.clone(),
+                dest: handle,
+            });
+            Ok(())
+        })
+    }
+
     #[cfg_attr(not(feature = "jpegxr"), allow(unused))]
     pub(crate) fn copy_pixels_to_texture(
         &self,
@@ -410,6 +475,40 @@ impl<'gc> Context3DObject<'gc> {
         });
     }
 
+    pub(crate) fn set_stencil_actions(
+        &self,
+        face: Context3DTriangleFace,
+        compare_mode: Context3DCompareMode,
+        action_on_both_pass: Context3DStencilAction,
+        action_on_depth_fail: Context3DStencilAction,
+        action_on_depth_pass_stencil_fail: Context3DStencilAction,
+    ) {
+        self.with_context_3d(|ctx| {
+            ctx.process_command(Context3DCommand::SetStencilActions {
+                face,
+                compare_mode,
+                action_on_both_pass,
+                action_on_depth_fail,
+                action_on_depth_pass_stencil_fail,
+            })
+        });
+    }
+
+    pub(crate) fn set_stencil_reference_value(
+        &self,
+        reference_value: u32,
+        read_mask: u32,
+        write_mask: u32,
+    ) {
+        self.with_context_3d(|ctx| {
+            ctx.process_command(Context3DCommand::SetStencilReferenceValue {
+                reference_value,
+                read_mask,
+                write_mask,
+            })
+        });
+    }
+
     pub(crate) fn create_cube_texture(
         &self,
         size: u32,
@@ -418,6 +517,7 @@ impl<'gc> Context3DObject<'gc> {
         streaming_levels: u32,
         activation: &mut Activation<'_, 'gc>,
     ) -> Result<Value<'gc>, Error<'gc>> {
+        self.check_texture_limits(activation, size, size, format)?;
         check_texture_stub(activation, format);
         let texture = self.with_context_3d(|ctx| {
             ctx.create_cube_texture(
@@ -465,6 +565,9 @@ pub struct Context3DData<'gc> {
 
     #[collect(require_static)]
     render_context: Cell<Option<Box<dyn Context3D>>>,
+
+    #[collect(require_static)]
+    profile: Context3DProfile,
 }
 
 impl<'gc> TObject<'gc> for Context3DObject<'gc> {
@@ -1,14 +1,14 @@
 //! Object representation for `flash.utils.Dictionary`
 
 use crate::avm2::activation::Activation;
-use crate::avm2::dynamic_map::StringOrObject;
+use crate::avm2::dynamic_map::{DynamicMap, StringOrObject};
 use crate::avm2::object::script_object::ScriptObjectData;
-use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject};
+use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject, WeakObject};
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::string::AvmString;
 use core::fmt;
-use gc_arena::{Collect, GcCell, GcWeakCell, Mutation};
+use gc_arena::{Collect, Finalization, Gc, GcCell, GcWeak, GcWeakCell, Mutation};
 use std::cell::{Ref, RefMut};
 
 /// A class instance allocator that allocates Dictionary objects.
@@ -20,7 +20,10 @@ pub fn dictionary_allocator<'gc>(
 
     Ok(DictionaryObject(GcCell::new(
         activation.context.gc_context,
-        DictionaryObjectData { base },
+        DictionaryObjectData {
+            base,
+            weak_values: None,
+        },
     ))
     .into())
 }
@@ -51,11 +54,79 @@ impl fmt::Debug for DictionaryObject<'_> {
 pub struct DictionaryObjectData<'gc> {
     /// Base script object
     base: ScriptObjectData<'gc>,
+
+    /// The object space of a dictionary constructed with `weakKeys`.
+    ///
+    /// Both keys and values are only held weakly here: each entry behaves
+    /// as an ephemeron, and its value is kept alive by
+    /// `Avm2::finalize_weak_dictionaries` only for as long as its key is
+    /// reachable from somewhere else. A value that refers back to its own
+    /// key therefore doesn't keep the entry alive.
+    weak_values: Option<DynamicMap<WeakKey, WeakEntry<'gc>>>,
+}
+
+/// The identity of an object used as a weak dictionary key.
+///
+/// Entries are removed before their key is swept, so the address can't be
+/// reused by another object while the entry exists.
+#[derive(Clone, Collect, Copy, PartialEq, Eq, Hash)]
+#[collect(require_static)]
+struct WeakKey(*const ObjectPtr);
+
+impl WeakKey {
+    fn new(object: Object<'_>) -> Self {
+        Self(object.as_ptr())
+    }
+}
+
+/// An entry in the weak object space of a dictionary.
+#[derive(Clone, Collect, Copy)]
+#[collect(no_drop)]
+struct WeakEntry<'gc> {
+    key: WeakObject<'gc>,
+
+    /// The value, boxed so that it's only traced after being resurrected.
+    value: GcWeak<'gc, Value<'gc>>,
+}
+
+impl<'gc> WeakEntry<'gc> {
+    fn new(key: Object<'gc>, value: Value<'gc>, mc: &Mutation<'gc>) -> Self {
+        Self {
+            key: key.downgrade(),
+            value: Gc::downgrade(Gc::new(mc, value)),
+        }
+    }
+
+    /// The key and value of this entry, if its key hasn't been collected.
+    fn upgrade(&self, mc: &Mutation<'gc>) -> Option<(Object<'gc>, Value<'gc>)> {
+        let key = self.key.upgrade(mc)?;
+        let value = self.value.upgrade(mc)?;
+        Some((key, *value))
+    }
 }
 
 impl<'gc> DictionaryObject<'gc> {
+    pub fn downgrade(self) -> DictionaryObjectWeak<'gc> {
+        DictionaryObjectWeak(GcCell::downgrade(self.0))
+    }
+
+    /// Make this dictionary hold the objects it's keyed by weakly.
+    ///
+    /// This must be called before any object keys are added.
+    pub fn set_weak_keys(self, activation: &mut Activation<'_, 'gc>) {
+        self.0.write(activation.context.gc_context).weak_values = Some(DynamicMap::new());
+        activation.avm2().register_weak_dictionary(self);
+    }
+
     /// Retrieve a value in the dictionary's object space.
-    pub fn get_property_by_object(self, name: Object<'gc>) -> Value<'gc> {
+    pub fn get_property_by_object(self, name: Object<'gc>, mc: &Mutation<'gc>) -> Value<'gc> {
+        if self.0.read().weak_values.is_some() {
+            return self
+                .weak_entry(name, mc)
+                .map(|(_, value)| value)
+                .unwrap_or(Value::Undefined);
+        }
+
         self.0
             .read()
             .base
@@ -69,8 +140,13 @@ impl<'gc> DictionaryObject<'gc> {
 
     /// Set a value in the dictionary's object space.
     pub fn set_property_by_object(self, name: Object<'gc>, value: Value<'gc>, mc: &Mutation<'gc>) {
-        self.0
-            .write(mc)
+        let mut write = self.0.write(mc);
+        if let Some(weak_values) = &mut write.weak_values {
+            weak_values.insert(WeakKey::new(name), WeakEntry::new(name, value, mc));
+            return;
+        }
+
+        write
             .base
             .values
             .insert(StringOrObject::Object(name), value);
@@ -78,14 +154,20 @@ impl<'gc> DictionaryObject<'gc> {
 
     /// Delete a value from the dictionary's object space.
     pub fn delete_property_by_object(self, name: Object<'gc>, mc: &Mutation<'gc>) {
-        self.0
-            .write(mc)
-            .base
-            .values
-            .remove(&StringOrObject::Object(name));
+        let mut write = self.0.write(mc);
+        if let Some(weak_values) = &mut write.weak_values {
+            weak_values.remove(&WeakKey::new(name));
+            return;
+        }
+
+        write.base.values.remove(&StringOrObject::Object(name));
     }
 
-    pub fn has_property_by_object(self, name: Object<'gc>) -> bool {
+    pub fn has_property_by_object(self, name: Object<'gc>, mc: &Mutation<'gc>) -> bool {
+        if self.0.read().weak_values.is_some() {
+            return self.weak_entry(name, mc).is_some();
+        }
+
         self.0
             .read()
             .base
@@ -94,6 +176,115 @@ impl<'gc> DictionaryObject<'gc> {
             .get(&StringOrObject::Object(name))
             .is_some()
     }
+
+    /// Look up the weak entry for an object, removing it if it's dead.
+    fn weak_entry(
+        self,
+        name: Object<'gc>,
+        mc: &Mutation<'gc>,
+    ) -> Option<(Object<'gc>, Value<'gc>)> {
+        let key = WeakKey::new(name);
+        let entry = self
+            .0
+            .read()
+            .weak_values
+            .as_ref()?
+            .as_hashmap()
+            .get(&key)?
+            .value;
+
+        let upgraded = entry.upgrade(mc);
+        if upgraded.is_none() {
+            if let Some(weak_values) = &mut self.0.write(mc).weak_values {
+                weak_values.remove(&key);
+            }
+        }
+
+        upgraded
+    }
+
+    /// Remove every entry whose key has been garbage collected.
+    fn remove_dead_keys(self, mc: &Mutation<'gc>) {
+        let mut write = self.0.write(mc);
+        if let Some(weak_values) = &mut write.weak_values {
+            let dead_keys: Vec<_> = weak_values
+                .as_hashmap()
+                .iter()
+                .filter(|(_, v)| v.value.upgrade(mc).is_none())
+                .map(|(k, _)| *k)
+                .collect();
+            for key in dead_keys {
+                weak_values.remove(&key);
+            }
+        }
+    }
+
+    /// The number of enumerants in the weak object space, which are
+    /// enumerated before any string-keyed properties.
+    fn weak_enumerant_count(self) -> Option<u32> {
+        self.0
+            .read()
+            .weak_values
+            .as_ref()
+            .map(|weak_values| weak_values.as_hashmap().len() as u32)
+    }
+}
+
+impl<'gc> DictionaryObjectWeak<'gc> {
+    /// Called once marking has finished: keeps alive the value of every
+    /// entry whose key is still reachable.
+    ///
+    /// Returns whether any values were resurrected, in which case marking has
+    /// to continue, as those values may make more keys reachable. Once
+    /// nothing more is resurrected, the entries of dead keys can be removed
+    /// with `remove_dead_entries`.
+    pub fn resurrect_live_values(self, fc: &Finalization<'gc>) -> bool {
+        if self.0.is_dead(fc) {
+            return false;
+        }
+        let Some(dictionary) = self.0.upgrade(fc) else {
+            return false;
+        };
+
+        let read = dictionary.read();
+        let Some(weak_values) = &read.weak_values else {
+            return false;
+        };
+
+        let mut resurrected = false;
+        for entry in weak_values.as_hashmap().values() {
+            if !entry.value.key.is_dead(fc) && entry.value.value.is_dead(fc) {
+                entry.value.value.resurrect(fc);
+                resurrected = true;
+            }
+        }
+        resurrected
+    }
+
+    /// Remove the entries whose keys are about to be collected.
+    ///
+    /// Returns `false` if the dictionary itself is about to be collected.
+    pub fn remove_dead_entries(self, fc: &Finalization<'gc>) -> bool {
+        if self.0.is_dead(fc) {
+            return false;
+        }
+        let Some(dictionary) = self.0.upgrade(fc) else {
+            return false;
+        };
+
+        if let Some(weak_values) = &mut dictionary.write(fc).weak_values {
+            let dead_keys: Vec<_> = weak_values
+                .as_hashmap()
+                .iter()
+                .filter(|(_, v)| v.value.key.is_dead(fc))
+                .map(|(k, _)| *k)
+                .collect();
+            for key in dead_keys {
+                weak_values.remove(&key);
+            }
+        }
+        true
+    }
 }
 
 impl<'gc> TObject<'gc> for DictionaryObject<'gc> {
@@ -127,17 +318,251 @@ impl<'gc> TObject<'gc> for DictionaryObject<'gc> {
     ) {
     }
 
+    fn get_next_enumerant(
+        self,
+        last_index: u32,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Result<Option<u32>, Error<'gc>> {
+        let mc = activation.context.gc_context;
+        if last_index == 0 {
+            self.remove_dead_keys(mc);
+        }
+
+        let read = self.0.read();
+        let Some(weak_values) = &read.weak_values else {
+            return Ok(read.base.get_next_enumerant(last_index));
+        };
+
+        let weak_count = weak_values.as_hashmap().len() as u32;
+        let mut index = last_index;
+        while index < weak_count {
+            let Some(next) = weak_values.next(index as usize) else {
+                break;
+            };
+
+            // Skip over entries whose key died since enumeration started.
+            let is_live = weak_values
+                .value_at(next)
+                .is_some_and(|entry| entry.upgrade(mc).is_some());
+            if is_live {
+                return Ok(Some(next as u32));
+            }
+            index = next as u32;
+        }
+
+        Ok(read
+            .base
+            .get_next_enumerant(last_index.saturating_sub(weak_count))
+            .map(|index| index + weak_count))
+    }
+
+    fn get_enumerant_name(
+        self,
+        index: u32,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Result<Value<'gc>, Error<'gc>> {
+        let weak_count = self.weak_enumerant_count().unwrap_or(0);
+        let read = self.0.read();
+        if index <= weak_count {
+            if let Some(weak_values) = &read.weak_values {
+                return Ok(weak_values
+                    .value_at(index as usize)
+                    .and_then(|entry| entry.upgrade(activation.context.gc_context))
+                    .map(|(key, _)| Value::Object(key))
+                    .unwrap_or(Value::Undefined));
+            }
+        }
+
+        Ok(read
+            .base
+            .get_enumerant_name(index - weak_count)
+            .unwrap_or(Value::Undefined))
+    }
+
     fn get_enumerant_value(
         self,
         index: u32,
         activation: &mut Activation<'_, 'gc>,
     ) -> Result<Value<'gc>, Error<'gc>> {
-        Ok(*self
-            .0
-            .write(activation.context.gc_context)
+        let weak_count = self.weak_enumerant_count().unwrap_or(0);
+        let write = self.0.write(activation.context.gc_context);
+        if index <= weak_count {
+            if let Some(weak_values) = &write.weak_values {
+                return Ok(weak_values
+                    .value_at(index as usize)
+                    .and_then(|entry| entry.upgrade(activation.context.gc_context))
+                    .map(|(_, value)| value)
+                    .unwrap_or(Value::Undefined));
+            }
+        }
+
+        Ok(*write
             .base
             .values
-            .value_at(index as usize)
+            .value_at((index - weak_count) as usize)
             .unwrap_or(&Value::Undefined))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm2::api_version::ApiVersion;
+    use crate::avm2::{Namespace, QName};
+    use crate::player::PlayerBuilder;
+    use crate::tag_utils::SwfMovie;
+    use gc_arena::Rootable;
+
+    /// The result of looking at a weak dictionary after a full collection.
+    #[derive(Debug, PartialEq)]
+    struct Survivors {
+        /// The indices of the surviving keys, in the order they were created.
+        keys: Vec<usize>,
+
+        /// How many times `get_next_enumerant` yielded an enumerant.
+        enumerants: usize,
+    }
+
+    /// Populates a weak dictionary with `populate`, runs a full garbage
+    /// collection, and reports which of its keys survived.
+    ///
+    /// `populate` is given the dictionary and some fresh objects to use as
+    /// keys and values, and returns the objects that should remain reachable.
+    fn collect_weak_dictionary(
+        populate: for<'gc> fn(
+            &mut Activation<'_, 'gc>,
+            DictionaryObject<'gc>,
+            &[Object<'gc>],
+        ) -> Vec<Object<'gc>>,
+    ) -> Survivors {
+        let player = PlayerBuilder::new().with_movie(SwfMovie::empty(10)).build();
+        let mut player = player.lock().unwrap();
+
+        let (dictionary, objects, reachable) = player.mutate_with_update_context(|context| {
+            let mut activation = Activation::from_nothing(context.reborrow());
+            let ns = Namespace::package(
+                "flash.utils",
+                ApiVersion::AllVersions,
+                &mut activation.context.borrow_gc(),
+            );
+            let dictionary_class = activation
+                .avm2()
+                .playerglobals_domain()
+                .get_defined_value(&mut activation, QName::new(ns, "Dictionary"))
+                .expect("Dictionary should be defined")
+                .as_object()
+                .and_then(|class| class.as_class_object())
+                .expect("Dictionary should be a class");
+            let dictionary = dictionary_class
+                .construct(&mut activation, &[true.into()])
+                .expect("Dictionary should be constructed")
+                .as_dictionary_object()
+                .expect("Dictionary should be a DictionaryObject");
+
+            let object_class = activation.avm2().classes().object;
+            let objects: Vec<_> = (0..4)
+                .map(|_| object_class.construct(&mut activation, &[]).unwrap())
+                .collect();
+            let reachable = populate(&mut activation, dictionary, &objects);
+
+            let dynamic_root = activation.context.dynamic_root;
+            let mc = activation.context.gc_context;
+            let weak_objects: Vec<_> = objects.iter().map(|o| o.downgrade()).collect();
+            (
+                dynamic_root.stash::<Rootable![DictionaryObject<'_>]>(mc, dictionary),
+                dynamic_root.stash::<Rootable![Vec<WeakObject<'_>>]>(mc, weak_objects),
+                dynamic_root.stash::<Rootable![Vec<Object<'_>>]>(mc, reachable),
+            )
+        });
+
+        player.collect_garbage(true);
+
+        player.mutate_with_update_context(|context| {
+            let dictionary = *context.dynamic_root.fetch(&dictionary);
+            let objects = context.dynamic_root.fetch(&objects).clone();
+            let _ = context.dynamic_root.fetch(&reachable);
+
+            let mut activation = Activation::from_nothing(context.reborrow());
+            let mut keys = Vec::new();
+            let mut enumerants = 0;
+            let mut index = 0;
+            while let Some(next) = dictionary
+                .get_next_enumerant(index, &mut activation)
+                .unwrap()
+            {
+                enumerants += 1;
+                let name = dictionary
+                    .get_enumerant_name(next, &mut activation)
+                    .unwrap();
+                let key = name.as_object().expect("Enumerated keys should be objects");
+                keys.extend(objects.iter().position(|o| {
+                    o.upgrade(activation.context.gc_context)
+                        .is_some_and(|o| Object::ptr_eq(o, key))
+                }));
+                index = next;
+            }
+            keys.sort_unstable();
+
+            Survivors { keys, enumerants }
+        })
+    }
+
+    #[test]
+    fn collects_unreachable_keys() {
+        let survivors = collect_weak_dictionary(|activation, dictionary, objects| {
+            let mc = activation.context.gc_context;
+            dictionary.set_property_by_object(objects[0], 1.into(), mc);
+            dictionary.set_property_by_object(objects[1], 2.into(), mc);
+            dictionary.set_property_by_object(objects[2], 3.into(), mc);
+            vec![objects[1]]
+        });
+        assert_eq!(
+            survivors,
+            Survivors {
+                keys: vec![1],
+                enumerants: 1
+            }
+        );
+    }
+
+    #[test]
+    fn values_referencing_their_key_do_not_keep_it_alive() {
+        let survivors = collect_weak_dictionary(|activation, dictionary, objects| {
+            let mc = activation.context.gc_context;
+            dictionary.set_property_by_object(objects[0], objects[0].into(), mc);
+            dictionary.set_property_by_object(objects[2], objects[1].into(), mc);
+            objects[1]
+                .set_public_property("key", objects[2].into(), activation)
+                .unwrap();
+            vec![]
+        });
+        assert_eq!(
+            survivors,
+            Survivors {
+                keys: vec![],
+                enumerants: 0
+            }
+        );
+    }
+
+    #[test]
+    fn live_keys_keep_their_values_alive() {
+        // 0 is reachable, and keeps 1 alive through its value, which keeps
+        // 2 alive in turn. Nothing refers to 3 except its own value.
+        let survivors = collect_weak_dictionary(|activation, dictionary, objects| {
+            let mc = activation.context.gc_context;
+            dictionary.set_property_by_object(objects[2], 5.into(), mc);
+            dictionary.set_property_by_object(objects[3], objects[3].into(), mc);
+            dictionary.set_property_by_object(objects[1], objects[2].into(), mc);
+            dictionary.set_property_by_object(objects[0], objects[1].into(), mc);
+            vec![objects[0]]
+        });
+        assert_eq!(
+            survivors,
+            Survivors {
+                keys: vec![0, 1, 2],
+                enumerants: 3
+            }
+        );
+    }
+}
@@ -5,6 +5,7 @@ use crate::avm2::function::Executable;
 use crate::avm2::method::{Method, NativeMethod};
 use crate::avm2::object::script_object::{ScriptObject, ScriptObjectData};
 use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject};
+use crate::avm2::sampler::Sampler;
 use crate::avm2::scope::ScopeChain;
 use crate::avm2::value::Value;
 use crate::avm2::{Error, Multiname};
@@ -120,14 +121,18 @@ impl<'gc> FunctionObject<'gc> {
         let fn_class = activation.avm2().classes().function;
         let exec = Executable::from_method(method, scope, receiver, subclass_object);
 
-        FunctionObject(GcCell::new(
+        let function = FunctionObject(GcCell::new(
             activation.context.gc_context,
             FunctionObjectData {
                 base: ScriptObjectData::new(fn_class),
                 exec,
                 prototype: None,
             },
-        ))
+        ));
+
+        Sampler::record_new_object(activation, function.into());
+
+        function
     }
 
     pub fn prototype(&self) -> Option<Object<'gc>> {
@@ -3,6 +3,7 @@
 use crate::avm2::activation::Activation;
 use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject};
+use crate::avm2::string::AvmString;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::avm2::Namespace;
@@ -22,6 +23,7 @@ pub fn namespace_allocator<'gc>(
         NamespaceObjectData {
             base,
             namespace: activation.context.avm2.public_namespace_base_version,
+            prefix: None,
         },
     ))
     .into())
@@ -52,6 +54,12 @@ pub struct NamespaceObjectData<'gc> {
 
     /// The namespace name this object is associated with.
     namespace: Namespace<'gc>,
+
+    /// The prefix of this namespace, or `None` if the prefix is undefined.
+    ///
+    /// Prefixes are only meaningful to E4X, which uses them to serialize
+    /// qualified names.
+    prefix: Option<AvmString<'gc>>,
 }
 
 impl<'gc> NamespaceObject<'gc> {
@@ -59,13 +67,26 @@ impl<'gc> NamespaceObject<'gc> {
     pub fn from_namespace(
         activation: &mut Activation<'_, 'gc>,
         namespace: Namespace<'gc>,
+    ) -> Result<Object<'gc>, Error<'gc>> {
+        Self::from_ns_and_prefix(activation, namespace, None)
+    }
+
+    /// Box a namespace with the given prefix into an object.
+    pub fn from_ns_and_prefix(
+        activation: &mut Activation<'_, 'gc>,
+        namespace: Namespace<'gc>,
+        prefix: Option<AvmString<'gc>>,
     ) -> Result<Object<'gc>, Error<'gc>> {
         let class = activation.avm2().classes().namespace;
         let base = ScriptObjectData::new(class);
 
         let this: Object<'gc> = NamespaceObject(GcCell::new(
             activation.context.gc_context,
-            NamespaceObjectData { base, namespace },
+            NamespaceObjectData {
+                base,
+                namespace,
+                prefix,
+            },
         ))
         .into();
         this.install_instance_slots(activation.context.gc_context);
@@ -82,6 +103,14 @@ impl<'gc> NamespaceObject<'gc> {
     pub fn namespace(self) -> Namespace<'gc> {
         return self.0.read().namespace;
     }
+
+    pub fn init_prefix(&self, mc: &Mutation<'gc>, prefix: Option<AvmString<'gc>>) {
+        self.0.write(mc).prefix = prefix;
+    }
+
+    pub fn prefix(self) -> Option<AvmString<'gc>> {
+        self.0.read().prefix
+    }
 }
 
 impl<'gc> TObject<'gc> for NamespaceObject<'gc> {
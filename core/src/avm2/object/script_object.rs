@@ -422,6 +422,25 @@ impl<'gc> ScriptObjectData<'gc> {
         self.vtable = Some(vtable);
     }
 
+    /// Estimate how many bytes of memory this object's own storage is using.
+    pub fn estimated_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + self.slots.len() * std::mem::size_of::<Value<'gc>>()
+            + self.bound_methods.len() * std::mem::size_of::<Option<FunctionObject<'gc>>>()
+            + self.values.as_hashmap().len()
+                * (std::mem::size_of::<StringOrObject<'gc>>() + std::mem::size_of::<Value<'gc>>())
+    }
+
+    /// Iterate over the values held in this object's slots and dynamic properties.
+    pub fn owned_values(&self) -> impl Iterator<Item = Value<'gc>> + '_ {
+        self.slots.iter().copied().chain(
+            self.values
+                .as_hashmap()
+                .values()
+                .map(|property| property.value),
+        )
+    }
+
     pub fn debug_class_name(&self) -> Box<dyn std::fmt::Debug + 'gc> {
         let class_name = self
             .instance_of()
@@ -6,13 +6,14 @@ use crate::avm2::object::{Object, ObjectPtr, TObject};
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use gc_arena::barrier::unlock;
-use gc_arena::lock::RefLock;
+use gc_arena::lock::{Lock, RefLock};
 use gc_arena::{Collect, Gc, GcWeak, Mutation};
 use ruffle_render::backend::{Context3DTextureFormat, Texture};
-use std::cell::{Ref, RefMut};
+use std::cell::{Ref, RefCell, RefMut};
 use std::rc::Rc;
 
 use super::{ClassObject, Context3DObject};
+use crate::streams::NetStream;
 
 #[derive(Clone, Collect, Copy)]
 #[collect(no_drop)]
@@ -36,7 +37,8 @@ impl<'gc> TextureObject<'gc> {
                 base: RefLock::new(ScriptObjectData::new(class)),
                 context3d,
                 original_format,
-                handle,
+                handle: RefCell::new(handle),
+                video_stream: Lock::new(None),
             },
         ))
         .into();
@@ -52,7 +54,24 @@ impl<'gc> TextureObject<'gc> {
     }
 
     pub fn handle(&self) -> Rc<dyn Texture> {
-        self.0.handle.clone()
+        self.0.handle.borrow().clone()
+    }
+
+    /// Replace the underlying texture handle.
+    ///
+    /// This is used by `VideoTexture`, which needs to recreate its texture
+    /// whenever the size of the attached video changes.
+    pub fn set_handle(&self, handle: Rc<dyn Texture>) {
+        *self.0.handle.borrow_mut() = handle;
+    }
+
+    /// The `NetStream` attached to this `VideoTexture`, if any.
+    pub fn video_stream(&self) -> Option<NetStream<'gc>> {
+        self.0.video_stream.get()
+    }
+
+    pub fn set_video_stream(&self, mc: &Mutation<'gc>, stream: Option<NetStream<'gc>>) {
+        unlock!(Gc::write(mc, self.0), TextureObjectData, video_stream).set(stream);
     }
 
     pub fn context3d(&self) -> Context3DObject<'gc> {
@@ -72,7 +91,10 @@ pub struct TextureObjectData<'gc> {
     original_format: Context3DTextureFormat,
 
     #[collect(require_static)]
-    handle: Rc<dyn Texture>,
+    handle: RefCell<Rc<dyn Texture>>,
+
+    /// The `NetStream` this texture is attached to (`VideoTexture` only).
+    video_stream: Lock<Option<NetStream<'gc>>>,
 }
 
 impl<'gc> TObject<'gc> for TextureObject<'gc> {
@@ -3,6 +3,7 @@
 use crate::avm2::activation::Activation;
 use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject};
+use crate::avm2::sampler::Sampler;
 use crate::avm2::value::Value;
 use crate::avm2::vector::VectorStorage;
 use crate::avm2::Error;
@@ -81,6 +82,8 @@ impl<'gc> VectorObject<'gc> {
 
         object.install_instance_slots(activation.context.gc_context);
 
+        Sampler::record_new_object(activation, object);
+
         Ok(object)
     }
 }
@@ -1,11 +1,10 @@
 use crate::avm2::activation::Activation;
-use crate::avm2::api_version::ApiVersion;
-use crate::avm2::e4x::{E4XNode, E4XNodeKind};
+use crate::avm2::e4x::{E4XNamespace, E4XNode, E4XNodeKind};
 use crate::avm2::error::make_error_1089;
 use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{Object, ObjectPtr, TObject};
 use crate::avm2::value::Value;
-use crate::avm2::{Error, Multiname, Namespace};
+use crate::avm2::{Error, Multiname};
 use crate::string::AvmString;
 use gc_arena::{Collect, GcCell, GcWeakCell, Mutation};
 use ruffle_wstr::WString;
@@ -174,11 +173,7 @@ impl<'gc> XmlListObject<'gc> {
             if !matches!(*last_node.kind(), E4XNodeKind::ProcessingInstruction(_)) {
                 if let Some(name) = last_node.local_name() {
                     let ns = match last_node.namespace() {
-                        Some(ns) => Namespace::package(
-                            ns,
-                            ApiVersion::AllVersions,
-                            &mut activation.context.borrow_gc(),
-                        ),
+                        Some(ns) => ns.as_namespace(activation),
                         None => activation.avm2().public_namespace_base_version,
                     };
 
@@ -716,7 +711,7 @@ impl<'gc> TObject<'gc> for XmlListObject<'gc> {
                             // 2.c.vi. Else let y.[[Class]] = "element"
                             Some(property) => E4XNode::element(
                                 activation.gc(),
-                                property.explicit_namespace(),
+                                property.explicit_namespace().map(E4XNamespace::new_uri),
                                 property.local_name().expect("Local name should exist"),
                                 r,
                             ),
@@ -772,7 +767,7 @@ impl<'gc> TObject<'gc> for XmlListObject<'gc> {
                                     y.set_local_name(name, activation.gc());
                                 }
                                 if let Some(namespace) = xml.node().namespace() {
-                                    y.set_namespace(namespace, activation.gc());
+                                    y.set_namespace(Some(namespace), activation.gc());
                                 }
                             }
 
@@ -786,7 +781,10 @@ impl<'gc> TObject<'gc> for XmlListObject<'gc> {
                                         y.set_local_name(name, activation.gc());
                                     }
                                     if let Some(namespace) = target_property.explicit_namespace() {
-                                        y.set_namespace(namespace, activation.gc());
+                                        y.set_namespace(
+                                            Some(E4XNamespace::new_uri(namespace)),
+                                            activation.gc(),
+                                        );
                                     }
                                 }
                             }
@@ -1068,14 +1066,18 @@ impl<'gc> TObject<'gc> for XmlListObject<'gc> {
             if let Some(local_name) = name.local_name() {
                 if let Ok(index) = local_name.parse::<usize>() {
                     if index < write.children.len() {
-                        let removed = write.children.remove(index);
-                        let removed_node = removed.node();
+                        let mut removed = write.children.remove(index);
+                        drop(write);
+
+                        let removed_node = *removed.node();
                         if let Some(parent) = removed_node.parent() {
                             if removed_node.is_attribute() {
                                 parent
                                     .remove_attribute(activation.context.gc_context, &removed_node);
                             } else {
                                 parent.remove_child(activation.context.gc_context, &removed_node);
+                                let removed = removed.get_or_create_xml(activation).into();
+                                parent.notify(activation, "nodeRemoved", removed, Value::Null)?;
                             }
                         }
                     }
@@ -1,8 +1,7 @@
 //! Object representation for XML objects
 
 use crate::avm2::activation::Activation;
-use crate::avm2::api_version::ApiVersion;
-use crate::avm2::e4x::{string_to_multiname, E4XNode, E4XNodeKind};
+use crate::avm2::e4x::{string_to_multiname, E4XNamespace, E4XNode, E4XNodeKind};
 use crate::avm2::error::make_error_1087;
 use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject, XmlListObject};
@@ -128,11 +127,7 @@ impl<'gc> XmlObject<'gc> {
 
     pub fn namespace(&self, activation: &mut Activation<'_, 'gc>) -> Namespace<'gc> {
         match self.0.read().node.namespace() {
-            Some(ns) => Namespace::package(
-                ns,
-                ApiVersion::AllVersions,
-                &mut activation.context.borrow_gc(),
-            ),
+            Some(ns) => ns.as_namespace(activation),
             None => activation.avm2().public_namespace_base_version,
         }
     }
@@ -141,6 +136,51 @@ impl<'gc> XmlObject<'gc> {
         self.0.read().node.matches_name(multiname)
     }
 
+    /// Removes the attributes or children matching a name, returning the removed nodes,
+    /// or `None` if this is not an element.
+    fn remove_matching_properties(
+        &self,
+        mc: &Mutation<'gc>,
+        name: &Multiname<'gc>,
+    ) -> Result<Option<Vec<E4XNode<'gc>>>, Error<'gc>> {
+        if name.has_explicit_namespace() {
+            return Err(format!(
+                "Can not set property {:?} with an explicit namespace yet",
+                name
+            )
+            .into());
+        }
+
+        let node = *self.node();
+        let mut kind = node.kind_mut(mc);
+        let E4XNodeKind::Element {
+            children,
+            attributes,
+            ..
+        } = &mut *kind
+        else {
+            return Ok(None);
+        };
+
+        let properties = if name.is_attribute() {
+            attributes
+        } else {
+            children
+        };
+
+        let mut removed = Vec::new();
+        properties.retain(|property| {
+            if property.matches_name(name) {
+                property.set_parent(None, mc);
+                removed.push(*property);
+                false
+            } else {
+                true
+            }
+        });
+        Ok(Some(removed))
+    }
+
     pub fn node(&self) -> Ref<'_, E4XNode<'gc>> {
         Ref::map(self.0.read(), |data| &data.node)
     }
@@ -471,20 +511,37 @@ impl<'gc> TObject<'gc> for XmlObject<'gc> {
             };
 
             let mc = activation.context.gc_context;
-            self.delete_property_local(activation, name)?;
+            let old_value = self
+                .remove_matching_properties(mc, name)?
+                .and_then(|removed| removed.first().copied())
+                .and_then(|attribute| match &*attribute.kind() {
+                    E4XNodeKind::Attribute(value) => Some(*value),
+                    _ => None,
+                });
             let Some(local_name) = name.local_name() else {
                 return Err(format!("Cannot set attribute {:?} without a local name", name).into());
             };
-            let new_attr = E4XNode::attribute(mc, local_name, value, Some(*self.node()));
+            let node = *self.node();
+            let new_attr = E4XNode::attribute(mc, local_name, value, Some(node));
 
-            let write = self.0.write(mc);
-            let mut kind = write.node.kind_mut(mc);
-            let E4XNodeKind::Element { attributes, .. } = &mut *kind else {
-                return Ok(());
-            };
+            if let E4XNodeKind::Element { attributes, .. } = &mut *node.kind_mut(mc) {
+                attributes.push(new_attr);
+            }
 
-            attributes.push(new_attr);
-            return Ok(());
+            return match old_value {
+                Some(old_value) => node.notify(
+                    activation,
+                    "attributeChanged",
+                    local_name.into(),
+                    old_value.into(),
+                ),
+                None => node.notify(
+                    activation,
+                    "attributeAdded",
+                    local_name.into(),
+                    value.into(),
+                ),
+            };
         }
 
         // 7. Let isValidName be the result of calling the function isXMLName (section 13.1.2.1) with argument n
@@ -502,11 +559,12 @@ impl<'gc> TObject<'gc> for XmlObject<'gc> {
             x.as_xml_list_object().is_some() || x.as_xml_object().is_some()
         }) && !name.is_any_name();
 
-        let self_node = self.node();
+        let self_node = *self.node();
 
         // 9. Let i = undefined
         // 11.
         let index = self_node.remove_matching_children(activation.gc(), name);
+        let replaces_existing = index.is_some();
 
         let index = if let Some((index, node)) = index {
             self_node.insert_at(activation.gc(), index, node);
@@ -528,14 +586,16 @@ impl<'gc> TObject<'gc> for XmlObject<'gc> {
                 // 12.b.iii. Create a new XML object y with y.[[Name]] = name, y.[[Class]] = "element" and y.[[Parent]] = x
                 let node = E4XNode::element(
                     activation.gc(),
-                    name.explicit_namespace(),
+                    name.explicit_namespace().map(E4XNamespace::new_uri),
                     name.local_name().unwrap(),
-                    Some(*self_node),
+                    Some(self_node),
                 );
                 // 12.b.v. Call the [[Replace]] method of x with arguments ToString(i) and y
                 self_node.replace(index, XmlObject::new(node, activation).into(), activation)?;
-                // FIXME: 12.b.iv. Let ns be the result of calling [[GetNamespace]] on name with no arguments
+                // 12.b.iv. Let ns be the result of calling [[GetNamespace]] on name with no arguments
+                let ns = node.get_namespace(&[]);
                 // 12.b.vi. Call [[AddInScopeNamespace]] on y with argument ns
+                node.add_in_scope_namespace(activation.gc(), ns);
             }
 
             index
@@ -543,25 +603,38 @@ impl<'gc> TObject<'gc> for XmlObject<'gc> {
 
         // 13. If (primitiveAssign == true)
         if primitive_assign {
-            let E4XNodeKind::Element { children, .. } = &mut *self_node.kind_mut(activation.gc())
-            else {
-                unreachable!("Node should be of Element kind");
+            let child = match &*self_node.kind() {
+                E4XNodeKind::Element { children, .. } => children[index],
+                _ => unreachable!("Node should be of Element kind"),
             };
 
             // 13.a. Delete all the properties of the XML object x[i]
-            children[index].remove_all_children(activation.gc());
+            child.remove_all_children(activation.gc());
 
             // 13.b. Let s = ToString(c)
             let val = value.coerce_to_string(activation)?;
 
             // 13.c. If s is not the empty string, call the [[Replace]] method of x[i] with arguments "0" and s
             if !val.is_empty() {
-                children[index].replace(0, value, activation)?;
+                child.replace(0, value, activation)?;
+            }
+
+            if !replaces_existing {
+                let child_xml = XmlObject::new(child, activation).into();
+                self_node.notify(activation, "nodeAdded", child_xml, Value::Null)?;
             }
+            child.notify(activation, "textSet", val.into(), Value::Null)?;
         // 14. Else
         } else {
             // 14.a. Call the [[Replace]] method of x with arguments ToString(i) and c
             self_node.replace(index, value, activation)?;
+
+            let kind = if replaces_existing {
+                "nodeChanged"
+            } else {
+                "nodeAdded"
+            };
+            self_node.notify(activation, kind, value, Value::Null)?;
         }
 
         // 15. Return
@@ -605,40 +678,26 @@ impl<'gc> TObject<'gc> for XmlObject<'gc> {
         activation: &mut Activation<'_, 'gc>,
         name: &Multiname<'gc>,
     ) -> Result<bool, Error<'gc>> {
-        if name.has_explicit_namespace() {
-            return Err(format!(
-                "Can not set property {:?} with an explicit namespace yet",
-                name
-            )
-            .into());
-        }
-
-        let mc = activation.context.gc_context;
-        let write = self.0.write(mc);
-        let mut kind = write.node.kind_mut(mc);
-        let E4XNodeKind::Element {
-            children,
-            attributes,
-            ..
-        } = &mut *kind
-        else {
+        let Some(removed) = self.remove_matching_properties(activation.gc(), name)? else {
             return Ok(false);
         };
 
-        let retain_non_matching = |node: &E4XNode<'gc>| {
-            if node.matches_name(name) {
-                node.set_parent(None, mc);
-                false
+        let node = *self.node();
+        for removed in removed {
+            let attribute_value = match &*removed.kind() {
+                E4XNodeKind::Attribute(value) => Some(*value),
+                _ => None,
+            };
+
+            if let Some(attribute_value) = attribute_value {
+                let name = removed.local_name().map_or(Value::Null, Value::String);
+                node.notify(activation, "attributeRemoved", name, attribute_value.into())?;
             } else {
-                true
+                let removed = XmlObject::new(removed, activation).into();
+                node.notify(activation, "nodeRemoved", removed, Value::Null)?;
             }
-        };
-
-        if name.is_attribute() {
-            attributes.retain(retain_non_matching);
-        } else {
-            children.retain(retain_non_matching);
         }
+
         Ok(true)
     }
 }
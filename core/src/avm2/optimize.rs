@@ -0,0 +1,921 @@
+//! AVM2 method optimization
+//!
+//! The first time a method is called, we decode all of its reachable code
+//! and work out what we can about the types of the values it handles. This
+//! lets the interpreter skip work it would otherwise repeat on every call:
+//!
+//!  * Properties accessed on `this` in an instance method are resolved to
+//!    their traits up-front, since the receiver is known to be an instance of
+//!    the method's class.
+//!  * Other property accesses remember the trait they found for the last
+//!    receiver's vtable, so that repeated accesses on objects of the same
+//!    class skip the lookup.
+//!  * `findprop` ops that will always find `this` are replaced with
+//!    `getlocal0`.
+//!  * `getlex` of a class remembers the class once it's been found.
+//!  * `coerce` ops that would leave their value unchanged are dropped.
+//!
+//! Ops are still addressed by their offset in the original bytecode, so that
+//! branches and exception handlers work exactly as they do for code we
+//! haven't optimized.
+
+use crate::avm2::activation::Activation;
+use crate::avm2::method::{BytecodeMethod, Method};
+use crate::avm2::object::{ClassObject, Object, TObject};
+use crate::avm2::property::Property;
+use crate::avm2::verify::stack_effect;
+use crate::avm2::vtable::VTable;
+use crate::avm2::{Error, Multiname, Value};
+use crate::swf::extensions::ReadSwfExt;
+use gc_arena::{Collect, Gc, GcCell, Mutation};
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use swf::avm2::read::Reader;
+use swf::avm2::types::{AbcFile, Index, MethodBody, Multiname as AbcMultiname, Op};
+
+/// A decoded instruction.
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    /// An op that the interpreter runs as usual.
+    Op(Op),
+
+    /// An op that the optimizer rewrote.
+    Optimized(OptimizedOp),
+}
+
+/// An op rewritten by the optimizer.
+#[derive(Debug, Clone)]
+pub enum OptimizedOp {
+    /// Does nothing. This replaces coercions of values that already have the
+    /// right type.
+    Nop,
+
+    /// `getproperty` with a static name, with a trait lookup cache.
+    GetProperty {
+        index: Index<AbcMultiname>,
+        cache: u32,
+    },
+
+    /// `setproperty` with a static name, with a trait lookup cache.
+    SetProperty {
+        index: Index<AbcMultiname>,
+        cache: u32,
+    },
+
+    /// `initproperty` with a static name, with a trait lookup cache.
+    InitProperty {
+        index: Index<AbcMultiname>,
+        cache: u32,
+    },
+
+    /// `callproperty` or `callpropvoid` with a static name, with a trait
+    /// lookup cache.
+    CallProperty {
+        index: Index<AbcMultiname>,
+        num_args: u32,
+        cache: u32,
+        push_result: bool,
+    },
+
+    /// `getlex` of a name that can't be shadowed by the local scope stack, so
+    /// that a class it resolves to can be cached.
+    GetLex {
+        index: Index<AbcMultiname>,
+        cache: u32,
+    },
+}
+
+/// What an optimized op has learned about the property it accesses.
+#[derive(Collect, Clone, Copy)]
+#[collect(no_drop)]
+pub enum CacheEntry<'gc> {
+    /// Nothing yet.
+    Empty,
+
+    /// The trait the property always resolves to.
+    Static(Property),
+
+    /// The trait the property resolved to on the last receiver we saw, along
+    /// with that receiver's vtable.
+    Receiver(VTable<'gc>, Property),
+
+    /// The class that a `getlex` resolved to, and the vtable of `this` at the
+    /// time.
+    Class(Value<'gc>, Option<VTable<'gc>>),
+}
+
+/// The result of optimizing a method body.
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct OptimizedCode<'gc> {
+    /// Every reachable op, along with the offsets of it and the instruction
+    /// after it.
+    #[collect(require_static)]
+    ops: Vec<(u32, Instruction, u32)>,
+
+    /// Indices into `ops` for each offset in the original bytecode, or
+    /// `u32::MAX` for offsets that don't start a reachable op.
+    #[collect(require_static)]
+    op_indices: Vec<u32>,
+
+    /// The class this method was optimized as an instance method of, if any.
+    ///
+    /// The optimizations made for `this` only hold if the method is called
+    /// with the same bound class.
+    bound_class: Option<ClassObject<'gc>>,
+
+    caches: GcCell<'gc, Vec<CacheEntry<'gc>>>,
+}
+
+impl<'gc> OptimizedCode<'gc> {
+    /// Whether this code can be run by an activation with the given bound
+    /// class.
+    pub fn applies_to(&self, bound_class: Option<ClassObject<'gc>>) -> bool {
+        match (self.bound_class, bound_class) {
+            (None, _) => true,
+            (Some(a), Some(b)) => Object::ptr_eq(a, b),
+            (Some(_), None) => false,
+        }
+    }
+
+    /// The op starting at `offset`, and the offset of the instruction after
+    /// it.
+    pub fn op_at(&self, offset: usize) -> Option<(&Instruction, usize)> {
+        let index = *self.op_indices.get(offset)?;
+        let (_, op, next) = self.ops.get(index as usize)?;
+        Some((op, *next as usize))
+    }
+
+    /// Look up a property's trait on `object`, using and updating the given
+    /// cache.
+    pub fn lookup_trait(
+        &self,
+        mc: &Mutation<'gc>,
+        cache: u32,
+        object: Object<'gc>,
+        multiname: &Multiname<'gc>,
+    ) -> Option<Property> {
+        let vtable = object.vtable();
+        match self.caches.read()[cache as usize] {
+            CacheEntry::Static(property) => return Some(property),
+            CacheEntry::Receiver(cached, property)
+                if vtable.is_some_and(|vtable| VTable::ptr_eq(vtable, cached)) =>
+            {
+                return Some(property)
+            }
+            _ => {}
+        }
+
+        let vtable = vtable?;
+        let property = vtable.get_trait(multiname)?;
+        self.caches.write(mc)[cache as usize] = CacheEntry::Receiver(vtable, property);
+        Some(property)
+    }
+
+    /// The class a `getlex` resolved to, if it's been cached for the given
+    /// vtable of `this`.
+    pub fn cached_class(&self, cache: u32, this_vtable: Option<VTable<'gc>>) -> Option<Value<'gc>> {
+        match self.caches.read()[cache as usize] {
+            CacheEntry::Class(value, cached) => {
+                let matches = match (cached, this_vtable) {
+                    (Some(a), Some(b)) => VTable::ptr_eq(a, b),
+                    (None, None) => true,
+                    _ => false,
+                };
+                matches.then_some(value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Remember the class a `getlex` resolved to while `this` had the given
+    /// vtable.
+    pub fn cache_class(
+        &self,
+        mc: &Mutation<'gc>,
+        cache: u32,
+        value: Value<'gc>,
+        this_vtable: Option<VTable<'gc>>,
+    ) {
+        self.caches.write(mc)[cache as usize] = CacheEntry::Class(value, this_vtable);
+    }
+}
+
+/// Optimize a method for the class bound to the given activation.
+///
+/// The method must already have been verified.
+pub fn optimize_method<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    method: Gc<'gc, BytecodeMethod<'gc>>,
+) -> Result<OptimizedCode<'gc>, Error<'gc>> {
+    let Some(body) = method.body() else {
+        return Err("Cannot optimize non-native method without body".into());
+    };
+
+    let bound_class = activation
+        .subclass_object()
+        .filter(|class| is_instance_method(*class, method));
+
+    let mut resolve_trait = |index: Index<AbcMultiname>| {
+        let vtable = bound_class?.instance_vtable();
+        let multiname = method
+            .translation_unit()
+            .pool_multiname_static(index, &mut activation.context)
+            .ok()?;
+        vtable.get_trait(&multiname)
+    };
+    let receiver: Option<&mut dyn FnMut(Index<AbcMultiname>) -> Option<Property>> =
+        if bound_class.is_some() {
+            Some(&mut resolve_trait)
+        } else {
+            None
+        };
+
+    let analysis = analyze(&method.abc, body, receiver);
+
+    let mut op_indices = vec![u32::MAX; body.code.len()];
+    for (index, (offset, _, _)) in analysis.ops.iter().enumerate() {
+        op_indices[*offset as usize] = index as u32;
+    }
+
+    let caches = analysis
+        .caches
+        .into_iter()
+        .map(|property| property.map_or(CacheEntry::Empty, CacheEntry::Static))
+        .collect();
+
+    Ok(OptimizedCode {
+        ops: analysis.ops,
+        op_indices,
+        bound_class: analysis.uses_receiver.then_some(bound_class).flatten(),
+        caches: GcCell::new(activation.context.gc_context, caches),
+    })
+}
+
+/// Whether `method` is an instance method (or the constructor) of `class`,
+/// meaning that it's always called with an instance of `class` as `this`.
+fn is_instance_method<'gc>(class: ClassObject<'gc>, method: Gc<'gc, BytecodeMethod<'gc>>) -> bool {
+    let is_method =
+        |other: Method<'gc>| matches!(other, Method::Bytecode(other) if Gc::ptr_eq(other, method));
+
+    let class = class.inner_class_definition();
+    let class = class.read();
+    is_method(class.instance_init())
+        || class
+            .instance_traits()
+            .iter()
+            .any(|instance_trait| instance_trait.as_method().is_some_and(is_method))
+}
+
+/// What we know about a value on the stack or scope stack.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ValueType {
+    Any,
+
+    /// The method's receiver, which is an instance of its class.
+    This,
+
+    /// An activation object created by `newactivation`.
+    Activation,
+
+    Boolean,
+    Int,
+    Uint,
+    Number,
+
+    /// A string that isn't null.
+    String,
+
+    /// The result of a `coerce` to the given type name.
+    Coerced(u32),
+}
+
+impl ValueType {
+    /// Whether values of this type are never null or undefined.
+    fn is_non_null(self) -> bool {
+        !matches!(self, ValueType::Any | ValueType::Coerced(_))
+    }
+}
+
+/// The types of the stack and scope stack on entry to an op.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Frame {
+    stack: Vec<ValueType>,
+    scope: Vec<ValueType>,
+}
+
+impl Frame {
+    /// Merge in the types from another path to the same op, returning whether
+    /// anything changed.
+    fn join(&mut self, other: &Frame) -> bool {
+        let mut changed = false;
+        for (mine, theirs) in self
+            .stack
+            .iter_mut()
+            .zip(&other.stack)
+            .chain(self.scope.iter_mut().zip(&other.scope))
+        {
+            if *mine != *theirs && *mine != ValueType::Any {
+                *mine = ValueType::Any;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// The type of the value `depth` entries below the top of the stack.
+    fn peek(&self, depth: usize) -> ValueType {
+        self.stack
+            .len()
+            .checked_sub(depth + 1)
+            .map_or(ValueType::Any, |index| self.stack[index])
+    }
+}
+
+struct Analysis {
+    /// Every reachable op, along with the offsets of it and the instruction
+    /// after it.
+    ops: Vec<(u32, Instruction, u32)>,
+
+    /// The initial contents of each op's cache.
+    caches: Vec<Option<Property>>,
+
+    /// Whether any of the ops rely on the type of the receiver.
+    uses_receiver: bool,
+}
+
+struct Analyzer<'a> {
+    abc: &'a AbcFile,
+    body: &'a MethodBody,
+
+    /// Looks up a multiname on the receiver's class, if the receiver's type
+    /// is known.
+    receiver: Option<&'a mut dyn FnMut(Index<AbcMultiname>) -> Option<Property>>,
+
+    /// Traits already looked up with `receiver`.
+    receiver_traits: HashMap<u32, Option<Property>>,
+}
+
+/// Work out what can be optimized in a (verified) method body.
+///
+/// `receiver` looks up multinames on the method's class, if the method is
+/// known to always be called with an instance of that class as `this`.
+fn analyze<'a>(
+    abc: &'a AbcFile,
+    body: &'a MethodBody,
+    receiver: Option<&'a mut dyn FnMut(Index<AbcMultiname>) -> Option<Property>>,
+) -> Analysis {
+    let ops = decode(body);
+
+    let mut analyzer = Analyzer {
+        abc,
+        body,
+        receiver,
+        receiver_traits: HashMap::new(),
+    };
+
+    // `this` can only be relied on if the method never overwrites it.
+    let writes_this = ops.values().any(|(op, _)| {
+        matches!(
+            op,
+            Op::SetLocal { index: 0 }
+                | Op::Kill { index: 0 }
+                | Op::IncLocal { index: 0 }
+                | Op::IncLocalI { index: 0 }
+                | Op::DecLocal { index: 0 }
+                | Op::DecLocalI { index: 0 }
+                | Op::HasNext2 {
+                    object_register: 0,
+                    ..
+                }
+                | Op::HasNext2 {
+                    index_register: 0,
+                    ..
+                }
+        )
+    });
+    if writes_this {
+        analyzer.receiver = None;
+    }
+
+    let frames = analyzer.frames(&ops);
+
+    let mut analysis = Analysis {
+        ops: Vec::with_capacity(ops.len()),
+        caches: Vec::new(),
+        uses_receiver: false,
+    };
+    for (offset, (op, next)) in ops {
+        let optimized = match frames.get(&offset) {
+            Some(frame) => analyzer.optimize_op(&op, frame, &mut analysis),
+            None => Instruction::Op(op),
+        };
+        analysis.ops.push((offset as u32, optimized, next as u32));
+    }
+
+    analysis
+}
+
+/// Decode every op that can be reached from the start of the method or from
+/// one of its exception handlers.
+fn decode(body: &MethodBody) -> BTreeMap<usize, (Op, usize)> {
+    let code = &body.code;
+    let mut ops = BTreeMap::new();
+    let mut worklist = vec![0];
+    worklist.extend(
+        body.exceptions
+            .iter()
+            .map(|exception| exception.target_offset as usize),
+    );
+
+    while let Some(offset) = worklist.pop() {
+        if offset >= code.len() {
+            continue;
+        }
+        let Entry::Vacant(entry) = ops.entry(offset) else {
+            continue;
+        };
+
+        let mut reader = Reader::new(code);
+        reader.seek_absolute(code, offset);
+        let Ok(op) = reader.read_op() else {
+            continue;
+        };
+        let next = reader.pos(code);
+
+        worklist.extend(successors(offset, next, &op));
+        entry.insert((op, next));
+    }
+
+    ops
+}
+
+/// The offsets that execution can continue at after an op.
+fn successors(offset: usize, next: usize, op: &Op) -> Vec<usize> {
+    let target =
+        |from: usize, relative: i32| usize::try_from(from as i64 + i64::from(relative)).ok();
+
+    match op {
+        Op::Jump { offset } => target(next, *offset).into_iter().collect(),
+        Op::IfEq { offset }
+        | Op::IfFalse { offset }
+        | Op::IfGe { offset }
+        | Op::IfGt { offset }
+        | Op::IfLe { offset }
+        | Op::IfLt { offset }
+        | Op::IfNe { offset }
+        | Op::IfNge { offset }
+        | Op::IfNgt { offset }
+        | Op::IfNle { offset }
+        | Op::IfNlt { offset }
+        | Op::IfStrictEq { offset }
+        | Op::IfStrictNe { offset }
+        | Op::IfTrue { offset } => std::iter::once(next).chain(target(next, *offset)).collect(),
+        Op::LookupSwitch {
+            default_offset,
+            case_offsets,
+        } => std::iter::once(*default_offset)
+            .chain(case_offsets.iter().copied())
+            .filter_map(|case_offset| target(offset, case_offset))
+            .collect(),
+        Op::ReturnValue | Op::ReturnVoid | Op::Throw => vec![],
+        _ => vec![next],
+    }
+}
+
+/// Whether a multiname operand is known without looking at the stack.
+fn is_static_multiname(abc: &AbcFile, index: Index<AbcMultiname>) -> bool {
+    let multiname = (index.0 as usize)
+        .checked_sub(1)
+        .and_then(|index| abc.constant_pool.multinames.get(index));
+
+    matches!(
+        multiname,
+        Some(
+            AbcMultiname::QName { .. }
+                | AbcMultiname::QNameA { .. }
+                | AbcMultiname::Multiname { .. }
+                | AbcMultiname::MultinameA { .. }
+        )
+    )
+}
+
+impl<'a> Analyzer<'a> {
+    /// The trait that a multiname resolves to on the receiver.
+    ///
+    /// Only qualified names are resolved: a subclass can't add a trait that
+    /// would shadow one of those.
+    fn receiver_trait(&mut self, index: Index<AbcMultiname>) -> Option<Property> {
+        let receiver = self.receiver.as_mut()?;
+        let is_qname = matches!(
+            (index.0 as usize).checked_sub(1).and_then(|index| self
+                .abc
+                .constant_pool
+                .multinames
+                .get(index)),
+            Some(AbcMultiname::QName { .. })
+        );
+        if !is_qname {
+            return None;
+        }
+
+        *self
+            .receiver_traits
+            .entry(index.0)
+            .or_insert_with(|| receiver(index))
+    }
+
+    /// Work out the types on entry to each op, starting from the method's
+    /// entry point and its exception handlers.
+    fn frames(&mut self, ops: &BTreeMap<usize, (Op, usize)>) -> HashMap<usize, Frame> {
+        let mut frames = HashMap::new();
+        let mut worklist = vec![0];
+        frames.insert(0, Frame::default());
+
+        for exception in &self.body.exceptions {
+            let target = exception.target_offset as usize;
+            let frame = Frame {
+                stack: vec![ValueType::Any],
+                scope: vec![],
+            };
+            match frames.get_mut(&target) {
+                Some(existing) => {
+                    existing.join(&frame);
+                }
+                None => {
+                    frames.insert(target, frame);
+                }
+            }
+            worklist.push(target);
+        }
+
+        while let Some(offset) = worklist.pop() {
+            let Some((op, next)) = ops.get(&offset) else {
+                continue;
+            };
+            let Some(exit) = self.transfer(op, &frames[&offset]) else {
+                continue;
+            };
+
+            for successor in successors(offset, *next, op) {
+                let changed = match frames.get_mut(&successor) {
+                    Some(existing) => existing.join(&exit),
+                    None => {
+                        frames.insert(successor, exit.clone());
+                        true
+                    }
+                };
+                if changed {
+                    worklist.push(successor);
+                }
+            }
+        }
+
+        frames
+    }
+
+    /// The types after running an op.
+    fn transfer(&mut self, op: &Op, frame: &Frame) -> Option<Frame> {
+        let effect = stack_effect(self.abc, self.body, op).ok()?;
+        let mut stack = frame.stack.clone();
+        let popped = stack.split_off(stack.len().checked_sub(effect.pop)?);
+
+        match op {
+            Op::Dup => stack.extend([popped[0], popped[0]]),
+            Op::Swap => stack.extend([popped[1], popped[0]]),
+            _ => {
+                let result = self.result_type(op, frame);
+                stack.resize(stack.len() + effect.push, result);
+            }
+        }
+
+        let mut scope = frame.scope.clone();
+        match op {
+            Op::PushScope => scope.push(popped[0]),
+            Op::PushWith => scope.push(ValueType::Any),
+            Op::PopScope => {
+                scope.pop();
+            }
+            _ => {}
+        }
+
+        Some(Frame { stack, scope })
+    }
+
+    /// The type of the value an op pushes.
+    fn result_type(&mut self, op: &Op, frame: &Frame) -> ValueType {
+        if is_redundant(op, frame) {
+            return frame.peek(0);
+        }
+
+        match op {
+            Op::GetLocal { index: 0 } if self.receiver.is_some() => ValueType::This,
+            Op::FindProperty { index } | Op::FindPropStrict { index }
+                if self.finds_receiver(*index, frame) =>
+            {
+                ValueType::This
+            }
+            Op::NewActivation => ValueType::Activation,
+
+            Op::ConvertB
+            | Op::CoerceB
+            | Op::DeleteProperty { .. }
+            | Op::Equals
+            | Op::GreaterEquals
+            | Op::GreaterThan
+            | Op::HasNext2 { .. }
+            | Op::In
+            | Op::InstanceOf
+            | Op::IsType { .. }
+            | Op::IsTypeLate
+            | Op::LessEquals
+            | Op::LessThan
+            | Op::Not
+            | Op::PushFalse
+            | Op::PushTrue
+            | Op::StrictEquals => ValueType::Boolean,
+
+            Op::AddI
+            | Op::BitAnd
+            | Op::BitNot
+            | Op::BitOr
+            | Op::BitXor
+            | Op::CoerceI
+            | Op::ConvertI
+            | Op::DecrementI
+            | Op::IncrementI
+            | Op::LShift
+            | Op::MultiplyI
+            | Op::NegateI
+            | Op::PushByte { .. }
+            | Op::PushInt { .. }
+            | Op::PushShort { .. }
+            | Op::RShift
+            | Op::SubtractI => ValueType::Int,
+
+            Op::CoerceU | Op::ConvertU | Op::PushUint { .. } | Op::URShift => ValueType::Uint,
+
+            Op::CoerceD
+            | Op::ConvertD
+            | Op::Decrement
+            | Op::Divide
+            | Op::Increment
+            | Op::Multiply
+            | Op::Negate
+            | Op::PushDouble { .. }
+            | Op::PushNaN => ValueType::Number,
+
+            Op::ConvertS | Op::EscXAttr | Op::EscXElem | Op::PushString { .. } | Op::TypeOf => {
+                ValueType::String
+            }
+
+            Op::Coerce { index } => ValueType::Coerced(index.0),
+
+            _ => ValueType::Any,
+        }
+    }
+
+    /// Whether a `findproperty` will always find `this`, because it's at the
+    /// top of the scope stack and has a trait with the given name.
+    fn finds_receiver(&mut self, index: Index<AbcMultiname>, frame: &Frame) -> bool {
+        frame.scope.last() == Some(&ValueType::This) && self.receiver_trait(index).is_some()
+    }
+
+    /// Allocate a cache for a property op, filled in with the property's
+    /// trait if the receiver is `this`.
+    fn property_cache(
+        &mut self,
+        index: Index<AbcMultiname>,
+        receiver: ValueType,
+        analysis: &mut Analysis,
+    ) -> u32 {
+        let property = if receiver == ValueType::This {
+            self.receiver_trait(index)
+        } else {
+            None
+        };
+        analysis.uses_receiver |= property.is_some();
+        analysis.caches.push(property);
+        analysis.caches.len() as u32 - 1
+    }
+
+    fn optimize_op(&mut self, op: &Op, frame: &Frame, analysis: &mut Analysis) -> Instruction {
+        if is_redundant(op, frame) {
+            return Instruction::Optimized(OptimizedOp::Nop);
+        }
+
+        match op {
+            Op::FindProperty { index } | Op::FindPropStrict { index }
+                if self.finds_receiver(*index, frame) =>
+            {
+                analysis.uses_receiver = true;
+                Instruction::Op(Op::GetLocal { index: 0 })
+            }
+            Op::GetProperty { index } if is_static_multiname(self.abc, *index) => {
+                Instruction::Optimized(OptimizedOp::GetProperty {
+                    index: *index,
+                    cache: self.property_cache(*index, frame.peek(0), analysis),
+                })
+            }
+            Op::SetProperty { index } if is_static_multiname(self.abc, *index) => {
+                Instruction::Optimized(OptimizedOp::SetProperty {
+                    index: *index,
+                    cache: self.property_cache(*index, frame.peek(1), analysis),
+                })
+            }
+            Op::InitProperty { index } if is_static_multiname(self.abc, *index) => {
+                Instruction::Optimized(OptimizedOp::InitProperty {
+                    index: *index,
+                    cache: self.property_cache(*index, frame.peek(1), analysis),
+                })
+            }
+            Op::CallProperty { index, num_args } | Op::CallPropVoid { index, num_args }
+                if is_static_multiname(self.abc, *index) =>
+            {
+                let receiver = frame.peek(*num_args as usize);
+                Instruction::Optimized(OptimizedOp::CallProperty {
+                    index: *index,
+                    num_args: *num_args,
+                    cache: self.property_cache(*index, receiver, analysis),
+                    push_result: matches!(op, Op::CallProperty { .. }),
+                })
+            }
+            Op::GetLex { index }
+                if self.receiver.is_some()
+                    && frame
+                        .scope
+                        .iter()
+                        .all(|scope| matches!(scope, ValueType::This | ValueType::Activation)) =>
+            {
+                // The class is only cached if the local scope stack doesn't
+                // have it, and the only things that can be on it are objects
+                // whose traits are fixed by `this`'s class and this method.
+                analysis.uses_receiver = true;
+                analysis.caches.push(None);
+                Instruction::Optimized(OptimizedOp::GetLex {
+                    index: *index,
+                    cache: analysis.caches.len() as u32 - 1,
+                })
+            }
+            op => Instruction::Op(op.clone()),
+        }
+    }
+}
+
+/// Whether an op would leave the value on top of the stack unchanged.
+fn is_redundant(op: &Op, frame: &Frame) -> bool {
+    let top = frame.peek(0);
+    match op {
+        Op::CoerceA => true,
+        Op::CoerceB | Op::ConvertB => top == ValueType::Boolean,
+        Op::CoerceD | Op::ConvertD => top == ValueType::Number,
+        Op::CoerceI | Op::ConvertI => top == ValueType::Int,
+        Op::CoerceU | Op::ConvertU => top == ValueType::Uint,
+        Op::CoerceS | Op::ConvertS => top == ValueType::String,
+        Op::CoerceO | Op::ConvertO => top.is_non_null(),
+        Op::Coerce { index } => top == ValueType::Coerced(index.0),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swf::avm2::types::ConstantPool;
+
+    fn abc() -> AbcFile {
+        AbcFile {
+            major_version: 46,
+            minor_version: 16,
+            constant_pool: ConstantPool {
+                ints: vec![],
+                uints: vec![],
+                doubles: vec![],
+                strings: vec!["foo".to_string()],
+                namespaces: vec![],
+                namespace_sets: vec![],
+                multinames: vec![
+                    AbcMultiname::QName {
+                        namespace: Index::new(0),
+                        name: Index::new(1),
+                    },
+                    AbcMultiname::RTQNameL,
+                ],
+            },
+            methods: vec![],
+            metadata: vec![],
+            instances: vec![],
+            classes: vec![],
+            scripts: vec![],
+            method_bodies: vec![],
+        }
+    }
+
+    fn body(code: &[u8]) -> MethodBody {
+        MethodBody {
+            method: Index::new(0),
+            max_stack: 4,
+            num_locals: 2,
+            init_scope_depth: 0,
+            max_scope_depth: 2,
+            code: code.to_vec(),
+            exceptions: vec![],
+            traits: vec![],
+        }
+    }
+
+    /// Optimize `code` as an instance method whose class has a slot `foo`.
+    fn optimize(code: &[u8]) -> Analysis {
+        let abc = abc();
+        let body = body(code);
+        let mut resolve =
+            |index: Index<AbcMultiname>| (index.0 == 1).then_some(Property::Slot { slot_id: 1 });
+        analyze(&abc, &body, Some(&mut resolve))
+    }
+
+    fn ops(analysis: &Analysis) -> Vec<&Instruction> {
+        analysis.ops.iter().map(|(_, op, _)| op).collect()
+    }
+
+    // Opcodes used below.
+    const COERCE_A: u8 = 0x82;
+    const COERCE_I: u8 = 0x83;
+    const CONVERT_I: u8 = 0x73;
+    const FINDPROPSTRICT: u8 = 0x5d;
+    const GETLOCAL0: u8 = 0xd0;
+    const GETLOCAL1: u8 = 0xd1;
+    const GETPROPERTY: u8 = 0x66;
+    const PUSHBYTE: u8 = 0x24;
+    const PUSHSCOPE: u8 = 0x30;
+    const RETURNVALUE: u8 = 0x48;
+    const SETLOCAL0: u8 = 0xd4;
+
+    #[test]
+    fn drops_redundant_coercions() {
+        let analysis = optimize(&[PUSHBYTE, 1, COERCE_I, CONVERT_I, COERCE_A, RETURNVALUE]);
+        assert!(matches!(
+            ops(&analysis)[..],
+            [
+                Instruction::Op(Op::PushByte { value: 1 }),
+                Instruction::Optimized(OptimizedOp::Nop),
+                Instruction::Optimized(OptimizedOp::Nop),
+                Instruction::Optimized(OptimizedOp::Nop),
+                Instruction::Op(Op::ReturnValue),
+            ]
+        ));
+    }
+
+    #[test]
+    fn keeps_needed_coercions() {
+        let analysis = optimize(&[GETLOCAL1, COERCE_I, RETURNVALUE]);
+        assert!(matches!(
+            ops(&analysis)[..],
+            [
+                Instruction::Op(Op::GetLocal { index: 1 }),
+                Instruction::Op(Op::CoerceI),
+                Instruction::Op(Op::ReturnValue),
+            ]
+        ));
+    }
+
+    #[test]
+    fn resolves_receiver_traits() {
+        let analysis = optimize(&[
+            GETLOCAL0,
+            PUSHSCOPE,
+            FINDPROPSTRICT,
+            1,
+            GETPROPERTY,
+            1,
+            RETURNVALUE,
+        ]);
+        assert!(matches!(
+            ops(&analysis)[2..4],
+            [
+                Instruction::Op(Op::GetLocal { index: 0 }),
+                Instruction::Optimized(OptimizedOp::GetProperty { cache: 0, .. }),
+            ]
+        ));
+        assert!(matches!(
+            analysis.caches[..],
+            [Some(Property::Slot { slot_id: 1 })]
+        ));
+        assert!(analysis.uses_receiver);
+    }
+
+    #[test]
+    fn overwritten_receiver_is_unknown() {
+        let analysis = optimize(&[GETLOCAL1, SETLOCAL0, GETLOCAL0, GETPROPERTY, 1, RETURNVALUE]);
+        assert!(matches!(analysis.caches[..], [None]));
+        assert!(!analysis.uses_receiver);
+    }
+
+    #[test]
+    fn runtime_names_are_not_cached() {
+        let analysis = optimize(&[GETLOCAL0, PUSHBYTE, 1, GETPROPERTY, 2, RETURNVALUE]);
+        assert!(matches!(
+            ops(&analysis)[2],
+            Instruction::Op(Op::GetProperty { .. })
+        ));
+    }
+}
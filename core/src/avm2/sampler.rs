@@ -0,0 +1,283 @@
+//! Memory sampling, as exposed through `flash.sampler`
+
+use crate::avm2::activation::Activation;
+use crate::avm2::object::{ClassObject, Object, TObject, WeakObject};
+use crate::avm2::Value;
+use crate::string::AvmString;
+use gc_arena::{Collect, Mutation};
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::mem::size_of;
+use web_time::Instant;
+
+/// Records object allocations (and their eventual collection) while
+/// sampling is enabled.
+#[derive(Collect, Default)]
+#[collect(no_drop)]
+pub struct Sampler<'gc> {
+    sampling: bool,
+
+    next_id: u64,
+
+    samples: Vec<Sample<'gc>>,
+
+    /// Objects we've recorded a `NewObjectSample` for, but not yet a
+    /// `DeleteObjectSample`.
+    live_objects: Vec<LiveObject<'gc>>,
+}
+
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct Sample<'gc> {
+    /// Microseconds since the player was started.
+    pub time: f64,
+
+    /// The name of every function on the call stack, innermost first.
+    #[collect(require_static)]
+    pub stack: Vec<String>,
+
+    pub kind: SampleKind<'gc>,
+}
+
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+pub enum SampleKind<'gc> {
+    NewObject {
+        id: u64,
+        class: ClassObject<'gc>,
+        object: WeakObject<'gc>,
+        size: usize,
+    },
+    DeleteObject {
+        id: u64,
+        size: usize,
+    },
+}
+
+#[derive(Collect)]
+#[collect(no_drop)]
+struct LiveObject<'gc> {
+    id: u64,
+    object: WeakObject<'gc>,
+    size: usize,
+}
+
+impl<'gc> Sampler<'gc> {
+    pub fn is_sampling(&self) -> bool {
+        self.sampling
+    }
+
+    pub fn start(&mut self) {
+        self.sampling = true;
+    }
+
+    /// Stop recording new samples, while keeping track of the objects
+    /// already sampled.
+    pub fn pause(&mut self) {
+        self.sampling = false;
+    }
+
+    pub fn stop(&mut self) {
+        self.sampling = false;
+        self.live_objects.clear();
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+        self.live_objects.clear();
+    }
+
+    pub fn samples(&self) -> &[Sample<'gc>] {
+        &self.samples
+    }
+
+    /// Look up a sampled object by its id, if it hasn't been collected yet.
+    pub fn live_object(&self, id: u64, mc: &Mutation<'gc>) -> Option<Object<'gc>> {
+        self.live_objects
+            .iter()
+            .find(|live| live.id == id)
+            .and_then(|live| live.object.upgrade(mc))
+    }
+
+    /// Record the allocation of a new object.
+    ///
+    /// This should be called by anything that allocates an object on behalf
+    /// of user code, once its slots have been installed. Class-less objects
+    /// (such as activations) are reported as instances of `Object`.
+    pub fn record_new_object(activation: &mut Activation<'_, 'gc>, object: Object<'gc>) {
+        if !activation.avm2().sampler.sampling {
+            return;
+        }
+        let class = object
+            .instance_of()
+            .unwrap_or_else(|| activation.avm2().classes().object);
+
+        let time = sample_time(activation);
+        let stack = activation.avm2().call_stack().read().frame_names();
+        let size = estimate_size(object.into());
+
+        let sampler = &mut activation.avm2().sampler;
+        let id = sampler.next_id;
+        sampler.next_id += 1;
+        sampler.live_objects.push(LiveObject {
+            id,
+            object: object.downgrade(),
+            size,
+        });
+        sampler.samples.push(Sample {
+            time,
+            stack,
+            kind: SampleKind::NewObject {
+                id,
+                class,
+                object: object.downgrade(),
+                size,
+            },
+        });
+    }
+
+    /// Record a `DeleteObjectSample` for every sampled object that has been
+    /// garbage collected since the last call.
+    pub fn record_deleted_objects(activation: &mut Activation<'_, 'gc>) {
+        let time = sample_time(activation);
+        let mc = activation.context.gc_context;
+        let sampler = &mut activation.avm2().sampler;
+
+        let mut deleted = Vec::new();
+        sampler.live_objects.retain(|live| {
+            if live.object.upgrade(mc).is_none() {
+                deleted.push(Sample {
+                    time,
+                    stack: Vec::new(),
+                    kind: SampleKind::DeleteObject {
+                        id: live.id,
+                        size: live.size,
+                    },
+                });
+                false
+            } else {
+                true
+            }
+        });
+
+        if sampler.sampling {
+            sampler.samples.extend(deleted);
+        }
+    }
+
+    /// Write every sample out as tab-separated text, one sample per line.
+    pub fn export(&self, mc: &Mutation<'gc>) -> String {
+        let mut output = String::from("kind\tid\ttime\ttype\tsize\tstack\n");
+        for sample in &self.samples {
+            let (kind, id, class_name, size) = match &sample.kind {
+                SampleKind::NewObject {
+                    id, class, size, ..
+                } => (
+                    "new",
+                    *id,
+                    class
+                        .inner_class_definition()
+                        .read()
+                        .name()
+                        .to_qualified_name(mc)
+                        .to_string(),
+                    *size,
+                ),
+                SampleKind::DeleteObject { id, size } => ("delete", *id, String::new(), *size),
+            };
+            let _ = writeln!(
+                output,
+                "{kind}\t{id}\t{}\t{class_name}\t{size}\t{}",
+                sample.time,
+                sample.stack.join(" < ")
+            );
+        }
+        output
+    }
+}
+
+fn sample_time(activation: &mut Activation<'_, '_>) -> f64 {
+    Instant::now()
+        .duration_since(activation.context.start_time)
+        .as_micros() as f64
+}
+
+/// Estimate how many bytes a value takes up by itself.
+///
+/// This doesn't follow references to other objects, so it's closer to Flash
+/// Player's "shallow" size. It's the size reported for new objects.
+pub fn estimate_size(value: Value<'_>) -> usize {
+    match value {
+        Value::String(string) => size_of::<Value>() + string_data_size(string),
+        Value::Object(object) => {
+            let mut size = object.base().estimated_size();
+            if let Some(bytes) = object.as_bytearray() {
+                size += bytes.len();
+            }
+            if let Some(array) = object.as_array_storage() {
+                size += array.length() * size_of::<Value>();
+            }
+            if let Some(vector) = object.as_vector_storage() {
+                size += vector.length() * size_of::<Value>();
+            }
+            if let Some(bitmap_data) = object.as_bitmap_data() {
+                size += (bitmap_data.width() * bitmap_data.height()) as usize * 4;
+            }
+            size
+        }
+        _ => size_of::<Value>(),
+    }
+}
+
+/// Estimate how many bytes a value is keeping alive, including the objects
+/// it holds in its slots, dynamic properties and array or vector elements.
+///
+/// Classes, functions and display objects are shared with the rest of the
+/// movie rather than owned by whoever references them, so they're only
+/// counted when they're the value being measured.
+pub fn estimate_retained_size(value: Value<'_>) -> usize {
+    let mut size = estimate_size(value);
+    let Value::Object(root) = value else {
+        return size;
+    };
+
+    let mut visited = HashSet::new();
+    visited.insert(root.as_ptr());
+    let mut pending = owned_values(root);
+    while let Some(value) = pending.pop() {
+        // The holder's size already covers the `Value` itself, so only count
+        // what it points to.
+        match value {
+            Value::String(string) => size += string_data_size(string),
+            Value::Object(object) => {
+                if object.as_class_object().is_some()
+                    || object.as_function_object().is_some()
+                    || object.as_display_object().is_some()
+                    || !visited.insert(object.as_ptr())
+                {
+                    continue;
+                }
+                size += estimate_size(value);
+                pending.extend(owned_values(object));
+            }
+            _ => {}
+        }
+    }
+    size
+}
+
+fn string_data_size(string: AvmString<'_>) -> usize {
+    let char_size = if string.is_wide() { 2 } else { 1 };
+    string.len() * char_size
+}
+
+fn owned_values<'gc>(object: Object<'gc>) -> Vec<Value<'gc>> {
+    let mut values: Vec<_> = object.base().owned_values().collect();
+    if let Some(array) = object.as_array_storage() {
+        values.extend(array.iter().flatten());
+    }
+    if let Some(vector) = object.as_vector_storage() {
+        values.extend(vector.iter());
+    }
+    values
+}
@@ -0,0 +1,762 @@
+//! AVM2 bytecode verification
+//!
+//! Flash Player checks every method body before running it for the first
+//! time, and throws a `VerifyError` if the body could misbehave at runtime.
+//! We check the same structural properties here, so that broken (or
+//! deliberately obfuscated) bytecode is rejected up-front rather than going
+//! wrong halfway through execution.
+
+use crate::avm2::error::verify_error;
+use crate::avm2::method::BytecodeMethod;
+use crate::avm2::{Activation, Error};
+use crate::swf::extensions::ReadSwfExt;
+use gc_arena::Gc;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use swf::avm2::read::Reader;
+use swf::avm2::types::{AbcFile, Index, MethodBody, Multiname, Op};
+
+/// Why a method body failed verification, along with Flash Player's error
+/// code for that kind of failure.
+#[derive(Debug, PartialEq, Eq)]
+pub struct VerifyFailure {
+    pub message: String,
+    pub code: u32,
+}
+
+impl VerifyFailure {
+    fn new(code: u32, message: String) -> Self {
+        Self {
+            message: format!("Error #{code}: {message}"),
+            code,
+        }
+    }
+
+    fn illegal_opcode(method_name: &str, opcode: u8, offset: usize) -> Self {
+        Self::new(
+            1011,
+            format!("Method {method_name} contained illegal opcode {opcode} at offset {offset}."),
+        )
+    }
+
+    fn scope_overflow() -> Self {
+        Self::new(1017, "Scope stack overflow occurred.".to_string())
+    }
+
+    fn scope_underflow() -> Self {
+        Self::new(1018, "Scope stack underflow occurred.".to_string())
+    }
+
+    fn scope_object_out_of_bounds(index: u8) -> Self {
+        Self::new(1019, format!("Getscopeobject {index} is out of bounds."))
+    }
+
+    fn falls_off_end() -> Self {
+        Self::new(
+            1020,
+            "Code cannot fall off the end of a method.".to_string(),
+        )
+    }
+
+    fn invalid_branch_target() -> Self {
+        Self::new(
+            1021,
+            "At least one branch target was not on a valid instruction in the method.".to_string(),
+        )
+    }
+
+    fn stack_overflow() -> Self {
+        Self::new(1023, "Stack overflow occurred.".to_string())
+    }
+
+    fn stack_underflow() -> Self {
+        Self::new(1024, "Stack underflow occurred.".to_string())
+    }
+
+    fn invalid_register(index: u32) -> Self {
+        Self::new(1025, format!("An invalid register {index} was accessed."))
+    }
+
+    fn method_out_of_range(index: u32, count: usize) -> Self {
+        Self::new(
+            1027,
+            format!("Method_info {index} exceeds method_count={count}."),
+        )
+    }
+
+    fn unbalanced_stack(a: usize, b: usize) -> Self {
+        Self::new(1030, format!("Stack depth is unbalanced. {a} != {b}."))
+    }
+
+    fn unbalanced_scope(a: usize, b: usize) -> Self {
+        Self::new(1031, format!("Scope depth is unbalanced. {a} != {b}."))
+    }
+
+    fn cpool_out_of_range(index: u32, count: usize) -> Self {
+        Self::new(
+            1032,
+            format!("Cpool index {index} is out of range {count}."),
+        )
+    }
+
+    fn invalid_code_length(length: usize) -> Self {
+        Self::new(1043, format!("Invalid code_length={length}."))
+    }
+
+    fn invalid_exception_handler() -> Self {
+        Self::new(
+            1054,
+            "Illegal range or target offsets in exception handler.".to_string(),
+        )
+    }
+
+    fn class_out_of_range(index: u32, count: usize) -> Self {
+        Self::new(
+            1060,
+            format!("ClassInfo-{index} exceeds class_count={count}."),
+        )
+    }
+
+    fn corrupt() -> Self {
+        Self::new(
+            1107,
+            "The ABC data is corrupt, attempt to read out of bounds.".to_string(),
+        )
+    }
+}
+
+/// Verify a bytecode method, throwing a `VerifyError` if it fails.
+///
+/// Methods only need to be verified once, before their first call.
+pub fn verify_method<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    method: Gc<'gc, BytecodeMethod<'gc>>,
+) -> Result<(), Error<'gc>> {
+    let Some(body) = method.body() else {
+        return Err("Cannot verify non-native method without body".into());
+    };
+
+    match verify_body(&method.abc, body, method.method_name()) {
+        Ok(()) => Ok(()),
+        Err(failure) => Err(Error::AvmError(verify_error(
+            activation,
+            &failure.message,
+            failure.code,
+        )?)),
+    }
+}
+
+/// The stack and scope depth on entry to an instruction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct FrameState {
+    stack: usize,
+    scope: usize,
+}
+
+/// Check a method body for structural problems.
+///
+/// Only code that can actually be reached (either from the start of the
+/// method or from an exception handler) is checked, as Flash Player does.
+/// Obfuscators rely on this to hide junk bytes in dead code.
+pub fn verify_body(
+    abc: &AbcFile,
+    body: &MethodBody,
+    method_name: &str,
+) -> Result<(), VerifyFailure> {
+    let code = &body.code;
+    if code.is_empty() {
+        return Err(VerifyFailure::invalid_code_length(0));
+    }
+
+    let max_stack = body.max_stack as usize;
+    let max_scope = body.max_scope_depth.saturating_sub(body.init_scope_depth) as usize;
+
+    let mut states: HashMap<usize, FrameState> = HashMap::new();
+    let mut worklist = vec![(0, FrameState { stack: 0, scope: 0 })];
+
+    for exception in &body.exceptions {
+        let (from, to, target) = (
+            exception.from_offset as usize,
+            exception.to_offset as usize,
+            exception.target_offset as usize,
+        );
+        if from > to || to > code.len() || target >= code.len() {
+            return Err(VerifyFailure::invalid_exception_handler());
+        }
+        if max_stack < 1 {
+            return Err(VerifyFailure::stack_overflow());
+        }
+
+        // The handler starts with the error on an otherwise empty stack.
+        worklist.push((target, FrameState { stack: 1, scope: 0 }));
+    }
+
+    // The start and end offsets of every reachable instruction.
+    let mut instructions = Vec::new();
+
+    while let Some((offset, state)) = worklist.pop() {
+        match states.entry(offset) {
+            Entry::Occupied(existing) => {
+                let existing = *existing.get();
+                if existing.stack != state.stack {
+                    return Err(VerifyFailure::unbalanced_stack(existing.stack, state.stack));
+                }
+                if existing.scope != state.scope {
+                    return Err(VerifyFailure::unbalanced_scope(existing.scope, state.scope));
+                }
+                continue;
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(state);
+            }
+        }
+
+        let mut reader = Reader::new(code);
+        reader.seek_absolute(code, offset);
+        let op = reader
+            .read_op()
+            .map_err(|_| VerifyFailure::illegal_opcode(method_name, code[offset], offset))?;
+        let next = reader.pos(code);
+        instructions.push((offset, next));
+
+        let effect = stack_effect(abc, body, &op)?;
+
+        if effect.pop > state.stack {
+            return Err(VerifyFailure::stack_underflow());
+        }
+        let stack = state.stack - effect.pop + effect.push;
+        if stack > max_stack {
+            return Err(VerifyFailure::stack_overflow());
+        }
+
+        let scope = match op {
+            Op::PushScope | Op::PushWith => {
+                if state.scope >= max_scope {
+                    return Err(VerifyFailure::scope_overflow());
+                }
+                state.scope + 1
+            }
+            Op::PopScope => state
+                .scope
+                .checked_sub(1)
+                .ok_or_else(VerifyFailure::scope_underflow)?,
+            Op::GetScopeObject { index } if usize::from(index) >= state.scope => {
+                return Err(VerifyFailure::scope_object_out_of_bounds(index));
+            }
+            _ => state.scope,
+        };
+
+        let next_state = FrameState { stack, scope };
+        let branch = |relative_to: usize, offset: i32| {
+            usize::try_from(relative_to as i64 + i64::from(offset))
+                .ok()
+                .filter(|target| *target < code.len())
+                .ok_or_else(VerifyFailure::invalid_branch_target)
+        };
+
+        match &op {
+            Op::Jump { offset } => {
+                worklist.push((branch(next, *offset)?, next_state));
+                continue;
+            }
+            Op::IfEq { offset }
+            | Op::IfFalse { offset }
+            | Op::IfGe { offset }
+            | Op::IfGt { offset }
+            | Op::IfLe { offset }
+            | Op::IfLt { offset }
+            | Op::IfNe { offset }
+            | Op::IfNge { offset }
+            | Op::IfNgt { offset }
+            | Op::IfNle { offset }
+            | Op::IfNlt { offset }
+            | Op::IfStrictEq { offset }
+            | Op::IfStrictNe { offset }
+            | Op::IfTrue { offset } => {
+                worklist.push((branch(next, *offset)?, next_state));
+            }
+            Op::LookupSwitch {
+                default_offset,
+                case_offsets,
+            } => {
+                // Switch targets are relative to the start of the instruction.
+                worklist.push((branch(offset, *default_offset)?, next_state));
+                for case_offset in case_offsets.iter() {
+                    worklist.push((branch(offset, *case_offset)?, next_state));
+                }
+                continue;
+            }
+            Op::ReturnValue | Op::ReturnVoid | Op::Throw => continue,
+            _ => {}
+        }
+
+        if next >= code.len() {
+            return Err(VerifyFailure::falls_off_end());
+        }
+        worklist.push((next, next_state));
+    }
+
+    // Every branch must land at the start of an instruction, so no two
+    // reachable instructions may overlap.
+    instructions.sort_unstable();
+    if instructions.windows(2).any(|pair| pair[1].0 < pair[0].1) {
+        return Err(VerifyFailure::invalid_branch_target());
+    }
+
+    Ok(())
+}
+
+/// How many values an instruction takes off the stack, and how many it puts
+/// back.
+pub struct StackEffect {
+    pub pop: usize,
+    pub push: usize,
+}
+
+fn effect(pop: usize, push: usize) -> StackEffect {
+    StackEffect { pop, push }
+}
+
+/// Works out an instruction's effect on the stack, checking its operands
+/// along the way.
+pub fn stack_effect(
+    abc: &AbcFile,
+    body: &MethodBody,
+    op: &Op,
+) -> Result<StackEffect, VerifyFailure> {
+    let pool = &abc.constant_pool;
+    let register = |index: u32| {
+        if index < body.num_locals {
+            Ok(())
+        } else {
+            Err(VerifyFailure::invalid_register(index))
+        }
+    };
+    let args = |num_args: u32| num_args as usize;
+
+    Ok(match op {
+        Op::Add
+        | Op::AddI
+        | Op::AsTypeLate
+        | Op::BitAnd
+        | Op::BitOr
+        | Op::BitXor
+        | Op::Divide
+        | Op::Equals
+        | Op::GreaterEquals
+        | Op::GreaterThan
+        | Op::HasNext
+        | Op::In
+        | Op::InstanceOf
+        | Op::IsTypeLate
+        | Op::LessEquals
+        | Op::LessThan
+        | Op::LShift
+        | Op::Modulo
+        | Op::Multiply
+        | Op::MultiplyI
+        | Op::NextName
+        | Op::NextValue
+        | Op::RShift
+        | Op::StrictEquals
+        | Op::Subtract
+        | Op::SubtractI
+        | Op::URShift => effect(2, 1),
+
+        Op::BitNot
+        | Op::CheckFilter
+        | Op::CoerceA
+        | Op::CoerceB
+        | Op::CoerceD
+        | Op::CoerceI
+        | Op::CoerceO
+        | Op::CoerceS
+        | Op::CoerceU
+        | Op::ConvertB
+        | Op::ConvertD
+        | Op::ConvertI
+        | Op::ConvertO
+        | Op::ConvertS
+        | Op::ConvertU
+        | Op::Decrement
+        | Op::DecrementI
+        | Op::EscXAttr
+        | Op::EscXElem
+        | Op::Increment
+        | Op::IncrementI
+        | Op::Lf32
+        | Op::Lf64
+        | Op::Li16
+        | Op::Li32
+        | Op::Li8
+        | Op::Negate
+        | Op::NegateI
+        | Op::Not
+        | Op::Sxi1
+        | Op::Sxi16
+        | Op::Sxi8
+        | Op::TypeOf => effect(1, 1),
+
+        Op::Sf32 | Op::Sf64 | Op::Si16 | Op::Si32 | Op::Si8 => effect(2, 0),
+
+        Op::AsType { type_name: index } | Op::Coerce { index } | Op::IsType { index } => {
+            multiname(abc, index)?;
+            effect(1, 1)
+        }
+
+        Op::ApplyType { num_types } => effect(args(*num_types) + 1, 1),
+        Op::Call { num_args } => effect(args(*num_args) + 2, 1),
+        Op::Construct { num_args } => effect(args(*num_args) + 1, 1),
+        Op::ConstructSuper { num_args } => effect(args(*num_args) + 1, 0),
+        Op::CallMethod { num_args, .. } => effect(args(*num_args) + 1, 1),
+        Op::CallStatic { index, num_args } => {
+            method(abc, index)?;
+            effect(args(*num_args) + 1, 1)
+        }
+
+        Op::CallProperty { index, num_args }
+        | Op::CallPropLex { index, num_args }
+        | Op::CallSuper { index, num_args }
+        | Op::ConstructProp { index, num_args } => {
+            effect(args(*num_args) + 1 + multiname(abc, index)?, 1)
+        }
+        Op::CallPropVoid { index, num_args } | Op::CallSuperVoid { index, num_args } => {
+            effect(args(*num_args) + 1 + multiname(abc, index)?, 0)
+        }
+
+        Op::DeleteProperty { index }
+        | Op::GetDescendants { index }
+        | Op::GetProperty { index }
+        | Op::GetSuper { index } => effect(1 + multiname(abc, index)?, 1),
+        Op::InitProperty { index } | Op::SetProperty { index } | Op::SetSuper { index } => {
+            effect(2 + multiname(abc, index)?, 0)
+        }
+        Op::FindProperty { index } | Op::FindPropStrict { index } => {
+            effect(multiname(abc, index)?, 1)
+        }
+        Op::FindDef { index } | Op::GetLex { index } => {
+            multiname(abc, index)?;
+            effect(0, 1)
+        }
+
+        Op::Bkpt
+        | Op::BkptLine { .. }
+        | Op::DebugLine { .. }
+        | Op::Label
+        | Op::Nop
+        | Op::Timestamp
+        | Op::Jump { .. }
+        | Op::ReturnVoid => effect(0, 0),
+        Op::Debug { register_name, .. } => {
+            string(abc, register_name)?;
+            effect(0, 0)
+        }
+        Op::DebugFile { file_name } => {
+            string(abc, file_name)?;
+            effect(0, 0)
+        }
+        Op::Dxns { index } => {
+            string(abc, index)?;
+            effect(0, 0)
+        }
+        Op::DxnsLate => effect(1, 0),
+
+        Op::Dup => effect(1, 2),
+        Op::Swap => effect(2, 2),
+        Op::Pop => effect(1, 0),
+
+        Op::GetLocal { index } => {
+            register(*index)?;
+            effect(0, 1)
+        }
+        Op::SetLocal { index } => {
+            register(*index)?;
+            effect(1, 0)
+        }
+        Op::Kill { index }
+        | Op::IncLocal { index }
+        | Op::IncLocalI { index }
+        | Op::DecLocal { index }
+        | Op::DecLocalI { index } => {
+            register(*index)?;
+            effect(0, 0)
+        }
+        Op::HasNext2 {
+            object_register,
+            index_register,
+        } => {
+            register(*object_register)?;
+            register(*index_register)?;
+            effect(0, 1)
+        }
+
+        Op::GetGlobalScope
+        | Op::GetGlobalSlot { .. }
+        | Op::GetOuterScope { .. }
+        | Op::GetScopeObject { .. }
+        | Op::NewActivation
+        | Op::PushConstant { .. }
+        | Op::PushByte { .. }
+        | Op::PushFalse
+        | Op::PushNaN
+        | Op::PushNull
+        | Op::PushShort { .. }
+        | Op::PushTrue
+        | Op::PushUndefined => effect(0, 1),
+        Op::PushDouble { value } => {
+            pool_index(value.0, pool.doubles.len())?;
+            effect(0, 1)
+        }
+        Op::PushInt { value } => {
+            pool_index(value.0, pool.ints.len())?;
+            effect(0, 1)
+        }
+        Op::PushUint { value } => {
+            pool_index(value.0, pool.uints.len())?;
+            effect(0, 1)
+        }
+        Op::PushNamespace { value } => {
+            pool_index(value.0, pool.namespaces.len())?;
+            effect(0, 1)
+        }
+        Op::PushString { value } => {
+            string(abc, value)?;
+            effect(0, 1)
+        }
+
+        Op::SetGlobalSlot { .. } => effect(1, 0),
+        Op::GetSlot { .. } => effect(1, 1),
+        Op::SetSlot { .. } => effect(2, 0),
+
+        Op::IfEq { .. }
+        | Op::IfGe { .. }
+        | Op::IfGt { .. }
+        | Op::IfLe { .. }
+        | Op::IfLt { .. }
+        | Op::IfNe { .. }
+        | Op::IfNge { .. }
+        | Op::IfNgt { .. }
+        | Op::IfNle { .. }
+        | Op::IfNlt { .. }
+        | Op::IfStrictEq { .. }
+        | Op::IfStrictNe { .. } => effect(2, 0),
+        Op::IfTrue { .. } | Op::IfFalse { .. } | Op::LookupSwitch { .. } => effect(1, 0),
+
+        Op::NewArray { num_args } => effect(args(*num_args), 1),
+        Op::NewObject { num_args } => effect(args(*num_args).saturating_mul(2), 1),
+        Op::NewCatch { index } => {
+            if index.0 as usize >= body.exceptions.len() {
+                return Err(VerifyFailure::corrupt());
+            }
+            effect(0, 1)
+        }
+        Op::NewClass { index } => {
+            if index.0 as usize >= abc.classes.len() {
+                return Err(VerifyFailure::class_out_of_range(
+                    index.0,
+                    abc.classes.len(),
+                ));
+            }
+            effect(1, 1)
+        }
+        Op::NewFunction { index } => {
+            method(abc, index)?;
+            effect(0, 1)
+        }
+
+        Op::PushScope | Op::PushWith => effect(1, 0),
+        Op::PopScope => effect(0, 0),
+
+        Op::ReturnValue | Op::Throw => effect(1, 0),
+    })
+}
+
+/// Checks an index into one of the constant pools. Index 0 is never valid
+/// for an instruction operand.
+fn pool_index(index: u32, len: usize) -> Result<(), VerifyFailure> {
+    if index == 0 || index as usize > len {
+        Err(VerifyFailure::cpool_out_of_range(index, len + 1))
+    } else {
+        Ok(())
+    }
+}
+
+fn string<T>(abc: &AbcFile, index: &Index<T>) -> Result<(), VerifyFailure> {
+    pool_index(index.0, abc.constant_pool.strings.len())
+}
+
+fn method<T>(abc: &AbcFile, index: &Index<T>) -> Result<(), VerifyFailure> {
+    if index.0 as usize >= abc.methods.len() {
+        Err(VerifyFailure::method_out_of_range(
+            index.0,
+            abc.methods.len(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks a multiname operand, returning how many extra values a runtime
+/// multiname takes off the stack.
+fn multiname(
+    abc: &AbcFile,
+    index: &Index<swf::avm2::types::Multiname>,
+) -> Result<usize, VerifyFailure> {
+    let multinames = &abc.constant_pool.multinames;
+    pool_index(index.0, multinames.len())?;
+
+    Ok(match multinames[index.0 as usize - 1] {
+        Multiname::RTQName { .. } | Multiname::RTQNameA { .. } => 1,
+        Multiname::RTQNameL | Multiname::RTQNameLA => 2,
+        Multiname::MultinameL { .. } | Multiname::MultinameLA { .. } => 1,
+        Multiname::QName { .. }
+        | Multiname::QNameA { .. }
+        | Multiname::Multiname { .. }
+        | Multiname::MultinameA { .. }
+        | Multiname::TypeName { .. } => 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swf::avm2::types::{ConstantPool, Exception};
+
+    fn abc() -> AbcFile {
+        AbcFile {
+            major_version: 46,
+            minor_version: 16,
+            constant_pool: ConstantPool {
+                ints: vec![],
+                uints: vec![],
+                doubles: vec![],
+                strings: vec!["foo".to_string()],
+                namespaces: vec![],
+                namespace_sets: vec![],
+                multinames: vec![],
+            },
+            methods: vec![],
+            metadata: vec![],
+            instances: vec![],
+            classes: vec![],
+            scripts: vec![],
+            method_bodies: vec![],
+        }
+    }
+
+    fn body(code: &[u8], max_stack: u32, num_locals: u32) -> MethodBody {
+        MethodBody {
+            method: Index::new(0),
+            max_stack,
+            num_locals,
+            init_scope_depth: 0,
+            max_scope_depth: 1,
+            code: code.to_vec(),
+            exceptions: vec![],
+            traits: vec![],
+        }
+    }
+
+    fn verify(body: &MethodBody) -> Result<(), u32> {
+        verify_body(&abc(), body, "test").map_err(|failure| failure.code)
+    }
+
+    // Opcodes used below.
+    const GETLOCAL0: u8 = 0xd0;
+    const PUSHSCOPE: u8 = 0x30;
+    const POPSCOPE: u8 = 0x1d;
+    const PUSHBYTE: u8 = 0x24;
+    const PUSHSTRING: u8 = 0x2c;
+    const POP: u8 = 0x29;
+    const JUMP: u8 = 0x10;
+    const IFTRUE: u8 = 0x11;
+    const GETLOCAL: u8 = 0x62;
+    const RETURNVOID: u8 = 0x47;
+    const RETURNVALUE: u8 = 0x48;
+
+    #[test]
+    fn valid_method() {
+        let code = [
+            GETLOCAL0, PUSHSCOPE, PUSHSTRING, 1, POP, POPSCOPE, RETURNVOID,
+        ];
+        assert_eq!(verify(&body(&code, 1, 1)), Ok(()));
+    }
+
+    #[test]
+    fn empty_method() {
+        assert_eq!(verify(&body(&[], 0, 0)), Err(1043));
+    }
+
+    #[test]
+    fn stack_underflow() {
+        assert_eq!(verify(&body(&[POP, RETURNVOID], 1, 1)), Err(1024));
+    }
+
+    #[test]
+    fn stack_overflow() {
+        let code = [PUSHBYTE, 1, PUSHBYTE, 2, POP, POP, RETURNVOID];
+        assert_eq!(verify(&body(&code, 1, 1)), Err(1023));
+    }
+
+    #[test]
+    fn scope_underflow() {
+        assert_eq!(verify(&body(&[POPSCOPE, RETURNVOID], 1, 1)), Err(1018));
+    }
+
+    #[test]
+    fn invalid_register() {
+        let code = [GETLOCAL, 3, RETURNVALUE];
+        assert_eq!(verify(&body(&code, 1, 1)), Err(1025));
+    }
+
+    #[test]
+    fn cpool_out_of_range() {
+        let code = [PUSHSTRING, 2, RETURNVALUE];
+        assert_eq!(verify(&body(&code, 1, 1)), Err(1032));
+    }
+
+    #[test]
+    fn falls_off_end() {
+        assert_eq!(verify(&body(&[GETLOCAL0, POP], 1, 1)), Err(1020));
+    }
+
+    #[test]
+    fn branch_outside_method() {
+        let code = [JUMP, 0x10, 0, 0, RETURNVOID];
+        assert_eq!(verify(&body(&code, 1, 1)), Err(1021));
+    }
+
+    #[test]
+    fn branch_into_instruction() {
+        // Jumps into the operand of the `pushbyte`, which happens to decode
+        // as a `returnvoid`.
+        let code = [PUSHBYTE, RETURNVOID, JUMP, 0xfb, 0xff, 0xff];
+        assert_eq!(verify(&body(&code, 1, 1)), Err(1021));
+    }
+
+    #[test]
+    fn unbalanced_stack() {
+        // One path pushes an extra value before joining the other.
+        let code = [PUSHBYTE, 1, IFTRUE, 2, 0, 0, PUSHBYTE, 1, RETURNVOID];
+        assert_eq!(verify(&body(&code, 2, 1)), Err(1030));
+    }
+
+    #[test]
+    fn dead_code_is_ignored() {
+        let code = [JUMP, 1, 0, 0, 0xff, RETURNVOID];
+        assert_eq!(verify(&body(&code, 1, 1)), Ok(()));
+    }
+
+    #[test]
+    fn invalid_exception_handler() {
+        let mut body = body(&[RETURNVOID], 1, 1);
+        body.exceptions.push(Exception {
+            from_offset: 0,
+            to_offset: 1,
+            target_offset: 5,
+            variable_name: Index::new(0),
+            type_name: Index::new(0),
+        });
+        assert_eq!(verify(&body), Err(1054));
+    }
+}
@@ -106,6 +106,10 @@ impl<'gc> VTable<'gc> {
         vt
     }
 
+    pub fn ptr_eq(a: VTable<'gc>, b: VTable<'gc>) -> bool {
+        GcCell::ptr_eq(a.0, b.0)
+    }
+
     pub fn duplicate(self, mc: &Mutation<'gc>) -> Self {
         VTable(GcCell::new(mc, self.0.read().clone()))
     }
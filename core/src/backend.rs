@@ -1,5 +1,8 @@
 pub mod audio;
+#[cfg(feature = "debugger")]
+pub mod debugger;
 pub mod log;
 pub mod navigator;
+pub mod print;
 pub mod storage;
 pub mod ui;
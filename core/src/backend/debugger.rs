@@ -0,0 +1,30 @@
+/// The outcome of asking a [`DebuggerBackend`] for more data.
+#[derive(Debug)]
+pub enum DebuggerReceive {
+    /// Some bytes arrived from the debugger.
+    Data(Vec<u8>),
+
+    /// Nothing has arrived yet. Only returned by non-blocking reads.
+    Pending,
+
+    /// The debugger went away, or the connection to it failed.
+    Disconnected,
+}
+
+/// The connection to an ActionScript debugger such as `fdb`.
+///
+/// Core only speaks the debugger protocol; how its bytes get to the debugger
+/// and back is up to the frontend.
+pub trait DebuggerBackend {
+    /// Sends some bytes to the debugger.
+    ///
+    /// Errors are left for the next call to [`DebuggerBackend::receive`] to
+    /// report as [`DebuggerReceive::Disconnected`].
+    fn send(&self, data: &[u8]);
+
+    /// Fetches the next bytes the debugger sent us.
+    ///
+    /// If `blocking` is true, this waits until something arrives; this is
+    /// how the player stays stopped while the debugger is looking around.
+    fn receive(&mut self, blocking: bool) -> DebuggerReceive;
+}
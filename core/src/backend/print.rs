@@ -0,0 +1,79 @@
+use downcast_rs::Downcast;
+
+/// The dimensions of the paper selected for a print job, in points (1/72 of an inch).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PaperSize {
+    /// The total width of the paper.
+    pub paper_width: u32,
+    /// The total height of the paper.
+    pub paper_height: u32,
+    /// The width of the printable area of the paper.
+    pub page_width: u32,
+    /// The height of the printable area of the paper.
+    pub page_height: u32,
+}
+
+impl PaperSize {
+    /// US Letter paper with half-inch margins, which Flash Player reports when no printer
+    /// preference is available.
+    pub const LETTER: Self = Self {
+        paper_width: 612,
+        paper_height: 792,
+        page_width: 540,
+        page_height: 720,
+    };
+
+    pub fn is_landscape(&self) -> bool {
+        self.paper_width > self.paper_height
+    }
+}
+
+impl Default for PaperSize {
+    fn default() -> Self {
+        Self::LETTER
+    }
+}
+
+/// A single rasterised page of a print job.
+#[derive(Clone, Debug)]
+pub struct PrintPage {
+    pub width: u32,
+    pub height: u32,
+    /// The page contents as non-premultiplied RGBA, one byte per channel.
+    pub rgba: Vec<u8>,
+}
+
+pub trait PrintBackend: Downcast {
+    /// Called when a movie starts a new print job, typically to show a print dialog.
+    ///
+    /// Returns the paper the pages will be printed on, or `None` if the user cancelled
+    /// the job or printing isn't supported.
+    fn start_job(&mut self) -> Option<PaperSize>;
+
+    /// Sends the pages of a finished print job to the printer.
+    fn print(&mut self, pages: Vec<PrintPage>);
+}
+impl_downcast!(PrintBackend);
+
+/// Print backend that doesn't support printing.
+pub struct NullPrintBackend {}
+
+impl NullPrintBackend {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PrintBackend for NullPrintBackend {
+    fn start_job(&mut self) -> Option<PaperSize> {
+        None
+    }
+
+    fn print(&mut self, _pages: Vec<PrintPage>) {}
+}
+
+impl Default for NullPrintBackend {
+    fn default() -> Self {
+        NullPrintBackend::new()
+    }
+}
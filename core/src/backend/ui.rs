@@ -6,7 +6,7 @@ use downcast_rs::Downcast;
 use fluent_templates::loader::langid;
 pub use fluent_templates::LanguageIdentifier;
 use std::borrow::Cow;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use url::Url;
 
 pub type FullscreenError = Cow<'static, str>;
@@ -62,6 +62,13 @@ pub trait UiBackend: Downcast {
     /// Changes the mouse cursor image.
     fn set_mouse_cursor(&mut self, cursor: MouseCursor);
 
+    /// Changes the mouse cursor to a custom image registered by the movie.
+    /// This stays in effect until the next call to `set_mouse_cursor`.
+    fn set_custom_mouse_cursor(&mut self, cursor: CustomMouseCursor);
+
+    /// Whether `set_custom_mouse_cursor` actually displays the cursor it's given.
+    fn supports_custom_mouse_cursor(&self) -> bool;
+
     /// Get the clipboard content
     fn clipboard_content(&mut self) -> String;
 
@@ -140,6 +147,179 @@ pub enum MouseCursor {
     Grab,
 }
 
+impl MouseCursor {
+    /// Looks up a built-in cursor by its AS3 `MouseCursor` name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "arrow" => Some(Self::Arrow),
+            "button" => Some(Self::Hand),
+            "ibeam" => Some(Self::IBeam),
+            "hand" => Some(Self::Grab),
+            _ => None,
+        }
+    }
+
+    /// The AS3 `MouseCursor` name of this cursor.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Arrow => "arrow",
+            Self::Hand => "button",
+            Self::IBeam => "ibeam",
+            Self::Grab => "hand",
+        }
+    }
+}
+
+/// A custom mouse cursor registered by a movie via AS3 `Mouse.registerCursor`.
+/// Communicated from the core to the UI backend via `UiBackend::set_custom_mouse_cursor`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomMouseCursor {
+    /// The images making up the cursor. An animated cursor cycles through these.
+    pub frames: Vec<CursorImage>,
+
+    /// The number of frames per second an animated cursor is played at.
+    pub frame_rate: f64,
+
+    /// The position of the cursor's hot spot, in pixels from the top-left corner.
+    pub hot_spot: (u32, u32),
+}
+
+/// A single frame of a `CustomMouseCursor`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CursorImage {
+    pub width: u32,
+    pub height: u32,
+
+    /// The pixels of the image, as non-premultiplied RGBA.
+    pub rgba: Vec<u8>,
+}
+
+/// The mouse cursor requested by a movie via AS3 `Mouse.cursor`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CursorSelection {
+    /// The player picks a cursor based on the object under the mouse.
+    /// Equivalent to AS3 `MouseCursor.AUTO`.
+    Auto,
+
+    /// A built-in cursor is always displayed.
+    Builtin(MouseCursor),
+
+    /// A cursor registered with `Mouse.registerCursor` is always displayed.
+    Registered(String),
+}
+
+impl CursorSelection {
+    /// The AS3 `Mouse.cursor` name of this selection.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Auto => "auto",
+            Self::Builtin(cursor) => cursor.name(),
+            Self::Registered(name) => name,
+        }
+    }
+}
+
+/// Decides which cursor is shown, combining the cursor picked by the player
+/// with any cursor the movie has requested.
+pub struct MouseCursorManager {
+    automatic: MouseCursor,
+    selection: CursorSelection,
+    registered: HashMap<String, CustomMouseCursor>,
+}
+
+impl MouseCursorManager {
+    pub fn new() -> Self {
+        Self {
+            automatic: MouseCursor::Arrow,
+            selection: CursorSelection::Auto,
+            registered: HashMap::new(),
+        }
+    }
+
+    /// The cursor the player would display for the object under the mouse.
+    pub fn automatic_cursor(&self) -> MouseCursor {
+        self.automatic
+    }
+
+    /// Updates the cursor picked by the player. This is only shown if the
+    /// movie hasn't requested another cursor.
+    pub fn set_automatic_cursor(&mut self, cursor: MouseCursor, ui: &mut dyn UiBackend) {
+        if cursor != self.automatic {
+            self.automatic = cursor;
+            if self.selection == CursorSelection::Auto {
+                ui.set_mouse_cursor(cursor);
+            }
+        }
+    }
+
+    pub fn selection(&self) -> &CursorSelection {
+        &self.selection
+    }
+
+    /// Resolves a `Mouse.cursor` name to a selection, returning `None` if the
+    /// name is neither a built-in nor a registered cursor.
+    pub fn selection_by_name(&self, name: &str) -> Option<CursorSelection> {
+        if name == "auto" {
+            Some(CursorSelection::Auto)
+        } else if let Some(cursor) = MouseCursor::from_name(name) {
+            Some(CursorSelection::Builtin(cursor))
+        } else if self.registered.contains_key(name) {
+            Some(CursorSelection::Registered(name.to_string()))
+        } else {
+            None
+        }
+    }
+
+    pub fn select(&mut self, selection: CursorSelection, ui: &mut dyn UiBackend) {
+        if selection != self.selection {
+            self.selection = selection;
+            self.apply(ui);
+        }
+    }
+
+    pub fn register_cursor(
+        &mut self,
+        name: String,
+        cursor: CustomMouseCursor,
+        ui: &mut dyn UiBackend,
+    ) {
+        let is_selected = self.selection == CursorSelection::Registered(name.clone());
+        self.registered.insert(name, cursor);
+        if is_selected {
+            self.apply(ui);
+        }
+    }
+
+    /// Removes a registered cursor. If it is currently displayed, the cursor
+    /// goes back to being picked automatically.
+    pub fn unregister_cursor(&mut self, name: &str, ui: &mut dyn UiBackend) {
+        if self.registered.remove(name).is_some() {
+            if let CursorSelection::Registered(selected) = &self.selection {
+                if selected == name {
+                    self.select(CursorSelection::Auto, ui);
+                }
+            }
+        }
+    }
+
+    fn apply(&self, ui: &mut dyn UiBackend) {
+        match &self.selection {
+            CursorSelection::Auto => ui.set_mouse_cursor(self.automatic),
+            CursorSelection::Builtin(cursor) => ui.set_mouse_cursor(*cursor),
+            CursorSelection::Registered(name) => match self.registered.get(name) {
+                Some(cursor) => ui.set_custom_mouse_cursor(cursor.clone()),
+                None => ui.set_mouse_cursor(self.automatic),
+            },
+        }
+    }
+}
+
+impl Default for MouseCursorManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 pub struct InputManager {
     keys_down: HashSet<KeyCode>,
     keys_toggled: HashSet<KeyCode>,
@@ -257,6 +437,12 @@ impl UiBackend for NullUiBackend {
 
     fn set_mouse_cursor(&mut self, _cursor: MouseCursor) {}
 
+    fn set_custom_mouse_cursor(&mut self, _cursor: CustomMouseCursor) {}
+
+    fn supports_custom_mouse_cursor(&self) -> bool {
+        false
+    }
+
     fn clipboard_content(&mut self) -> String {
         "".into()
     }
@@ -759,6 +759,37 @@ pub fn compare<'gc>(
     }
 }
 
+/// Counts the occurrences of each channel value within the given region.
+/// Returns one histogram per channel, in the order red, green, blue, alpha.
+///
+/// This is only used by AVM2; AVM1's `BitmapData` has no `histogram` method.
+pub fn histogram(
+    target: BitmapDataWrapper,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+) -> [[f64; 256]; 4] {
+    let mut region = PixelRegion::for_region_i32(x, y, width, height);
+    region.clamp(target.width(), target.height());
+
+    let mut result = [[0.0; 256]; 4];
+
+    let read = target.read_area(region);
+
+    for y in region.y_min..region.y_max {
+        for x in region.x_min..region.x_max {
+            let color = read.get_pixel32_raw(x, y).to_un_multiplied_alpha();
+            result[0][color.red() as usize] += 1.0;
+            result[1][color.green() as usize] += 1.0;
+            result[2][color.blue() as usize] += 1.0;
+            result[3][color.alpha() as usize] += 1.0;
+        }
+    }
+
+    result
+}
+
 pub fn hit_test_point(
     target: BitmapDataWrapper,
     alpha_threshold: u8,
@@ -8,10 +8,13 @@ use crate::backend::{
     audio::{AudioBackend, AudioManager, SoundHandle, SoundInstanceHandle},
     log::LogBackend,
     navigator::NavigatorBackend,
+    print::PrintBackend,
     storage::StorageBackend,
-    ui::{InputManager, UiBackend},
+    ui::{InputManager, MouseCursorManager, UiBackend},
 };
 use crate::context_menu::ContextMenuState;
+#[cfg(feature = "debugger")]
+use crate::debugger::Debugger;
 use crate::display_object::{EditText, InteractiveObject, MovieClip, SoundTransform, Stage};
 use crate::external::ExternalInterface;
 use crate::focus_tracker::FocusTracker;
@@ -21,6 +24,7 @@ use crate::loader::LoadManager;
 use crate::net_connection::NetConnections;
 use crate::player::Player;
 use crate::prelude::*;
+use crate::printing::PrintJob;
 use crate::socket::Sockets;
 use crate::streams::StreamManager;
 use crate::string::AvmStringInterner;
@@ -128,6 +132,12 @@ pub struct UpdateContext<'a, 'gc> {
     /// The video backend, used for video decoding
     pub video: &'a mut dyn VideoBackend,
 
+    /// The print backend, which receives the pages of print jobs.
+    pub print: &'a mut dyn PrintBackend,
+
+    /// The print job currently being assembled by the movie, if any.
+    pub print_job: &'a mut Option<PrintJob>,
+
     /// The RNG, used by the AVM `RandomNumber` opcode, `Math.random(),` and `random()`.
     pub rng: &'a mut SmallRng,
 
@@ -146,6 +156,9 @@ pub struct UpdateContext<'a, 'gc> {
     /// The location of the mouse when it was last over the player.
     pub mouse_position: &'a Point<Twips>,
 
+    /// The cursor displayed by the player, including cursors requested by the movie.
+    pub mouse_cursors: &'a mut MouseCursorManager,
+
     /// The object being dragged via a `startDrag` action.
     pub drag_object: &'a mut Option<crate::player::DragObject<'gc>>,
 
@@ -235,6 +248,10 @@ pub struct UpdateContext<'a, 'gc> {
 
     /// Dynamic root for allowing handles to GC objects to exist outside of the GC.
     pub dynamic_root: gc_arena::DynamicRootSet<'gc>,
+
+    /// The ActionScript debugger we're connected to, if any.
+    #[cfg(feature = "debugger")]
+    pub debugger: &'a mut Option<Debugger>,
 }
 
 /// Convenience methods for controlling audio.
@@ -361,6 +378,8 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
             log: self.log,
             ui: self.ui,
             video: self.video,
+            print: self.print,
+            print_job: self.print_job,
             storage: self.storage,
             rng: self.rng,
             stage: self.stage,
@@ -368,6 +387,7 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
             mouse_down_object: self.mouse_down_object,
             input: self.input,
             mouse_position: self.mouse_position,
+            mouse_cursors: self.mouse_cursors,
             drag_object: self.drag_object,
             player: self.player.clone(),
             load_manager: self.load_manager,
@@ -396,6 +416,8 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
             sockets: self.sockets,
             net_connections: self.net_connections,
             dynamic_root: self.dynamic_root,
+            #[cfg(feature = "debugger")]
+            debugger: self.debugger,
         }
     }
 
@@ -411,7 +433,12 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
     }
 
     pub fn avm_trace(&self, message: &str) {
-        self.log.avm_trace(&message.replace('\r', "\n"));
+        let message = message.replace('\r', "\n");
+        #[cfg(feature = "debugger")]
+        if let Some(debugger) = self.debugger.as_ref() {
+            debugger.trace(&message);
+        }
+        self.log.avm_trace(&message);
     }
 }
 
@@ -474,6 +501,19 @@ impl<'gc> ActionQueue<'gc> {
             .rev()
             .find_map(VecDeque::pop_front)
     }
+
+    /// Marks the current end of the queue, so that any actions queued after this point
+    /// can be thrown away with [`ActionQueue::discard_since`].
+    pub fn checkpoint(&self) -> [usize; ActionQueue::NUM_PRIORITIES] {
+        std::array::from_fn(|priority| self.action_queue[priority].len())
+    }
+
+    /// Throws away every action queued since `checkpoint` was taken.
+    pub fn discard_since(&mut self, checkpoint: [usize; ActionQueue::NUM_PRIORITIES]) {
+        for (queue, len) in self.action_queue.iter_mut().zip(checkpoint) {
+            queue.truncate(len);
+        }
+    }
 }
 
 impl<'gc> Default for ActionQueue<'gc> {
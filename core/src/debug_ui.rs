@@ -44,6 +44,7 @@ pub enum Message {
     ShowKnownMovies,
     SaveFile(ItemToSave),
     SearchForDisplayObject,
+    SaveSamples,
 }
 
 impl DebugUi {
@@ -114,6 +115,13 @@ impl DebugUi {
                 Message::SearchForDisplayObject => {
                     self.display_object_search = Some(Default::default());
                 }
+                Message::SaveSamples => {
+                    let samples = context.avm2.sampler.export(context.gc_context);
+                    self.items_to_save.push(ItemToSave {
+                        suggested_name: "samples.tsv".to_string(),
+                        data: samples.into_bytes(),
+                    });
+                }
             }
         }
     }
@@ -0,0 +1,257 @@
+//! A client for the Flash Player debugger protocol.
+//!
+//! Like the Flash Player content debugger, we connect *out* to a debugger that
+//! is already listening (`fdb` and FlashDevelop both listen on port 7935), and
+//! then let it set breakpoints, step through code and inspect variables.
+//!
+//! Breakpoints rely on the `debugfile`/`debugline` opcodes that compilers emit
+//! into AVM2 bytecode. AVM1 bytecode has no line information of its own (Flash
+//! kept it in separate SWD files), so for AVM1 content only `trace` output is
+//! forwarded.
+//!
+//! Watch expressions are only understood if they're a plain name or a dotted
+//! path such as `foo.bar.baz`. Anything else (operators, calls, indexing)
+//! evaluates to `undefined`.
+//!
+//! The connection itself is provided by the frontend, through a
+//! [`DebuggerBackend`].
+
+mod avm2;
+mod protocol;
+
+pub use avm2::suspend_avm2;
+pub use protocol::Location;
+
+use crate::backend::debugger::{DebuggerBackend, DebuggerReceive};
+use crate::debugger::protocol::{MessageWriter, PlayerMessage, Request, PROTOCOL_VERSION};
+use byteorder::{ByteOrder, LittleEndian};
+use std::collections::HashSet;
+
+/// How far execution should run before stopping again, after the debugger
+/// asked us to step.
+#[derive(Debug, Copy, Clone)]
+enum Step {
+    /// Stop at the next line, whichever function it's in.
+    Into,
+
+    /// Stop at the next line in this function or one of its callers.
+    Over { depth: usize },
+
+    /// Stop at the next line in one of this function's callers.
+    Out { depth: usize },
+}
+
+pub struct Debugger {
+    backend: Box<dyn DebuggerBackend>,
+
+    /// Bytes received from the debugger that don't make up a whole message yet.
+    received: Vec<u8>,
+
+    /// Whether the debugger is still attached.
+    connected: bool,
+
+    /// Every source file we've announced to the debugger. A file's id is its
+    /// index in this list, plus one.
+    files: Vec<String>,
+
+    breakpoints: HashSet<Location>,
+
+    step: Option<Step>,
+}
+
+impl Debugger {
+    /// Start a debugging session over an already established connection.
+    pub fn new(backend: Box<dyn DebuggerBackend>) -> Self {
+        let debugger = Self {
+            backend,
+            received: Vec::new(),
+            connected: true,
+            files: Vec::new(),
+            breakpoints: HashSet::new(),
+            step: None,
+        };
+
+        debugger.send(
+            PlayerMessage::Version,
+            MessageWriter::new()
+                .write_u32(PROTOCOL_VERSION)
+                .write_u8(std::mem::size_of::<u32>() as u8),
+        );
+
+        debugger
+    }
+
+    fn send(&self, kind: PlayerMessage, payload: &MessageWriter) {
+        if !self.connected {
+            return;
+        }
+
+        self.backend.send(&payload.finish(kind));
+    }
+
+    /// Forward a `trace` call to the debugger.
+    pub fn trace(&self, message: &str) {
+        self.send(
+            PlayerMessage::Trace,
+            MessageWriter::new().write_string(message),
+        );
+    }
+
+    /// Returns the id of the given source file, announcing it to the debugger
+    /// if we haven't done so already.
+    pub fn file_id(&mut self, name: &str) -> u16 {
+        if let Some(index) = self.files.iter().position(|file| file == name) {
+            return index as u16 + 1;
+        }
+
+        self.register_files([name.to_string()]);
+        self.files.len() as u16
+    }
+
+    /// Announce any new source files to the debugger, and give it a chance to
+    /// set breakpoints in them before any of their code runs.
+    pub fn register_files(&mut self, names: impl IntoIterator<Item = String>) {
+        let first_new = self.files.len();
+        for name in names {
+            if !self.files.contains(&name) {
+                self.files.push(name);
+            }
+        }
+
+        if first_new == self.files.len() {
+            return;
+        }
+
+        self.send(
+            PlayerMessage::NumScript,
+            MessageWriter::new().write_u32(self.files.len() as u32),
+        );
+        for (index, name) in self.files.iter().enumerate().skip(first_new) {
+            self.send(
+                PlayerMessage::Script,
+                MessageWriter::new()
+                    .write_u32(index as u32 + 1)
+                    .write_u32(0)
+                    .write_string(name)
+                    .write_string(""),
+            );
+        }
+        self.send(PlayerMessage::AskBreakpoints, &MessageWriter::new());
+        self.send(PlayerMessage::ProcessTag, &MessageWriter::new());
+
+        while let Some(request) = self.receive(true) {
+            match request {
+                Request::ProcessedTag => break,
+                request => self.handle_request(request),
+            }
+        }
+    }
+
+    /// Whether execution should stop at the given line.
+    ///
+    /// `depth` is the length of the call stack, used to decide when a step
+    /// has finished.
+    pub fn should_break(&self, location: Location, depth: usize) -> bool {
+        if !self.connected {
+            return false;
+        }
+
+        match self.step {
+            Some(Step::Into) => true,
+            Some(Step::Over { depth: from }) if depth <= from => true,
+            Some(Step::Out { depth: from }) if depth < from => true,
+            _ => self.breakpoints.contains(&location),
+        }
+    }
+
+    /// Handle any requests the debugger sent while the movie was running.
+    pub fn poll(&mut self) {
+        while let Some(request) = self.receive(false) {
+            self.handle_request(request);
+        }
+    }
+
+    /// Handle a request that doesn't need to look at the running code.
+    fn handle_request(&mut self, request: Request) {
+        match request {
+            Request::SetBreakpoints(locations) => {
+                let locations: Vec<_> = locations
+                    .into_iter()
+                    .filter(|location| usize::from(location.file) <= self.files.len())
+                    .collect();
+                self.breakpoints.extend(locations.iter().copied());
+                self.send(
+                    PlayerMessage::SetBreakpoint,
+                    MessageWriter::new().write_locations(&locations),
+                );
+            }
+            Request::RemoveBreakpoints(locations) => {
+                for location in &locations {
+                    self.breakpoints.remove(location);
+                }
+                self.send(
+                    PlayerMessage::RemoveBreakpoint,
+                    MessageWriter::new().write_locations(&locations),
+                );
+            }
+            Request::RemoveAllBreakpoints => {
+                let locations: Vec<_> = self.breakpoints.drain().collect();
+                self.send(
+                    PlayerMessage::RemoveBreakpoint,
+                    MessageWriter::new().write_locations(&locations),
+                );
+            }
+            Request::Detach => self.detach(),
+            Request::Unknown(kind) => {
+                tracing::warn!("Unsupported debugger message type {kind}");
+            }
+            // We're not stopped, so there's nothing to continue, step or inspect.
+            Request::Continue
+            | Request::StepInto
+            | Request::StepOver
+            | Request::StepOut
+            | Request::ProcessedTag
+            | Request::GetFrame { .. }
+            | Request::GetField { .. } => {}
+        }
+    }
+
+    fn detach(&mut self) {
+        tracing::info!("Debugger detached");
+        self.connected = false;
+        self.breakpoints.clear();
+        self.step = None;
+    }
+
+    /// Read the next request from the debugger.
+    ///
+    /// If `blocking` is false, this returns `None` as soon as there's no
+    /// complete request waiting for us. It also returns `None` once the
+    /// debugger has gone away.
+    fn receive(&mut self, blocking: bool) -> Option<Request> {
+        while self.connected {
+            if self.received.len() >= 8 {
+                let length = LittleEndian::read_u32(&self.received[0..4]) as usize;
+                if self.received.len() >= length + 8 {
+                    let kind = LittleEndian::read_u32(&self.received[4..8]);
+                    let message: Vec<_> = self.received.drain(..length + 8).collect();
+                    match Request::parse(kind, &message[8..]) {
+                        Ok(request) => return Some(request),
+                        Err(e) => {
+                            tracing::warn!("Malformed debugger message of type {kind}: {e}");
+                            continue;
+                        }
+                    }
+                }
+            }
+
+            match self.backend.receive(blocking) {
+                DebuggerReceive::Data(data) => self.received.extend_from_slice(&data),
+                DebuggerReceive::Pending => return None,
+                DebuggerReceive::Disconnected => self.detach(),
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,317 @@
+//! Pausing AVM2 code and describing its state to the debugger.
+
+use crate::avm2::property::Property;
+use crate::avm2::{Activation, Object, TObject, Value};
+use crate::debugger::protocol::{Location, MessageWriter, PlayerMessage, Request, VariableType};
+use crate::debugger::Step;
+use crate::string::{AvmString, WString};
+use web_time::Instant;
+
+/// Objects we've described to the debugger while stopped, so that it can ask
+/// for their members later. An object's id is its index here, plus one.
+///
+/// Ids are only valid until execution resumes.
+#[derive(Default)]
+struct ObjectTable<'gc> {
+    objects: Vec<Object<'gc>>,
+}
+
+impl<'gc> ObjectTable<'gc> {
+    fn id(&mut self, object: Object<'gc>) -> u32 {
+        let index = self
+            .objects
+            .iter()
+            .position(|o| Object::ptr_eq(*o, object))
+            .unwrap_or_else(|| {
+                self.objects.push(object);
+                self.objects.len() - 1
+            });
+        index as u32 + 1
+    }
+
+    fn get(&self, id: u32) -> Option<Object<'gc>> {
+        let index = (id as usize).checked_sub(1)?;
+        self.objects.get(index).copied()
+    }
+}
+
+/// Stop at `location` and let the debugger look around, until it tells us to
+/// continue or step.
+///
+/// `locals` are the names of the current method's local registers, as given
+/// by its `debug` opcodes.
+pub fn suspend_avm2<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    location: Location,
+    locals: &[(String, u32)],
+) {
+    // Take the debugger for the duration, so that any code we run to inspect
+    // values (such as getters) can't stop again.
+    let Some(mut debugger) = activation.context.debugger.take() else {
+        return;
+    };
+
+    let paused_at = Instant::now();
+    let depth = activation.avm2().call_stack().read().len();
+    let mut stack = WString::new();
+    activation.avm2().call_stack().read().display(&mut stack);
+
+    debugger.step = None;
+    debugger.send(
+        PlayerMessage::BreakAt,
+        MessageWriter::new()
+            .write_u32(location.to_u32())
+            .write_u32(depth as u32)
+            .write_string(stack.to_utf8_lossy().trim_start()),
+    );
+
+    let mut objects = ObjectTable::default();
+    while let Some(request) = debugger.receive(true) {
+        match request {
+            Request::Continue => break,
+            Request::StepInto => {
+                debugger.step = Some(Step::Into);
+                break;
+            }
+            Request::StepOver => {
+                debugger.step = Some(Step::Over { depth });
+                break;
+            }
+            Request::StepOut => {
+                debugger.step = Some(Step::Out { depth });
+                break;
+            }
+            Request::GetFrame { depth } => {
+                let mut writer = MessageWriter::new();
+                writer.write_u32(depth);
+                // Only the innermost frame is still reachable from here; the
+                // others are further up the Rust stack.
+                if depth == 0 {
+                    let variables = frame_variables(activation, locals);
+                    write_variables(activation, &mut writer, &mut objects, variables);
+                } else {
+                    writer.write_u32(0);
+                }
+                debugger.send(PlayerMessage::Frame, &writer);
+            }
+            Request::GetField { object, name } => {
+                let variables = if object == 0 {
+                    let value = resolve_path(activation, locals, &name);
+                    vec![(name, value)]
+                } else if let Some(object) = objects.get(object) {
+                    if name.is_empty() {
+                        object_members(activation, object)
+                    } else {
+                        let value = member_path(activation, object.into(), &name);
+                        vec![(name, value)]
+                    }
+                } else {
+                    vec![]
+                };
+
+                let mut writer = MessageWriter::new();
+                writer.write_u32(object);
+                write_variables(activation, &mut writer, &mut objects, variables);
+                debugger.send(PlayerMessage::SetVariable, &writer);
+            }
+            request => debugger.handle_request(request),
+        }
+    }
+
+    debugger.send(PlayerMessage::Continue, &MessageWriter::new());
+
+    // Time spent stopped in the debugger shouldn't count towards the script
+    // timeout.
+    activation.context.update_start += paused_at.elapsed();
+    *activation.context.debugger = Some(debugger);
+}
+
+/// `this`, the named locals, and then every object on the scope chain from
+/// the outermost inwards.
+fn frame_variables<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    locals: &[(String, u32)],
+) -> Vec<(String, Value<'gc>)> {
+    let mut variables = vec![(
+        "this".to_string(),
+        activation.local_register(0).unwrap_or(Value::Undefined),
+    )];
+
+    for (name, register) in locals {
+        let value = activation
+            .local_register(*register)
+            .unwrap_or(Value::Undefined);
+        variables.push((name.clone(), value));
+    }
+
+    let scope_chain = activation.create_scopechain();
+    let mut index = 0;
+    while let Some(scope) = scope_chain.get(index) {
+        variables.push((format!("$scope{index}"), scope.values().into()));
+        index += 1;
+    }
+
+    variables
+}
+
+/// Resolve a dotted path such as `foo.bar.baz`, looking up its first
+/// component the same way a `getlex` in the current method would.
+///
+/// Debuggers evaluate watch expressions by asking for each of the names
+/// involved in them this way.
+fn resolve_path<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    locals: &[(String, u32)],
+    path: &str,
+) -> Value<'gc> {
+    let (first, rest) = path.split_once('.').unwrap_or((path, ""));
+
+    let value = if first == "this" {
+        activation.local_register(0).unwrap_or(Value::Undefined)
+    } else if let Some((_, register)) = locals.iter().find(|(name, _)| name == first) {
+        activation
+            .local_register(*register)
+            .unwrap_or(Value::Undefined)
+    } else {
+        let scope_chain = activation.create_scopechain();
+        let mut scopes = Vec::new();
+        let mut index = 0;
+        while let Some(scope) = scope_chain.get(index) {
+            scopes.push(scope.values());
+            index += 1;
+        }
+
+        let name = AvmString::new_utf8(activation.context.gc_context, first);
+        scopes
+            .into_iter()
+            .rev()
+            .find(|scope| scope.has_public_property(name, activation))
+            .map(|scope| {
+                scope
+                    .get_public_property(name, activation)
+                    .unwrap_or(Value::Undefined)
+            })
+            .unwrap_or(Value::Undefined)
+    };
+
+    if rest.is_empty() {
+        value
+    } else {
+        member_path(activation, value, rest)
+    }
+}
+
+fn member_path<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    mut value: Value<'gc>,
+    path: &str,
+) -> Value<'gc> {
+    for name in path.split('.') {
+        let Value::Object(object) = value else {
+            return Value::Undefined;
+        };
+        value = object
+            .get_public_property(
+                AvmString::new_utf8(activation.context.gc_context, name),
+                activation,
+            )
+            .unwrap_or(Value::Undefined);
+    }
+    value
+}
+
+/// Every public property of an object, both declared and dynamic.
+fn object_members<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    object: Object<'gc>,
+) -> Vec<(String, Value<'gc>)> {
+    let mut names = Vec::new();
+
+    if let Some(vtable) = object.vtable() {
+        for (name, property) in vtable.public_properties() {
+            if matches!(
+                property,
+                Property::Slot { .. }
+                    | Property::ConstSlot { .. }
+                    | Property::Virtual { get: Some(_), .. }
+            ) {
+                names.push(name);
+            }
+        }
+    }
+
+    let mut index = 0;
+    while let Ok(Some(next)) = object.get_next_enumerant(index, activation) {
+        let name = object
+            .get_enumerant_name(next, activation)
+            .and_then(|name| name.coerce_to_string(activation));
+        if let Ok(name) = name {
+            names.push(name);
+        }
+        index = next;
+    }
+
+    names
+        .into_iter()
+        .map(|name| {
+            let value = object
+                .get_public_property(name, activation)
+                .unwrap_or(Value::Undefined);
+            (name.to_utf8_lossy().into_owned(), value)
+        })
+        .collect()
+}
+
+/// Write a count followed by each variable's name, type, flags and value.
+fn write_variables<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    writer: &mut MessageWriter,
+    objects: &mut ObjectTable<'gc>,
+    variables: Vec<(String, Value<'gc>)>,
+) {
+    writer.write_u32(variables.len() as u32);
+    for (name, value) in variables {
+        writer.write_string(&name);
+        match value {
+            Value::Undefined => {
+                writer
+                    .write_u16(VariableType::Undefined as u16)
+                    .write_u32(0);
+            }
+            Value::Null => {
+                writer.write_u16(VariableType::Null as u16).write_u32(0);
+            }
+            Value::Bool(value) => {
+                writer
+                    .write_u16(VariableType::Boolean as u16)
+                    .write_u32(0)
+                    .write_u8(value as u8);
+            }
+            Value::Number(_) | Value::Integer(_) => {
+                let number = value
+                    .coerce_to_string(activation)
+                    .map(|string| string.to_utf8_lossy().into_owned())
+                    .unwrap_or_default();
+                writer
+                    .write_u16(VariableType::Number as u16)
+                    .write_u32(0)
+                    .write_string(&number);
+            }
+            Value::String(string) => {
+                writer
+                    .write_u16(VariableType::String as u16)
+                    .write_u32(0)
+                    .write_string(&string.to_utf8_lossy());
+            }
+            Value::Object(object) => {
+                let class_name = object.instance_of_class_name(activation.context.gc_context);
+                writer
+                    .write_u16(VariableType::Object as u16)
+                    .write_u32(0)
+                    .write_u32(objects.id(object))
+                    .write_u8(object.as_executable().is_some() as u8)
+                    .write_string(&class_name.to_utf8_lossy());
+            }
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//! The wire format of the Flash Player debugger protocol, as spoken by `fdb`.
+//!
+//! Every message is a little-endian `u32` payload length, followed by a
+//! little-endian `u32` message type and then the payload itself. Strings are
+//! null-terminated UTF-8.
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use num_derive::FromPrimitive;
+use num_traits::FromPrimitive;
+use std::io::{self, Cursor, Read};
+
+/// The protocol version we claim to speak.
+pub const PROTOCOL_VERSION: u32 = 4;
+
+/// Messages sent from the player to the debugger.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u32)]
+pub enum PlayerMessage {
+    Trace = 5,
+    Script = 14,
+    AskBreakpoints = 15,
+    BreakAt = 16,
+    Continue = 17,
+    SetBreakpoint = 19,
+    NumScript = 20,
+    RemoveBreakpoint = 22,
+    ProcessTag = 25,
+    Version = 26,
+    SetVariable = 28,
+    Frame = 31,
+}
+
+/// Messages sent from the debugger to the player.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, FromPrimitive)]
+enum DebuggerMessage {
+    Exit = 13,
+    Continue = 15,
+    StopDebug = 16,
+    SetBreakpoints = 17,
+    RemoveBreakpoints = 18,
+    RemoveAllBreakpoints = 19,
+    StepOver = 20,
+    StepInto = 21,
+    StepOut = 22,
+    ProcessedTag = 23,
+    GetField = 25,
+    GetFrame = 33,
+}
+
+/// The type tag of a variable sent in `SetVariable` and `Frame` messages.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u16)]
+pub enum VariableType {
+    Number = 0,
+    Boolean = 1,
+    String = 2,
+    Object = 3,
+    Null = 5,
+    Undefined = 6,
+}
+
+/// A position in a source file, as understood by the debugger.
+///
+/// This is sent over the wire as a single `u32`, with the file in the lower
+/// 16 bits and the line in the upper 16 bits.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Location {
+    /// The id of the file, as given in the `Script` message announcing it.
+    pub file: u16,
+
+    pub line: u16,
+}
+
+impl Location {
+    pub fn new(file: u16, line: u32) -> Self {
+        Self {
+            file,
+            line: line.min(u16::MAX.into()) as u16,
+        }
+    }
+
+    fn from_u32(value: u32) -> Self {
+        Self {
+            file: value as u16,
+            line: (value >> 16) as u16,
+        }
+    }
+
+    pub fn to_u32(self) -> u32 {
+        (u32::from(self.line) << 16) | u32::from(self.file)
+    }
+}
+
+/// A request received from the debugger.
+#[derive(Debug)]
+pub enum Request {
+    Continue,
+    StepInto,
+    StepOver,
+    StepOut,
+    SetBreakpoints(Vec<Location>),
+    RemoveBreakpoints(Vec<Location>),
+    RemoveAllBreakpoints,
+    ProcessedTag,
+
+    /// Describe the locals and scope chain of the frame `depth` calls up from
+    /// the current one.
+    GetFrame {
+        depth: u32,
+    },
+
+    /// Describe the member `name` of a previously sent object, or all of its
+    /// members if `name` is empty. Object `0` stands for the current scope.
+    GetField {
+        object: u32,
+        name: String,
+    },
+
+    /// The debugger has detached, and we should carry on without it.
+    Detach,
+
+    Unknown(u32),
+}
+
+impl Request {
+    pub fn parse(kind: u32, payload: &[u8]) -> io::Result<Self> {
+        let mut reader = Cursor::new(payload);
+        let Some(message) = DebuggerMessage::from_u32(kind) else {
+            return Ok(Request::Unknown(kind));
+        };
+
+        Ok(match message {
+            DebuggerMessage::Continue => Request::Continue,
+            DebuggerMessage::StepInto => Request::StepInto,
+            DebuggerMessage::StepOver => Request::StepOver,
+            DebuggerMessage::StepOut => Request::StepOut,
+            DebuggerMessage::SetBreakpoints => {
+                Request::SetBreakpoints(read_locations(&mut reader)?)
+            }
+            DebuggerMessage::RemoveBreakpoints => {
+                Request::RemoveBreakpoints(read_locations(&mut reader)?)
+            }
+            DebuggerMessage::RemoveAllBreakpoints => Request::RemoveAllBreakpoints,
+            DebuggerMessage::ProcessedTag => Request::ProcessedTag,
+            DebuggerMessage::GetFrame => Request::GetFrame {
+                depth: reader.read_u32::<LittleEndian>()?,
+            },
+            DebuggerMessage::GetField => Request::GetField {
+                object: reader.read_u32::<LittleEndian>()?,
+                name: read_string(&mut reader)?,
+            },
+            DebuggerMessage::Exit | DebuggerMessage::StopDebug => Request::Detach,
+        })
+    }
+}
+
+fn read_locations(reader: &mut impl Read) -> io::Result<Vec<Location>> {
+    let count = reader.read_u32::<LittleEndian>()?;
+    (0..count)
+        .map(|_| Ok(Location::from_u32(reader.read_u32::<LittleEndian>()?)))
+        .collect()
+}
+
+fn read_string(reader: &mut impl Read) -> io::Result<String> {
+    let mut bytes = Vec::new();
+    loop {
+        match reader.read_u8()? {
+            0 => break,
+            byte => bytes.push(byte),
+        }
+    }
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Builds the payload of a message to the debugger.
+#[derive(Default)]
+pub struct MessageWriter {
+    payload: Vec<u8>,
+}
+
+impl MessageWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn write_u8(&mut self, value: u8) -> &mut Self {
+        self.payload.push(value);
+        self
+    }
+
+    pub fn write_u16(&mut self, value: u16) -> &mut Self {
+        // Writing to a `Vec` can't fail.
+        let _ = self.payload.write_u16::<LittleEndian>(value);
+        self
+    }
+
+    pub fn write_u32(&mut self, value: u32) -> &mut Self {
+        let _ = self.payload.write_u32::<LittleEndian>(value);
+        self
+    }
+
+    pub fn write_string(&mut self, value: &str) -> &mut Self {
+        // Strings are null-terminated, so they can't contain nulls themselves.
+        self.payload.extend(value.bytes().filter(|byte| *byte != 0));
+        self.payload.push(0);
+        self
+    }
+
+    pub fn write_locations(&mut self, locations: &[Location]) -> &mut Self {
+        self.write_u32(locations.len() as u32);
+        for location in locations {
+            self.write_u32(location.to_u32());
+        }
+        self
+    }
+
+    /// Frame the payload as a complete message of the given type.
+    pub fn finish(&self, kind: PlayerMessage) -> Vec<u8> {
+        let mut message = Vec::with_capacity(self.payload.len() + 8);
+        let _ = message.write_u32::<LittleEndian>(self.payload.len() as u32);
+        let _ = message.write_u32::<LittleEndian>(kind as u32);
+        message.extend_from_slice(&self.payload);
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location_round_trip() {
+        let location = Location::new(3, 42);
+        assert_eq!(location.to_u32(), (42 << 16) | 3);
+        assert_eq!(Location::from_u32(location.to_u32()), location);
+    }
+
+    #[test]
+    fn parse_set_breakpoints() {
+        let mut payload = MessageWriter::new();
+        payload.write_locations(&[Location::new(1, 10), Location::new(2, 20)]);
+        let message = payload.finish(PlayerMessage::SetBreakpoint);
+
+        match Request::parse(DebuggerMessage::SetBreakpoints as u32, &message[8..]).unwrap() {
+            Request::SetBreakpoints(locations) => {
+                assert_eq!(locations, vec![Location::new(1, 10), Location::new(2, 20)])
+            }
+            other => panic!("Unexpected request {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_get_field() {
+        let mut payload = MessageWriter::new();
+        payload.write_u32(7).write_string("foo");
+        let message = payload.finish(PlayerMessage::SetVariable);
+
+        match Request::parse(DebuggerMessage::GetField as u32, &message[8..]).unwrap() {
+            Request::GetField { object, name } => {
+                assert_eq!(object, 7);
+                assert_eq!(name, "foo");
+            }
+            other => panic!("Unexpected request {other:?}"),
+        }
+    }
+}
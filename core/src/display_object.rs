@@ -13,8 +13,9 @@ use crate::vminterface::Instantiator;
 use bitflags::bitflags;
 use gc_arena::{Collect, Mutation};
 use ruffle_macros::enum_trait_object;
+use ruffle_render::matrix3d::{Matrix3D, Matrix3DComponents};
 use ruffle_render::pixel_bender::PixelBenderShaderHandle;
-use ruffle_render::transform::{Transform, TransformStack};
+use ruffle_render::transform::{PerspectiveTransform, Transform, TransformStack};
 use std::cell::{Ref, RefMut};
 use std::fmt::Debug;
 use std::hash::Hash;
@@ -56,7 +57,7 @@ use ruffle_render::blend::ExtendedBlendMode;
 use ruffle_render::commands::{CommandHandler, CommandList, RenderBlendMode};
 use ruffle_render::filters::Filter;
 pub use stage::{Stage, StageAlign, StageDisplayState, StageScaleMode, WindowMode};
-pub use text::Text;
+pub use text::{Text, TextGlyph};
 pub use video::Video;
 
 use self::loader_display::LoaderDisplayWeak;
@@ -182,6 +183,67 @@ impl BitmapCache {
     }
 }
 
+/// The parts of a display object's 3D transform that can't be represented by its 2D matrix.
+///
+/// The x and y position, Z rotation and X/Y scale are shared with the 2D matrix.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform3D {
+    pub z: f64,
+    pub rotation_x: Degrees,
+    pub rotation_y: Degrees,
+    pub scale_z: f64,
+}
+
+impl Default for Transform3D {
+    fn default() -> Self {
+        Self {
+            z: 0.0,
+            rotation_x: Degrees::from_radians(0.0),
+            rotation_y: Degrees::from_radians(0.0),
+            scale_z: 1.0,
+        }
+    }
+}
+
+/// The perspective used to project the 3D children of a display object onto it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PerspectiveProjection {
+    /// The field of view, in degrees. Always between 0 and 180, exclusive.
+    pub field_of_view: f64,
+
+    /// The vanishing point, in the coordinate space of the display object.
+    pub projection_center: Point<Twips>,
+}
+
+impl PerspectiveProjection {
+    pub const DEFAULT_FIELD_OF_VIEW: f64 = 55.0;
+
+    /// The default projection of the root movie, centered on the stage.
+    pub fn for_stage(stage_width: u32, stage_height: u32) -> Self {
+        Self {
+            field_of_view: Self::DEFAULT_FIELD_OF_VIEW,
+            projection_center: Point::new(
+                Twips::from_pixels(stage_width as f64 / 2.0),
+                Twips::from_pixels(stage_height as f64 / 2.0),
+            ),
+        }
+    }
+
+    /// The distance from the viewer to the z=0 plane, in pixels.
+    pub fn focal_length(&self, stage_width: u32) -> f64 {
+        let half_angle = (self.field_of_view / 2.0).to_radians();
+        stage_width as f64 / 2.0 / half_angle.tan()
+    }
+
+    /// Sets the focal length by adjusting the field of view.
+    pub fn set_focal_length(&mut self, focal_length: f64, stage_width: u32) {
+        self.field_of_view = (stage_width as f64 / 2.0 / focal_length)
+            .atan()
+            .to_degrees()
+            * 2.0;
+    }
+}
+
 #[derive(Clone, Collect)]
 #[collect(no_drop)]
 pub struct DisplayObjectBase<'gc> {
@@ -260,6 +322,16 @@ pub struct DisplayObjectBase<'gc> {
     /// None means not cached, Some means cached.
     #[collect(require_static)]
     cache: Option<BitmapCache>,
+
+    /// The 3D parts of this object's transform, if it has one.
+    /// Objects with a 3D transform are rendered offscreen and projected onto their parent.
+    #[collect(require_static)]
+    transform_3d: Option<Transform3D>,
+
+    /// The perspective projection used for the 3D children of this object.
+    /// `None` means the projection of the nearest ancestor with one is used.
+    #[collect(require_static)]
+    perspective_projection: Option<PerspectiveProjection>,
 }
 
 impl<'gc> Default for DisplayObjectBase<'gc> {
@@ -288,6 +360,8 @@ impl<'gc> Default for DisplayObjectBase<'gc> {
             next_scroll_rect: Default::default(),
             scaling_grid: Default::default(),
             cache: None,
+            transform_3d: None,
+            perspective_projection: None,
         }
     }
 }
@@ -493,6 +567,77 @@ impl<'gc> DisplayObjectBase<'gc> {
         changed
     }
 
+    fn transform_3d(&self) -> Option<Transform3D> {
+        self.transform_3d
+    }
+
+    fn set_transform_3d(&mut self, transform_3d: Option<Transform3D>) -> bool {
+        let changed = self.transform_3d != transform_3d;
+        self.set_transformed_by_script(true);
+        self.transform_3d = transform_3d;
+        self.recheck_cache_as_bitmap();
+        changed
+    }
+
+    /// The full 3D transform of this object, or `None` if it only has a 2D transform.
+    fn matrix_3d(&mut self) -> Option<Matrix3D> {
+        let transform_3d = self.transform_3d?;
+        self.cache_scale_rotation();
+        Some(Matrix3D::compose(&Matrix3DComponents {
+            translation: [self.x().to_pixels(), self.y().to_pixels(), transform_3d.z],
+            rotation: [
+                transform_3d.rotation_x.into_radians(),
+                transform_3d.rotation_y.into_radians(),
+                self.rotation.into_radians(),
+            ],
+            scale: [
+                self.scale_x.unit(),
+                self.scale_y.unit(),
+                transform_3d.scale_z,
+            ],
+            skew: self.skew,
+        }))
+    }
+
+    fn set_matrix_3d(&mut self, matrix: &Matrix3D) {
+        let Matrix3DComponents {
+            translation: [x, y, z],
+            rotation: [rotation_x, rotation_y, rotation_z],
+            scale: [scale_x, scale_y, scale_z],
+            skew,
+        } = matrix.decompose();
+        let (sin_x, cos_x) = rotation_z.sin_cos();
+        let (sin_y, cos_y) = (rotation_z + skew).sin_cos();
+        self.transform.matrix = Matrix {
+            a: (scale_x * cos_x) as f32,
+            b: (scale_x * sin_x) as f32,
+            c: (scale_y * -sin_y) as f32,
+            d: (scale_y * cos_y) as f32,
+            tx: Twips::from_pixels(x),
+            ty: Twips::from_pixels(y),
+        };
+        self.rotation = Degrees::from_radians(rotation_z);
+        self.scale_x = Percent::from_unit(scale_x);
+        self.scale_y = Percent::from_unit(scale_y);
+        self.skew = skew;
+        self.set_transform_3d(Some(Transform3D {
+            z,
+            rotation_x: Degrees::from_radians(rotation_x),
+            rotation_y: Degrees::from_radians(rotation_y),
+            scale_z,
+        }));
+    }
+
+    fn perspective_projection(&self) -> Option<PerspectiveProjection> {
+        self.perspective_projection
+    }
+
+    fn set_perspective_projection(&mut self, projection: Option<PerspectiveProjection>) -> bool {
+        let changed = self.perspective_projection != projection;
+        self.perspective_projection = projection;
+        changed
+    }
+
     fn name(&self) -> Option<AvmString<'gc>> {
         self.name
     }
@@ -717,7 +862,9 @@ impl<'gc> DisplayObjectBase<'gc> {
     }
 
     fn recheck_cache_as_bitmap(&mut self) {
-        let should_cache = self.is_bitmap_cached_preference() || !self.filters.is_empty();
+        let should_cache = self.is_bitmap_cached_preference()
+            || !self.filters.is_empty()
+            || self.transform_3d.is_some();
         if should_cache && self.cache.is_none() {
             self.cache = Some(Default::default());
         } else if !should_cache && self.cache.is_some() {
@@ -781,6 +928,11 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
     if this.maskee().is_some() {
         return;
     }
+    // Objects with a 3D transform are projected onto their parent, so they need its matrix.
+    let parent_matrix = this
+        .base()
+        .transform_3d()
+        .map(|_| context.transform_stack.transform().matrix);
     context.transform_stack.push(this.base().transform());
     let blend_mode = this.blend_mode();
     let original_commands = if blend_mode != ExtendedBlendMode::Normal {
@@ -791,7 +943,16 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
 
     let cache_info = if context.use_bitmap_cache && this.is_bitmap_cached() {
         let mut cache_info: Option<DrawCacheInfo> = None;
-        let base_transform = context.transform_stack.transform();
+        let mut base_transform = context.transform_stack.transform();
+        if parent_matrix.is_some() {
+            // Only keep the scale, so that the offscreen rendering has about the same resolution
+            // as the projected result. Everything else is applied when projecting.
+            let matrix = base_transform.matrix;
+            base_transform.matrix = Matrix::scale(
+                f32::hypot(matrix.a, matrix.b),
+                f32::hypot(matrix.c, matrix.d),
+            );
+        }
         let bounds: Rectangle<Twips> = this.render_bounds_with_transform(
             &base_transform.matrix,
             false, // we want to do the filter growth for this object ourselves, to know the offsets
@@ -913,22 +1074,44 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
             });
         }
 
-        // When rendering it back, ensure we're only keeping the translation - scale/rotation is within the image already
-        apply_standard_mask_and_scroll(this, context, |context| {
-            context.commands.render_bitmap(
-                cache_info.handle,
-                Transform {
-                    matrix: Matrix {
-                        tx: context.transform_stack.transform().matrix.tx + offset_x,
-                        ty: context.transform_stack.transform().matrix.ty + offset_y,
-                        ..Default::default()
+        if let Some(parent_matrix) = parent_matrix {
+            let transform = perspective_transform(
+                this,
+                context,
+                parent_matrix,
+                &cache_info.base_transform.matrix,
+                Point::new(offset_x, offset_y),
+            );
+            apply_standard_mask_and_scroll(this, context, |context| {
+                if let Some(matrix) = transform {
+                    context.commands.render_bitmap_projected(
+                        cache_info.handle,
+                        PerspectiveTransform {
+                            matrix,
+                            color_transform: cache_info.base_transform.color_transform,
+                        },
+                        true,
+                    );
+                }
+            });
+        } else {
+            // When rendering it back, ensure we're only keeping the translation - scale/rotation is within the image already
+            apply_standard_mask_and_scroll(this, context, |context| {
+                context.commands.render_bitmap(
+                    cache_info.handle,
+                    Transform {
+                        matrix: Matrix {
+                            tx: context.transform_stack.transform().matrix.tx + offset_x,
+                            ty: context.transform_stack.transform().matrix.ty + offset_y,
+                            ..Default::default()
+                        },
+                        color_transform: cache_info.base_transform.color_transform,
                     },
-                    color_transform: cache_info.base_transform.color_transform,
-                },
-                true,
-                PixelSnapping::Always, // cacheAsBitmap forces pixel snapping
-            )
-        });
+                    true,
+                    PixelSnapping::Always, // cacheAsBitmap forces pixel snapping
+                )
+            });
+        }
     } else {
         if let Some(background) = this.opaque_background() {
             // This is intended for use with cacheAsBitmap, but can be set for non-cached objects too
@@ -969,6 +1152,74 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
     context.transform_stack.pop();
 }
 
+/// Calculates the matrix used to project the offscreen rendering of an object with a 3D transform
+/// onto its parent, as described by `PerspectiveTransform::matrix`.
+///
+/// `offscreen_matrix` is the matrix the object was rendered offscreen with, and `offset` is the
+/// position of the top left corner of the bitmap in that space.
+fn perspective_transform<'gc>(
+    this: DisplayObject<'gc>,
+    context: &mut RenderContext<'_, 'gc>,
+    parent_matrix: Matrix,
+    offscreen_matrix: &Matrix,
+    offset: Point<Twips>,
+) -> Option<[[f32; 3]; 3]> {
+    let matrix_3d = this.base_mut(context.gc_context).matrix_3d()?;
+    let parent = this.parent()?;
+    let (stage_width, stage_height) = context.stage.movie_size();
+
+    // The projection of the nearest ancestor with one is used, with its center converted into the
+    // coordinate space of the parent.
+    let mut projection = None;
+    let mut node = Some(parent);
+    while let Some(ancestor) = node {
+        if let Some(ancestor_projection) = ancestor.base().perspective_projection() {
+            let center = ancestor.local_to_global(ancestor_projection.projection_center);
+            projection = Some((ancestor_projection, center));
+            break;
+        }
+        node = ancestor.parent();
+    }
+    let (projection, center) = projection.unwrap_or_else(|| {
+        let projection = PerspectiveProjection::for_stage(stage_width, stage_height);
+        (projection, projection.projection_center)
+    });
+    let center = parent.global_to_local(center)?;
+    let (center_x, center_y) = (center.x.to_pixels(), center.y.to_pixels());
+    let focal_length = projection.focal_length(stage_width);
+
+    // Maps a homogeneous point in the parent's 3D space onto the render target.
+    let project = |[x, y, z, w]: [f64; 4]| {
+        let x = x + center_x * z / focal_length;
+        let y = y + center_y * z / focal_length;
+        let w = w + z / focal_length;
+        [
+            (f64::from(parent_matrix.a) * x
+                + f64::from(parent_matrix.c) * y
+                + parent_matrix.tx.to_pixels() * w) as f32,
+            (f64::from(parent_matrix.b) * x
+                + f64::from(parent_matrix.d) * y
+                + parent_matrix.ty.to_pixels() * w) as f32,
+            w as f32,
+        ]
+    };
+    let column = |column| [0, 1, 2, 3].map(|row| matrix_3d.get(row, column));
+
+    // A pixel of the bitmap maps to `(x + offset) / scale` in the object's local space.
+    let scale_x = f64::from(offscreen_matrix.a);
+    let scale_y = f64::from(offscreen_matrix.d);
+    if scale_x == 0.0 || scale_y == 0.0 {
+        return None;
+    }
+    let (offset_x, offset_y) = (offset.x.to_pixels(), offset.y.to_pixels());
+    let [x_axis, y_axis, _, origin] = [0, 1, 2, 3].map(column);
+    let x_axis = x_axis.map(|value| value / scale_x);
+    let y_axis = y_axis.map(|value| value / scale_y);
+    let translation: [f64; 4] =
+        std::array::from_fn(|i| origin[i] + x_axis[i] * offset_x + y_axis[i] * offset_y);
+    Some([project(x_axis), project(y_axis), project(translation)])
+}
+
 /// This applies the **standard** method of `mask` and `scrollRect`.
 ///
 /// It uses the stencil buffer so that any pixel drawn in the mask will allow the inner contents to show.
@@ -1363,6 +1614,80 @@ pub trait TDisplayObject<'gc>:
         }
     }
 
+    /// The parts of this object's 3D transform that aren't shared with its 2D matrix.
+    /// `None` if this object only has a 2D transform.
+    fn transform_3d(&self) -> Option<Transform3D> {
+        self.base().transform_3d()
+    }
+
+    /// Sets the parts of this object's 3D transform that aren't shared with its 2D matrix.
+    /// Set by the `z`, `rotationX`, `rotationY` and `scaleZ` ActionScript properties.
+    /// This invalidates any ancestors cacheAsBitmap automatically.
+    fn set_transform_3d(&self, gc_context: &Mutation<'gc>, transform_3d: Option<Transform3D>) {
+        if self.base_mut(gc_context).set_transform_3d(transform_3d) {
+            if let Some(parent) = self.parent() {
+                parent.invalidate_cached_bitmap(gc_context);
+            }
+        }
+    }
+
+    /// The full 3D transform of this object in local space.
+    /// Returned by the `transform.matrix3D` ActionScript property.
+    fn matrix_3d(&self, gc_context: &Mutation<'gc>) -> Option<Matrix3D> {
+        let matrix = self.base_mut(gc_context).matrix_3d();
+        self.set_scale_rotation_cached(gc_context);
+        matrix
+    }
+
+    /// Sets the full 3D transform of this object in local space.
+    /// Set by the `transform.matrix3D` ActionScript property.
+    /// This invalidates any ancestors cacheAsBitmap automatically.
+    fn set_matrix_3d(&self, gc_context: &Mutation<'gc>, matrix: &Matrix3D) {
+        self.base_mut(gc_context).set_matrix_3d(matrix);
+        self.set_scale_rotation_cached(gc_context);
+        if let Some(parent) = self.parent() {
+            parent.invalidate_cached_bitmap(gc_context);
+        }
+    }
+
+    /// Returns the matrix for transforming from this object's local space to global stage space,
+    /// including the 3D transforms of this object and its ancestors.
+    fn local_to_global_matrix_3d(&self, gc_context: &Mutation<'gc>) -> Matrix3D {
+        let mut matrix = Matrix3D::IDENTITY;
+        let mut node = Some((*self).into());
+        while let Some(display_object) = node {
+            if display_object.as_stage().is_some() {
+                break;
+            }
+            let local = display_object
+                .matrix_3d(gc_context)
+                .unwrap_or_else(|| (*display_object.base().matrix()).into());
+            matrix = local * matrix;
+            node = display_object.parent();
+        }
+        matrix
+    }
+
+    /// The perspective projection this object uses for its 3D children, if it has its own.
+    fn perspective_projection(&self) -> Option<PerspectiveProjection> {
+        self.base().perspective_projection()
+    }
+
+    /// Sets the perspective projection this object uses for its 3D children.
+    /// `None` makes it use the projection of its ancestors.
+    fn set_perspective_projection(
+        &self,
+        gc_context: &Mutation<'gc>,
+        projection: Option<PerspectiveProjection>,
+    ) {
+        if self
+            .base_mut(gc_context)
+            .set_perspective_projection(projection)
+        {
+            self.invalidate_cached_bitmap(gc_context);
+        }
+    }
+
     /// Gets the pixel width of the AABB containing this display object in local space.
     /// Returned by the ActionScript `_width`/`width` properties.
     fn width(&self) -> f64 {
@@ -2104,6 +2429,9 @@ pub trait TDisplayObject<'gc>:
     fn as_video(self) -> Option<Video<'gc>> {
         None
     }
+    fn as_text(self) -> Option<Text<'gc>> {
+        None
+    }
     fn as_drawing(&self, _gc_context: &Mutation<'gc>) -> Option<RefMut<'_, Drawing>> {
         None
     }
@@ -168,6 +168,12 @@ pub struct EditTextData<'gc> {
 
     /// Whether this EditText represents an AVM2 TextLine.
     is_tlf: bool,
+
+    /// The bidi embedding level forced onto every paragraph, if any.
+    ///
+    /// This is only set for AVM2 TextLines, from their `TextBlock.bidiLevel`.
+    /// Otherwise, each paragraph takes its direction from its own text.
+    bidi_level: Option<u8>,
 }
 
 impl<'gc> EditTextData<'gc> {
@@ -246,7 +252,7 @@ impl<'gc> EditText<'gc> {
         let text = swf_tag.initial_text().unwrap_or_default().decode(encoding);
 
         let mut text_spans = if swf_tag.is_html() {
-            FormatSpans::from_html(&text, default_format, swf_tag.is_multiline())
+            FormatSpans::from_html(&text, default_format, swf_tag.is_multiline(), false)
         } else {
             FormatSpans::from_text(text.into_owned(), default_format)
         };
@@ -268,6 +274,7 @@ impl<'gc> EditText<'gc> {
             swf_tag.bounds().width() - Twips::from_pixels(Self::INTERNAL_PADDING * 2.0),
             swf_tag.is_word_wrap(),
             !swf_tag.use_outlines(),
+            None,
         );
         let line_data = get_line_data(&layout);
 
@@ -334,6 +341,7 @@ impl<'gc> EditText<'gc> {
                 scroll: 1,
                 max_chars: swf_tag.max_length().unwrap_or_default() as i32,
                 is_tlf: false,
+                bidi_level: None,
             },
         ));
 
@@ -426,6 +434,7 @@ impl<'gc> EditText<'gc> {
                 text,
                 default_format,
                 write.flags.contains(EditTextFlag::MULTILINE),
+                write.flags.contains(EditTextFlag::CONDENSE_WHITE),
             );
             drop(write);
 
@@ -509,6 +518,20 @@ impl<'gc> EditText<'gc> {
         self.relayout(context);
     }
 
+    pub fn condense_white(self) -> bool {
+        self.0.read().flags.contains(EditTextFlag::CONDENSE_WHITE)
+    }
+
+    /// Sets whether whitespace in HTML text is collapsed.
+    /// This only applies to HTML text that is set afterwards.
+    pub fn set_condense_white(self, condense_white: bool, context: &mut UpdateContext<'_, 'gc>) {
+        self.0
+            .write(context.gc_context)
+            .flags
+            .set(EditTextFlag::CONDENSE_WHITE, condense_white);
+        self.relayout(context);
+    }
+
     pub fn is_selectable(self) -> bool {
         !self.0.read().flags.contains(EditTextFlag::NO_SELECT)
     }
@@ -614,6 +637,14 @@ impl<'gc> EditText<'gc> {
         self.0.write(gc_context).is_tlf = is_tlf;
     }
 
+    /// Force the bidi embedding level of every paragraph.
+    ///
+    /// This doesn't relayout the text by itself; the level is used the next
+    /// time the text changes.
+    pub fn set_bidi_level(self, gc_context: &Mutation<'gc>, bidi_level: Option<u8>) {
+        self.0.write(gc_context).bidi_level = bidi_level;
+    }
+
     pub fn replace_text(
         self,
         from: usize,
@@ -788,6 +819,7 @@ impl<'gc> EditText<'gc> {
             content_width,
             is_word_wrap,
             !edit_text.flags.contains(EditTextFlag::USE_OUTLINES),
+            edit_text.bidi_level,
         );
 
         edit_text.line_data = get_line_data(&new_layout);
@@ -959,10 +991,12 @@ impl<'gc> EditText<'gc> {
         {
             let baseline_adjustment =
                 font.get_baseline_for_height(params.height()) - params.height();
-            font.evaluate(
+            let is_rtl = lbox.is_rtl();
+            font.evaluate_with_direction(
                 text,
                 self.text_transform(color, baseline_adjustment),
                 params,
+                is_rtl,
                 |pos, transform, glyph: &Glyph, advance, x| {
                     if let Some(glyph_shape_handle) = glyph.shape_handle(context.renderer) {
                         // If it's highlighted, override the color.
@@ -998,23 +1032,28 @@ impl<'gc> EditText<'gc> {
                     }
 
                     if let Some((caret_pos, length)) = caret {
-                        if caret_pos == pos {
-                            let caret = context.transform_stack.transform().matrix
-                                * Matrix::create_box(
-                                    1.0,
-                                    params.height().to_pixels() as f32,
-                                    0.0,
-                                    x + Twips::from_pixels(-1.0),
-                                    Twips::from_pixels(2.0),
-                                );
-                            context.commands.draw_rect(color, caret);
+                        // The leading edge of a right-to-left glyph is its right side.
+                        let (leading_edge, trailing_edge) = if is_rtl {
+                            (x + advance, x + Twips::from_pixels(-1.0))
+                        } else {
+                            (x + Twips::from_pixels(-1.0), x + advance)
+                        };
+
+                        let caret_x = if caret_pos == pos {
+                            Some(leading_edge)
                         } else if pos == length - 1 && caret_pos == length {
+                            Some(trailing_edge)
+                        } else {
+                            None
+                        };
+
+                        if let Some(caret_x) = caret_x {
                             let caret = context.transform_stack.transform().matrix
                                 * Matrix::create_box(
                                     1.0,
                                     params.height().to_pixels() as f32,
                                     0.0,
-                                    x + advance,
+                                    caret_x,
                                     Twips::from_pixels(2.0),
                                 );
                             context.commands.draw_rect(color, caret);
@@ -1246,17 +1285,21 @@ impl<'gc> EditText<'gc> {
                 let mut result = None;
                 let baseline_adjustment =
                     font.get_baseline_for_height(params.height()) - params.height();
-                font.evaluate(
+                let is_rtl = layout_box.is_rtl();
+                font.evaluate_with_direction(
                     text,
                     self.text_transform(color, baseline_adjustment),
                     params,
+                    is_rtl,
                     |pos, _transform, _glyph: &Glyph, advance, x| {
                         if local_position.x >= x
                             && local_position.x <= x + advance
                             && local_position.y >= Twips::ZERO
                             && local_position.y <= params.height()
                         {
-                            if local_position.x >= x + (advance / 2) {
+                            // Right-to-left glyphs start on their right side.
+                            let is_past_middle = local_position.x >= x + (advance / 2);
+                            if is_past_middle != is_rtl {
                                 result = Some(string_utils::next_char_boundary(text, pos));
                             } else {
                                 result = Some(pos);
@@ -1279,6 +1322,28 @@ impl<'gc> EditText<'gc> {
         None
     }
 
+    /// Whether the text around the given position is laid out right-to-left.
+    ///
+    /// This looks at the character following the position, or at the one
+    /// preceding it if the position is at the end of a run of text.
+    fn is_rtl_at(self, position: usize) -> bool {
+        let text = self.0.read();
+        let text_range = |layout_box: &&LayoutBox<'gc>| match layout_box.content() {
+            LayoutContent::Text { start, end, .. } => Some(*start..*end),
+            _ => None,
+        };
+
+        text.layout
+            .iter()
+            .find(|layout_box| text_range(layout_box).map_or(false, |r| r.contains(&position)))
+            .or_else(|| {
+                text.layout
+                    .iter()
+                    .find(|layout_box| text_range(layout_box).map_or(false, |r| r.end == position))
+            })
+            .map_or(false, |layout_box| layout_box.is_rtl())
+    }
+
     /// The number of characters that currently can be inserted, considering `TextField.maxChars`
     /// constraint, current text length, and current text selection length.
     fn available_chars(self) -> usize {
@@ -1310,8 +1375,17 @@ impl<'gc> EditText<'gc> {
             let mut changed = false;
             let is_selectable = self.is_selectable();
             match control_code {
-                TextControlCode::MoveLeft => {
-                    let new_pos = if selection.is_caret() && selection.to > 0 {
+                TextControlCode::MoveLeft | TextControlCode::MoveRight => {
+                    // Arrow keys move visually, which is backwards in right-to-left text.
+                    let is_forward = matches!(control_code, TextControlCode::MoveRight)
+                        != self.is_rtl_at(selection.to);
+                    let new_pos = if is_forward {
+                        if selection.is_caret() && selection.to < self.text().len() {
+                            string_utils::next_char_boundary(&self.text(), selection.to)
+                        } else {
+                            selection.end()
+                        }
+                    } else if selection.is_caret() && selection.to > 0 {
                         string_utils::prev_char_boundary(&self.text(), selection.to)
                     } else {
                         selection.start()
@@ -1321,35 +1395,23 @@ impl<'gc> EditText<'gc> {
                         context.gc_context,
                     );
                 }
-                TextControlCode::MoveRight => {
-                    let new_pos = if selection.is_caret() && selection.to < self.text().len() {
-                        string_utils::next_char_boundary(&self.text(), selection.to)
+                TextControlCode::SelectLeft | TextControlCode::SelectRight => {
+                    let is_forward = matches!(control_code, TextControlCode::SelectRight)
+                        != self.is_rtl_at(selection.to);
+                    let new_pos = if is_forward && selection.to < self.text().len() {
+                        Some(string_utils::next_char_boundary(&self.text(), selection.to))
+                    } else if !is_forward && selection.to > 0 {
+                        Some(string_utils::prev_char_boundary(&self.text(), selection.to))
                     } else {
-                        selection.end()
+                        None
                     };
-                    self.set_selection(
-                        Some(TextSelection::for_position(new_pos)),
-                        context.gc_context,
-                    );
-                }
-                TextControlCode::SelectLeft => {
-                    if is_selectable && selection.to > 0 {
-                        let new_pos = string_utils::prev_char_boundary(&self.text(), selection.to);
+                    if let Some(new_pos) = new_pos.filter(|_| is_selectable) {
                         self.set_selection(
                             Some(TextSelection::for_range(selection.from, new_pos)),
                             context.gc_context,
                         );
                     }
                 }
-                TextControlCode::SelectRight => {
-                    if is_selectable && selection.to < self.text().len() {
-                        let new_pos = string_utils::next_char_boundary(&self.text(), selection.to);
-                        self.set_selection(
-                            Some(TextSelection::for_range(selection.from, new_pos)),
-                            context.gc_context,
-                        )
-                    }
-                }
                 TextControlCode::SelectAll => {
                     if is_selectable {
                         self.set_selection(
@@ -2212,6 +2274,7 @@ bitflags::bitflags! {
         const WAS_STATIC = 1 << 10;
         const BORDER = 1 << 11;
         const NO_SELECT = 1 << 12;
+        const CONDENSE_WHITE = 1 << 13;
         const SWF_FLAGS = Self::READ_ONLY.bits() | Self::PASSWORD.bits() | Self::MULTILINE.bits() | Self::WORD_WRAP.bits() | Self::USE_OUTLINES.bits() |
                           Self::HTML.bits() | Self::WAS_STATIC.bits() | Self::BORDER.bits() | Self::NO_SELECT.bits();
     }
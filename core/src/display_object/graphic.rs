@@ -109,6 +109,16 @@ impl<'gc> Graphic<'gc> {
             w.drawing.get_or_insert_with(Drawing::new)
         })
     }
+
+    /// A copy of what this graphic currently displays, converting the
+    /// timeline shape if it hasn't been replaced with a drawing.
+    pub fn current_drawing(&self) -> Drawing {
+        let read = self.0.read();
+        match &read.drawing {
+            Some(drawing) => drawing.clone(),
+            None => Drawing::from_swf_shape(&read.static_data.shape),
+        }
+    }
 }
 
 impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
@@ -316,6 +316,28 @@ impl<'gc> MovieClip<'gc> {
         ))
     }
 
+    /// Creates a new instance of this clip's symbol, sitting before its first frame.
+    ///
+    /// The copy shares this clip's definition but none of its children or other state,
+    /// so it can be moved around the timeline without affecting this clip.
+    pub fn fresh_copy(self, gc_context: &Mutation<'gc>) -> Self {
+        let read = self.0.read();
+        let copy = Self::new_with_data(
+            gc_context,
+            read.id(),
+            read.static_data.swf.clone(),
+            read.total_frames(),
+        );
+        let mut write = copy.0.write(gc_context);
+        write.static_data = read.static_data;
+        #[cfg(feature = "timeline_debug")]
+        {
+            write.tag_frame_boundaries = read.tag_frame_boundaries.clone();
+        }
+        drop(write);
+        copy
+    }
+
     pub fn downgrade(self) -> MovieClipWeak<'gc> {
         MovieClipWeak(GcCell::downgrade(self.0))
     }
@@ -1095,6 +1117,24 @@ impl<'gc> MovieClip<'gc> {
         }
     }
 
+    /// Moves an AVM2 clip that is not on the display list to `frame`.
+    ///
+    /// The clip is expected to be a [`MovieClip::fresh_copy`] that has not run any frames
+    /// yet. Its timeline children are placed, but nothing is constructed, so no frame
+    /// scripts, constructors or events run.
+    pub fn goto_frame_detached(self, context: &mut UpdateContext<'_, 'gc>, frame: FrameNumber) {
+        // Like any newly placed clip, the copy enters its first frame before it can move.
+        self.enter_frame(context);
+        self.stop(context);
+
+        if frame > self.current_frame() {
+            // Looping gotos leave new children queued for the next frame,
+            // so they have to be placed here instead.
+            self.run_goto(context, frame, true);
+            self.run_queued_place_objects(context);
+        }
+    }
+
     pub fn current_frame(self) -> FrameNumber {
         self.0.read().current_frame()
     }
@@ -2431,6 +2471,24 @@ impl<'gc> MovieClip<'gc> {
         unqueued
     }
 
+    /// Run all `PlaceObject` tags on the internal tag queue.
+    fn run_queued_place_objects(self, context: &mut UpdateContext<'_, 'gc>) {
+        let data = self.0.read().static_data.swf.clone();
+        let place_actions = self.unqueue_adds(context);
+
+        for (_, tag) in place_actions {
+            let mut reader = data.read_from(tag.tag_start);
+            let version = match tag.tag_type {
+                QueuedTagAction::Place(v) => v,
+                _ => unreachable!(),
+            };
+
+            if let Err(e) = self.place_object(context, &mut reader, version) {
+                tracing::error!("Error running queued tag: {:?}, got {}", tag.tag_type, e);
+            }
+        }
+    }
+
     /// Remove all `RemoveObject` tags off the internal tag queue.
     fn unqueue_removes(&self, context: &mut UpdateContext<'_, 'gc>) -> Vec<(Depth, QueuedTag)> {
         let mut write = self.0.write(context.gc_context);
@@ -2616,20 +2674,7 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
             // PlaceObject tags execute at this time.
             // Note that this is NOT when constructors run; that happens later
             // after tags have executed.
-            let data = self.0.read().static_data.swf.clone();
-            let place_actions = self.unqueue_adds(context);
-
-            for (_, tag) in place_actions {
-                let mut reader = data.read_from(tag.tag_start);
-                let version = match tag.tag_type {
-                    QueuedTagAction::Place(v) => v,
-                    _ => unreachable!(),
-                };
-
-                if let Err(e) = self.place_object(context, &mut reader, version) {
-                    tracing::error!("Error running queued tag: {:?}, got {}", tag.tag_type, e);
-                }
-            }
+            self.run_queued_place_objects(context);
         }
     }
 
@@ -3,8 +3,9 @@ use crate::avm2::{
 };
 use crate::context::{RenderContext, UpdateContext};
 use crate::display_object::{DisplayObjectBase, DisplayObjectPtr, TDisplayObject};
-use crate::font::TextRenderSettings;
+use crate::font::{Font, TextRenderSettings};
 use crate::prelude::*;
+use crate::string::WString;
 use crate::tag_utils::SwfMovie;
 use crate::vminterface::Instantiator;
 use core::fmt;
@@ -12,6 +13,7 @@ use gc_arena::{Collect, GcCell, Mutation};
 use ruffle_render::commands::CommandHandler;
 use ruffle_render::transform::Transform;
 use std::cell::{Ref, RefMut};
+use std::ops::Range;
 use std::sync::Arc;
 
 #[derive(Clone, Collect, Copy)]
@@ -34,6 +36,29 @@ pub struct TextData<'gc> {
     #[collect(require_static)]
     render_settings: TextRenderSettings,
     avm2_object: Option<Avm2Object<'gc>>,
+
+    /// Which glyphs have been selected through a `TextSnapshot`, by glyph index.
+    selected_glyphs: Vec<bool>,
+
+    /// The color used to highlight selected glyphs.
+    #[collect(require_static)]
+    selection_color: Color,
+}
+
+/// A single glyph of static text, along with the character it represents.
+#[derive(Clone, Debug)]
+pub struct TextGlyph<'gc> {
+    pub character: char,
+    pub font: Font<'gc>,
+    pub color: Color,
+    pub height: Twips,
+
+    /// The position of the glyph's origin on the baseline, before the text transform is applied.
+    pub position: Point<Twips>,
+    pub advance: Twips,
+
+    /// Whether this glyph is the first glyph of a line after the first one.
+    pub starts_line: bool,
 }
 
 impl<'gc> Text<'gc> {
@@ -58,10 +83,106 @@ impl<'gc> Text<'gc> {
                 ),
                 render_settings: Default::default(),
                 avm2_object: None,
+                selected_glyphs: Vec::new(),
+                // Flash Player highlights selected static text in yellow by default.
+                selection_color: Color::from_rgb(0xFFFF00, 255),
             },
         ))
     }
 
+    /// The transform from the text records into the local space of this text object.
+    pub fn text_transform(self) -> Matrix {
+        self.0.read().static_data.text_transform
+    }
+
+    /// Lists the glyphs of this text, in the order they appear in the text records.
+    ///
+    /// Glyphs whose font can't be found are skipped, as they aren't rendered either.
+    pub fn glyphs(self, context: &UpdateContext<'_, 'gc>) -> Vec<TextGlyph<'gc>> {
+        let read = self.0.read();
+        let Some(library) = context.library.library_for_movie(self.movie()) else {
+            return Vec::new();
+        };
+
+        let mut glyphs = Vec::new();
+        let mut color = Color::BLACK;
+        let mut font_id = 0;
+        let mut height = Twips::ZERO;
+        let mut position = Point::ZERO;
+        let mut line_y = None;
+        for block in &read.static_data.text_blocks {
+            if let Some(x) = block.x_offset {
+                position.x = x;
+            }
+            if let Some(y) = block.y_offset {
+                position.y = y;
+            }
+            color = block.color.unwrap_or(color);
+            font_id = block.font_id.unwrap_or(font_id);
+            height = block.height.unwrap_or(height);
+
+            let Some(font) = library.get_font(font_id) else {
+                continue;
+            };
+            let mut starts_line = line_y.map_or(false, |y| y != position.y);
+            line_y = Some(position.y);
+            for c in &block.glyphs {
+                if let Some(glyph) = font.get_glyph(c.index as usize) {
+                    glyphs.push(TextGlyph {
+                        character: glyph.character(),
+                        font,
+                        color,
+                        height,
+                        position,
+                        advance: Twips::new(c.advance),
+                        starts_line,
+                    });
+                    starts_line = false;
+                    position.x += Twips::new(c.advance);
+                }
+            }
+        }
+        glyphs
+    }
+
+    /// The characters of this text, without any line endings.
+    pub fn text(self, context: &UpdateContext<'_, 'gc>) -> WString {
+        let mut text = WString::new();
+        for glyph in self.glyphs(context) {
+            text.push_char(glyph.character);
+        }
+        text
+    }
+
+    pub fn is_glyph_selected(self, index: usize) -> bool {
+        self.0
+            .read()
+            .selected_glyphs
+            .get(index)
+            .copied()
+            .unwrap_or(false)
+    }
+
+    pub fn set_glyphs_selected(
+        self,
+        gc_context: &Mutation<'gc>,
+        range: Range<usize>,
+        selected: bool,
+    ) {
+        let mut write = self.0.write(gc_context);
+        if write.selected_glyphs.len() < range.end {
+            write.selected_glyphs.resize(range.end, false);
+        }
+        write.selected_glyphs[range].fill(selected);
+        drop(write);
+        self.invalidate_cached_bitmap(gc_context);
+    }
+
+    pub fn set_selection_color(self, gc_context: &Mutation<'gc>, color: Color) {
+        self.0.write(gc_context).selection_color = color;
+        self.invalidate_cached_bitmap(gc_context);
+    }
+
     pub fn set_render_settings(self, gc_context: &Mutation<'gc>, settings: TextRenderSettings) {
         self.0.write(gc_context).render_settings = settings;
         self.invalidate_cached_bitmap(gc_context);
@@ -106,6 +227,10 @@ impl<'gc> TDisplayObject<'gc> for Text<'gc> {
         self.invalidate_cached_bitmap(context.gc_context);
     }
 
+    fn as_text(self) -> Option<Text<'gc>> {
+        Some(self)
+    }
+
     fn run_frame_avm1(&self, _context: &mut UpdateContext) {
         // Noop
     }
@@ -126,6 +251,7 @@ impl<'gc> TDisplayObject<'gc> for Text<'gc> {
         let mut font_id = 0;
         let mut height = Twips::ZERO;
         let mut transform: Transform = Default::default();
+        let mut glyph_index = 0;
         for block in &tf.static_data.text_blocks {
             if let Some(x) = block.x_offset {
                 transform.matrix.tx = x;
@@ -148,6 +274,24 @@ impl<'gc> TDisplayObject<'gc> for Text<'gc> {
                 transform.color_transform.set_mult_color(&color);
                 for c in &block.glyphs {
                     if let Some(glyph) = font.get_glyph(c.index as usize) {
+                        if tf
+                            .selected_glyphs
+                            .get(glyph_index)
+                            .copied()
+                            .unwrap_or(false)
+                        {
+                            let highlight = context.transform_stack.transform().matrix
+                                * Matrix::create_box(
+                                    Twips::new(c.advance).to_pixels() as f32,
+                                    height.to_pixels() as f32,
+                                    0.0,
+                                    transform.matrix.tx,
+                                    transform.matrix.ty - font.get_baseline_for_height(height),
+                                );
+                            context.commands.draw_rect(tf.selection_color, highlight);
+                        }
+                        glyph_index += 1;
+
                         if let Some(glyph_shape_handle) = glyph.shape_handle(context.renderer) {
                             context.transform_stack.push(&transform);
                             context.commands.render_shape(
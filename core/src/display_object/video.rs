@@ -192,6 +192,14 @@ impl<'gc> Video<'gc> {
         video.keyframes = BTreeSet::new();
     }
 
+    /// The `NetStream` attached to this video, if any.
+    pub fn netstream(self) -> Option<NetStream<'gc>> {
+        match &*self.0.read().source.read() {
+            VideoSource::NetStream { stream } => Some(*stream),
+            _ => None,
+        }
+    }
+
     /// Preload frame data from an SWF.
     ///
     /// This function yields an error if this video player is not playing an
@@ -2,6 +2,11 @@ use crate::context::RenderContext;
 use ruffle_render::backend::{RenderBackend, ShapeHandle};
 use ruffle_render::bitmap::{BitmapHandle, BitmapInfo, BitmapSize, BitmapSource};
 use ruffle_render::commands::CommandHandler;
+use ruffle_render::matrix::Matrix;
+use ruffle_render::pixel_bender::{
+    PixelBenderParam, PixelBenderShaderArgument, PixelBenderShaderHandle, PixelBenderType,
+    OUT_COORD_NAME,
+};
 use ruffle_render::shape_utils::{
     cubic_curve_bounds, quadratic_curve_bounds, DistilledShape, DrawCommand, DrawPath, FillRule,
 };
@@ -15,7 +20,7 @@ pub struct Drawing {
     edge_bounds: Rectangle<Twips>,
     dirty: Cell<bool>,
     paths: Vec<DrawingPath>,
-    bitmaps: Vec<BitmapInfo>,
+    bitmaps: Vec<DrawingBitmap>,
     current_fill: Option<DrawingFill>,
     current_line: Option<DrawingLine>,
     pending_lines: Vec<DrawingLine>,
@@ -235,70 +240,127 @@ impl Drawing {
 
     pub fn add_bitmap(&mut self, bitmap: BitmapInfo) -> u16 {
         let id = self.bitmaps.len() as u16;
-        self.bitmaps.push(bitmap);
+        self.bitmaps.push(DrawingBitmap::Bitmap(bitmap));
         id
     }
 
-    pub fn register_or_replace(&self, renderer: &mut dyn RenderBackend) -> ShapeHandle {
-        if self.dirty.get() || self.render_handle.borrow().is_none() {
-            self.dirty.set(false);
-            let mut paths = Vec::with_capacity(self.paths.len());
-
-            for path in &self.paths {
-                match path {
-                    DrawingPath::Fill(fill) => {
-                        paths.push(DrawPath::Fill {
-                            style: &fill.style,
-                            commands: fill.commands.to_owned(),
-                            winding_rule: FillRule::EvenOdd,
-                        });
-                    }
-                    DrawingPath::Line(line) => {
-                        paths.push(DrawPath::Stroke {
-                            style: &line.style,
-                            commands: line.commands.to_owned(),
-                            is_closed: line.is_closed,
-                        });
-                    }
+    /// Add a Pixel Bender shader fill to this drawing, returning the fill
+    /// style that draws it.
+    ///
+    /// `matrix` maps shader coordinates to twips, like the matrix of a bitmap
+    /// fill.
+    pub fn add_shader_fill(
+        &mut self,
+        shader: PixelBenderShaderHandle,
+        arguments: Vec<PixelBenderShaderArgument<'static>>,
+        matrix: swf::Matrix,
+    ) -> FillStyle {
+        let id = self.bitmaps.len() as u16;
+        self.bitmaps.push(DrawingBitmap::Shader(DrawingShaderFill {
+            shader,
+            arguments,
+            matrix,
+            output: RefCell::new(None),
+        }));
+        FillStyle::Bitmap {
+            id,
+            matrix,
+            is_smoothed: false,
+            is_repeating: false,
+        }
+    }
+
+    /// The paths making up this drawing, in the order they're drawn.
+    ///
+    /// Any pending fill or lines are included as if they had been ended.
+    pub fn draw_paths(&self) -> Vec<DrawPath<'_>> {
+        let mut paths = Vec::with_capacity(self.paths.len());
+
+        for path in &self.paths {
+            match path {
+                DrawingPath::Fill(fill) => {
+                    paths.push(DrawPath::Fill {
+                        style: &fill.style,
+                        commands: fill.commands.to_owned(),
+                        winding_rule: FillRule::EvenOdd,
+                    });
+                }
+                DrawingPath::Line(line) => {
+                    paths.push(DrawPath::Stroke {
+                        style: &line.style,
+                        commands: line.commands.to_owned(),
+                        is_closed: line.is_closed,
+                    });
                 }
             }
+        }
 
-            if let Some(fill) = &self.current_fill {
-                paths.push(DrawPath::Fill {
-                    style: &fill.style,
-                    commands: fill.commands.to_owned(),
-                    winding_rule: FillRule::EvenOdd,
-                })
-            }
+        if let Some(fill) = &self.current_fill {
+            paths.push(DrawPath::Fill {
+                style: &fill.style,
+                commands: fill.commands.to_owned(),
+                winding_rule: FillRule::EvenOdd,
+            })
+        }
 
-            for line in &self.pending_lines {
-                let mut commands = line.commands.to_owned();
-                let is_closed = if self.current_fill.is_some() {
-                    commands.push(DrawCommand::LineTo(self.fill_start));
-                    true
-                } else {
-                    self.cursor == self.fill_start
-                };
-                paths.push(DrawPath::Stroke {
-                    style: &line.style,
-                    commands,
-                    is_closed,
-                })
-            }
+        for line in &self.pending_lines {
+            let mut commands = line.commands.to_owned();
+            let is_closed = if self.current_fill.is_some() {
+                commands.push(DrawCommand::LineTo(self.fill_start));
+                true
+            } else {
+                self.cursor == self.fill_start
+            };
+            paths.push(DrawPath::Stroke {
+                style: &line.style,
+                commands,
+                is_closed,
+            })
+        }
 
-            if let Some(line) = &self.current_line {
-                let mut commands = line.commands.to_owned();
-                let is_closed = if self.current_fill.is_some() {
-                    commands.push(DrawCommand::LineTo(self.fill_start));
-                    true
-                } else {
-                    self.cursor == self.fill_start
-                };
-                paths.push(DrawPath::Stroke {
-                    style: &line.style,
-                    commands,
-                    is_closed,
+        if let Some(line) = &self.current_line {
+            let mut commands = line.commands.to_owned();
+            let is_closed = if self.current_fill.is_some() {
+                commands.push(DrawCommand::LineTo(self.fill_start));
+                true
+            } else {
+                self.cursor == self.fill_start
+            };
+            paths.push(DrawPath::Stroke {
+                style: &line.style,
+                commands,
+                is_closed,
+            })
+        }
+
+        paths
+    }
+
+    pub fn register_or_replace(&self, renderer: &mut dyn RenderBackend) -> ShapeHandle {
+        if self.dirty.get() || self.render_handle.borrow().is_none() {
+            self.dirty.set(false);
+
+            let shader_styles: Vec<_> = self
+                .bitmaps
+                .iter()
+                .enumerate()
+                .map(|(id, bitmap)| match bitmap {
+                    DrawingBitmap::Shader(fill) => {
+                        Some(fill.evaluate(renderer, &self.shape_bounds, id as u16))
+                    }
+                    DrawingBitmap::Bitmap(_) => None,
                 })
+                .collect();
+
+            let mut paths = self.draw_paths();
+            for path in &mut paths {
+                if let DrawPath::Fill { style, .. } = path {
+                    if let FillStyle::Bitmap { id, .. } = **style {
+                        if let Some(Some(shader_style)) = shader_styles.get(id as usize) {
+                            *style = shader_style;
+                        }
+                    }
+                }
             }
 
             let shape = DistilledShape {
@@ -419,13 +481,125 @@ impl Drawing {
 
 impl BitmapSource for Drawing {
     fn bitmap_size(&self, id: u16) -> Option<BitmapSize> {
-        self.bitmaps.get(id as usize).map(|bm| BitmapSize {
+        self.bitmaps.get(id as usize)?.info().map(|bm| BitmapSize {
             width: bm.width,
             height: bm.height,
         })
     }
     fn bitmap_handle(&self, id: u16, _backend: &mut dyn RenderBackend) -> Option<BitmapHandle> {
-        self.bitmaps.get(id as usize).map(|bm| bm.handle.clone())
+        self.bitmaps.get(id as usize)?.info().map(|bm| bm.handle)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum DrawingBitmap {
+    Bitmap(BitmapInfo),
+    Shader(DrawingShaderFill),
+}
+
+impl DrawingBitmap {
+    fn info(&self) -> Option<BitmapInfo> {
+        match self {
+            DrawingBitmap::Bitmap(bitmap) => Some(bitmap.clone()),
+            DrawingBitmap::Shader(fill) => fill.output.borrow().clone(),
+        }
+    }
+}
+
+/// A shader fill, which is drawn as a non-repeating bitmap fill of the
+/// shader's output.
+#[derive(Debug, Clone)]
+struct DrawingShaderFill {
+    shader: PixelBenderShaderHandle,
+    arguments: Vec<PixelBenderShaderArgument<'static>>,
+    matrix: swf::Matrix,
+
+    /// The result of the last evaluation of the shader.
+    output: RefCell<Option<BitmapInfo>>,
+}
+
+impl DrawingShaderFill {
+    /// Run the shader for every pixel of the fill that lies within `bounds`,
+    /// returning the fill style that draws the result.
+    fn evaluate(
+        &self,
+        renderer: &mut dyn RenderBackend,
+        bounds: &Rectangle<Twips>,
+        id: u16,
+    ) -> FillStyle {
+        let mut origin = (0, 0);
+        let inverse = Matrix::from(self.matrix).inverse();
+        let output = match inverse {
+            Some(inverse) if bounds.is_valid() => {
+                // The inverse fill matrix maps twips to shader pixels, so the
+                // resulting "twips" are really pixel coordinates.
+                let extent = inverse * bounds.clone();
+                origin = (extent.x_min.get(), extent.y_min.get());
+                let width = (extent.x_max.get() - origin.0).clamp(1, u16::MAX.into()) as u32;
+                let height = (extent.y_max.get() - origin.1).clamp(1, u16::MAX.into()) as u32;
+
+                // The shader adds `outCoord` to the pixel coordinate, so this
+                // evaluates the region starting at `origin`.
+                let arguments: Vec<_> = self
+                    .arguments
+                    .iter()
+                    .map(|argument| match argument {
+                        PixelBenderShaderArgument::ValueInput { index, .. }
+                            if self.is_out_coord(*index) =>
+                        {
+                            PixelBenderShaderArgument::ValueInput {
+                                index: *index,
+                                value: PixelBenderType::TFloat2(origin.0 as f32, origin.1 as f32),
+                            }
+                        }
+                        argument => argument.clone(),
+                    })
+                    .collect();
+
+                let result = renderer
+                    .create_empty_texture(width, height)
+                    .and_then(|handle| {
+                        renderer.run_pixelbender_shader(
+                            self.shader.clone(),
+                            &arguments,
+                            handle.clone(),
+                        )?;
+                        Ok(handle)
+                    });
+
+                match result {
+                    Ok(handle) => Some(BitmapInfo {
+                        handle,
+                        width: width as u16,
+                        height: height as u16,
+                    }),
+                    Err(e) => {
+                        tracing::warn!("Failed to evaluate shader fill: {}", e);
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        *self.output.borrow_mut() = output;
+
+        // Pixel (0, 0) of the output is at `origin` in shader coordinates.
+        let matrix = Matrix::from(self.matrix)
+            * Matrix::translate(Twips::new(origin.0), Twips::new(origin.1));
+        FillStyle::Bitmap {
+            id,
+            matrix: matrix.into(),
+            is_smoothed: false,
+            is_repeating: false,
+        }
+    }
+
+    fn is_out_coord(&self, index: u8) -> bool {
+        matches!(
+            self.shader.0.parsed_shader().params.get(index as usize),
+            Some(PixelBenderParam::Normal { name, .. }) if name == OUT_COORD_NAME
+        )
     }
 }
 
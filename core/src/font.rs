@@ -28,6 +28,29 @@ pub fn round_down_to_pixel(t: Twips) -> Twips {
     Twips::from_pixels(t.to_pixels().floor())
 }
 
+/// Returns the mirrored form of a character, as substituted in right-to-left
+/// runs of text.
+///
+/// This only covers the paired brackets and quotation marks commonly found in
+/// text, not the full Unicode `Bidi_Mirroring_Glyph` property.
+fn mirrored_char(c: char) -> Option<char> {
+    Some(match c {
+        '(' => ')',
+        ')' => '(',
+        '<' => '>',
+        '>' => '<',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '\u{AB}' => '\u{BB}',
+        '\u{BB}' => '\u{AB}',
+        '\u{2039}' => '\u{203A}',
+        '\u{203A}' => '\u{2039}',
+        _ => return None,
+    })
+}
+
 /// Parameters necessary to evaluate a font.
 #[derive(Copy, Clone, Debug)]
 pub struct EvalParameters {
@@ -207,6 +230,7 @@ impl<'gc> Font<'gc> {
 
                 let glyph = Glyph {
                     shape_handle: None.into(),
+                    character: char::from_u32(code.into()).unwrap_or(char::REPLACEMENT_CHARACTER),
                     advance: Twips::new(swf_glyph.advance.into()),
                     shape: GlyphShape::Swf(RefCell::new(SwfGlyphOrShape::Glyph(swf_glyph))),
                 };
@@ -333,6 +357,52 @@ impl<'gc> Font<'gc> {
     pub fn evaluate<FGlyph>(
         &self,
         text: &WStr, // TODO: take an `IntoIterator<Item=char>`, to not depend on string representation?
+        transform: Transform,
+        params: EvalParameters,
+        glyph_func: FGlyph,
+    ) where
+        FGlyph: FnMut(usize, &Transform, &Glyph, Twips, Twips),
+    {
+        self.evaluate_with_direction(text, transform, params, false, glyph_func)
+    }
+
+    /// Evaluate this font against a particular string, optionally laying it
+    /// out from right to left.
+    ///
+    /// Right-to-left text is visited in reverse order, with mirrored glyphs
+    /// substituted for paired punctuation such as brackets. The position given
+    /// to `glyph_func` is always the logical position of the character within
+    /// `text`, while the transform and `x` offset are in visual order.
+    pub fn evaluate_with_direction<FGlyph>(
+        &self,
+        text: &WStr,
+        transform: Transform,
+        params: EvalParameters,
+        is_rtl: bool,
+        glyph_func: FGlyph,
+    ) where
+        FGlyph: FnMut(usize, &Transform, &Glyph, Twips, Twips),
+    {
+        let chars = text
+            .char_indices()
+            .map(|(pos, c)| (pos, c.unwrap_or(char::REPLACEMENT_CHARACTER)));
+
+        if is_rtl {
+            let mut chars: Vec<_> = chars
+                .map(|(pos, c)| (pos, mirrored_char(c).unwrap_or(c)))
+                .collect();
+            chars.reverse();
+            self.evaluate_chars(chars.into_iter(), transform, params, glyph_func);
+        } else {
+            self.evaluate_chars(chars, transform, params, glyph_func);
+        }
+    }
+
+    /// Evaluate this font against a sequence of characters in visual order,
+    /// each paired with its position in the source text.
+    fn evaluate_chars<FGlyph>(
+        &self,
+        chars: impl Iterator<Item = (usize, char)>,
         mut transform: Transform,
         params: EvalParameters,
         mut glyph_func: FGlyph,
@@ -344,16 +414,14 @@ impl<'gc> Font<'gc> {
 
         transform.matrix.a = scale;
         transform.matrix.d = scale;
-        let mut char_indices = text.char_indices().peekable();
+        let mut char_indices = chars.peekable();
         let has_kerning_info = self.has_kerning_info();
         let mut x = Twips::ZERO;
         while let Some((pos, c)) = char_indices.next() {
-            let c = c.unwrap_or(char::REPLACEMENT_CHARACTER);
             if let Some(glyph) = self.get_glyph_for_char(c) {
                 let mut advance = glyph.advance();
                 if has_kerning_info && params.kerning {
-                    let next_char = char_indices.peek().cloned().unwrap_or((0, Ok('\0'))).1;
-                    let next_char = next_char.unwrap_or(char::REPLACEMENT_CHARACTER);
+                    let next_char = char_indices.peek().map_or('\0', |(_, c)| *c);
                     advance += self.get_kerning_offset(c, next_char);
                 }
                 let twips_advance =
@@ -552,6 +620,9 @@ pub struct Glyph {
 
     shape: GlyphShape,
     advance: Twips,
+
+    /// The character this glyph represents, used to recover the text of `DefineText` tags.
+    character: char,
 }
 
 impl Glyph {
@@ -569,6 +640,10 @@ impl Glyph {
     pub fn advance(&self) -> Twips {
         self.advance
     }
+
+    pub fn character(&self) -> char {
+        self.character
+    }
 }
 
 /// Structure which identifies a particular font by name and properties.
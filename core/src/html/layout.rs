@@ -11,9 +11,10 @@ use crate::DefaultFont;
 use gc_arena::Collect;
 use ruffle_render::shape_utils::DrawCommand;
 use std::cmp::{max, min};
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 use std::sync::Arc;
 use swf::{Point, Twips};
+use unicode_bidi::{BidiClass, BidiInfo, Level};
 
 /// Draw an underline on a particular drawing.
 ///
@@ -45,6 +46,130 @@ fn draw_underline(
     )));
 }
 
+/// Determine the base embedding level of a paragraph of text.
+///
+/// This follows rules P2 and P3 of the Unicode bidirectional algorithm: the
+/// first strongly directional character in the paragraph decides whether it
+/// runs left-to-right or right-to-left. Paragraphs without any strongly
+/// directional characters are left-to-right.
+pub(super) fn paragraph_level(text: &WStr) -> Level {
+    for c in text.chars() {
+        match c.map(unicode_bidi::bidi_class) {
+            Ok(BidiClass::L) => return Level::ltr(),
+            Ok(BidiClass::R | BidiClass::AL) => return Level::rtl(),
+            Ok(BidiClass::B) => break,
+            _ => {}
+        }
+    }
+
+    Level::ltr()
+}
+
+/// A part of a line of text with a single embedding level, as found by
+/// [`bidi_runs`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct BidiRun {
+    /// The index of the layout box on the line that this run came from.
+    pub box_index: usize,
+
+    /// The part of the box's text in this run, or `None` if the box holds no
+    /// text.
+    pub text: Option<Range<usize>>,
+
+    /// The resolved embedding level of this run.
+    pub level: Level,
+}
+
+/// Split a line of layout boxes into runs of a single box and embedding
+/// level, and return them in visual order.
+///
+/// `boxes` holds the range of `text` in each box on the line, or `None` for
+/// boxes without text, which take part in the algorithm as a single neutral
+/// object replacement character. Returns `None` if the line needs no
+/// reordering.
+pub(super) fn bidi_runs(
+    text: &WStr,
+    boxes: &[Option<Range<usize>>],
+    paragraph_level: Level,
+) -> Option<Vec<BidiRun>> {
+    // Gather the text of the line, recording which box and text position
+    // each character came from.
+    let mut line_text = String::new();
+    let mut chars = Vec::new();
+    for (index, range) in boxes.iter().enumerate() {
+        let box_text = range
+            .clone()
+            .filter(|range| !range.is_empty())
+            .and_then(|range| Some((range.start, text.slice(range)?)));
+
+        if let Some((start, box_text)) = box_text {
+            for (pos, c) in box_text.char_indices() {
+                chars.push((index, Some(start + pos), line_text.len()));
+                line_text.push(c.unwrap_or(char::REPLACEMENT_CHARACTER));
+            }
+        } else {
+            chars.push((index, None, line_text.len()));
+            line_text.push('\u{FFFC}');
+        }
+    }
+
+    if paragraph_level.is_ltr()
+        && !line_text.chars().any(|c| {
+            matches!(
+                unicode_bidi::bidi_class(c),
+                BidiClass::R | BidiClass::AL | BidiClass::RLE | BidiClass::RLO | BidiClass::RLI
+            )
+        })
+    {
+        return None;
+    }
+
+    let bidi_info = BidiInfo::new(&line_text, Some(paragraph_level));
+    let paragraph = bidi_info.paragraphs.first()?;
+    let levels = bidi_info.reordered_levels(paragraph, 0..line_text.len());
+
+    // Split the line into runs of a single box and embedding level, in
+    // logical order.
+    let mut runs: Vec<BidiRun> = Vec::new();
+    for (i, &(box_index, pos, byte)) in chars.iter().enumerate() {
+        let level = levels[byte];
+        let text = pos.map(|pos| {
+            let end = chars
+                .get(i + 1)
+                .filter(|(next_index, _, _)| *next_index == box_index)
+                .and_then(|(_, next_pos, _)| *next_pos)
+                .or_else(|| boxes[box_index].as_ref().map(|range| range.end))
+                .unwrap_or(pos);
+            pos..end
+        });
+
+        match (runs.last_mut(), text) {
+            (Some(run), Some(text))
+                if run.box_index == box_index && run.level == level && run.text.is_some() =>
+            {
+                if let Some(run_text) = &mut run.text {
+                    run_text.end = text.end;
+                }
+            }
+            (_, text) => runs.push(BidiRun {
+                box_index,
+                text,
+                level,
+            }),
+        }
+    }
+
+    let run_levels: Vec<Level> = runs.iter().map(|run| run.level).collect();
+    let visual_order = BidiInfo::reorder_visual(&run_levels);
+
+    Some(
+        visual_order
+            .into_iter()
+            .map(|index| runs[index].clone())
+            .collect(),
+    )
+}
+
 /// Contains information relating to the current layout operation.
 pub struct LayoutContext<'a, 'gc> {
     /// The movie this layout context is pulling fonts from.
@@ -99,10 +224,23 @@ pub struct LayoutContext<'a, 'gc> {
 
     /// The total width of the text field being laid out.
     max_bounds: Twips,
+
+    /// The embedding level forced onto every paragraph, if any.
+    ///
+    /// If `None`, the level of each paragraph is determined from its text.
+    forced_paragraph_level: Option<Level>,
+
+    /// The base embedding level of the paragraph currently being laid out.
+    paragraph_level: Level,
 }
 
 impl<'a, 'gc> LayoutContext<'a, 'gc> {
-    fn new(movie: Arc<SwfMovie>, max_bounds: Twips, text: &'a WStr) -> Self {
+    fn new(
+        movie: Arc<SwfMovie>,
+        max_bounds: Twips,
+        text: &'a WStr,
+        forced_paragraph_level: Option<Level>,
+    ) -> Self {
         Self {
             movie,
             cursor: Default::default(),
@@ -116,6 +254,8 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
             current_line: 0,
             current_line_span: Default::default(),
             max_bounds,
+            forced_paragraph_level,
+            paragraph_level: forced_paragraph_level.unwrap_or_else(Level::ltr),
         }
     }
 
@@ -140,13 +280,105 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
     /// Determine the effective alignment mode for the current line of text.
     ///
     /// This function primarily exists to ensure all bulleted lists are
-    /// left-aligned, as no other alignment is respected otherwise.
+    /// aligned to the side of their bullets, as no other alignment is
+    /// respected otherwise. Left and right alignment are also mirrored within
+    /// right-to-left paragraphs.
     fn effective_alignment(&self) -> swf::TextAlign {
         if self.current_line_span.bullet {
-            swf::TextAlign::Left
+            return if self.paragraph_level.is_rtl() {
+                swf::TextAlign::Right
+            } else {
+                swf::TextAlign::Left
+            };
+        }
+
+        match self.current_line_span.align {
+            swf::TextAlign::Left if self.paragraph_level.is_rtl() => swf::TextAlign::Right,
+            swf::TextAlign::Right if self.paragraph_level.is_rtl() => swf::TextAlign::Left,
+            align => align,
+        }
+    }
+
+    /// Determine the base embedding level of the paragraph starting at the
+    /// current line.
+    fn start_paragraph(&mut self) {
+        self.paragraph_level = if let Some(level) = self.forced_paragraph_level {
+            level
         } else {
-            self.current_line_span.align
+            let paragraph_start = self.boxes.get(self.current_line..).and_then(|line| {
+                line.iter().find_map(|linebox| match linebox.content {
+                    LayoutContent::Text { start, .. } => Some(start),
+                    _ => None,
+                })
+            });
+
+            paragraph_start
+                .and_then(|start| self.text.slice(start..))
+                .map(paragraph_level)
+                .unwrap_or_else(Level::ltr)
+        };
+    }
+
+    /// Apply the Unicode bidirectional algorithm to the current line.
+    ///
+    /// Text boxes are split wherever the resolved embedding level changes,
+    /// and the resulting boxes are then placed again in visual order, starting
+    /// from where the first box on the line was placed. Boxes without text,
+    /// such as drawings, move along with the text around them. Lines that
+    /// contain no right-to-left text are left untouched.
+    fn reorder_line(&mut self) {
+        let Some(line) = self.boxes.get(self.current_line..) else {
+            return;
+        };
+
+        let ranges: Vec<_> = line
+            .iter()
+            .map(|linebox| match linebox.content {
+                LayoutContent::Text { start, end, .. } => Some(start..end),
+                _ => None,
+            })
+            .collect();
+        let Some(runs) = bidi_runs(self.text, &ranges, self.paragraph_level) else {
+            return;
+        };
+
+        let mut cursor_x = line
+            .first()
+            .map(|linebox| linebox.bounds.offset_x())
+            .unwrap_or_default();
+        let mut reordered = Vec::with_capacity(runs.len());
+        for run in runs {
+            let mut new_box = line[run.box_index].clone();
+            let mut width = new_box.bounds.width();
+
+            if let (
+                LayoutContent::Text {
+                    start,
+                    end,
+                    font,
+                    params,
+                    is_rtl,
+                    ..
+                },
+                Some(range),
+            ) = (&mut new_box.content, run.text)
+            {
+                *start = range.start;
+                *end = range.end;
+                *is_rtl = run.level.is_rtl();
+                width = font.measure(&self.text[range], *params, false).0;
+            }
+
+            new_box.bounds = BoxBounds::from_position_and_size(
+                Position::from((cursor_x, new_box.bounds.offset_y())),
+                Size::from((width, new_box.bounds.height())),
+            );
+            cursor_x += width;
+            reordered.push(new_box);
         }
+
+        self.boxes.truncate(self.current_line);
+        self.boxes.extend(reordered);
     }
 
     /// Construct an underline drawing for the current line of text and add it
@@ -255,6 +487,11 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
             return;
         }
 
+        if self.is_first_line {
+            self.start_paragraph();
+        }
+        self.reorder_line();
+
         let mut line_bounds = None;
         let mut box_count: i32 = 0;
         for linebox in self.boxes.get_mut(self.current_line..).unwrap() {
@@ -262,7 +499,8 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
                 linebox.as_renderable_text(self.text).expect("text");
 
             //Flash ignores trailing spaces when aligning lines, so should we
-            if self.current_line_span.align != swf::TextAlign::Left {
+            if self.current_line_span.align != swf::TextAlign::Left || self.paragraph_level.is_rtl()
+            {
                 linebox.bounds = linebox
                     .bounds
                     .with_size(font.measure(text.trim_end(), params, false).into());
@@ -279,14 +517,28 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
 
         let mut line_bounds = line_bounds.unwrap_or_default();
 
-        let left_adjustment =
+        let mut left_adjustment =
             Self::left_alignment_offset(&self.current_line_span, self.is_first_line);
-        let right_adjustment = Twips::from_pixels(self.current_line_span.right_margin);
+        let mut right_adjustment = Twips::from_pixels(self.current_line_span.right_margin);
+        if self.current_line_span.bullet && self.paragraph_level.is_rtl() {
+            // Bullets of right-to-left paragraphs sit on the right, so the
+            // space left for them moves over to that side.
+            let bullet_indent = left_adjustment
+                - Self::left_alignment_offset_without_bullet(
+                    &self.current_line_span,
+                    self.is_first_line,
+                );
+            left_adjustment -= bullet_indent;
+            right_adjustment += bullet_indent;
+        }
 
         let misalignment =
             self.max_bounds - left_adjustment - right_adjustment - line_bounds.width();
         let align_adjustment = max(
             match self.effective_alignment() {
+                swf::TextAlign::Justify if final_line_of_para && self.paragraph_level.is_rtl() => {
+                    misalignment
+                }
                 swf::TextAlign::Left | swf::TextAlign::Justify => Default::default(),
                 swf::TextAlign::Center => (misalignment) / 2,
                 swf::TextAlign::Right => misalignment,
@@ -562,9 +814,10 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
 
     /// Append a bullet to the start of the current line.
     ///
-    /// The bullet will always be placed at the start of the current line. It
-    /// should be appended after line fixup has completed, but before the text
-    /// cursor is moved down.
+    /// The bullet will always be placed at the start of the current line,
+    /// which is its right edge in right-to-left paragraphs. It should be
+    /// appended after line fixup has completed, but before the text cursor is
+    /// moved down.
     fn append_bullet(
         &mut self,
         context: &mut UpdateContext<'_, 'gc>,
@@ -575,16 +828,23 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
             .resolve_font(context, span, is_device_font)
             .or(self.font)
         {
-            let mut bullet_cursor = self.cursor;
-
-            bullet_cursor.set_x(
-                Twips::from_pixels(18.0)
-                    + Self::left_alignment_offset_without_bullet(span, self.is_first_line),
-            );
-
             let params = EvalParameters::from_span(span);
             let bullet = WStr::from_units(&[0x2022u16]);
             let text_size = Size::from(bullet_font.measure(bullet, params, false));
+
+            let mut bullet_cursor = self.cursor;
+            if self.paragraph_level.is_rtl() {
+                bullet_cursor.set_x(
+                    self.max_bounds
+                        - Twips::from_pixels(18.0 + span.right_margin)
+                        - text_size.width(),
+                );
+            } else {
+                bullet_cursor.set_x(
+                    Twips::from_pixels(18.0)
+                        + Self::left_alignment_offset_without_bullet(span, self.is_first_line),
+                );
+            }
             let text_bounds = BoxBounds::from_position_and_size(bullet_cursor, text_size);
             let mut new_bullet = LayoutBox::from_bullet(bullet_font, span);
 
@@ -715,6 +975,10 @@ pub enum LayoutContent<'gc> {
         /// The color to render the font with.
         #[collect(require_static)]
         color: swf::Color,
+
+        /// Whether the text runs right-to-left, as resolved by the Unicode
+        /// bidirectional algorithm.
+        is_rtl: bool,
     },
 
     /// A layout box containing a bullet.
@@ -761,6 +1025,7 @@ impl<'gc> LayoutBox<'gc> {
                 font,
                 params,
                 color: span.color,
+                is_rtl: false,
             },
         }
     }
@@ -792,6 +1057,10 @@ impl<'gc> LayoutBox<'gc> {
     ///
     /// The returned bounds will include both the text bounds itself, as well
     /// as left and right margins on any of the lines.
+    ///
+    /// If `bidi_level` is given, it is used as the base embedding level of
+    /// every paragraph; otherwise, each paragraph's direction is determined
+    /// from its first strongly directional character.
     pub fn lower_from_text_spans(
         fs: &FormatSpans,
         context: &mut UpdateContext<'_, 'gc>,
@@ -799,8 +1068,14 @@ impl<'gc> LayoutBox<'gc> {
         bounds: Twips,
         is_word_wrap: bool,
         is_device_font: bool,
+        bidi_level: Option<u8>,
     ) -> (Vec<LayoutBox<'gc>>, BoxBounds<Twips>) {
-        let mut layout_context = LayoutContext::new(movie, bounds, fs.displayed_text());
+        let mut layout_context = LayoutContext::new(
+            movie,
+            bounds,
+            fs.displayed_text(),
+            bidi_level.and_then(|level| Level::new(level).ok()),
+        );
 
         for (span_start, _end, span_text, span) in fs.iter_spans() {
             if let Some(font) = layout_context.resolve_font(context, span, is_device_font) {
@@ -955,6 +1230,7 @@ impl<'gc> LayoutBox<'gc> {
                 font,
                 params,
                 color,
+                ..
             } => Some((
                 text.slice(*start..*end)?,
                 text_format,
@@ -994,6 +1270,11 @@ impl<'gc> LayoutBox<'gc> {
     pub fn is_bullet(&self) -> bool {
         matches!(&self.content, LayoutContent::Bullet { .. })
     }
+
+    /// Whether this box holds text that runs right-to-left.
+    pub fn is_rtl(&self) -> bool {
+        matches!(&self.content, LayoutContent::Text { is_rtl: true, .. })
+    }
 }
 
 pub struct LayoutMetrics {
@@ -1,9 +1,11 @@
 //! Tests for HTML module
 
 use crate::html::dimensions::{BoxBounds, Position, Size};
+use crate::html::layout::{bidi_runs, paragraph_level, BidiRun};
 use crate::html::text_format::{FormatSpans, TextFormat, TextSpan};
 use crate::string::{WStr, WString};
 use swf::{Rectangle, Twips};
+use unicode_bidi::Level;
 
 #[test]
 fn position_add() {
@@ -864,3 +866,140 @@ fn formatspans_replace_text_degenerate() {
     assert_eq!((0, 1), fs.get_span_boundaries(0, 5));
     assert_eq!((1, 2), fs.get_span_boundaries(5, 9));
 }
+
+#[test]
+fn formatspans_from_html_keeps_white() {
+    let fs = FormatSpans::from_html(
+        WStr::from_units(b"<p>a  b\n\tc</p>"),
+        TextFormat::default(),
+        true,
+        false,
+    );
+
+    assert_eq!(fs.text(), WStr::from_units(b"a  b\n\tc\n"));
+}
+
+#[test]
+fn formatspans_from_html_condense_white() {
+    let fs = FormatSpans::from_html(
+        WStr::from_units(b"  <p>\n  Hello,\n\t <b>world</b>  </p>\n<p>  again</p>"),
+        TextFormat::default(),
+        true,
+        true,
+    );
+
+    assert_eq!(fs.text(), WStr::from_units(b"Hello, world \nagain\n"));
+}
+
+#[test]
+fn paragraph_level_ltr() {
+    assert!(paragraph_level(WStr::from_units(b"Hello, world")).is_ltr());
+}
+
+#[test]
+fn paragraph_level_rtl() {
+    let text = WString::from_utf8("\u{5E9}\u{5DC}\u{5D5}\u{5DD} world");
+    assert!(paragraph_level(&text).is_rtl());
+}
+
+#[test]
+fn paragraph_level_skips_neutrals() {
+    let text = WString::from_utf8("123 (\u{627}\u{644}\u{639}\u{631}\u{628}\u{64A}\u{629})");
+    assert!(paragraph_level(&text).is_rtl());
+}
+
+#[test]
+fn paragraph_level_no_strong_characters() {
+    assert!(paragraph_level(WStr::from_units(b"123 !?")).is_ltr());
+}
+
+/// Shorthand for the runs returned by `bidi_runs`.
+fn run(box_index: usize, text: Option<std::ops::Range<usize>>, level: u8) -> BidiRun {
+    BidiRun {
+        box_index,
+        text,
+        level: Level::new(level).unwrap(),
+    }
+}
+
+#[test]
+fn bidi_runs_ltr_line() {
+    let text = WStr::from_units(b"Hello, world");
+    assert_eq!(
+        bidi_runs(text, &[Some(0..5), Some(5..12)], Level::ltr()),
+        None
+    );
+}
+
+#[test]
+fn bidi_runs_rtl_paragraph() {
+    // Hebrew "shalom olam", in two boxes.
+    let text = WString::from_utf8("\u{5E9}\u{5DC}\u{5D5}\u{5DD} \u{5E2}\u{5D5}\u{5DC}\u{5DD}");
+    assert_eq!(
+        bidi_runs(&text, &[Some(0..5), Some(5..9)], Level::rtl()),
+        Some(vec![run(1, Some(5..9), 1), run(0, Some(0..5), 1)])
+    );
+}
+
+#[test]
+fn bidi_runs_mixed_text_in_one_box() {
+    // "abc ", then Hebrew, then " def".
+    let text = WString::from_utf8("abc \u{5D0}\u{5D1}\u{5D2} def");
+    assert_eq!(
+        bidi_runs(&text, &[Some(0..11)], Level::ltr()),
+        Some(vec![
+            run(0, Some(0..4), 0),
+            run(0, Some(4..7), 1),
+            run(0, Some(7..11), 0)
+        ])
+    );
+}
+
+#[test]
+fn bidi_runs_numbers_in_rtl_paragraph() {
+    // Numbers stay left-to-right inside right-to-left text.
+    let text = WString::from_utf8("\u{5D0} 123 \u{5D1}");
+    assert_eq!(
+        bidi_runs(&text, &[Some(0..7)], Level::rtl()),
+        Some(vec![
+            run(0, Some(5..7), 1),
+            run(0, Some(2..5), 2),
+            run(0, Some(0..2), 1)
+        ])
+    );
+}
+
+#[test]
+fn bidi_runs_boxes_without_text() {
+    // A drawing between two Hebrew words moves along with them, and an
+    // empty text box is treated the same way.
+    let text = WString::from_utf8("\u{5D0}\u{5D1}\u{5D2}\u{5D3}");
+    assert_eq!(
+        bidi_runs(
+            &text,
+            &[Some(0..2), None, Some(2..4), Some(4..4)],
+            Level::rtl()
+        ),
+        Some(vec![
+            run(3, None, 1),
+            run(2, Some(2..4), 1),
+            run(1, None, 1),
+            run(0, Some(0..2), 1)
+        ])
+    );
+}
+
+#[test]
+fn bidi_runs_ltr_paragraph_with_drawing() {
+    // Neutral objects between left-to-right and right-to-left text take the
+    // paragraph direction.
+    let text = WString::from_utf8("ab\u{5D0}\u{5D1}");
+    assert_eq!(
+        bidi_runs(&text, &[Some(0..2), None, Some(2..4)], Level::ltr()),
+        Some(vec![
+            run(0, Some(0..2), 0),
+            run(1, None, 0),
+            run(2, Some(2..4), 1)
+        ])
+    );
+}
@@ -98,6 +98,28 @@ fn process_html_entity(src: &WStr) -> Option<WString> {
     Some(result_str)
 }
 
+/// Appends `src` to `text`, collapsing each run of whitespace into a single space.
+///
+/// Runs continue across calls, and whitespace at the start of a line is dropped.
+/// Returns the number of units appended.
+fn push_condensed_white(text: &mut WString, src: &WStr) -> usize {
+    let start_len = text.len();
+    for ch in src.iter() {
+        if matches!(u8::try_from(ch), Ok(b' ' | b'\t' | b'\n' | b'\r')) {
+            let follows_white_or_line_start = match text.len().checked_sub(1) {
+                Some(last) => text.at(last) == b' ' as u16 || text.at(last) == b'\n' as u16,
+                None => true,
+            };
+            if !follows_white_or_line_start {
+                text.push_byte(b' ');
+            }
+        } else {
+            text.push(ch);
+        }
+    }
+    text.len() - start_len
+}
+
 /// A set of text formatting options to be applied to some part, or the whole
 /// of, a given text field.
 ///
@@ -566,7 +588,16 @@ impl FormatSpans {
     /// a handful of presentational attributes in the HTML tree to generate
     /// styling. There's also a `lower_from_css` that respects both
     /// presentational markup and CSS stylesheets.
-    pub fn from_html(html: &WStr, default_format: TextFormat, is_multiline: bool) -> Self {
+    ///
+    /// If `condense_white` is set, runs of whitespace in the text content are
+    /// collapsed into a single space, and whitespace at the start of a line is
+    /// removed, as with `TextField.condenseWhite`.
+    pub fn from_html(
+        html: &WStr,
+        default_format: TextFormat,
+        is_multiline: bool,
+        condense_white: bool,
+    ) -> Self {
         let mut format_stack = vec![default_format.clone()];
         let mut text = WString::new();
         let mut spans: Vec<TextSpan> = Vec::new();
@@ -772,8 +803,16 @@ impl FormatSpans {
                     let e = decode_to_wstr(&e.into_inner());
                     let e = process_html_entity(&e).unwrap_or(e);
                     let format = format_stack.last().unwrap().clone();
-                    text.push_str(&e);
-                    spans.push(TextSpan::with_length_and_format(e.len(), format));
+                    if condense_white {
+                        // Text that condenses away entirely doesn't get a span.
+                        let length = push_condensed_white(&mut text, &e);
+                        if length > 0 {
+                            spans.push(TextSpan::with_length_and_format(length, format));
+                        }
+                    } else {
+                        text.push_str(&e);
+                        spans.push(TextSpan::with_length_and_format(e.len(), format));
+                    }
                 }
                 Ok(Event::End(e)) => {
                     // Check for a mismatch.
@@ -45,10 +45,12 @@ mod net_connection;
 pub mod pixel_bender;
 mod player;
 mod prelude;
+mod printing;
 pub mod socket;
 mod streams;
 pub mod string;
 pub mod tag_utils;
+mod text_snapshot;
 pub mod timer;
 mod types;
 mod vminterface;
@@ -59,6 +61,8 @@ pub mod compatibility_rules;
 pub mod config;
 #[cfg(feature = "egui")]
 pub mod debug_ui;
+#[cfg(feature = "debugger")]
+pub mod debugger;
 pub mod external;
 pub mod i18n;
 pub mod stub;
@@ -267,6 +267,23 @@ impl<'gc> LoadManager<'gc> {
         self.0.get_mut(handle)
     }
 
+    /// Cancel any in-progress movie load into the given AVM2 `LoaderInfo`.
+    ///
+    /// The loader's future will notice that its handle is gone and stop with
+    /// `Error::Cancelled`. Loads that have already completed are left alone.
+    pub fn cancel_avm2_movie_load(&mut self, target_loader_info: Avm2Object<'gc>) {
+        self.0.retain(|_, loader| {
+            !matches!(
+                loader,
+                Loader::Movie {
+                    vm_data: MovieLoaderVMData::Avm2 { loader_info, .. },
+                    loader_status: LoaderStatus::Pending | LoaderStatus::Parsing,
+                    ..
+                } if Avm2Object::ptr_eq(*loader_info, target_loader_info)
+            )
+        });
+    }
+
     /// Kick off the root movie load.
     ///
     /// The root movie is special because it determines a few bits of player
@@ -2047,12 +2064,16 @@ impl<'gc> Loader<'gc> {
             }
         }
 
-        if let Loader::Movie { loader_status, .. } = uc.load_manager.get_loader_mut(handle).unwrap()
-        {
-            *loader_status = LoaderStatus::Succeeded;
-        };
-
-        Ok(())
+        // A script event handler may have called `Loader.close()`, which
+        // removes the loader before we get here.
+        match uc.load_manager.get_loader_mut(handle) {
+            Some(Loader::Movie { loader_status, .. }) => {
+                *loader_status = LoaderStatus::Succeeded;
+                Ok(())
+            }
+            None => Err(Error::Cancelled),
+            _ => unreachable!(),
+        }
     }
 
     /// Report a movie loader error to script code.
@@ -2144,12 +2165,16 @@ impl<'gc> Loader<'gc> {
             }
         }
 
-        if let Loader::Movie { loader_status, .. } = uc.load_manager.get_loader_mut(handle).unwrap()
-        {
-            *loader_status = LoaderStatus::Failed;
-        };
-
-        Ok(())
+        // A script event handler may have called `Loader.close()`, which
+        // removes the loader before we get here.
+        match uc.load_manager.get_loader_mut(handle) {
+            Some(Loader::Movie { loader_status, .. }) => {
+                *loader_status = LoaderStatus::Failed;
+                Ok(())
+            }
+            None => Err(Error::Cancelled),
+            _ => unreachable!(),
+        }
     }
 
     /// This makes the MovieClip enter the initial loading state in which some
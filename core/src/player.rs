@@ -8,16 +8,17 @@ use crate::avm1::{Activation, ActivationIdentifier};
 use crate::avm1::{ScriptObject, TObject, Value};
 use crate::avm2::api_version::ApiVersion;
 use crate::avm2::{
-    object::LoaderInfoObject, object::TObject as _, Activation as Avm2Activation, Avm2, CallStack,
-    Object as Avm2Object,
+    object::LoaderInfoObject, object::TObject as _, run_pending_shader_jobs,
+    Activation as Avm2Activation, Avm2, CallStack, Object as Avm2Object,
 };
 use crate::backend::ui::FontDefinition;
 use crate::backend::{
     audio::{AudioBackend, AudioManager},
     log::LogBackend,
     navigator::{NavigatorBackend, Request},
+    print::PrintBackend,
     storage::StorageBackend,
-    ui::{InputManager, MouseCursor, UiBackend},
+    ui::{InputManager, MouseCursor, MouseCursorManager, UiBackend},
 };
 use crate::compatibility_rules::CompatibilityRules;
 use crate::config::Letterbox;
@@ -26,6 +27,8 @@ use crate::context::{ActionQueue, ActionType, RenderContext, UpdateContext};
 use crate::context_menu::{
     BuiltInItemFlags, ContextMenuCallback, ContextMenuItem, ContextMenuState,
 };
+#[cfg(feature = "debugger")]
+use crate::debugger::Debugger;
 use crate::display_object::Avm2MousePick;
 use crate::display_object::{
     EditText, InteractiveObject, MovieClip, Stage, StageAlign, StageDisplayState, StageScaleMode,
@@ -42,6 +45,7 @@ use crate::loader::{LoadBehavior, LoadManager};
 use crate::locale::get_current_date_time;
 use crate::net_connection::NetConnections;
 use crate::prelude::*;
+use crate::printing::PrintJob;
 use crate::socket::Sockets;
 use crate::streams::StreamManager;
 use crate::string::{AvmString, AvmStringInterner};
@@ -231,6 +235,7 @@ type GcArena = gc_arena::Arena<Rootable![GcRoot<'_>]>;
 
 type Audio = Box<dyn AudioBackend>;
 type Navigator = Box<dyn NavigatorBackend>;
+type Print = Box<dyn PrintBackend>;
 type Renderer = Box<dyn RenderBackend>;
 type Storage = Box<dyn StorageBackend>;
 type Log = Box<dyn LogBackend>;
@@ -267,6 +272,7 @@ pub struct Player {
     log: Log,
     ui: Ui,
     video: Video,
+    print: Print,
 
     transform_stack: TransformStack,
 
@@ -297,10 +303,13 @@ pub struct Player {
     mouse_in_stage: bool,
     mouse_position: Point<Twips>,
 
-    /// The current mouse cursor icon.
-    mouse_cursor: MouseCursor,
+    /// The current mouse cursor icon, and any cursors requested by the movie.
+    mouse_cursors: MouseCursorManager,
     mouse_cursor_needs_check: bool,
 
+    /// The print job currently being assembled by the movie, if any.
+    print_job: Option<PrintJob>,
+
     system: SystemProperties,
 
     page_url: Option<String>,
@@ -342,6 +351,10 @@ pub struct Player {
     /// Debug UI windows
     #[cfg(feature = "egui")]
     debug_ui: Rc<RefCell<crate::debug_ui::DebugUi>>,
+
+    /// The ActionScript debugger we're connected to, if any.
+    #[cfg(feature = "debugger")]
+    debugger: Option<Debugger>,
 }
 
 impl Player {
@@ -1298,7 +1311,7 @@ impl Player {
 
     /// Updates the hover state of buttons.
     fn update_mouse_state(&mut self, is_mouse_button_changed: bool, is_mouse_moved: bool) -> bool {
-        let mut new_cursor = self.mouse_cursor;
+        let mut new_cursor = self.mouse_cursors.automatic_cursor();
         let mut mouse_cursor_needs_check = self.mouse_cursor_needs_check;
         let mouse_in_stage = self.mouse_in_stage();
 
@@ -1544,10 +1557,8 @@ impl Player {
         });
 
         // Update mouse cursor if it has changed.
-        if new_cursor != self.mouse_cursor {
-            self.mouse_cursor = new_cursor;
-            self.ui.set_mouse_cursor(new_cursor)
-        }
+        self.mouse_cursors
+            .set_automatic_cursor(new_cursor, self.ui.deref_mut());
         self.mouse_cursor_needs_check = mouse_cursor_needs_check;
 
         needs_render
@@ -1659,8 +1670,14 @@ impl Player {
             return;
         }
 
+        #[cfg(feature = "debugger")]
+        if let Some(debugger) = &mut self.debugger {
+            debugger.poll();
+        }
+
         self.update(|context| {
             // TODO: Is this order correct?
+            run_pending_shader_jobs(context);
             run_all_phases_avm2(context);
             Avm1::run_frame(context);
             AudioManager::update_sounds(context);
@@ -1926,6 +1943,7 @@ impl Player {
                 mouse_down_object: mouse_pressed_object,
                 input: &self.input,
                 mouse_position: &self.mouse_position,
+                mouse_cursors: &mut self.mouse_cursors,
                 drag_object,
                 player: self.self_reference.clone(),
                 load_manager,
@@ -1935,6 +1953,8 @@ impl Player {
                 storage: self.storage.deref_mut(),
                 log: self.log.deref_mut(),
                 video: self.video.deref_mut(),
+                print: self.print.deref_mut(),
+                print_job: &mut self.print_job,
                 avm1_shared_objects,
                 avm2_shared_objects,
                 unbound_text_fields,
@@ -1960,6 +1980,8 @@ impl Player {
                 sockets,
                 net_connections,
                 dynamic_root,
+                #[cfg(feature = "debugger")]
+                debugger: &mut self.debugger,
             };
 
             let prev_frame_rate = *update_context.frame_rate;
@@ -2034,11 +2056,43 @@ impl Player {
         self.update_mouse_state(false, false);
 
         // GC
-        self.gc_arena.borrow_mut().collect_debt();
+        self.collect_garbage(false);
 
         rval
     }
 
+    /// Pays off the current allocation debt of the garbage collector, or
+    /// runs a whole collection cycle if `full` is set.
+    pub(crate) fn collect_garbage(&mut self, full: bool) {
+        let mut gc_arena = self.gc_arena.borrow_mut();
+
+        // Weak dictionaries only keep their values alive while the keys are
+        // reachable, which can only be known once marking has finished. Every
+        // value they resurrect may make more keys reachable, so this has to
+        // be repeated until nothing else gets resurrected.
+        loop {
+            let marked = if full {
+                gc_arena.mark_all()
+            } else {
+                gc_arena.mark_debt()
+            };
+            let Some(marked) = marked else {
+                break;
+            };
+            let resurrected =
+                marked.finalize(|fc, root| root.data.write(fc).avm2.finalize_weak_dictionaries(fc));
+            if !resurrected {
+                break;
+            }
+        }
+
+        if full {
+            gc_arena.collect_all();
+        } else {
+            gc_arena.collect_debt();
+        }
+    }
+
     pub fn flush_shared_objects(&mut self) {
         self.update(|context| {
             if let Some(mut avm1_activation) =
@@ -2165,6 +2219,7 @@ pub struct PlayerBuilder {
     audio: Option<Audio>,
     log: Option<Log>,
     navigator: Option<Navigator>,
+    print: Option<Print>,
     renderer: Option<Renderer>,
     storage: Option<Storage>,
     ui: Option<Ui>,
@@ -2193,6 +2248,8 @@ pub struct PlayerBuilder {
     frame_rate: Option<f64>,
     external_interface_providers: Vec<Box<dyn ExternalInterfaceProvider>>,
     fs_command_provider: Box<dyn FsCommandProvider>,
+    #[cfg(feature = "debugger")]
+    debugger: Option<Debugger>,
 }
 
 impl PlayerBuilder {
@@ -2208,6 +2265,7 @@ impl PlayerBuilder {
             audio: None,
             log: None,
             navigator: None,
+            print: None,
             renderer: None,
             storage: None,
             ui: None,
@@ -2240,6 +2298,8 @@ impl PlayerBuilder {
             frame_rate: None,
             external_interface_providers: vec![],
             fs_command_provider: Box::new(NullFsCommandProvider),
+            #[cfg(feature = "debugger")]
+            debugger: None,
         }
     }
 
@@ -2271,6 +2331,13 @@ impl PlayerBuilder {
         self
     }
 
+    /// Sets the print backend of the player.
+    #[inline]
+    pub fn with_print(mut self, print: impl 'static + PrintBackend) -> Self {
+        self.print = Some(Box::new(print));
+        self
+    }
+
     /// Sets the rendering backend of the player.
     #[inline]
     pub fn with_renderer(mut self, renderer: impl 'static + RenderBackend) -> Self {
@@ -2429,6 +2496,13 @@ impl PlayerBuilder {
         self
     }
 
+    /// Connects the player to an ActionScript debugger.
+    #[cfg(feature = "debugger")]
+    pub fn with_debugger(mut self, debugger: Debugger) -> Self {
+        self.debugger = Some(debugger);
+        self
+    }
+
     fn create_gc_root<'gc>(
         gc_context: &'gc gc_arena::Mutation<'gc>,
         player_version: u8,
@@ -2492,6 +2566,9 @@ impl PlayerBuilder {
         let navigator = self
             .navigator
             .unwrap_or_else(|| Box::new(navigator::NullNavigatorBackend::new()));
+        let print = self
+            .print
+            .unwrap_or_else(|| Box::new(print::NullPrintBackend::new()));
         let renderer = self.renderer.unwrap_or_else(|| {
             Box::new(NullRenderer::new(ViewportDimensions {
                 width: self.viewport_width,
@@ -2525,6 +2602,7 @@ impl PlayerBuilder {
                 storage,
                 ui,
                 video,
+                print,
 
                 // SWF info
                 swf: fake_movie.clone(),
@@ -2546,8 +2624,9 @@ impl PlayerBuilder {
                 input: Default::default(),
                 mouse_in_stage: true,
                 mouse_position: Point::ZERO,
-                mouse_cursor: MouseCursor::Arrow,
+                mouse_cursors: MouseCursorManager::new(),
                 mouse_cursor_needs_check: false,
+                print_job: None,
 
                 // Misc. state
                 rng: SmallRng::seed_from_u64(get_current_date_time().timestamp_millis() as u64),
@@ -2566,6 +2645,8 @@ impl PlayerBuilder {
                 stub_tracker: StubCollection::new(),
                 #[cfg(feature = "egui")]
                 debug_ui: Default::default(),
+                #[cfg(feature = "debugger")]
+                debugger: self.debugger,
 
                 // GC data
                 gc_arena: Rc::new(RefCell::new(GcArena::new(|gc_context| {
@@ -0,0 +1,165 @@
+//! Print jobs, shared between AVM1 and AVM2.
+
+use crate::backend::print::{PaperSize, PrintPage};
+use crate::bitmap::bitmap_data::{
+    BitmapData, BitmapDataDrawError, BitmapDataWrapper, IBitmapDrawable,
+};
+use crate::bitmap::operations;
+use crate::context::UpdateContext;
+use crate::display_object::MovieClip;
+use crate::prelude::*;
+use gc_arena::GcCell;
+use ruffle_render::transform::Transform;
+use swf::BlendMode;
+
+/// A print job that pages are being added to.
+///
+/// Flash Player only allows a single print job to be active at a time, so the player holds
+/// at most one of these.
+pub struct PrintJob {
+    paper: PaperSize,
+    pages: Vec<PrintPage>,
+}
+
+impl PrintJob {
+    /// Starts a new print job, asking the print backend for the paper to print on.
+    ///
+    /// Any job that was started but never sent is discarded. Returns `None` if the user
+    /// cancelled the job.
+    pub fn start(context: &mut UpdateContext<'_, '_>) -> Option<PaperSize> {
+        *context.print_job = None;
+        let paper = context.print.start_job()?;
+        *context.print_job = Some(PrintJob {
+            paper,
+            pages: Vec::new(),
+        });
+        Some(paper)
+    }
+
+    /// Rasterises `target` and adds it to the active print job as a new page.
+    ///
+    /// `area` is the region of `target` to print, in its local coordinate space. When it
+    /// isn't given, the printable area of the paper is used, starting at the registration
+    /// point of `target`. If `frame` is given, that frame of the target clip is printed
+    /// instead of the current one, without running any of its scripts.
+    ///
+    /// Returns `false` if no print job is active.
+    pub fn add_page<'gc>(
+        context: &mut UpdateContext<'_, 'gc>,
+        target: DisplayObject<'gc>,
+        area: Option<Rectangle<Twips>>,
+        frame: Option<u16>,
+    ) -> bool {
+        let Some(paper) = context.print_job.as_ref().map(|job| job.paper) else {
+            return false;
+        };
+
+        // Scripts never see the frame being printed, so it's rendered from a detached copy
+        // of the clip instead of moving the clip itself.
+        let checkpoint = context.action_queue.checkpoint();
+        let copy = match (target.as_movie_clip(), frame) {
+            (Some(clip), Some(frame)) if frame != clip.current_frame() => {
+                Some(frame_copy(context, clip, frame))
+            }
+            _ => None,
+        };
+
+        let area = area.unwrap_or_else(|| Rectangle {
+            x_min: Twips::ZERO,
+            y_min: Twips::ZERO,
+            x_max: Twips::from_pixels(paper.page_width.into()),
+            y_max: Twips::from_pixels(paper.page_height.into()),
+        });
+        let page = rasterize(context, copy.map_or(target, Into::into), area, paper);
+
+        if let Some(copy) = copy {
+            release_copy(context, copy);
+        }
+        context.action_queue.discard_since(checkpoint);
+
+        if let Some(job) = context.print_job {
+            job.pages.push(page);
+        }
+        true
+    }
+
+    /// Sends the pages of the active print job to the print backend.
+    ///
+    /// Returns `false` if no print job is active.
+    pub fn send(context: &mut UpdateContext<'_, '_>) -> bool {
+        if let Some(job) = context.print_job.take() {
+            context.print.print(job.pages);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Creates a copy of `clip` that is stopped on `frame`, without running any ActionScript.
+///
+/// Anything the copy queues up on the AVM1 action queue is left for the caller to discard,
+/// and the copy should be passed to [`release_copy`] once it has been printed.
+pub fn frame_copy<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    clip: MovieClip<'gc>,
+    frame: u16,
+) -> MovieClip<'gc> {
+    let copy = clip.fresh_copy(context.gc_context);
+    if clip.movie().is_action_script_3() {
+        copy.goto_frame_detached(context, frame);
+    } else {
+        // Like any newly placed clip, the copy enters its first frame before it can move.
+        copy.run_frame_avm1(context);
+        copy.goto_frame(context, frame, true);
+    }
+    copy
+}
+
+/// Cleans up after a copy made by [`frame_copy`].
+pub fn release_copy<'gc>(context: &mut UpdateContext<'_, 'gc>, copy: MovieClip<'gc>) {
+    if !copy.movie().is_action_script_3() {
+        copy.avm1_unload(context);
+    }
+    context.stop_sounds_with_display_object(copy.into());
+}
+
+/// Renders `area` of `target` onto a white page, clipped to the printable area of the paper.
+fn rasterize<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    target: DisplayObject<'gc>,
+    area: Rectangle<Twips>,
+    paper: PaperSize,
+) -> PrintPage {
+    let width = (area.width().to_pixels().ceil().max(1.0) as u32).min(paper.page_width);
+    let height = (area.height().to_pixels().ceil().max(1.0) as u32).min(paper.page_height);
+
+    let page = BitmapDataWrapper::new(GcCell::new(
+        context.gc_context,
+        BitmapData::new(width, height, false, 0xFFFFFFFF),
+    ));
+    let transform = Transform {
+        matrix: Matrix::translate(-area.x_min, -area.y_min),
+        ..Default::default()
+    };
+    let quality = context.stage.quality();
+    if let Err(BitmapDataDrawError::Unimplemented) = operations::draw(
+        context,
+        page,
+        IBitmapDrawable::DisplayObject(target),
+        transform,
+        true,
+        BlendMode::Normal,
+        None,
+        quality,
+    ) {
+        tracing::warn!("Render backend does not support printing, the page will be blank");
+    }
+
+    let rgba = page.sync().read().pixels_rgba();
+    PrintPage {
+        width,
+        height,
+        rgba,
+    }
+}
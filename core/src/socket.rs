@@ -4,8 +4,8 @@ use crate::{
         ExecutionReason, Object as Avm1Object, TObject as Avm1TObject,
     },
     avm2::{
-        object::SocketObject, Activation as Avm2Activation, Avm2, EventObject,
-        TObject as Avm2TObject,
+        object::SocketObject, Activation as Avm2Activation, Avm2, Domain as Avm2Domain,
+        EventObject, TObject as Avm2TObject,
     },
     backend::navigator::NavigatorBackend,
     context::UpdateContext,
@@ -34,13 +34,22 @@ enum SocketKind<'gc> {
 struct Socket<'gc> {
     target: SocketKind<'gc>,
     sender: RefCell<AsyncSender<Vec<u8>>>,
+
+    /// The application domain of the code that opened this socket, if it was
+    /// opened from AVM2.
+    domain: Option<Avm2Domain<'gc>>,
 }
 
 impl<'gc> Socket<'gc> {
-    fn new(target: SocketKind<'gc>, sender: AsyncSender<Vec<u8>>) -> Self {
+    fn new(
+        target: SocketKind<'gc>,
+        sender: AsyncSender<Vec<u8>>,
+        domain: Option<Avm2Domain<'gc>>,
+    ) -> Self {
         Self {
             target,
             sender: RefCell::new(sender),
+            domain,
         }
     }
 }
@@ -90,12 +99,13 @@ impl<'gc> Sockets<'gc> {
         &mut self,
         backend: &mut dyn NavigatorBackend,
         target: SocketObject<'gc>,
+        domain: Option<Avm2Domain<'gc>>,
         host: String,
         port: u16,
     ) {
         let (sender, receiver) = unbounded();
 
-        let socket = Socket::new(SocketKind::Avm2(target), sender);
+        let socket = Socket::new(SocketKind::Avm2(target), sender, domain);
         let handle = self.sockets.insert(socket);
 
         // NOTE: This call will send SocketAction::Connect to sender with connection status.
@@ -129,7 +139,7 @@ impl<'gc> Sockets<'gc> {
             None => return,
         };
 
-        let socket = Socket::new(SocketKind::Avm1(target), sender);
+        let socket = Socket::new(SocketKind::Avm1(target), sender, None);
         let handle = self.sockets.insert(socket);
 
         // NOTE: This call will send SocketAction::Connect to sender with connection status.
@@ -164,7 +174,7 @@ impl<'gc> Sockets<'gc> {
     }
 
     pub fn close(&mut self, handle: SocketHandle) {
-        if let Some(Socket { sender, target }) = self.sockets.remove(handle) {
+        if let Some(Socket { sender, target, .. }) = self.sockets.remove(handle) {
             drop(sender); // NOTE: By dropping the sender, the reading task will close automatically.
 
             // Clear the buffers if the connection was closed.
@@ -183,6 +193,24 @@ impl<'gc> Sockets<'gc> {
         }
     }
 
+    /// Close every socket that was opened by code in the given AVM2 domain.
+    pub fn close_avm2_sockets_in_domain(&mut self, domain: Avm2Domain<'gc>) {
+        let handles: Vec<_> = self
+            .sockets
+            .iter()
+            .filter(|(_, socket)| {
+                socket
+                    .domain
+                    .is_some_and(|other| Avm2Domain::ptr_eq(other, domain))
+            })
+            .map(|(handle, _)| handle)
+            .collect();
+
+        for handle in handles {
+            self.close(handle);
+        }
+    }
+
     pub fn update_sockets(context: &mut UpdateContext<'_, 'gc>) {
         let mut actions = vec![];
 
@@ -5,7 +5,7 @@ use crate::avm1::{
     ExecutionReason as Avm1ExecutionReason, FlvValueAvm1Ext, ScriptObject as Avm1ScriptObject,
     TObject as Avm1TObject, Value as Avm1Value,
 };
-use crate::avm2::object::TObject as Avm2TObject;
+use crate::avm2::object::{TObject as Avm2TObject, TextureObject};
 use crate::avm2::{
     Activation as Avm2Activation, Avm2, Error as Avm2Error, EventObject as Avm2EventObject,
     FlvValueAvm2Ext, Object as Avm2Object,
@@ -239,6 +239,12 @@ pub struct NetStreamData<'gc> {
     /// The MovieClip this `NetStream` is attached to.
     attached_to: Option<MovieClip<'gc>>,
 
+    /// The `VideoTexture`s this `NetStream` is attached to.
+    ///
+    /// Each of these is updated with the new frame whenever a frame is
+    /// decoded.
+    video_textures: Vec<TextureObject<'gc>>,
+
     /// True if the stream should play when ticked.
     playing: bool,
 }
@@ -263,6 +269,7 @@ impl<'gc> NetStream<'gc> {
                 audio_stream: None,
                 sound_instance: None,
                 attached_to: None,
+                video_textures: Vec::new(),
                 playing: false,
             },
         ))
@@ -539,6 +546,14 @@ impl<'gc> NetStream<'gc> {
         }
     }
 
+    /// Stop playback and discard any buffered data, as `NetStream.close`
+    /// does.
+    pub fn close(self, context: &mut UpdateContext<'_, 'gc>) {
+        self.0.write(context.gc_context).playing = false;
+        self.reset_buffer(context);
+        StreamManager::deactivate(context, self);
+    }
+
     /// Resume stream playback.
     pub fn resume(self, context: &mut UpdateContext<'_, 'gc>) {
         self.0.write(context.gc_context).playing = true;
@@ -587,6 +602,54 @@ impl<'gc> NetStream<'gc> {
         write.attached_to = Some(clip);
     }
 
+    /// Attach a `VideoTexture` to this `NetStream`, detaching it from any
+    /// other stream it was previously attached to.
+    pub fn attach_video_texture(self, gc_context: &Mutation<'gc>, texture: TextureObject<'gc>) {
+        if let Some(old_stream) = texture.video_stream() {
+            old_stream.detach_video_texture(gc_context, texture);
+        }
+
+        self.0.write(gc_context).video_textures.push(texture);
+        texture.set_video_stream(gc_context, Some(self));
+    }
+
+    /// Detach a `VideoTexture` from this `NetStream`.
+    pub fn detach_video_texture(self, gc_context: &Mutation<'gc>, texture: TextureObject<'gc>) {
+        self.0
+            .write(gc_context)
+            .video_textures
+            .retain(|t| !Avm2Object::ptr_eq(*t, texture));
+        texture.set_video_stream(gc_context, None);
+    }
+
+    /// Copy the last decoded frame into all attached `VideoTexture`s and
+    /// notify them that a new frame is ready.
+    fn update_video_textures(self, context: &mut UpdateContext<'_, 'gc>) {
+        let (bitmap, textures) = {
+            let read = self.0.read();
+            (
+                read.last_decoded_bitmap.clone(),
+                read.video_textures.clone(),
+            )
+        };
+        let Some(bitmap) = bitmap else {
+            return;
+        };
+
+        for texture in textures {
+            if let Err(e) = texture
+                .context3d()
+                .copy_video_frame_to_texture(&bitmap, texture)
+            {
+                tracing::error!("Updating VideoTexture failed: {}", e);
+                continue;
+            }
+
+            let event = Avm2EventObject::bare_default_event(context, "textureReady");
+            Avm2::dispatch_event(context, event, texture.into());
+        }
+    }
+
     /// Process a parsed FLV audio tag.
     ///
     /// `write` must be an active borrow of the current `NetStream`. `slice`
@@ -817,6 +880,8 @@ impl<'gc> NetStream<'gc> {
     ///
     /// `tag_needs_preloading` indicates that this video tag has not been
     /// encountered before.
+    ///
+    /// Returns `true` if a new frame was decoded.
     fn flv_video_tag(
         self,
         context: &mut UpdateContext<'_, 'gc>,
@@ -824,7 +889,7 @@ impl<'gc> NetStream<'gc> {
         slice: &Slice,
         video_data: FlvVideoData<'_>,
         tag_needs_preloading: bool,
-    ) {
+    ) -> bool {
         let (video_handle, frame_id) = match write.stream_type {
             Some(NetStreamType::Flv {
                 video_stream,
@@ -835,6 +900,7 @@ impl<'gc> NetStream<'gc> {
         };
         let codec = VideoCodec::from_u8(video_data.codec_id as u8);
         let buffer = slice.data();
+        let mut frame_decoded = false;
 
         match (video_handle, codec, video_data.data) {
             (maybe_video_handle, Some(codec), FlvVideoPacket::Data(mut data))
@@ -874,7 +940,7 @@ impl<'gc> NetStream<'gc> {
                                     "Got error when registring FLV video stream: {}",
                                     e
                                 );
-                                return; //TODO: This originally breaks and halts tag processing
+                                return false; //TODO: This originally breaks and halts tag processing
                             }
                         }
                     }
@@ -920,6 +986,7 @@ impl<'gc> NetStream<'gc> {
                 ) {
                     Ok(bitmap_info) => {
                         write.last_decoded_bitmap = Some(bitmap_info);
+                        frame_decoded = true;
                     }
                     Err(e) => {
                         tracing::error!("Decoding video frame {} failed: {}", frame_id, e);
@@ -952,6 +1019,8 @@ impl<'gc> NetStream<'gc> {
             }) => *frame_id += 1,
             _ => unreachable!(),
         };
+
+        frame_decoded
     }
 
     /// Process a parsed FLV script tag.
@@ -1075,6 +1144,7 @@ impl<'gc> NetStream<'gc> {
         let mut error = false;
         let mut max_lookahead_audio_tags = 5;
         let mut is_lookahead_tag = false;
+        let mut frame_decoded = false;
 
         //At this point we should know our stream type.
         if matches!(write.stream_type, Some(NetStreamType::Flv { .. })) {
@@ -1120,13 +1190,15 @@ impl<'gc> NetStream<'gc> {
                             tracing::error!("Error committing sound stream: {}", e);
                         }
                     }
-                    FlvTagData::Video(video_data) if !is_lookahead_tag => self.flv_video_tag(
-                        context,
-                        &mut write,
-                        &slice,
-                        video_data,
-                        tag_needs_preloading,
-                    ),
+                    FlvTagData::Video(video_data) if !is_lookahead_tag => {
+                        frame_decoded |= self.flv_video_tag(
+                            context,
+                            &mut write,
+                            &slice,
+                            video_data,
+                            tag_needs_preloading,
+                        );
+                    }
                     FlvTagData::Script(script_data) if !is_lookahead_tag => {
                         drop(write);
                         self.flv_script_tag(context, script_data, tag_needs_preloading);
@@ -1155,6 +1227,10 @@ impl<'gc> NetStream<'gc> {
         }
         drop(write);
 
+        if frame_decoded {
+            self.update_video_textures(context);
+        }
+
         if end_of_video {
             self.trigger_status_event(
                 context,
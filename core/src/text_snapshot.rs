@@ -0,0 +1,286 @@
+//! Searching and selecting the static text of a display object container.
+
+use crate::context::UpdateContext;
+use crate::display_object::{Text, TextGlyph};
+use crate::prelude::*;
+use crate::string::{WStr, WString};
+use gc_arena::Mutation;
+use std::ops::Range;
+
+/// The static text directly contained by a display object container.
+///
+/// Characters are indexed across all `Text` children of the container, in render order. Text
+/// inside nested containers isn't included.
+pub struct TextSnapshot<'gc> {
+    texts: Vec<(Text<'gc>, Vec<TextGlyph<'gc>>)>,
+}
+
+/// Information about a single character of a `TextSnapshot`.
+pub struct TextRunInfo {
+    /// The index of the character within the snapshot.
+    pub index_in_run: usize,
+    pub selected: bool,
+    pub font_name: String,
+    pub color: Color,
+    pub height: Twips,
+
+    /// The transform from the EM square of the font into the container, in pixels.
+    pub matrix: Matrix,
+
+    /// The corners of the glyph's box in the container, clockwise from the top left.
+    pub corners: [Point<Twips>; 4],
+}
+
+impl<'gc> TextSnapshot<'gc> {
+    pub fn new(context: &UpdateContext<'_, 'gc>, container: DisplayObjectContainer<'gc>) -> Self {
+        let texts = container
+            .iter_render_list()
+            .filter_map(|child| child.as_text())
+            .map(|text| (text, text.glyphs(context)))
+            .collect();
+        Self { texts }
+    }
+
+    pub fn char_count(&self) -> usize {
+        self.texts.iter().map(|(_, glyphs)| glyphs.len()).sum()
+    }
+
+    /// Iterates over every glyph along with its text object, and its index within that text.
+    fn glyphs(&self) -> impl Iterator<Item = (Text<'gc>, usize, &TextGlyph<'gc>)> {
+        self.texts.iter().flat_map(|(text, glyphs)| {
+            glyphs
+                .iter()
+                .enumerate()
+                .map(|(index, glyph)| (*text, index, glyph))
+        })
+    }
+
+    /// Clamps a character range to the characters in this snapshot.
+    fn clamp(&self, range: Range<usize>) -> Range<usize> {
+        let count = self.char_count();
+        range.start.min(count)..range.end.min(count)
+    }
+
+    /// Returns the characters in `range`.
+    ///
+    /// With `include_line_endings`, a newline is inserted before each line after the first,
+    /// including the first line of each text object.
+    pub fn text(&self, range: Range<usize>, include_line_endings: bool) -> WString {
+        let range = self.clamp(range);
+        let mut text = WString::new();
+        for (snapshot_index, (_, index, glyph)) in self.glyphs().enumerate() {
+            if !range.contains(&snapshot_index) {
+                continue;
+            }
+            if include_line_endings
+                && snapshot_index != range.start
+                && (glyph.starts_line || index == 0)
+            {
+                text.push_char('\n');
+            }
+            text.push_char(glyph.character);
+        }
+        text
+    }
+
+    /// Returns the index of the first occurrence of `needle` at or after `start`.
+    ///
+    /// Both `start` and the result are character indices, not UTF-16 code unit indices.
+    pub fn find_text(&self, start: usize, needle: &WStr, case_sensitive: bool) -> Option<usize> {
+        let haystack: Vec<char> = self.glyphs().map(|(_, _, glyph)| glyph.character).collect();
+        let needle: Vec<char> = needle
+            .chars()
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect();
+        find_chars(&haystack, start, &needle, case_sensitive)
+    }
+
+    /// Returns whether any character in `range` is selected.
+    pub fn is_selected(&self, range: Range<usize>) -> bool {
+        let range = self.clamp(range);
+        self.glyphs()
+            .enumerate()
+            .any(|(snapshot_index, (text, index, _))| {
+                range.contains(&snapshot_index) && text.is_glyph_selected(index)
+            })
+    }
+
+    /// Returns the selected characters of this snapshot.
+    pub fn selected_text(&self, include_line_endings: bool) -> WString {
+        let mut text = WString::new();
+        let mut previous = None;
+        for (snapshot_index, (glyph_text, index, glyph)) in self.glyphs().enumerate() {
+            if !glyph_text.is_glyph_selected(index) {
+                continue;
+            }
+            if include_line_endings
+                && previous.is_some()
+                && (glyph.starts_line || index == 0 || previous != Some(snapshot_index - 1))
+            {
+                text.push_char('\n');
+            }
+            text.push_char(glyph.character);
+            previous = Some(snapshot_index);
+        }
+        text
+    }
+
+    pub fn set_selected(&self, gc_context: &Mutation<'gc>, range: Range<usize>, selected: bool) {
+        let range = self.clamp(range);
+        let mut text_start = 0;
+        for (text, glyphs) in &self.texts {
+            let text_end = text_start + glyphs.len();
+            let start = range.start.max(text_start);
+            let end = range.end.min(text_end);
+            if start < end {
+                text.set_glyphs_selected(
+                    gc_context,
+                    start - text_start..end - text_start,
+                    selected,
+                );
+            }
+            text_start = text_end;
+        }
+    }
+
+    pub fn set_selection_color(&self, gc_context: &Mutation<'gc>, color: Color) {
+        for (text, _) in &self.texts {
+            text.set_selection_color(gc_context, color);
+        }
+    }
+
+    /// Returns the index of the character at `point`, given in the coordinate space of the
+    /// container.
+    ///
+    /// If no character contains the point, the closest one within `max_distance` is returned.
+    pub fn hit_test_text_near_pos(
+        &self,
+        point: Point<Twips>,
+        max_distance: Twips,
+    ) -> Option<usize> {
+        let mut closest = None;
+        for (snapshot_index, (text, _, glyph)) in self.glyphs().enumerate() {
+            let [top_left, _, bottom_right, _] = glyph_corners(text, glyph);
+            let bounds = Rectangle {
+                x_min: top_left.x.min(bottom_right.x),
+                x_max: top_left.x.max(bottom_right.x),
+                y_min: top_left.y.min(bottom_right.y),
+                y_max: top_left.y.max(bottom_right.y),
+            };
+            if bounds.contains(point) {
+                return Some(snapshot_index);
+            }
+
+            let dx = (bounds.x_min - point.x)
+                .max(point.x - bounds.x_max)
+                .to_pixels();
+            let dy = (bounds.y_min - point.y)
+                .max(point.y - bounds.y_max)
+                .to_pixels();
+            let distance = dx.max(0.0).hypot(dy.max(0.0));
+            if distance <= max_distance.to_pixels()
+                && closest.map_or(true, |(_, closest_distance)| distance < closest_distance)
+            {
+                closest = Some((snapshot_index, distance));
+            }
+        }
+        closest.map(|(index, _)| index)
+    }
+
+    pub fn text_run_info(&self, range: Range<usize>) -> Vec<TextRunInfo> {
+        let range = self.clamp(range);
+        self.glyphs()
+            .enumerate()
+            .filter(|(snapshot_index, _)| range.contains(snapshot_index))
+            .map(|(snapshot_index, (text, index, glyph))| {
+                // Flash reports the glyph transform relative to a 1024 unit EM square.
+                let scale = glyph.height.to_pixels() as f32 / 1024.0;
+                let matrix = glyph_matrix(text, glyph) * Matrix::scale(scale, scale);
+                TextRunInfo {
+                    index_in_run: snapshot_index,
+                    selected: text.is_glyph_selected(index),
+                    font_name: glyph.font.descriptor().name().to_string(),
+                    color: glyph.color,
+                    height: glyph.height,
+                    matrix,
+                    corners: glyph_corners(text, glyph),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the index of the first occurrence of `needle` in `haystack` at or after `start`.
+fn find_chars(
+    haystack: &[char],
+    start: usize,
+    needle: &[char],
+    case_sensitive: bool,
+) -> Option<usize> {
+    let start = start.min(haystack.len());
+    if needle.is_empty() {
+        return Some(start);
+    }
+
+    let chars_match =
+        |a: char, b: char| a == b || (!case_sensitive && a.to_lowercase().eq(b.to_lowercase()));
+    haystack[start..]
+        .windows(needle.len())
+        .position(|window| window.iter().zip(needle).all(|(a, b)| chars_match(*a, *b)))
+        .map(|index| index + start)
+}
+
+/// The transform from a glyph's origin into the coordinate space of the container.
+fn glyph_matrix(text: Text<'_>, glyph: &TextGlyph<'_>) -> Matrix {
+    *text.base().matrix()
+        * text.text_transform()
+        * Matrix::translate(glyph.position.x, glyph.position.y)
+}
+
+fn glyph_corners(text: Text<'_>, glyph: &TextGlyph<'_>) -> [Point<Twips>; 4] {
+    let matrix = glyph_matrix(text, glyph);
+    let ascent = glyph.font.get_baseline_for_height(glyph.height);
+    let descent = glyph.font.get_descent_for_height(glyph.height);
+    [
+        matrix * Point::new(Twips::ZERO, -ascent),
+        matrix * Point::new(glyph.advance, -ascent),
+        matrix * Point::new(glyph.advance, descent),
+        matrix * Point::new(Twips::ZERO, descent),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_chars;
+
+    fn find(haystack: &str, start: usize, needle: &str, case_sensitive: bool) -> Option<usize> {
+        let haystack: Vec<char> = haystack.chars().collect();
+        let needle: Vec<char> = needle.chars().collect();
+        find_chars(&haystack, start, &needle, case_sensitive)
+    }
+
+    #[test]
+    fn finds_text() {
+        assert_eq!(find("Hello world", 0, "world", true), Some(6));
+        assert_eq!(find("Hello world", 0, "World", true), None);
+        assert_eq!(find("Hello world", 0, "WORLD", false), Some(6));
+        assert_eq!(find("Hello world", 7, "o", true), Some(7));
+        assert_eq!(find("Hello world", 8, "o", true), None);
+        assert_eq!(find("Hello", 20, "", true), Some(5));
+        assert_eq!(find("Hello", 20, "H", true), None);
+    }
+
+    #[test]
+    fn finds_text_by_character_index() {
+        // Characters outside the BMP take two UTF-16 code units, but only one glyph.
+        assert_eq!(find("\u{1F600}\u{1F600}ab", 0, "ab", true), Some(2));
+        assert_eq!(find("\u{1F600}ab\u{1F600}ab", 2, "ab", true), Some(4));
+    }
+
+    #[test]
+    fn folds_non_ascii_case() {
+        assert_eq!(find("ÄÖÜ straße", 0, "äöü", false), Some(0));
+        assert_eq!(find("ДОБРО", 0, "добро", false), Some(0));
+        assert_eq!(find("ДОБРО", 0, "добро", true), None);
+    }
+}
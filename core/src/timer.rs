@@ -9,7 +9,9 @@ use crate::avm1::{
     Activation, ActivationIdentifier, Object as Avm1Object, TObject as _, Value as Avm1Value,
 };
 use crate::avm2::object::TObject;
-use crate::avm2::{Activation as Avm2Activation, Object as Avm2Object, Value as Avm2Value};
+use crate::avm2::{
+    Activation as Avm2Activation, Domain as Avm2Domain, Object as Avm2Object, Value as Avm2Value,
+};
 use crate::context::UpdateContext;
 use crate::display_object::{DisplayObject, TDisplayObject};
 use crate::string::AvmString;
@@ -149,7 +151,9 @@ impl<'gc> Timers<'gc> {
                         true
                     }
                 }
-                TimerCallback::Avm2Callback { closure, params } => {
+                TimerCallback::Avm2Callback {
+                    closure, params, ..
+                } => {
                     let domain = context.avm2.stage_domain();
                     let mut avm2_activation =
                         Avm2Activation::from_domain(context.reborrow(), domain);
@@ -251,6 +255,31 @@ impl<'gc> Timers<'gc> {
         }
     }
 
+    /// Removes every AVM2 timer started by code from `domain`, or from any of
+    /// its child domains.
+    pub fn remove_avm2_domain(&mut self, domain: Avm2Domain<'gc>) {
+        for timer in self.timers.iter() {
+            let TimerCallback::Avm2Callback {
+                domain: Some(mut timer_domain),
+                ..
+            } = timer.callback
+            else {
+                continue;
+            };
+
+            loop {
+                if Avm2Domain::ptr_eq(timer_domain, domain) {
+                    timer.is_alive.set(false);
+                    break;
+                }
+                let Some(parent) = timer_domain.parent_domain() else {
+                    break;
+                };
+                timer_domain = parent;
+            }
+        }
+    }
+
     fn peek(&self) -> Option<&Timer<'gc>> {
         self.timers.peek()
     }
@@ -342,5 +371,7 @@ pub enum TimerCallback<'gc> {
     Avm2Callback {
         closure: Avm2Object<'gc>,
         params: Vec<Avm2Value<'gc>>,
+        /// The domain of the code that started this timer.
+        domain: Option<Avm2Domain<'gc>>,
     },
 }
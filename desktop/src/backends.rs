@@ -1,13 +1,19 @@
 mod audio;
+#[cfg(feature = "debugger")]
+mod debugger;
 mod external_interface;
 mod fscommand;
 mod navigator;
+mod print;
 mod storage;
 mod ui;
 
 pub use audio::CpalAudioBackend;
+#[cfg(feature = "debugger")]
+pub use debugger::TcpDebuggerBackend;
 pub use external_interface::DesktopExternalInterfaceProvider;
 pub use fscommand::DesktopFSCommandProvider;
 pub use navigator::ExternalNavigatorBackend;
+pub use print::DesktopPrintBackend;
 pub use storage::DiskStorageBackend;
 pub use ui::DesktopUiBackend;
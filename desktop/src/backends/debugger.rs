@@ -0,0 +1,57 @@
+use ruffle_core::backend::debugger::{DebuggerBackend, DebuggerReceive};
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+
+/// Talks to an ActionScript debugger that's listening on a TCP port.
+pub struct TcpDebuggerBackend {
+    stream: TcpStream,
+}
+
+impl TcpDebuggerBackend {
+    pub fn connect(address: impl ToSocketAddrs) -> io::Result<Self> {
+        let stream = TcpStream::connect(address)?;
+        stream.set_nodelay(true)?;
+        Ok(Self { stream })
+    }
+}
+
+impl DebuggerBackend for TcpDebuggerBackend {
+    fn send(&self, data: &[u8]) {
+        // `Write` is implemented for `&TcpStream`, which lets us send traces
+        // without needing mutable access to the debugger.
+        if let Err(e) = (&self.stream).write_all(data) {
+            tracing::warn!("Couldn't send message to debugger: {e}");
+        }
+    }
+
+    fn receive(&mut self, blocking: bool) -> DebuggerReceive {
+        if let Err(e) = self.stream.set_nonblocking(!blocking) {
+            tracing::warn!("Couldn't configure debugger connection: {e}");
+            return DebuggerReceive::Disconnected;
+        }
+
+        let mut buffer = [0; 4096];
+        let received = loop {
+            break match self.stream.read(&mut buffer) {
+                Ok(0) => DebuggerReceive::Disconnected,
+                Ok(read) => DebuggerReceive::Data(buffer[..read].to_vec()),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => DebuggerReceive::Pending,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    tracing::warn!("Lost connection to debugger: {e}");
+                    DebuggerReceive::Disconnected
+                }
+            };
+        };
+
+        // `send` needs a blocking stream, or a full send buffer would leave
+        // a message half written.
+        if !blocking {
+            if let Err(e) = self.stream.set_nonblocking(false) {
+                tracing::warn!("Couldn't configure debugger connection: {e}");
+                return DebuggerReceive::Disconnected;
+            }
+        }
+        received
+    }
+}
@@ -0,0 +1,210 @@
+use crate::custom_event::RuffleEvent;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use rfd::AsyncFileDialog;
+use ruffle_core::backend::navigator::OwnedFuture;
+use ruffle_core::backend::print::{PaperSize, PrintBackend, PrintPage};
+use ruffle_core::loader::Error;
+use std::cell::Cell;
+use std::io::{self, Write};
+use std::path::Path;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+use tracing::error;
+use winit::event_loop::EventLoopProxy;
+
+/// Print backend that saves the pages of each print job as a PDF document, or as a set of
+/// PNG images.
+pub struct DesktopPrintBackend {
+    channel: Sender<OwnedFuture<(), Error>>,
+    event_loop: EventLoopProxy<RuffleEvent>,
+    /// Is a dialog currently open
+    dialog_open: Rc<Cell<bool>>,
+}
+
+impl DesktopPrintBackend {
+    pub fn new(
+        channel: Sender<OwnedFuture<(), Error>>,
+        event_loop: EventLoopProxy<RuffleEvent>,
+    ) -> Self {
+        Self {
+            channel,
+            event_loop,
+            dialog_open: Rc::new(Cell::new(false)),
+        }
+    }
+}
+
+impl PrintBackend for DesktopPrintBackend {
+    fn start_job(&mut self) -> Option<PaperSize> {
+        // The destination is only asked for once the job is sent, so that the movie isn't
+        // blocked while the dialog is open. Until it's closed, no new jobs can be started.
+        if self.dialog_open.get() {
+            return None;
+        }
+        Some(PaperSize::LETTER)
+    }
+
+    fn print(&mut self, pages: Vec<PrintPage>) {
+        if pages.is_empty() || self.dialog_open.get() {
+            return;
+        }
+        self.dialog_open.set(true);
+
+        let dialog_open = self.dialog_open.clone();
+        let future: OwnedFuture<(), Error> = Box::pin(async move {
+            let destination = AsyncFileDialog::new()
+                .set_title("Ruffle - Print")
+                .set_file_name("print.pdf")
+                .add_filter("PDF document", &["pdf"])
+                .add_filter("PNG image", &["png"])
+                .save_file()
+                .await;
+            if let Some(destination) = destination {
+                save_pages(destination.path(), pages);
+            }
+            dialog_open.set(false);
+            Ok(())
+        });
+
+        self.channel.send(future).expect("working channel send");
+        if self.event_loop.send_event(RuffleEvent::TaskPoll).is_err() {
+            tracing::warn!(
+                "A task was queued on an event loop that has already ended. It will not be polled."
+            );
+        }
+    }
+}
+
+/// Saves `pages` as a PNG image if `destination` asks for one, or as a PDF document otherwise.
+fn save_pages(destination: &Path, pages: Vec<PrintPage>) {
+    let is_png = destination
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("png"));
+    if !is_png {
+        let result =
+            write_pdf(&pages, PaperSize::LETTER).and_then(|pdf| std::fs::write(destination, pdf));
+        if let Err(e) = result {
+            error!(
+                "Couldn't save printed pages to {}: {e}",
+                destination.display()
+            );
+        }
+        return;
+    }
+
+    // A single page is saved to the chosen file, multiple pages are numbered after it.
+    let stem = destination
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "print".to_string());
+    let page_count = pages.len();
+    for (index, page) in pages.into_iter().enumerate() {
+        let path = if page_count == 1 {
+            destination.to_owned()
+        } else {
+            destination.with_file_name(format!("{stem}-{}.png", index + 1))
+        };
+        if let Err(e) = image::save_buffer(
+            &path,
+            &page.rgba,
+            page.width,
+            page.height,
+            image::ColorType::Rgba8,
+        ) {
+            error!("Couldn't save printed page to {}: {e}", path.display());
+        }
+    }
+}
+
+/// Builds a PDF document with one sheet of `paper` per page, each page being placed at the
+/// top left of the printable area.
+fn write_pdf(pages: &[PrintPage], paper: PaperSize) -> io::Result<Vec<u8>> {
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::new();
+    let mut write_object = |pdf: &mut Vec<u8>, dictionary: &str, stream: Option<&[u8]>| {
+        offsets.push(pdf.len());
+        write!(pdf, "{} 0 obj\n{dictionary}\n", offsets.len())?;
+        if let Some(stream) = stream {
+            pdf.extend_from_slice(b"stream\n");
+            pdf.extend_from_slice(stream);
+            pdf.extend_from_slice(b"\nendstream\n");
+        }
+        pdf.extend_from_slice(b"endobj\n");
+        io::Result::Ok(())
+    };
+
+    // Objects 1 and 2 are the catalog and page tree, followed by the page, contents and
+    // image of each page in turn.
+    let kids: Vec<String> = (0..pages.len())
+        .map(|index| format!("{} 0 R", 3 + index * 3))
+        .collect();
+    write_object(&mut pdf, "<< /Type /Catalog /Pages 2 0 R >>", None)?;
+    write_object(
+        &mut pdf,
+        &format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids.join(" "),
+            pages.len()
+        ),
+        None,
+    )?;
+
+    let margin_x = (paper.paper_width - paper.page_width) / 2;
+    let margin_y = (paper.paper_height - paper.page_height) / 2;
+    for (index, page) in pages.iter().enumerate() {
+        let page_id = 3 + index * 3;
+        write_object(
+            &mut pdf,
+            &format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /XObject << /Page {} 0 R >> >> /Contents {} 0 R >>",
+                paper.paper_width,
+                paper.paper_height,
+                page_id + 2,
+                page_id + 1
+            ),
+            None,
+        )?;
+
+        // Pages are rasterised at 72 DPI, so one pixel is one point.
+        let contents = format!(
+            "q {} 0 0 {} {margin_x} {} cm /Page Do Q",
+            page.width,
+            page.height,
+            paper.paper_height - margin_y - page.height
+        );
+        write_object(
+            &mut pdf,
+            &format!("<< /Length {} >>", contents.len()),
+            Some(contents.as_bytes()),
+        )?;
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        for pixel in page.rgba.chunks_exact(4) {
+            encoder.write_all(&pixel[..3])?;
+        }
+        let image = encoder.finish()?;
+        write_object(
+            &mut pdf,
+            &format!(
+                "<< /Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB /BitsPerComponent 8 /Filter /FlateDecode /Length {} >>",
+                page.width,
+                page.height,
+                image.len()
+            ),
+            Some(&image),
+        )?;
+    }
+
+    let xref_offset = pdf.len();
+    write!(pdf, "xref\n0 {}\n0000000000 65535 f \n", offsets.len() + 1)?;
+    for offset in &offsets {
+        writeln!(pdf, "{offset:010} 00000 n ")?;
+    }
+    write!(
+        pdf,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+        offsets.len() + 1
+    )?;
+    Ok(pdf)
+}
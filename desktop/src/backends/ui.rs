@@ -6,8 +6,8 @@ use rfd::{
 };
 use ruffle_core::backend::navigator::OpenURLMode;
 use ruffle_core::backend::ui::{
-    DialogLoaderError, DialogResultFuture, FileDialogResult, FileFilter, FontDefinition,
-    FullscreenError, LanguageIdentifier, MouseCursor, UiBackend, US_ENGLISH,
+    CustomMouseCursor, DialogLoaderError, DialogResultFuture, FileDialogResult, FileFilter,
+    FontDefinition, FullscreenError, LanguageIdentifier, MouseCursor, UiBackend, US_ENGLISH,
 };
 use std::rc::Rc;
 use sys_locale::get_locale;
@@ -119,6 +119,8 @@ pub struct DesktopUiBackend {
     clipboard: Clipboard,
     language: LanguageIdentifier,
     preferred_cursor: MouseCursor,
+    /// A cursor image registered by the movie, which replaces `preferred_cursor` while set.
+    custom_cursor: Option<Rc<CustomMouseCursor>>,
     open_url_mode: OpenURLMode,
     /// Is a dialog currently open
     dialog_open: bool,
@@ -136,13 +138,14 @@ impl DesktopUiBackend {
             clipboard: Clipboard::new().context("Couldn't get platform clipboard")?,
             language,
             preferred_cursor: MouseCursor::Arrow,
+            custom_cursor: None,
             open_url_mode,
             dialog_open: false,
         })
     }
 
     pub fn cursor(&self) -> egui::CursorIcon {
-        if self.cursor_visible {
+        if self.cursor_visible && self.custom_cursor.is_none() {
             match self.preferred_cursor {
                 MouseCursor::Arrow => egui::CursorIcon::Default,
                 MouseCursor::Hand => egui::CursorIcon::PointingHand,
@@ -153,6 +156,15 @@ impl DesktopUiBackend {
             egui::CursorIcon::None
         }
     }
+
+    /// The custom cursor that should be drawn in place of the system cursor, if any.
+    pub fn custom_cursor(&self) -> Option<&Rc<CustomMouseCursor>> {
+        if self.cursor_visible {
+            self.custom_cursor.as_ref()
+        } else {
+            None
+        }
+    }
 }
 
 const DOWNLOAD_FAILED_MESSAGE: &str = "Ruffle failed to open or download this file.";
@@ -168,6 +180,15 @@ impl UiBackend for DesktopUiBackend {
 
     fn set_mouse_cursor(&mut self, cursor: MouseCursor) {
         self.preferred_cursor = cursor;
+        self.custom_cursor = None;
+    }
+
+    fn set_custom_mouse_cursor(&mut self, cursor: CustomMouseCursor) {
+        self.custom_cursor = Some(Rc::new(cursor));
+    }
+
+    fn supports_custom_mouse_cursor(&self) -> bool {
+        true
     }
 
     fn clipboard_content(&mut self) -> String {
@@ -140,6 +140,12 @@ pub struct Opt {
     /// Hides the menu bar (the bar at the top of the window).
     #[clap(long)]
     pub no_gui: bool,
+
+    /// Connect to an ActionScript debugger (such as `fdb`) listening at the given address.
+    /// If no address is given, connects to the default `localhost:7935`.
+    #[cfg(feature = "debugger")]
+    #[clap(long, num_args = 0..=1, default_missing_value = "localhost:7935")]
+    pub debugger: Option<String>,
 }
 
 fn parse_movie_file_or_url(path: &str) -> Result<Url, Error> {
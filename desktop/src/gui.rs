@@ -301,6 +301,12 @@ impl RuffleGui {
                                 player.debug_ui().queue_message(DebugMessage::SearchForDisplayObject);
                             }
                         }
+                        if Button::new(text(&self.locale, "debug-menu-save-samples")).ui(ui).clicked() {
+                            ui.close_menu();
+                            if let Some(player) = &mut player {
+                                player.debug_ui().queue_message(DebugMessage::SaveSamples);
+                            }
+                        }
                     });
                 });
                 menu::menu_button(ui, text(&self.locale, "help-menu"), |ui| {
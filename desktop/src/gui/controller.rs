@@ -7,6 +7,7 @@ use crate::player::{PlayerController, PlayerOptions};
 use anyhow::anyhow;
 use egui::Context;
 use fontdb::{Database, Family, Query, Source};
+use ruffle_core::backend::ui::CustomMouseCursor;
 use ruffle_core::Player;
 use ruffle_render_wgpu::backend::{request_adapter_and_device, WgpuRenderBackend};
 use ruffle_render_wgpu::descriptors::Descriptors;
@@ -38,6 +39,15 @@ pub struct GuiController {
     size: PhysicalSize<u32>,
     /// If this is set, we should not render the main menu.
     no_gui: bool,
+    /// Textures for the custom cursor that was most recently drawn.
+    custom_cursor_textures: Option<CustomCursorTextures>,
+}
+
+/// The uploaded frames of a movie's custom cursor.
+struct CustomCursorTextures {
+    cursor: Rc<CustomMouseCursor>,
+    textures: Vec<egui::TextureHandle>,
+    start: Instant,
 }
 
 impl GuiController {
@@ -124,6 +134,7 @@ impl GuiController {
             movie_view_renderer,
             size,
             no_gui: opt.no_gui,
+            custom_cursor_textures: None,
         })
     }
 
@@ -213,18 +224,32 @@ impl GuiController {
                     0.0
                 },
             );
-        });
-        self.repaint_after = full_output.repaint_after;
 
-        // If we're not in a UI, tell egui which cursor we prefer to use instead
-        if !self.egui_ctx.wants_pointer_input() {
             if let Some(player) = player.as_deref() {
-                full_output.platform_output.cursor_icon = player
+                let custom_cursor = player
                     .ui()
                     .downcast_ref::<DesktopUiBackend>()
                     .unwrap_or_else(|| panic!("UI Backend should be DesktopUiBackend"))
-                    .cursor();
+                    .custom_cursor();
+                if let Some(cursor) = custom_cursor {
+                    draw_custom_cursor(context, &mut self.custom_cursor_textures, cursor);
+                }
             }
+        });
+        self.repaint_after = full_output.repaint_after;
+
+        // If we're not in a UI, tell egui which cursor we prefer to use instead
+        if self.egui_ctx.wants_pointer_input() {
+            // The custom cursor isn't drawn over the UI, so make sure the system cursor is back.
+            if full_output.platform_output.cursor_icon == egui::CursorIcon::None {
+                full_output.platform_output.cursor_icon = egui::CursorIcon::Default;
+            }
+        } else if let Some(player) = player.as_deref() {
+            full_output.platform_output.cursor_icon = player
+                .ui()
+                .downcast_ref::<DesktopUiBackend>()
+                .unwrap_or_else(|| panic!("UI Backend should be DesktopUiBackend"))
+                .cursor();
         }
         self.egui_winit.handle_platform_output(
             &self.window,
@@ -324,6 +349,77 @@ impl GuiController {
     }
 }
 
+/// Draws a movie's custom cursor at the pointer position.
+/// winit can't change the cursor image itself, so the system cursor is hidden and this is drawn on top of everything.
+/// Over the UI, the system cursor is shown instead.
+fn draw_custom_cursor(
+    context: &egui::Context,
+    cache: &mut Option<CustomCursorTextures>,
+    cursor: &Rc<CustomMouseCursor>,
+) {
+    if context.wants_pointer_input() {
+        return;
+    }
+    let Some(pointer) = context.input(|i| i.pointer.hover_pos()) else {
+        return;
+    };
+
+    if !matches!(cache, Some(cache) if Rc::ptr_eq(&cache.cursor, cursor)) {
+        let textures = cursor
+            .frames
+            .iter()
+            .enumerate()
+            .map(|(i, frame)| {
+                context.load_texture(
+                    format!("custom_cursor_{i}"),
+                    egui::ColorImage::from_rgba_unmultiplied(
+                        [frame.width as usize, frame.height as usize],
+                        &frame.rgba,
+                    ),
+                    egui::TextureOptions::NEAREST,
+                )
+            })
+            .collect();
+        *cache = Some(CustomCursorTextures {
+            cursor: cursor.clone(),
+            textures,
+            start: Instant::now(),
+        });
+    }
+    let Some(cache) = cache else {
+        return;
+    };
+
+    let frame = if cache.textures.len() > 1 && cursor.frame_rate > 0.0 {
+        context.request_repaint_after(Duration::from_secs_f64(1.0 / cursor.frame_rate));
+        (cache.start.elapsed().as_secs_f64() * cursor.frame_rate) as usize % cache.textures.len()
+    } else {
+        0
+    };
+    let Some(texture) = cache.textures.get(frame) else {
+        return;
+    };
+
+    // Cursor images are measured in physical pixels.
+    let pixels_per_point = context.pixels_per_point();
+    let hot_spot = egui::vec2(cursor.hot_spot.0 as f32, cursor.hot_spot.1 as f32);
+    let rect = egui::Rect::from_min_size(
+        pointer - hot_spot / pixels_per_point,
+        texture.size_vec2() / pixels_per_point,
+    );
+    context
+        .layer_painter(egui::LayerId::new(
+            egui::Order::Tooltip,
+            egui::Id::new("custom_cursor"),
+        ))
+        .image(
+            texture.id(),
+            rect,
+            egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+            egui::Color32::WHITE,
+        );
+}
+
 // try to load known unicode supporting fonts to draw cjk characters in egui
 fn load_system_fonts(locale: LanguageIdentifier) -> anyhow::Result<egui::FontDefinitions> {
     let mut font_database = Database::default();
@@ -1,6 +1,8 @@
+#[cfg(feature = "debugger")]
+use crate::backends::TcpDebuggerBackend;
 use crate::backends::{
-    CpalAudioBackend, DesktopExternalInterfaceProvider, DesktopFSCommandProvider, DesktopUiBackend,
-    DiskStorageBackend, ExternalNavigatorBackend,
+    CpalAudioBackend, DesktopExternalInterfaceProvider, DesktopFSCommandProvider,
+    DesktopPrintBackend, DesktopUiBackend, DiskStorageBackend, ExternalNavigatorBackend,
 };
 use crate::cli::Opt;
 use crate::custom_event::RuffleEvent;
@@ -10,6 +12,8 @@ use crate::{CALLSTACK, RENDER_INFO, SWF_INFO};
 use anyhow::anyhow;
 use ruffle_core::backend::navigator::{OpenURLMode, SocketMode};
 use ruffle_core::config::Letterbox;
+#[cfg(feature = "debugger")]
+use ruffle_core::debugger::Debugger;
 use ruffle_core::{
     LoadBehavior, Player, PlayerBuilder, PlayerEvent, PlayerRuntime, StageAlign, StageScaleMode,
 };
@@ -51,6 +55,8 @@ pub struct PlayerOptions {
     pub frame_rate: Option<f64>,
     pub open_url_mode: OpenURLMode,
     pub dummy_external_interface: bool,
+    #[cfg(feature = "debugger")]
+    pub debugger: Option<String>,
 }
 
 impl From<&Opt> for PlayerOptions {
@@ -78,6 +84,8 @@ impl From<&Opt> for PlayerOptions {
             dummy_external_interface: value.dummy_external_interface,
             socket_allowed: HashSet::from_iter(value.socket_allow.iter().cloned()),
             tcp_connections: value.tcp_connections,
+            #[cfg(feature = "debugger")]
+            debugger: value.debugger.clone(),
         }
     }
 }
@@ -110,6 +118,7 @@ impl ActivePlayer {
         };
 
         let (executor, channel) = WinitAsyncExecutor::new(event_loop.clone());
+        let print = DesktopPrintBackend::new(channel.clone(), event_loop.clone());
         let navigator = ExternalNavigatorBackend::new(
             opt.base.to_owned().unwrap_or_else(|| movie_url.clone()),
             channel,
@@ -137,6 +146,14 @@ impl ActivePlayer {
             }));
         }
 
+        #[cfg(feature = "debugger")]
+        if let Some(address) = &opt.debugger {
+            match TcpDebuggerBackend::connect(address) {
+                Ok(backend) => builder = builder.with_debugger(Debugger::new(Box::new(backend))),
+                Err(e) => tracing::error!("Couldn't connect to debugger at {address}: {e}"),
+            }
+        }
+
         let max_execution_duration = if opt.max_execution_duration == f64::INFINITY {
             Duration::MAX
         } else {
@@ -155,6 +172,7 @@ impl ActivePlayer {
                 DesktopUiBackend::new(window.clone(), opt.open_url_mode)
                     .expect("Couldn't create ui backend"),
             )
+            .with_print(print)
             .with_autoplay(true)
             .with_letterbox(opt.letterbox)
             .with_max_execution_duration(max_execution_duration)
@@ -3,7 +3,8 @@
 #![allow(clippy::arc_with_non_send_sync)]
 
 use ruffle_render::backend::{
-    BitmapCacheEntry, Context3D, RenderBackend, ShapeHandle, ShapeHandleImpl, ViewportDimensions,
+    BitmapCacheEntry, Context3D, Context3DProfile, RenderBackend, ShapeHandle, ShapeHandleImpl,
+    ViewportDimensions,
 };
 use ruffle_render::bitmap::{
     Bitmap, BitmapHandle, BitmapHandleImpl, BitmapSource, PixelRegion, PixelSnapping, SyncHandle,
@@ -13,7 +14,7 @@ use ruffle_render::error::Error;
 use ruffle_render::matrix::Matrix;
 use ruffle_render::quality::StageQuality;
 use ruffle_render::shape_utils::{DistilledShape, DrawCommand, LineScaleMode, LineScales};
-use ruffle_render::transform::Transform;
+use ruffle_render::transform::{PerspectiveTransform, Transform};
 use ruffle_web_common::{JsError, JsResult};
 use std::borrow::Cow;
 use std::sync::Arc;
@@ -491,7 +492,10 @@ impl RenderBackend for WebCanvasRenderBackend {
         Ok(())
     }
 
-    fn create_context3d(&mut self) -> Result<Box<dyn Context3D>, Error> {
+    fn create_context3d(
+        &mut self,
+        _profile: Context3DProfile,
+    ) -> Result<Box<dyn Context3D>, Error> {
         Err(Error::Unimplemented("createContext3D".into()))
     }
     fn context3d_present(&mut self, _context: &mut dyn Context3D) -> Result<(), Error> {
@@ -553,6 +557,18 @@ impl CommandHandler for WebCanvasRenderBackend {
         self.clear_color_filter();
     }
 
+    fn render_bitmap_projected(
+        &mut self,
+        bitmap: BitmapHandle,
+        transform: PerspectiveTransform,
+        smoothing: bool,
+    ) {
+        // Canvas transforms are affine, so perspective can only be approximated.
+        let canvas = &as_bitmap_data(&bitmap).canvas;
+        let transform = transform.to_affine(canvas.width() as f32, canvas.height() as f32);
+        self.render_bitmap(bitmap, transform, smoothing, PixelSnapping::Never);
+    }
+
     fn render_stage3d(&mut self, _bitmap: BitmapHandle, _transform: Transform) {
         panic!("Stage3D should not have been created on canvas backend")
     }
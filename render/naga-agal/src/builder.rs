@@ -15,12 +15,10 @@ use num_traits::FromPrimitive;
 
 use crate::varying::VaryingRegisters;
 use crate::{
-    types::*, Error, ShaderType, VertexAttributeFormat, MAX_VERTEX_ATTRIBUTES, SHADER_ENTRY_POINT,
+    types::*, Error, ShaderType, VertexAttributeFormat, MAX_TEXTURES, MAX_VERTEX_ATTRIBUTES,
+    SHADER_ENTRY_POINT,
 };
 
-const VERTEX_PROGRAM_CONTANTS: u64 = 128;
-const FRAGMENT_PROGRAM_CONSTANTS: u64 = 28;
-
 const SAMPLER_REPEAT_LINEAR: usize = 0;
 const SAMPLER_REPEAT_NEAREST: usize = 1;
 const SAMPLER_CLAMP_LINEAR: usize = 2;
@@ -113,6 +111,17 @@ pub(crate) struct NagaBuilder<'a> {
     // For a fragment shader, our 4 bound texture samplers
     texture_samplers: Option<TextureSamplers>,
 
+    // For a fragment shader, the additional color outputs (`oc1` to `oc3`) that
+    // we've written to, indexed by register number. Each entry holds a pointer to
+    // the local variable for the output, and its index in the output struct.
+    extra_color_outputs: Vec<Option<(Handle<Expression>, usize)>>,
+
+    // For a fragment shader, the depth output (`od`) if we've written to it.
+    depth_output: Option<(Handle<Expression>, usize)>,
+
+    // For a vertex shader, the instance id (`iid`) if we've read from it.
+    instance_id: Option<Handle<Expression>>,
+
     // A stack of if/else blocks, using to push statements
     // into the correct block.
     blocks: Vec<BlockStackEntry>,
@@ -267,10 +276,57 @@ pub struct ShaderConfig<'a> {
     pub version: AgalVersion,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone)]
 pub enum AgalVersion {
     Agal1,
     Agal2,
+    Agal3,
+}
+
+impl AgalVersion {
+    fn number(self) -> u32 {
+        match self {
+            AgalVersion::Agal1 => 1,
+            AgalVersion::Agal2 => 2,
+            AgalVersion::Agal3 => 3,
+        }
+    }
+
+    fn num_vertex_constants(self) -> u32 {
+        match self {
+            AgalVersion::Agal1 => 128,
+            AgalVersion::Agal2 | AgalVersion::Agal3 => 250,
+        }
+    }
+
+    fn num_fragment_constants(self) -> u32 {
+        match self {
+            AgalVersion::Agal1 => 28,
+            AgalVersion::Agal2 => 64,
+            AgalVersion::Agal3 => 200,
+        }
+    }
+
+    fn num_temporaries(self) -> usize {
+        match self {
+            AgalVersion::Agal1 => 8,
+            AgalVersion::Agal2 | AgalVersion::Agal3 => 26,
+        }
+    }
+
+    fn num_varyings(self) -> u16 {
+        match self {
+            AgalVersion::Agal1 => 8,
+            AgalVersion::Agal2 | AgalVersion::Agal3 => 10,
+        }
+    }
+
+    fn num_color_outputs(self) -> u16 {
+        match self {
+            AgalVersion::Agal1 => 1,
+            AgalVersion::Agal2 | AgalVersion::Agal3 => 4,
+        }
+    }
 }
 
 impl<'a> NagaBuilder<'a> {
@@ -292,6 +348,7 @@ impl<'a> NagaBuilder<'a> {
         let version = match version {
             1 => AgalVersion::Agal1,
             2 => AgalVersion::Agal2,
+            3 => AgalVersion::Agal3,
             _ => return Err(Error::InvalidVersion(version)),
         };
 
@@ -317,12 +374,14 @@ impl<'a> NagaBuilder<'a> {
             data.read_exact(&mut token)?;
             let raw_opcode = u32::from_le_bytes(token[0..4].try_into().unwrap());
 
-            let opcode = Opcode::from_u32(raw_opcode).ok_or(Error::InvalidOpcode(raw_opcode))?;
+            let opcode = Opcode::from_u32(raw_opcode)
+                .filter(|opcode| opcode.min_version() <= version.number())
+                .ok_or(Error::InvalidOpcode(raw_opcode))?;
 
             let dest = DestField::parse(u32::from_le_bytes(token[4..8].try_into().unwrap()))?;
             let source1 = SourceField::parse(u64::from_le_bytes(token[8..16].try_into().unwrap()))?;
 
-            let source2 = if let Opcode::Tex = opcode {
+            let source2 = if opcode.has_sampler() {
                 Source2::Sampler(SamplerField::parse(u64::from_le_bytes(
                     token[16..24].try_into().unwrap(),
                 ))?)
@@ -541,8 +600,12 @@ impl<'a> NagaBuilder<'a> {
                             base: vec4f,
                             size: ArraySize::Constant(
                                 NonZeroU32::new(match shader_config.shader_type {
-                                    ShaderType::Vertex => VERTEX_PROGRAM_CONTANTS as u32,
-                                    ShaderType::Fragment => FRAGMENT_PROGRAM_CONSTANTS as u32,
+                                    ShaderType::Vertex => {
+                                        shader_config.version.num_vertex_constants()
+                                    }
+                                    ShaderType::Fragment => {
+                                        shader_config.version.num_fragment_constants()
+                                    }
                                 })
                                 .unwrap(),
                             ),
@@ -601,11 +664,7 @@ impl<'a> NagaBuilder<'a> {
             Span::UNDEFINED,
         );
 
-        // FIXME - expose this to the wgpu code
-        let num_temporaries = match shader_config.version {
-            AgalVersion::Agal1 => 8,
-            AgalVersion::Agal2 => 26,
-        };
+        let num_temporaries = shader_config.version.num_temporaries();
 
         NagaBuilder {
             module,
@@ -623,6 +682,9 @@ impl<'a> NagaBuilder<'a> {
             u32_type,
             constant_registers,
             texture_samplers,
+            extra_color_outputs: vec![],
+            depth_output: None,
+            instance_id: None,
             texture_bindings: [None; 8],
             temporary_registers: vec![None; num_temporaries],
             image2d,
@@ -688,6 +750,160 @@ impl<'a> NagaBuilder<'a> {
         Ok(self.temporary_registers[index].unwrap())
     }
 
+    fn get_instance_id(&mut self) -> Handle<Expression> {
+        if let Some(instance_id) = self.instance_id {
+            return instance_id;
+        }
+        self.func.arguments.push(FunctionArgument {
+            name: None,
+            ty: self.u32_type,
+            binding: Some(Binding::BuiltIn(BuiltIn::InstanceIndex)),
+        });
+        let arg_index = self.func.arguments.len() - 1;
+        let expr = self.func.expressions.append(
+            Expression::FunctionArgument(arg_index as u32),
+            Span::UNDEFINED,
+        );
+        self.instance_id = Some(expr);
+        expr
+    }
+
+    /// Returns a pointer to the color output register `oc{reg_num}`.
+    fn get_color_output_pointer(&mut self, reg_num: u16) -> Result<Handle<Expression>> {
+        // The main output is always present, and is returned separately
+        if reg_num == 0 {
+            return Ok(self.dest);
+        }
+
+        let index = reg_num as usize;
+        if index >= self.extra_color_outputs.len() {
+            self.extra_color_outputs.resize(index + 1, None);
+        }
+
+        if self.extra_color_outputs[index].is_none() {
+            let local = self.func.local_variables.append(
+                LocalVariable {
+                    name: Some(format!("color_output_{index}")),
+                    ty: self.vec4f,
+                    init: None,
+                },
+                Span::UNDEFINED,
+            );
+            let expr = self
+                .func
+                .expressions
+                .append(Expression::LocalVariable(local), Span::UNDEFINED);
+            let output_struct_index = self.push_output_member(
+                format!("color_output_{index}"),
+                self.vec4f,
+                Binding::Location {
+                    location: index as u32,
+                    interpolation: None,
+                    sampling: None,
+                },
+            );
+            self.extra_color_outputs[index] = Some((expr, output_struct_index));
+        }
+        Ok(self.extra_color_outputs[index].unwrap().0)
+    }
+
+    /// Returns a pointer to the depth output register `od`.
+    /// Only the first component of this register is used.
+    fn get_depth_output_pointer(&mut self) -> Handle<Expression> {
+        if let Some((expr, _)) = self.depth_output {
+            return expr;
+        }
+
+        let local = self.func.local_variables.append(
+            LocalVariable {
+                name: Some("depth_output".to_string()),
+                ty: self.vec4f,
+                init: None,
+            },
+            Span::UNDEFINED,
+        );
+        let expr = self
+            .func
+            .expressions
+            .append(Expression::LocalVariable(local), Span::UNDEFINED);
+        let output_struct_index = self.push_output_member(
+            "depth_output".to_string(),
+            self.f32_type,
+            Binding::BuiltIn(BuiltIn::FragDepth),
+        );
+        self.depth_output = Some((expr, output_struct_index));
+        expr
+    }
+
+    /// Adds a member to the output struct, returning its index.
+    pub(crate) fn push_output_member(
+        &mut self,
+        name: String,
+        ty: Handle<Type>,
+        binding: Binding,
+    ) -> usize {
+        if let TypeInner::Struct { members, .. } = &mut self.return_type.inner {
+            members.push(StructMember {
+                name: Some(name),
+                ty,
+                binding: Some(binding),
+                offset: 0,
+            });
+            members.len() - 1
+        } else {
+            unreachable!();
+        }
+    }
+
+    /// Loads the extra fragment outputs that were written to, storing each
+    /// at its index in `components`.
+    pub(crate) fn emit_extra_output_loads(
+        &mut self,
+        components: &mut Vec<Option<Handle<Expression>>>,
+    ) {
+        let mut outputs = Vec::new();
+        for (pointer, index) in self.extra_color_outputs.clone().into_iter().flatten() {
+            outputs.push((self.evaluate_expr(Expression::Load { pointer }), index));
+        }
+        if let Some((pointer, index)) = self.depth_output {
+            let depth = self.evaluate_expr(Expression::Load { pointer });
+            let depth = self.evaluate_expr(Expression::AccessIndex {
+                base: depth,
+                index: 0,
+            });
+            outputs.push((depth, index));
+        }
+
+        for (expr, index) in outputs {
+            if index >= components.len() {
+                components.resize(index + 1, None);
+            }
+            components[index] = Some(expr);
+        }
+    }
+
+    /// Checks that a register number is within the limits of our AGAL version.
+    fn check_register_num(&self, register_type: &RegisterType, reg_num: u16) -> Result<()> {
+        let version = self.shader_config.version;
+        let is_vertex = matches!(self.shader_config.shader_type, ShaderType::Vertex);
+        let limit = match register_type {
+            RegisterType::Attribute => MAX_VERTEX_ATTRIBUTES as u16,
+            RegisterType::Constant if is_vertex => version.num_vertex_constants() as u16,
+            RegisterType::Constant => version.num_fragment_constants() as u16,
+            RegisterType::Temporary => version.num_temporaries() as u16,
+            RegisterType::Output if is_vertex => 1,
+            RegisterType::Output => version.num_color_outputs(),
+            RegisterType::Varying => version.num_varyings(),
+            RegisterType::Sampler => MAX_TEXTURES as u16,
+            RegisterType::DepthOutput => u16::from(!is_vertex && version.number() >= 2),
+            RegisterType::InstanceId => u16::from(is_vertex && version.number() >= 3),
+        };
+        if reg_num >= limit {
+            return Err(Error::RegisterOutOfRange(reg_num));
+        }
+        Ok(())
+    }
+
     fn emit_const_register_load(&mut self, index: usize) -> Result<Handle<Expression>> {
         let const_value_expr = self.module.const_expressions.append(
             Expression::Literal(Literal::U32(index as u32)),
@@ -778,6 +994,11 @@ impl<'a> NagaBuilder<'a> {
         extend_to_vec4: bool,
         output: VectorSize,
     ) -> Result<Handle<Expression>> {
+        match source.direct_mode {
+            DirectMode::Direct => self.check_register_num(&source.register_type, source.reg_num)?,
+            DirectMode::Indirect => self.check_register_num(&source.index_type, source.reg_num)?,
+        }
+
         let mut load_register = |register_type: &RegisterType, reg_num| {
             match register_type {
                 // We can use a function argument directly - we don't need
@@ -803,6 +1024,22 @@ impl<'a> NagaBuilder<'a> {
                         VertexAttributeFormat::Float4,
                     )
                 }),
+                // The instance id is an integer, which we make available in every component
+                RegisterType::InstanceId => Ok({
+                    let instance_id = self.get_instance_id();
+                    let instance_id = self.evaluate_expr(Expression::As {
+                        expr: instance_id,
+                        kind: ScalarKind::Float,
+                        convert: Some(4),
+                    });
+                    (
+                        self.evaluate_expr(Expression::Splat {
+                            size: VectorSize::Quad,
+                            value: instance_id,
+                        }),
+                        VertexAttributeFormat::Float4,
+                    )
+                }),
                 _ => Err(Error::Unimplemented(format!(
                     "Unimplemented source reg type {:?}",
                     source.register_type
@@ -927,8 +1164,10 @@ impl<'a> NagaBuilder<'a> {
     }
 
     fn emit_dest_store(&mut self, dest: &DestField, expr: Handle<Expression>) -> Result<()> {
+        self.check_register_num(&dest.register_type, dest.reg_num)?;
         let base_expr = match dest.register_type {
-            RegisterType::Output => self.dest,
+            RegisterType::Output => self.get_color_output_pointer(dest.reg_num)?,
+            RegisterType::DepthOutput => self.get_depth_output_pointer(),
             RegisterType::Varying => self.get_varying_pointer(dest.reg_num as usize)?,
             RegisterType::Temporary => self.get_temporary_register(dest.reg_num as usize)?,
             _ => {
@@ -1025,6 +1264,119 @@ impl<'a> NagaBuilder<'a> {
         block.push(stmt, Span::UNDEFINED);
     }
 
+    /// Samples the texture given by `sampler_field`, at the coordinates in `source`.
+    /// With `bias_from_source`, the 'w' component of `source` is used as the LOD bias.
+    fn emit_texture_sample(
+        &mut self,
+        source: &SourceField,
+        sampler_field: &SamplerField,
+        bias_from_source: bool,
+    ) -> Result<Handle<Expression>> {
+        let texture_samplers = self.texture_samplers.as_ref().unwrap();
+
+        let texture_id = sampler_field.reg_num;
+        if sampler_field.reg_type != RegisterType::Sampler {
+            panic!("Invalid sample register type {:?}", sampler_field);
+        }
+
+        let mut filter = sampler_field.filter;
+        let mut wrapping = sampler_field.wrapping;
+
+        // See https://github.com/openfl/openfl/issues/1332
+
+        // FIXME - Flash Player seems to unconditionally use sampler overrides,
+        // regardless of whether or not `ignore_sampler` is set. I haven't
+        // found any real SWFs that use it, so let's panic so that get
+        // get a bug report if it ever happens.
+        if sampler_field.special.ignore_sampler {
+            panic!("Found ignore_sampler in {:?}", sampler_field);
+        }
+
+        if let Some(sampler_override) = &self.shader_config.sampler_overrides[texture_id as usize] {
+            filter = sampler_override.filter;
+            wrapping = sampler_override.wrapping;
+        }
+
+        let sampler_binding = match (filter, wrapping) {
+            (Filter::Linear, Wrapping::Clamp) => texture_samplers.clamp_linear,
+            (Filter::Linear, Wrapping::Repeat) => texture_samplers.repeat_linear,
+            (Filter::Linear, Wrapping::ClampURepeatV) => texture_samplers.clamp_u_repeat_v_linear,
+            (Filter::Linear, Wrapping::RepeatUClampV) => texture_samplers.repeat_u_clamp_v_linear,
+            (Filter::Nearest, Wrapping::Clamp) => texture_samplers.clamp_nearest,
+            (Filter::Nearest, Wrapping::Repeat) => texture_samplers.repeat_nearest,
+            (Filter::Nearest, Wrapping::ClampURepeatV) => texture_samplers.clamp_u_repeat_v_nearest,
+            (Filter::Nearest, Wrapping::RepeatUClampV) => texture_samplers.repeat_u_clamp_v_nearest,
+            (
+                Filter::Anisotropic2x
+                | Filter::Anisotropic4x
+                | Filter::Anisotropic8x
+                | Filter::Anisotropic16x,
+                _,
+            ) => {
+                // FIXME - implement anisotropic filters with wgpu
+                match wrapping {
+                    Wrapping::Clamp => texture_samplers.clamp_linear,
+                    Wrapping::Repeat => texture_samplers.repeat_linear,
+                    Wrapping::ClampURepeatV => texture_samplers.clamp_u_repeat_v_linear,
+                    Wrapping::RepeatUClampV => texture_samplers.repeat_u_clamp_v_linear,
+                }
+            }
+        };
+
+        let coord = self.emit_source_field_load(source, false)?;
+        let level = if bias_from_source {
+            let bias = self.evaluate_expr(Expression::AccessIndex {
+                base: coord,
+                index: 3,
+            });
+            naga::SampleLevel::Bias(bias)
+        } else {
+            // FIXME - get this from 'LOD_bias' in the sampler field
+            naga::SampleLevel::Auto
+        };
+        let coord = match sampler_field.dimension {
+            Dimension::TwoD => {
+                self.evaluate_expr(Expression::Swizzle {
+                    size: VectorSize::Bi,
+                    vector: coord,
+                    // Only the first two components matter here
+                    pattern: [
+                        SwizzleComponent::X,
+                        SwizzleComponent::Y,
+                        SwizzleComponent::W,
+                        SwizzleComponent::W,
+                    ],
+                })
+            }
+            Dimension::Cube => {
+                self.evaluate_expr(Expression::Swizzle {
+                    size: VectorSize::Tri,
+                    vector: coord,
+                    // Only the first three components matter here
+                    pattern: [
+                        SwizzleComponent::X,
+                        SwizzleComponent::Y,
+                        SwizzleComponent::Z,
+                        SwizzleComponent::W,
+                    ],
+                })
+            }
+        };
+
+        let image = self.emit_texture_load(texture_id as usize, sampler_field.dimension)?;
+        let tex = self.evaluate_expr(Expression::ImageSample {
+            image,
+            sampler: sampler_binding,
+            coordinate: coord,
+            array_index: None,
+            offset: None,
+            level,
+            depth_ref: None,
+            gather: None,
+        });
+        Ok(tex)
+    }
+
     fn process_opcode(
         &mut self,
         opcode: &Opcode,
@@ -1134,112 +1486,20 @@ impl<'a> NagaBuilder<'a> {
             }
             Opcode::Tex => {
                 let sampler_field = source2.assert_sampler();
-
-                let texture_samplers = self.texture_samplers.as_ref().unwrap();
-
-                let texture_id = sampler_field.reg_num;
-                if sampler_field.reg_type != RegisterType::Sampler {
-                    panic!("Invalid sample register type {:?}", sampler_field);
-                }
-
-                let mut filter = sampler_field.filter;
-                let mut wrapping = sampler_field.wrapping;
-
-                // See https://github.com/openfl/openfl/issues/1332
-
-                // FIXME - Flash Player seems to unconditionally use sampler overrides,
-                // regardless of whether or not `ignore_sampler` is set. I haven't
-                // found any real SWFs that use it, so let's panic so that get
-                // get a bug report if it ever happens.
-                if sampler_field.special.ignore_sampler {
-                    panic!("Found ignore_sampler in {:?}", sampler_field);
-                }
-
-                if let Some(sampler_override) =
-                    &self.shader_config.sampler_overrides[texture_id as usize]
-                {
-                    filter = sampler_override.filter;
-                    wrapping = sampler_override.wrapping;
-                }
-
-                let sampler_binding = match (filter, wrapping) {
-                    (Filter::Linear, Wrapping::Clamp) => texture_samplers.clamp_linear,
-                    (Filter::Linear, Wrapping::Repeat) => texture_samplers.repeat_linear,
-                    (Filter::Linear, Wrapping::ClampURepeatV) => {
-                        texture_samplers.clamp_u_repeat_v_linear
-                    }
-                    (Filter::Linear, Wrapping::RepeatUClampV) => {
-                        texture_samplers.repeat_u_clamp_v_linear
-                    }
-                    (Filter::Nearest, Wrapping::Clamp) => texture_samplers.clamp_nearest,
-                    (Filter::Nearest, Wrapping::Repeat) => texture_samplers.repeat_nearest,
-                    (Filter::Nearest, Wrapping::ClampURepeatV) => {
-                        texture_samplers.clamp_u_repeat_v_nearest
-                    }
-                    (Filter::Nearest, Wrapping::RepeatUClampV) => {
-                        texture_samplers.repeat_u_clamp_v_nearest
-                    }
-                    (
-                        Filter::Anisotropic2x
-                        | Filter::Anisotropic4x
-                        | Filter::Anisotropic8x
-                        | Filter::Anisotropic16x,
-                        _,
-                    ) => {
-                        // FIXME - implement anisotropic filters with wgpu
-                        match wrapping {
-                            Wrapping::Clamp => texture_samplers.clamp_linear,
-                            Wrapping::Repeat => texture_samplers.repeat_linear,
-                            Wrapping::ClampURepeatV => texture_samplers.clamp_u_repeat_v_linear,
-                            Wrapping::RepeatUClampV => texture_samplers.repeat_u_clamp_v_linear,
-                        }
-                    }
-                };
-
-                let coord = self.emit_source_field_load(source1, false)?;
-                let coord = match sampler_field.dimension {
-                    Dimension::TwoD => {
-                        self.evaluate_expr(Expression::Swizzle {
-                            size: VectorSize::Bi,
-                            vector: coord,
-                            // Only the first two components matter here
-                            pattern: [
-                                SwizzleComponent::X,
-                                SwizzleComponent::Y,
-                                SwizzleComponent::W,
-                                SwizzleComponent::W,
-                            ],
-                        })
-                    }
-                    Dimension::Cube => {
-                        self.evaluate_expr(Expression::Swizzle {
-                            size: VectorSize::Tri,
-                            vector: coord,
-                            // Only the first three components matter here
-                            pattern: [
-                                SwizzleComponent::X,
-                                SwizzleComponent::Y,
-                                SwizzleComponent::Z,
-                                SwizzleComponent::W,
-                            ],
-                        })
-                    }
-                };
-
-                let image = self.emit_texture_load(texture_id as usize, sampler_field.dimension)?;
-                let tex = self.evaluate_expr(Expression::ImageSample {
-                    image,
-                    sampler: sampler_binding,
-                    coordinate: coord,
-                    array_index: None,
-                    offset: None,
-                    // FIXME - get this from 'LOD_bias' in the sampler field
-                    level: naga::SampleLevel::Auto,
-                    depth_ref: None,
-                    gather: None,
-                });
+                let tex = self.emit_texture_sample(source1, sampler_field, false)?;
                 self.emit_dest_store(dest, tex)?;
             }
+            // Like 'tex', but using the 'w' component of the coordinates as the LOD bias.
+            Opcode::Ted => {
+                let sampler_field = source2.assert_sampler();
+                let tex = self.emit_texture_sample(source1, sampler_field, true)?;
+                self.emit_dest_store(dest, tex)?;
+            }
+            Opcode::Tld => {
+                return Err(Error::Unimplemented(
+                    "Vertex texture fetch (tld) is not supported".to_string(),
+                ));
+            }
             Opcode::Cos => {
                 let source = self.emit_source_field_load(source1, true)?;
                 let cos = self.evaluate_expr(Expression::Math {
@@ -1418,45 +1678,33 @@ impl<'a> NagaBuilder<'a> {
                     condition: all_match,
                 })
             }
-            Opcode::Els => {
-                if let BlockStackEntry::IfElse {
-                    after_if: _,
-                    after_else: _,
-                    in_after_if,
-                    condition: _,
-                } = self.blocks.last_mut().unwrap()
-                {
-                    if !*in_after_if {
-                        panic!("Multiple' els' opcodes for single 'if' opcode");
-                    }
+            Opcode::Els => match self.blocks.last_mut() {
+                Some(BlockStackEntry::IfElse { in_after_if, .. }) if *in_after_if => {
                     *in_after_if = false;
-                } else {
-                    unreachable!()
                 }
-            }
+                // An 'els' without an 'if', or a second 'els' for the same 'if'
+                _ => return Err(Error::UnbalancedControlFlow),
+            },
             Opcode::Eif => {
-                let block = self.blocks.pop().unwrap();
-
-                match block {
-                    BlockStackEntry::IfElse {
-                        after_if,
-                        after_else,
-                        in_after_if: _,
+                if !matches!(self.blocks.last(), Some(BlockStackEntry::IfElse { .. })) {
+                    return Err(Error::UnbalancedControlFlow);
+                }
+                if let Some(BlockStackEntry::IfElse {
+                    after_if,
+                    after_else,
+                    in_after_if: _,
+                    condition,
+                }) = self.blocks.pop()
+                {
+                    self.push_statement(Statement::If {
                         condition,
-                    } => {
-                        self.push_statement(Statement::If {
-                            condition,
-                            // The opcodes occurig directly after the 'if' opcode
-                            // get run if the condition is true
-                            accept: after_if,
-                            // The opcodes occurring directly after the 'els' opcode
-                            // get run if the condition is false
-                            reject: after_else,
-                        });
-                    }
-                    BlockStackEntry::Normal(block) => {
-                        panic!("Eif opcode without matching 'if': {:?}", block)
-                    }
+                        // The opcodes occurig directly after the 'if' opcode
+                        // get run if the condition is true
+                        accept: after_if,
+                        // The opcodes occurring directly after the 'els' opcode
+                        // get run if the condition is false
+                        reject: after_else,
+                    });
                 }
             }
             Opcode::Dp3 => {
@@ -1553,6 +1801,17 @@ impl<'a> NagaBuilder<'a> {
                 });
                 self.emit_dest_store(dest, frc)?;
             }
+            Opcode::Sgn => {
+                let source = self.emit_source_field_load(source1, true)?;
+                let sgn = self.evaluate_expr(Expression::Math {
+                    fun: MathFunction::Sign,
+                    arg: source,
+                    arg1: None,
+                    arg2: None,
+                    arg3: None,
+                });
+                self.emit_dest_store(dest, sgn)?;
+            }
             Opcode::Abs => {
                 let source = self.emit_source_field_load(source1, true)?;
                 let abs = self.evaluate_expr(Expression::Math {
@@ -1671,6 +1930,11 @@ impl<'a> NagaBuilder<'a> {
     }
 
     fn finish(mut self) -> Result<Module> {
+        // Every 'if' opcode must be closed by an 'eif' opcode
+        if self.blocks.len() != 1 {
+            return Err(Error::UnbalancedControlFlow);
+        }
+
         // We're consuming 'self', so just store store garbage here so that we can continue
         // to use methods on 'self'
         let return_ty = std::mem::replace(
@@ -1698,12 +1962,9 @@ impl<'a> NagaBuilder<'a> {
 
         let block = match self.blocks.pop().unwrap() {
             BlockStackEntry::Normal(block) => block,
-            block => panic!("Unfinished if statement: {:?}", block),
+            BlockStackEntry::IfElse { .. } => unreachable!(),
         };
 
-        if !self.blocks.is_empty() {
-            panic!("Unbalanced blocks: {:?}", self.blocks);
-        }
         if !self.func.body.is_empty() {
             panic!("Incorrectly wrote to function body: {:?}", self.func.body);
         }
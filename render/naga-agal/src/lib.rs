@@ -11,6 +11,12 @@ pub const SHADER_ENTRY_POINT: &str = "main";
 pub const MAX_VERTEX_ATTRIBUTES: usize = 8;
 pub const MAX_TEXTURES: usize = 8;
 
+/// The number of vertex program constants available in the newest AGAL version.
+/// Older versions use a prefix of these registers.
+pub const MAX_VERTEX_PROGRAM_CONSTANTS: u64 = 250;
+/// The number of fragment program constants available in the newest AGAL version.
+pub const MAX_FRAGMENT_PROGRAM_CONSTANTS: u64 = 200;
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum VertexAttributeFormat {
     Float1,
@@ -29,6 +35,8 @@ pub enum Error {
     ReadError(std::io::Error),
     InvalidOpcode(u32),
     InvalidVersion(u32),
+    RegisterOutOfRange(u16),
+    UnbalancedControlFlow,
 }
 
 impl From<std::io::Error> for Error {
@@ -75,9 +83,10 @@ pub use types::{Filter, Mipmap, SamplerOverride, Wrapping};
  *   If a shader does not write to a varying register, then it is not included in the
  *   Naga output struct type.
  *
- * * Program constants - An AGAL vertex shader has access to 128 program constants.
- *   These are mapped to a single Naga uniform buffer, with a binding id of 0.
- *   Each program constant is a vec4, and are stored in increasing order of register number.
+ * * Program constants - An AGAL vertex shader has access to 128 program constants
+ *   (250 in AGAL2 and AGAL3). These are mapped to a single Naga uniform buffer,
+ *   with a binding id of 0. Each program constant is a vec4, and are stored in
+ *   increasing order of register number.
  *
  * # Fragment Shader
  *
@@ -86,8 +95,20 @@ pub use types::{Filter, Mipmap, SamplerOverride, Wrapping};
  *   binding in the Naga input type. For example, if a fragment shader uses varying registers
  *   2 and 5, then the Naga input type will have two members, with binding ids 2 and 5.
  *
- * * Program constants - An AGAL fragment shader has access to 28 program constants.
- *   These are mapped to a single Naga uniform buffer, with a binding id of 1.
+ * * Program constants - An AGAL fragment shader has access to 28 program constants
+ *   (64 in AGAL2, and 200 in AGAL3). These are mapped to a single Naga uniform buffer,
+ *   with a binding id of 1.
+ *
+ * * Fragment output - The main color output (`oc` or `oc0`) is mapped to location 0.
+ *   In AGAL2 and later, the additional color outputs `oc1` to `oc3` are mapped to
+ *   locations 1 to 3, and the depth output `od` is mapped to the Naga `FragDepth` output.
+ *   As with varying registers, only the outputs that are written to are included in the
+ *   Naga output struct type.
+ *
+ * # Control flow
+ *
+ * The AGAL2 conditional opcodes (`ife`, `ine`, `ifg`, `ifl`, `els` and `eif`) are translated
+ * to nested Naga `If` statements. A program with unbalanced conditionals is rejected.
  *
  */
 pub fn agal_to_naga(
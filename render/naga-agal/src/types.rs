@@ -30,12 +30,15 @@ pub enum Opcode {
     M33 = 0x17,
     M44 = 0x18,
     M34 = 0x19,
+    Ted = 0x26,
     Kil = 0x27,
     Tex = 0x28,
     Sge = 0x29,
     Slt = 0x2a,
+    Sgn = 0x2b,
     Seq = 0x2c,
     Sne = 0x2d,
+    Tld = 0x2e,
     Ddx = 0x1a,
     Ddy = 0x1b,
     Ife = 0x1c,
@@ -46,6 +49,29 @@ pub enum Opcode {
     Eif = 0x21,
 }
 
+impl Opcode {
+    /// The first AGAL version that supports this opcode.
+    pub fn min_version(&self) -> u32 {
+        match self {
+            Opcode::Ddx
+            | Opcode::Ddy
+            | Opcode::Ife
+            | Opcode::Ine
+            | Opcode::Ifg
+            | Opcode::Ifl
+            | Opcode::Els
+            | Opcode::Eif => 2,
+            Opcode::Ted | Opcode::Tld => 3,
+            _ => 1,
+        }
+    }
+
+    /// Whether the second source of this opcode is a sampler, rather than a register.
+    pub fn has_sampler(&self) -> bool {
+        matches!(self, Opcode::Tex | Opcode::Ted | Opcode::Tld)
+    }
+}
+
 #[derive(FromPrimitive, Debug, Clone, PartialEq, Eq)]
 pub enum RegisterType {
     Attribute = 0,
@@ -54,7 +80,8 @@ pub enum RegisterType {
     Output = 3,
     Varying = 4,
     Sampler = 5,
-    FragmentRegister = 6,
+    DepthOutput = 6,
+    InstanceId = 7,
 }
 
 #[derive(Debug, FromPrimitive, Clone)]
@@ -129,6 +129,12 @@ impl<'a> NagaBuilder<'a> {
             }
         }
 
+        // If the fragment shader wrote to any additional color outputs or to the depth output,
+        // we need to return them as well.
+        if let ShaderType::Fragment = self.shader_config.shader_type {
+            self.emit_extra_output_loads(&mut components);
+        }
+
         let components = components.into_iter().map(|c| c.unwrap()).collect();
 
         Ok(self.evaluate_expr(Expression::Compose {
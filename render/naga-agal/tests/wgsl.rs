@@ -2,7 +2,7 @@ use naga::{
     valid::{Capabilities, ValidationFlags, Validator},
     Module,
 };
-use naga_agal::{agal_to_naga, VertexAttributeFormat};
+use naga_agal::{agal_to_naga, Error, VertexAttributeFormat};
 
 pub fn to_wgsl(module: &Module) -> String {
     let mut out = String::new();
@@ -198,3 +198,90 @@ fn test_misc_opcodes() {
         ShaderType::Fragment
     );
 }
+
+#[test]
+fn test_control_flow() {
+    // ife v0.x, fc0.x
+    //   sgn ft0, v0
+    // els
+    //   ifl v0.y, fc0.y
+    //     mov ft0, fc63
+    //   eif
+    // eif
+    // mov od, ft0.z
+    // mov oc1, v9
+    // mov oc, ft0
+    const CONTROL_FLOW_FRAGMENT: &[u8] = include!("control_flow_fragment.agal");
+
+    test_shader!(
+        CONTROL_FLOW_FRAGMENT,
+        &[None, None, None, None, None, None, None, None],
+        ShaderType::Fragment
+    );
+}
+
+#[test]
+fn test_agal3() {
+    // add vt0, va0, iid0.x
+    // mov op, vt0
+    // mov v9, vc249
+    const AGAL3_VERTEX: &[u8] = include!("agal3_vertex.agal");
+
+    // ted ft0, v9, fs0 <2d, linear, repeat>
+    // mov oc, ft0
+    // mov ft1, fc199
+    // mov oc3, ft1
+    const AGAL3_FRAGMENT: &[u8] = include!("agal3_fragment.agal");
+
+    test_shader!(
+        AGAL3_VERTEX,
+        &[
+            Some(VertexAttributeFormat::Float4),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None
+        ],
+        ShaderType::Vertex
+    );
+
+    test_shader!(
+        AGAL3_FRAGMENT,
+        &[None, None, None, None, None, None, None, None],
+        ShaderType::Fragment
+    );
+}
+
+#[test]
+fn test_invalid_programs() {
+    const CONTROL_FLOW_FRAGMENT: &[u8] = include!("control_flow_fragment.agal");
+
+    // The conditional opcodes aren't available in AGAL1
+    let mut program = CONTROL_FLOW_FRAGMENT.to_vec();
+    program[1] = 1;
+    assert!(matches!(
+        agal_to_naga(&program, &[None; 8], &[None; 8]),
+        Err(Error::InvalidOpcode(0x1c))
+    ));
+
+    // An AGAL1 program reading from fc28
+    let program = [
+        160, 1, 0, 0, 0, 161, 1, 0, 0, 0, 0, 0, 0, 15, 3, 28, 0, 0, 228, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0,
+    ];
+    assert!(matches!(
+        agal_to_naga(&program, &[None; 8], &[None; 8]),
+        Err(Error::RegisterOutOfRange(28))
+    ));
+
+    // An 'ife' without a matching 'eif'
+    let mut program = CONTROL_FLOW_FRAGMENT.to_vec();
+    program.truncate(program.len() - 5 * 24);
+    assert!(matches!(
+        agal_to_naga(&program, &[None; 8], &[None; 8]),
+        Err(Error::UnbalancedControlFlow)
+    ));
+}
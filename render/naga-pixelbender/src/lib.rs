@@ -486,8 +486,10 @@ impl<'a> ShaderBuilder<'a> {
                     metadata: _,
                 } => {
                     if name == OUT_COORD_NAME {
-                        // This is passed in through a builtin, not a uniform
-                        out_coord = Some(reg);
+                        // The pixel coordinate is passed in through a builtin,
+                        // and the uniform holds an offset to add to it
+                        out_coord = Some((reg, num_vec4fs));
+                        num_vec4fs += 1;
                         continue;
                     }
 
@@ -652,11 +654,29 @@ impl<'a> ShaderBuilder<'a> {
         // Emit this after all other registers have been initialized
         // (it may use te same register as another parameter, but with different components)
 
-        if let Some(coord_reg) = out_coord {
-            let coord_val = self
+        if let Some((coord_reg, offset)) = out_coord {
+            let position = self
                 .func
                 .expressions
                 .append(Expression::FunctionArgument(0), Span::UNDEFINED);
+
+            let global_base = self.func.expressions.append(
+                Expression::GlobalVariable(shader_float_parameters),
+                Span::UNDEFINED,
+            );
+            let offset_ptr = self.evaluate_expr(Expression::AccessIndex {
+                base: global_base,
+                index: offset,
+            });
+            let offset_val = self.evaluate_expr(Expression::Load {
+                pointer: offset_ptr,
+            });
+
+            let coord_val = self.evaluate_expr(Expression::Binary {
+                op: BinaryOperator::Add,
+                left: position,
+                right: offset_val,
+            });
             self.emit_dest_store(coord_val, coord_reg)?;
         }
 
@@ -86,7 +86,7 @@ pub trait RenderBackend: Downcast {
         region: PixelRegion,
     ) -> Result<(), Error>;
 
-    fn create_context3d(&mut self) -> Result<Box<dyn Context3D>, Error>;
+    fn create_context3d(&mut self, profile: Context3DProfile) -> Result<Box<dyn Context3D>, Error>;
     fn context3d_present(&mut self, context: &mut dyn Context3D) -> Result<(), Error>;
 
     fn debug_info(&self) -> Cow<'static, str>;
@@ -169,6 +169,92 @@ impl Context3DTextureFormat {
     }
 }
 
+/// A Stage3D feature profile, as requested through `Stage3D.requestContext3D`.
+///
+/// Profiles are ordered from least to most capable.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Context3DProfile {
+    BaselineConstrained,
+    Baseline,
+    BaselineExtended,
+    StandardConstrained,
+    Standard,
+    StandardExtended,
+}
+
+impl Context3DProfile {
+    pub fn from_wstr(wstr: &WStr) -> Option<Context3DProfile> {
+        if wstr == b"baselineConstrained" {
+            Some(Context3DProfile::BaselineConstrained)
+        } else if wstr == b"baseline" {
+            Some(Context3DProfile::Baseline)
+        } else if wstr == b"baselineExtended" {
+            Some(Context3DProfile::BaselineExtended)
+        } else if wstr == b"standardConstrained" {
+            Some(Context3DProfile::StandardConstrained)
+        } else if wstr == b"standard" {
+            Some(Context3DProfile::Standard)
+        } else if wstr == b"standardExtended" {
+            Some(Context3DProfile::StandardExtended)
+        } else {
+            None
+        }
+    }
+
+    pub fn to_str(self) -> &'static str {
+        match self {
+            Context3DProfile::BaselineConstrained => "baselineConstrained",
+            Context3DProfile::Baseline => "baseline",
+            Context3DProfile::BaselineExtended => "baselineExtended",
+            Context3DProfile::StandardConstrained => "standardConstrained",
+            Context3DProfile::Standard => "standard",
+            Context3DProfile::StandardExtended => "standardExtended",
+        }
+    }
+
+    /// The largest width or height of a texture created with this profile.
+    pub fn max_texture_size(self) -> u32 {
+        match self {
+            Context3DProfile::BaselineConstrained | Context3DProfile::Baseline => 2048,
+            _ => 4096,
+        }
+    }
+
+    /// The largest width or height of the back buffer.
+    pub fn max_back_buffer_size(self) -> u32 {
+        self.max_texture_size()
+    }
+
+    /// The newest AGAL version that programs may be compiled with.
+    pub fn max_agal_version(self) -> u32 {
+        match self {
+            Context3DProfile::BaselineConstrained
+            | Context3DProfile::Baseline
+            | Context3DProfile::BaselineExtended => 1,
+            Context3DProfile::StandardConstrained | Context3DProfile::Standard => 2,
+            Context3DProfile::StandardExtended => 3,
+        }
+    }
+
+    /// The number of color outputs that can be rendered to at once.
+    pub fn max_render_targets(self) -> u32 {
+        if self.is_standard() {
+            4
+        } else {
+            1
+        }
+    }
+
+    /// Whether `Context3DTextureFormat::RgbaHalfFloat` textures can be created.
+    pub fn supports_float_textures(self) -> bool {
+        self.is_standard()
+    }
+
+    fn is_standard(self) -> bool {
+        self >= Context3DProfile::StandardConstrained
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub enum Context3DBlendFactor {
     DestinationAlpha,
@@ -228,6 +314,9 @@ pub trait Context3D: Downcast {
     // as part of stage rendering
     fn should_render(&self) -> bool;
 
+    // A description of the driver this context renders with, for `Context3D.driverInfo`
+    fn driver_info(&self) -> String;
+
     // Get a 'disposed' handle - this is what we store in all IndexBuffer3D
     // objects after dispose() has been called.
     fn disposed_index_buffer_handle(&self) -> Rc<dyn IndexBuffer>;
@@ -282,6 +371,22 @@ pub enum Context3DTriangleFace {
     FrontAndBack,
 }
 
+impl Context3DTriangleFace {
+    pub fn from_wstr(s: &WStr) -> Option<Self> {
+        if s == b"none" {
+            Some(Context3DTriangleFace::None)
+        } else if s == b"back" {
+            Some(Context3DTriangleFace::Back)
+        } else if s == b"front" {
+            Some(Context3DTriangleFace::Front)
+        } else if s == b"frontAndBack" {
+            Some(Context3DTriangleFace::FrontAndBack)
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 pub enum Context3DCompareMode {
     Never,
@@ -318,6 +423,42 @@ impl Context3DCompareMode {
     }
 }
 
+#[derive(Copy, Clone, Debug)]
+pub enum Context3DStencilAction {
+    Keep,
+    Zero,
+    Set,
+    IncrementSaturate,
+    DecrementSaturate,
+    IncrementWrap,
+    DecrementWrap,
+    Invert,
+}
+
+impl Context3DStencilAction {
+    pub fn from_wstr(s: &WStr) -> Option<Self> {
+        if s == b"keep" {
+            Some(Context3DStencilAction::Keep)
+        } else if s == b"zero" {
+            Some(Context3DStencilAction::Zero)
+        } else if s == b"set" {
+            Some(Context3DStencilAction::Set)
+        } else if s == b"incrementSaturate" {
+            Some(Context3DStencilAction::IncrementSaturate)
+        } else if s == b"decrementSaturate" {
+            Some(Context3DStencilAction::DecrementSaturate)
+        } else if s == b"incrementWrap" {
+            Some(Context3DStencilAction::IncrementWrap)
+        } else if s == b"decrementWrap" {
+            Some(Context3DStencilAction::DecrementWrap)
+        } else if s == b"invert" {
+            Some(Context3DStencilAction::Invert)
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 pub enum Context3DWrapMode {
     Clamp,
@@ -444,6 +585,10 @@ pub enum Context3DCommand<'a> {
         dest: Rc<dyn Texture>,
         layer: u32,
     },
+    CopyBitmapHandleToTexture {
+        source: BitmapHandle,
+        dest: Rc<dyn Texture>,
+    },
     SetTextureAt {
         sampler: u32,
         texture: Option<Rc<dyn Texture>>,
@@ -459,6 +604,18 @@ pub enum Context3DCommand<'a> {
         depth_mask: bool,
         pass_compare_mode: Context3DCompareMode,
     },
+    SetStencilActions {
+        face: Context3DTriangleFace,
+        compare_mode: Context3DCompareMode,
+        action_on_both_pass: Context3DStencilAction,
+        action_on_depth_fail: Context3DStencilAction,
+        action_on_depth_pass_stencil_fail: Context3DStencilAction,
+    },
+    SetStencilReferenceValue {
+        reference_value: u32,
+        read_mask: u32,
+        write_mask: u32,
+    },
     SetBlendFactors {
         source_factor: Context3DBlendFactor,
         destination_factor: Context3DBlendFactor,
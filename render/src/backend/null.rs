@@ -90,7 +90,10 @@ impl RenderBackend for NullRenderer {
         Ok(())
     }
 
-    fn create_context3d(&mut self) -> Result<Box<dyn super::Context3D>, Error> {
+    fn create_context3d(
+        &mut self,
+        _profile: super::Context3DProfile,
+    ) -> Result<Box<dyn super::Context3D>, Error> {
         Err(Error::Unimplemented("createContext3D".into()))
     }
 
@@ -2,7 +2,7 @@ use crate::backend::ShapeHandle;
 use crate::bitmap::{BitmapHandle, PixelSnapping};
 use crate::matrix::Matrix;
 use crate::pixel_bender::PixelBenderShaderHandle;
-use crate::transform::Transform;
+use crate::transform::{PerspectiveTransform, Transform};
 use swf::{BlendMode, Color};
 
 pub trait CommandHandler {
@@ -13,6 +13,12 @@ pub trait CommandHandler {
         smoothing: bool,
         pixel_snapping: PixelSnapping,
     );
+    fn render_bitmap_projected(
+        &mut self,
+        bitmap: BitmapHandle,
+        transform: PerspectiveTransform,
+        smoothing: bool,
+    );
     fn render_stage3d(&mut self, bitmap: BitmapHandle, transform: Transform);
     fn render_shape(&mut self, shape: ShapeHandle, transform: Transform);
     fn draw_rect(&mut self, color: Color, matrix: Matrix);
@@ -58,6 +64,11 @@ impl CommandList {
                     smoothing,
                     pixel_snapping,
                 } => handler.render_bitmap(bitmap, transform, smoothing, pixel_snapping),
+                Command::RenderBitmapProjected {
+                    bitmap,
+                    transform,
+                    smoothing,
+                } => handler.render_bitmap_projected(bitmap, transform, smoothing),
                 Command::RenderShape { shape, transform } => handler.render_shape(shape, transform),
                 Command::RenderStage3D { bitmap, transform } => {
                     handler.render_stage3d(bitmap, transform)
@@ -96,6 +107,22 @@ impl CommandHandler for CommandList {
         }
     }
 
+    #[inline]
+    fn render_bitmap_projected(
+        &mut self,
+        bitmap: BitmapHandle,
+        transform: PerspectiveTransform,
+        smoothing: bool,
+    ) {
+        if self.maskers_in_progress <= 1 {
+            self.commands.push(Command::RenderBitmapProjected {
+                bitmap,
+                transform,
+                smoothing,
+            });
+        }
+    }
+
     #[inline]
     fn render_stage3d(&mut self, bitmap: BitmapHandle, transform: Transform) {
         if self.maskers_in_progress <= 1 {
@@ -167,6 +194,11 @@ pub enum Command {
         smoothing: bool,
         pixel_snapping: PixelSnapping,
     },
+    RenderBitmapProjected {
+        bitmap: BitmapHandle,
+        transform: PerspectiveTransform,
+        smoothing: bool,
+    },
     RenderStage3D {
         bitmap: BitmapHandle,
         transform: Transform,
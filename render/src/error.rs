@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 use thiserror::Error;
 
+use crate::backend::Context3DProfile;
 use crate::bitmap::BitmapHandle;
 
 #[derive(Error, Debug)]
@@ -40,6 +41,9 @@ pub enum Error {
     #[error("Unknown handle {0:?}")]
     UnknownHandle(BitmapHandle),
 
+    #[error("Context3D profile {0:?} is not supported by this device")]
+    UnsupportedContext3DProfile(Context3DProfile),
+
     #[error("Not yet implemented: {0}")]
     Unimplemented(Cow<'static, str>),
 }
@@ -80,11 +80,55 @@ impl Filter {
             Filter::GlowFilter(filter) => filter.calculate_dest_rect(source_rect),
             Filter::DropShadowFilter(filter) => filter.calculate_dest_rect(source_rect),
             Filter::BevelFilter(filter) => filter.calculate_dest_rect(source_rect),
+            Filter::GradientGlowFilter(filter) => filter.calculate_glow_dest_rect(source_rect),
+            Filter::GradientBevelFilter(filter) => filter.calculate_bevel_dest_rect(source_rect),
             Filter::DisplacementMapFilter(filter) => filter.calculate_dest_rect(source_rect),
             _ => source_rect,
         }
     }
 
+    /// Calculates the rectangle reported by `BitmapData.generateFilterRect`,
+    /// rounded out to whole pixels.
+    ///
+    /// Unlike `calculate_dest_rect`, which sizes the textures used to render
+    /// a filter, this leaves out the space an inner filter only needs while
+    /// it's being applied, and includes the reach of filters that sample
+    /// neighbouring pixels.
+    pub fn calculate_generated_rect(&self, source_rect: Rectangle<Twips>) -> Rectangle<Twips> {
+        let dest_rect = match self {
+            Filter::GlowFilter(filter) if filter.is_inner() => source_rect,
+            Filter::DropShadowFilter(filter) if filter.is_inner() => source_rect,
+            Filter::BevelFilter(filter) if filter.is_inner() && !filter.is_on_top() => source_rect,
+            Filter::GradientGlowFilter(filter) | Filter::GradientBevelFilter(filter)
+                if filter.is_inner() && !filter.is_on_top() =>
+            {
+                source_rect
+            }
+            Filter::ConvolutionFilter(filter) => {
+                // Output pixel `x` samples the source from `x - cols / 2` to
+                // `x - cols / 2 + cols - 1`, so every source pixel reaches
+                // `cols / 2` pixels right and `(cols - 1) / 2` pixels left.
+                let cols = i32::from(filter.num_matrix_cols);
+                let rows = i32::from(filter.num_matrix_rows);
+                Rectangle {
+                    x_min: source_rect.x_min - Twips::from_pixels_i32((cols - 1) / 2),
+                    x_max: source_rect.x_max + Twips::from_pixels_i32(cols / 2),
+                    y_min: source_rect.y_min - Twips::from_pixels_i32((rows - 1) / 2),
+                    y_max: source_rect.y_max + Twips::from_pixels_i32(rows / 2),
+                }
+            }
+            Filter::DisplacementMapFilter(filter) => filter.calculate_generated_rect(source_rect),
+            _ => self.calculate_dest_rect(source_rect),
+        };
+
+        Rectangle {
+            x_min: Twips::from_pixels(dest_rect.x_min.to_pixels().floor()),
+            y_min: Twips::from_pixels(dest_rect.y_min.to_pixels().floor()),
+            x_max: Twips::from_pixels(dest_rect.x_max.to_pixels().ceil()),
+            y_max: Twips::from_pixels(dest_rect.y_max.to_pixels().ceil()),
+        }
+    }
+
     /// Checks if this filter is impotent.
     /// Impotent filters will have no effect if applied, and can safely be skipped.
     pub fn impotent(&self) -> bool {
@@ -185,6 +229,26 @@ impl DisplacementMapFilter {
     }
 }
 
+impl DisplacementMapFilter {
+    /// Calculates the rectangle reported by `BitmapData.generateFilterRect`.
+    ///
+    /// In `Color` mode, pixels displaced from outside the source are filled
+    /// with the filter color, so the result grows by the displacement scale.
+    pub fn calculate_generated_rect(&self, source_rect: Rectangle<Twips>) -> Rectangle<Twips> {
+        if self.mode != DisplacementMapFilterMode::Color {
+            return source_rect;
+        }
+        let scale_x = self.scale_x.abs() as f64;
+        let scale_y = self.scale_y.abs() as f64;
+        Rectangle {
+            x_min: source_rect.x_min - Twips::from_pixels((scale_x / 2.0).floor()),
+            x_max: source_rect.x_max + Twips::from_pixels(scale_x.floor()),
+            y_min: source_rect.y_min - Twips::from_pixels((scale_y / 2.0).floor()),
+            y_max: source_rect.y_max + Twips::from_pixels(scale_y.floor()),
+        }
+    }
+}
+
 impl Default for DisplacementMapFilter {
     fn default() -> Self {
         Self {
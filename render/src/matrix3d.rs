@@ -0,0 +1,412 @@
+use crate::matrix::Matrix;
+
+/// A 4x4 transformation matrix, as used by `flash.geom.Matrix3D` and the 3D transforms of
+/// display objects.
+///
+/// The elements are stored in column-major order, matching `Matrix3D.rawData`.
+/// Translations are in pixels.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matrix3D {
+    pub raw_data: [f64; 16],
+}
+
+/// The components of a `Matrix3D` without perspective.
+///
+/// Rotations are Euler angles in radians, applied around the X, Y and then Z axis.
+/// `skew` is the extra rotation of the Y axis around the Z axis, in radians, in the same way as
+/// the skew of a display object's 2D matrix.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matrix3DComponents {
+    pub translation: [f64; 3],
+    pub rotation: [f64; 3],
+    pub scale: [f64; 3],
+    pub skew: f64,
+}
+
+impl Matrix3D {
+    pub const IDENTITY: Self = Self {
+        raw_data: [
+            1.0, 0.0, 0.0, 0.0, //
+            0.0, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, //
+            0.0, 0.0, 0.0, 1.0, //
+        ],
+    };
+
+    /// Returns the element at `row` and `column`.
+    #[inline]
+    pub fn get(&self, row: usize, column: usize) -> f64 {
+        self.raw_data[column * 4 + row]
+    }
+
+    pub fn translation(x: f64, y: f64, z: f64) -> Self {
+        let mut matrix = Self::IDENTITY;
+        matrix.raw_data[12] = x;
+        matrix.raw_data[13] = y;
+        matrix.raw_data[14] = z;
+        matrix
+    }
+
+    pub fn scale(x: f64, y: f64, z: f64) -> Self {
+        let mut matrix = Self::IDENTITY;
+        matrix.raw_data[0] = x;
+        matrix.raw_data[5] = y;
+        matrix.raw_data[10] = z;
+        matrix
+    }
+
+    pub fn rotation_x(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let mut matrix = Self::IDENTITY;
+        matrix.raw_data[5] = cos;
+        matrix.raw_data[6] = sin;
+        matrix.raw_data[9] = -sin;
+        matrix.raw_data[10] = cos;
+        matrix
+    }
+
+    pub fn rotation_y(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let mut matrix = Self::IDENTITY;
+        matrix.raw_data[0] = cos;
+        matrix.raw_data[2] = -sin;
+        matrix.raw_data[8] = sin;
+        matrix.raw_data[10] = cos;
+        matrix
+    }
+
+    pub fn rotation_z(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let mut matrix = Self::IDENTITY;
+        matrix.raw_data[0] = cos;
+        matrix.raw_data[1] = sin;
+        matrix.raw_data[4] = -sin;
+        matrix.raw_data[5] = cos;
+        matrix
+    }
+
+    /// Builds a matrix which scales, skews, rotates and then translates, in the same way as
+    /// `Matrix3D.recompose`.
+    pub fn compose(components: &Matrix3DComponents) -> Self {
+        let [tx, ty, tz] = components.translation;
+        let [rx, ry, rz] = components.rotation;
+        let [sx, sy, sz] = components.scale;
+        let mut scale_skew = Self::scale(sx, sy, sz);
+        let (sin, cos) = components.skew.sin_cos();
+        scale_skew.raw_data[4] = -sy * sin;
+        scale_skew.raw_data[5] = sy * cos;
+        Self::translation(tx, ty, tz)
+            * Self::rotation_z(rz)
+            * Self::rotation_y(ry)
+            * Self::rotation_x(rx)
+            * scale_skew
+    }
+
+    /// Splits this matrix into its translation, rotation, scale and skew.
+    ///
+    /// Only skew between the X and Y axes is kept. Any other skew or perspective is lost.
+    pub fn decompose(&self) -> Matrix3DComponents {
+        let column = |column: usize| [0, 1, 2].map(|row| self.get(row, column));
+        let [c0, c1, c2] = [0, 1, 2].map(column);
+
+        // Orthonormalize the columns to find the rotated axes, keeping the part of the Y axis
+        // along the X axis as the skew.
+        let sx = length(c0);
+        let u0 = if sx != 0.0 {
+            c0.map(|value| value / sx)
+        } else {
+            [1.0, 0.0, 0.0]
+        };
+        let projection = dot(u0, c1);
+        let c1_perpendicular: [f64; 3] = std::array::from_fn(|i| c1[i] - projection * u0[i]);
+        let perpendicular_length = length(c1_perpendicular);
+        let sy = length(c1);
+        let (skew, u1) = if perpendicular_length > 1e-12 {
+            (
+                (-projection).atan2(perpendicular_length),
+                c1_perpendicular.map(|value| value / perpendicular_length),
+            )
+        } else {
+            // The Y axis is parallel to the X axis (or zero), so pick any axis perpendicular to X.
+            let skew = if sy != 0.0 {
+                (-projection).atan2(0.0)
+            } else {
+                0.0
+            };
+            let fallback = cross([0.0, 0.0, 1.0], u0);
+            let fallback_length = length(fallback);
+            if fallback_length != 0.0 {
+                (skew, fallback.map(|value| value / fallback_length))
+            } else {
+                (skew, [0.0, 1.0, 0.0])
+            }
+        };
+        let u2 = cross(u0, u1);
+        let sz = dot(c2, u2);
+
+        // The rotation part of the matrix, with the scale and skew removed.
+        let axes = [u0, u1, u2];
+        let r = |row: usize, column: usize| axes[column][row];
+        let ry = (-r(2, 0)).clamp(-1.0, 1.0).asin();
+        let (rx, rz) = if ry.cos().abs() > 1e-9 {
+            (r(2, 1).atan2(r(2, 2)), r(1, 0).atan2(r(0, 0)))
+        } else {
+            // Gimbal lock: only the sum of the X and Z rotations can be recovered.
+            ((-r(1, 2)).atan2(r(1, 1)), 0.0)
+        };
+
+        Matrix3DComponents {
+            translation: [self.raw_data[12], self.raw_data[13], self.raw_data[14]],
+            rotation: [rx, ry, rz],
+            scale: [sx, sy, sz],
+            skew,
+        }
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let m = &self.raw_data;
+        let mut inv = [0.0; 16];
+        inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15]
+            + m[9] * m[7] * m[14]
+            + m[13] * m[6] * m[11]
+            - m[13] * m[7] * m[10];
+        inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15]
+            - m[8] * m[7] * m[14]
+            - m[12] * m[6] * m[11]
+            + m[12] * m[7] * m[10];
+        inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15]
+            + m[8] * m[7] * m[13]
+            + m[12] * m[5] * m[11]
+            - m[12] * m[7] * m[9];
+        inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14]
+            - m[8] * m[6] * m[13]
+            - m[12] * m[5] * m[10]
+            + m[12] * m[6] * m[9];
+        inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15]
+            - m[9] * m[3] * m[14]
+            - m[13] * m[2] * m[11]
+            + m[13] * m[3] * m[10];
+        inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15]
+            + m[8] * m[3] * m[14]
+            + m[12] * m[2] * m[11]
+            - m[12] * m[3] * m[10];
+        inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15]
+            - m[8] * m[3] * m[13]
+            - m[12] * m[1] * m[11]
+            + m[12] * m[3] * m[9];
+        inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14]
+            + m[8] * m[2] * m[13]
+            + m[12] * m[1] * m[10]
+            - m[12] * m[2] * m[9];
+        inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15]
+            + m[5] * m[3] * m[14]
+            + m[13] * m[2] * m[7]
+            - m[13] * m[3] * m[6];
+        inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15]
+            - m[4] * m[3] * m[14]
+            - m[12] * m[2] * m[7]
+            + m[12] * m[3] * m[6];
+        inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15]
+            + m[4] * m[3] * m[13]
+            + m[12] * m[1] * m[7]
+            - m[12] * m[3] * m[5];
+        inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14]
+            - m[4] * m[2] * m[13]
+            - m[12] * m[1] * m[6]
+            + m[12] * m[2] * m[5];
+        inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11]
+            - m[5] * m[3] * m[10]
+            - m[9] * m[2] * m[7]
+            + m[9] * m[3] * m[6];
+        inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11]
+            + m[4] * m[3] * m[10]
+            + m[8] * m[2] * m[7]
+            - m[8] * m[3] * m[6];
+        inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11]
+            - m[4] * m[3] * m[9]
+            - m[8] * m[1] * m[7]
+            + m[8] * m[3] * m[5];
+        inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10]
+            + m[4] * m[2] * m[9]
+            + m[8] * m[1] * m[6]
+            - m[8] * m[2] * m[5];
+
+        let det = m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12];
+        if det.abs() <= f64::EPSILON {
+            return None;
+        }
+        Some(Self {
+            raw_data: inv.map(|value| value / det),
+        })
+    }
+
+    /// Transforms a point, ignoring the bottom row of the matrix.
+    pub fn transform_point(&self, [x, y, z]: [f64; 3]) -> [f64; 3] {
+        let m = &self.raw_data;
+        [
+            m[0] * x + m[4] * y + m[8] * z + m[12],
+            m[1] * x + m[5] * y + m[9] * z + m[13],
+            m[2] * x + m[6] * y + m[10] * z + m[14],
+        ]
+    }
+
+    /// Flattens this matrix into a 2D matrix, discarding anything involving the Z axis.
+    pub fn to_matrix(&self) -> Matrix {
+        let m = &self.raw_data;
+        Matrix {
+            a: m[0] as f32,
+            b: m[1] as f32,
+            c: m[4] as f32,
+            d: m[5] as f32,
+            tx: swf::Twips::from_pixels(m[12]),
+            ty: swf::Twips::from_pixels(m[13]),
+        }
+    }
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn length(a: [f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+impl Default for Matrix3D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Matrix> for Matrix3D {
+    fn from(matrix: Matrix) -> Self {
+        let mut matrix_3d = Self::IDENTITY;
+        matrix_3d.raw_data[0] = matrix.a.into();
+        matrix_3d.raw_data[1] = matrix.b.into();
+        matrix_3d.raw_data[4] = matrix.c.into();
+        matrix_3d.raw_data[5] = matrix.d.into();
+        matrix_3d.raw_data[12] = matrix.tx.to_pixels();
+        matrix_3d.raw_data[13] = matrix.ty.to_pixels();
+        matrix_3d
+    }
+}
+
+impl std::ops::Mul for Matrix3D {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let mut raw_data = [0.0; 16];
+        for column in 0..4 {
+            for row in 0..4 {
+                raw_data[column * 4 + row] =
+                    (0..4).map(|k| self.get(row, k) * rhs.get(k, column)).sum();
+            }
+        }
+        Self { raw_data }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn compose_matches_rotation_order() {
+        // Rotating (1, 0, 0) by 90 degrees around Y points it into -Z, and then Z has no effect.
+        let matrix = Matrix3D::compose(&Matrix3DComponents {
+            translation: [10.0, 20.0, 30.0],
+            rotation: [0.0, FRAC_PI_2, FRAC_PI_2],
+            scale: [2.0, 1.0, 1.0],
+            skew: 0.0,
+        });
+        let [x, y, z] = matrix.transform_point([1.0, 0.0, 0.0]);
+        assert_abs_diff_eq!(x, 10.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(y, 20.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(z, 28.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn decompose_round_trips() {
+        let components = Matrix3DComponents {
+            translation: [5.0, -3.0, 100.0],
+            rotation: [0.3, -0.7, 1.2],
+            scale: [1.5, 0.5, 2.0],
+            skew: 0.4,
+        };
+        let decomposed = Matrix3D::compose(&components).decompose();
+        for i in 0..3 {
+            assert_abs_diff_eq!(
+                decomposed.translation[i],
+                components.translation[i],
+                epsilon = 1e-9
+            );
+            assert_abs_diff_eq!(
+                decomposed.rotation[i],
+                components.rotation[i],
+                epsilon = 1e-9
+            );
+            assert_abs_diff_eq!(decomposed.scale[i], components.scale[i], epsilon = 1e-9);
+        }
+        assert_abs_diff_eq!(decomposed.skew, components.skew, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn decompose_keeps_2d_skew() {
+        // A 2D matrix rotated by 0.5 radians, with its Y axis rotated by a further 0.3 radians.
+        let matrix = Matrix3D::from(Matrix {
+            a: (2.0 * 0.5f64.cos()) as f32,
+            b: (2.0 * 0.5f64.sin()) as f32,
+            c: (-3.0 * 0.8f64.sin()) as f32,
+            d: (3.0 * 0.8f64.cos()) as f32,
+            tx: swf::Twips::ZERO,
+            ty: swf::Twips::ZERO,
+        });
+        let decomposed = matrix.decompose();
+        assert_abs_diff_eq!(decomposed.rotation[2], 0.5, epsilon = 1e-6);
+        assert_abs_diff_eq!(decomposed.skew, 0.3, epsilon = 1e-6);
+        assert_abs_diff_eq!(decomposed.scale[0], 2.0, epsilon = 1e-6);
+        assert_abs_diff_eq!(decomposed.scale[1], 3.0, epsilon = 1e-6);
+        let composed = Matrix3D::compose(&decomposed);
+        for (value, expected) in composed.raw_data.iter().zip(matrix.raw_data) {
+            assert_abs_diff_eq!(*value, expected, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn inverse_undoes_matrix() {
+        let matrix = Matrix3D::compose(&Matrix3DComponents {
+            translation: [5.0, -3.0, 100.0],
+            rotation: [0.3, -0.7, 1.2],
+            scale: [1.5, 0.5, 2.0],
+            skew: 0.4,
+        });
+        let identity = matrix * matrix.inverse().expect("Matrix should be invertible");
+        for (value, expected) in identity.raw_data.iter().zip(Matrix3D::IDENTITY.raw_data) {
+            assert_abs_diff_eq!(*value, expected, epsilon = 1e-9);
+        }
+        assert_eq!(Matrix3D::scale(0.0, 1.0, 1.0).inverse(), None);
+    }
+
+    #[test]
+    fn from_matrix_round_trips() {
+        let matrix = Matrix {
+            a: 2.0,
+            b: 0.5,
+            c: -0.5,
+            d: 3.0,
+            tx: swf::Twips::new(200),
+            ty: swf::Twips::new(-40),
+        };
+        assert_eq!(Matrix3D::from(matrix).to_matrix(), matrix);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::matrix::Matrix;
-use swf::ColorTransform;
+use swf::{ColorTransform, Twips};
 
 /// Represents the transform for a DisplayObject.
 /// This includes both the transformation matrix and the color transform.
@@ -9,6 +9,68 @@ pub struct Transform {
     pub color_transform: ColorTransform,
 }
 
+/// The transform used to draw a bitmap with perspective, such as a display object with a 3D
+/// transform that was rendered offscreen.
+#[derive(Clone, Debug)]
+pub struct PerspectiveTransform {
+    /// Maps a point `(x, y, 1)` in the pixel space of the bitmap to homogeneous stage coordinates
+    /// `(x' * w, y' * w, w)`, in pixels. The columns are stored in order.
+    pub matrix: [[f32; 3]; 3],
+    pub color_transform: ColorTransform,
+}
+
+impl PerspectiveTransform {
+    /// Maps a point in the pixel space of the bitmap onto the stage.
+    pub fn project(&self, x: f32, y: f32) -> (f32, f32) {
+        let [c0, c1, c2] = self.matrix;
+        let w = c0[2] * x + c1[2] * y + c2[2];
+        (
+            (c0[0] * x + c1[0] * y + c2[0]) / w,
+            (c0[1] * x + c1[1] * y + c2[1]) / w,
+        )
+    }
+
+    /// Returns the 4x4 world matrix which maps the unit square onto the stage for a `width` by
+    /// `height` bitmap. The perspective divide is left to the GPU, which also keeps the texture
+    /// coordinates perspective-correct.
+    pub fn world_matrix(&self, width: f32, height: f32) -> [[f32; 4]; 4] {
+        let [c0, c1, c2] = self.matrix;
+        [
+            [c0[0] * width, c0[1] * width, 0.0, c0[2] * width],
+            [c1[0] * height, c1[1] * height, 0.0, c1[2] * height],
+            [0.0, 0.0, 1.0, 0.0],
+            [c2[0], c2[1], 0.0, c2[2]],
+        ]
+    }
+
+    /// Approximates this transform with an affine one, for backends that can't draw with
+    /// perspective. The result fits the corners of a `width` by `height` bitmap as closely as
+    /// possible.
+    pub fn to_affine(&self, width: f32, height: f32) -> Transform {
+        let p00 = self.project(0.0, 0.0);
+        let p10 = self.project(width, 0.0);
+        let p01 = self.project(0.0, height);
+        let p11 = self.project(width, height);
+        let a = (p10.0 - p00.0 + p11.0 - p01.0) / (2.0 * width);
+        let b = (p10.1 - p00.1 + p11.1 - p01.1) / (2.0 * width);
+        let c = (p01.0 - p00.0 + p11.0 - p10.0) / (2.0 * height);
+        let d = (p01.1 - p00.1 + p11.1 - p10.1) / (2.0 * height);
+        let center_x = (p00.0 + p10.0 + p01.0 + p11.0) / 4.0;
+        let center_y = (p00.1 + p10.1 + p01.1 + p11.1) / 4.0;
+        Transform {
+            matrix: Matrix {
+                a,
+                b,
+                c,
+                d,
+                tx: Twips::from_pixels((center_x - (a * width + c * height) / 2.0).into()),
+                ty: Twips::from_pixels((center_y - (b * width + d * height) / 2.0).into()),
+            },
+            color_transform: self.color_transform,
+        }
+    }
+}
+
 pub struct TransformStack(Vec<Transform>);
 
 impl TransformStack {
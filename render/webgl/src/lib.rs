@@ -4,7 +4,8 @@
 
 use bytemuck::{Pod, Zeroable};
 use ruffle_render::backend::{
-    BitmapCacheEntry, Context3D, RenderBackend, ShapeHandle, ShapeHandleImpl, ViewportDimensions,
+    BitmapCacheEntry, Context3D, Context3DProfile, RenderBackend, ShapeHandle, ShapeHandleImpl,
+    ViewportDimensions,
 };
 use ruffle_render::bitmap::{
     Bitmap, BitmapFormat, BitmapHandle, BitmapHandleImpl, BitmapSource, PixelRegion, PixelSnapping,
@@ -17,11 +18,11 @@ use ruffle_render::shape_utils::{DistilledShape, GradientType};
 use ruffle_render::tessellator::{
     Gradient as TessGradient, ShapeTessellator, Vertex as TessVertex,
 };
-use ruffle_render::transform::Transform;
+use ruffle_render::transform::{PerspectiveTransform, Transform};
 use ruffle_web_common::{JsError, JsResult};
 use std::borrow::Cow;
 use std::sync::Arc;
-use swf::{BlendMode, Color};
+use swf::{BlendMode, Color, ColorTransform};
 use thiserror::Error;
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{
@@ -911,6 +912,84 @@ impl WebGlRenderBackend {
             self.apply_blend_mode(current.clone());
         }
     }
+
+    /// Draws the bitmap quad with the given world matrix, which may include perspective.
+    fn draw_bitmap_quad(
+        &mut self,
+        bitmap: &BitmapHandle,
+        world_matrix: [[f32; 4]; 4],
+        color_transform: &ColorTransform,
+        smoothing: bool,
+    ) {
+        self.set_stencil_state();
+        let entry = as_registry_data(bitmap);
+        // Adjust the quad draw to use the target bitmap.
+        let quad = &self.bitmap_quad_draws;
+        let draw = &quad[0];
+        let bitmap_matrix = if let DrawType::Bitmap(BitmapDraw { matrix, .. }) = &draw.draw_type {
+            matrix
+        } else {
+            unreachable!()
+        };
+
+        let mult_color = color_transform.mult_rgba_normalized();
+        let add_color = color_transform.add_rgba_normalized();
+
+        self.bind_vertex_array(Some(&draw.vao));
+
+        let program = &self.bitmap_program;
+
+        // Set common render state, while minimizing unnecessary state changes.
+        // TODO: Using designated layout specifiers in WebGL2/OpenGL ES 3, we could guarantee that uniforms
+        // are in the same location between shaders, and avoid changing them unless necessary.
+        if program as *const ShaderProgram != self.active_program {
+            self.gl.use_program(Some(&program.program));
+            self.active_program = program as *const ShaderProgram;
+
+            program.uniform_matrix4fv(&self.gl, ShaderUniform::ViewMatrix, &self.view_matrix);
+
+            self.mult_color = None;
+            self.add_color = None;
+        }
+
+        program.uniform_matrix4fv(&self.gl, ShaderUniform::WorldMatrix, &world_matrix);
+        if Some(mult_color) != self.mult_color {
+            program.uniform4fv(&self.gl, ShaderUniform::MultColor, &mult_color);
+            self.mult_color = Some(mult_color);
+        }
+        if Some(add_color) != self.add_color {
+            program.uniform4fv(&self.gl, ShaderUniform::AddColor, &add_color);
+            self.add_color = Some(add_color);
+        }
+
+        program.uniform_matrix3fv(&self.gl, ShaderUniform::TextureMatrix, bitmap_matrix);
+
+        // Bind texture.
+        self.gl.active_texture(Gl::TEXTURE0);
+        self.gl.bind_texture(Gl::TEXTURE_2D, Some(&entry.texture));
+        program.uniform1i(&self.gl, ShaderUniform::BitmapTexture, 0);
+
+        // Set texture parameters.
+        let filter = if smoothing {
+            Gl::LINEAR as i32
+        } else {
+            Gl::NEAREST as i32
+        };
+        self.gl
+            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MAG_FILTER, filter);
+        self.gl
+            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MIN_FILTER, filter);
+
+        let wrap = Gl::CLAMP_TO_EDGE as i32;
+        self.gl
+            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_S, wrap);
+        self.gl
+            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_T, wrap);
+
+        // Draw the triangles.
+        self.gl
+            .draw_elements_with_i32(Gl::TRIANGLES, draw.num_indices, Gl::UNSIGNED_INT, 0);
+    }
 }
 
 fn same_blend_mode(first: Option<&RenderBlendMode>, second: &RenderBlendMode) -> bool {
@@ -1078,7 +1157,10 @@ impl RenderBackend for WebGlRenderBackend {
         Ok(())
     }
 
-    fn create_context3d(&mut self) -> Result<Box<dyn Context3D>, BitmapError> {
+    fn create_context3d(
+        &mut self,
+        _profile: Context3DProfile,
+    ) -> Result<Box<dyn Context3D>, BitmapError> {
         Err(BitmapError::Unimplemented("createContext3D".into()))
     }
     fn context3d_present(&mut self, _context: &mut dyn Context3D) -> Result<(), BitmapError> {
@@ -1178,16 +1260,7 @@ impl CommandHandler for WebGlRenderBackend {
         smoothing: bool,
         pixel_snapping: PixelSnapping,
     ) {
-        self.set_stencil_state();
         let entry = as_registry_data(&bitmap);
-        // Adjust the quad draw to use the target bitmap.
-        let quad = &self.bitmap_quad_draws;
-        let draw = &quad[0];
-        let bitmap_matrix = if let DrawType::Bitmap(BitmapDraw { matrix, .. }) = &draw.draw_type {
-            matrix
-        } else {
-            unreachable!()
-        };
 
         // Scale the quad to the bitmap's dimensions.
         let mut matrix = transform.matrix;
@@ -1206,63 +1279,18 @@ impl CommandHandler for WebGlRenderBackend {
             ],
         ];
 
-        let mult_color = transform.color_transform.mult_rgba_normalized();
-        let add_color = transform.color_transform.add_rgba_normalized();
-
-        self.bind_vertex_array(Some(&draw.vao));
-
-        let program = &self.bitmap_program;
-
-        // Set common render state, while minimizing unnecessary state changes.
-        // TODO: Using designated layout specifiers in WebGL2/OpenGL ES 3, we could guarantee that uniforms
-        // are in the same location between shaders, and avoid changing them unless necessary.
-        if program as *const ShaderProgram != self.active_program {
-            self.gl.use_program(Some(&program.program));
-            self.active_program = program as *const ShaderProgram;
-
-            program.uniform_matrix4fv(&self.gl, ShaderUniform::ViewMatrix, &self.view_matrix);
-
-            self.mult_color = None;
-            self.add_color = None;
-        }
-
-        program.uniform_matrix4fv(&self.gl, ShaderUniform::WorldMatrix, &world_matrix);
-        if Some(mult_color) != self.mult_color {
-            program.uniform4fv(&self.gl, ShaderUniform::MultColor, &mult_color);
-            self.mult_color = Some(mult_color);
-        }
-        if Some(add_color) != self.add_color {
-            program.uniform4fv(&self.gl, ShaderUniform::AddColor, &add_color);
-            self.add_color = Some(add_color);
-        }
-
-        program.uniform_matrix3fv(&self.gl, ShaderUniform::TextureMatrix, bitmap_matrix);
-
-        // Bind texture.
-        self.gl.active_texture(Gl::TEXTURE0);
-        self.gl.bind_texture(Gl::TEXTURE_2D, Some(&entry.texture));
-        program.uniform1i(&self.gl, ShaderUniform::BitmapTexture, 0);
-
-        // Set texture parameters.
-        let filter = if smoothing {
-            Gl::LINEAR as i32
-        } else {
-            Gl::NEAREST as i32
-        };
-        self.gl
-            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MAG_FILTER, filter);
-        self.gl
-            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MIN_FILTER, filter);
-
-        let wrap = Gl::CLAMP_TO_EDGE as i32;
-        self.gl
-            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_S, wrap);
-        self.gl
-            .tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_T, wrap);
+        self.draw_bitmap_quad(&bitmap, world_matrix, &transform.color_transform, smoothing);
+    }
 
-        // Draw the triangles.
-        self.gl
-            .draw_elements_with_i32(Gl::TRIANGLES, draw.num_indices, Gl::UNSIGNED_INT, 0);
+    fn render_bitmap_projected(
+        &mut self,
+        bitmap: BitmapHandle,
+        transform: PerspectiveTransform,
+        smoothing: bool,
+    ) {
+        let entry = as_registry_data(&bitmap);
+        let world_matrix = transform.world_matrix(entry.width as f32, entry.height as f32);
+        self.draw_bitmap_quad(&bitmap, world_matrix, &transform.color_transform, smoothing);
     }
 
     fn render_shape(&mut self, shape: ShapeHandle, transform: Transform) {
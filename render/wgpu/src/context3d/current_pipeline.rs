@@ -22,8 +22,9 @@ use crate::descriptors::Descriptors;
 
 use super::{ShaderPairAgal, VertexAttributeInfo, MAX_VERTEX_ATTRIBUTES};
 
-const AGAL_NUM_VERTEX_CONSTANTS: u64 = 128;
-const AGAL_NUM_FRAGMENT_CONSTANTS: u64 = 28;
+// Our uniform buffers are large enough for the constants of any AGAL version.
+const AGAL_NUM_VERTEX_CONSTANTS: u64 = naga_agal::MAX_VERTEX_PROGRAM_CONSTANTS;
+const AGAL_NUM_FRAGMENT_CONSTANTS: u64 = naga_agal::MAX_FRAGMENT_PROGRAM_CONSTANTS;
 pub(super) const AGAL_FLOATS_PER_REGISTER: u64 = 4;
 
 const VERTEX_SHADER_UNIFORMS_BUFFER_SIZE: u64 =
@@ -75,6 +76,11 @@ pub struct CurrentPipeline {
     depth_mask: bool,
     pass_compare_mode: wgpu::CompareFunction,
 
+    stencil_front: StencilFaceState,
+    stencil_back: StencilFaceState,
+    stencil_read_mask: u32,
+    stencil_write_mask: u32,
+
     color_component: wgpu::BlendComponent,
     alpha_component: wgpu::BlendComponent,
 
@@ -153,6 +159,12 @@ impl CurrentPipeline {
 
             depth_mask: true,
             pass_compare_mode: wgpu::CompareFunction::LessEqual,
+
+            stencil_front: StencilFaceState::IGNORE,
+            stencil_back: StencilFaceState::IGNORE,
+            stencil_read_mask: 0xFF,
+            stencil_write_mask: 0xFF,
+
             color_component: wgpu::BlendComponent::REPLACE,
             alpha_component: wgpu::BlendComponent::REPLACE,
             sample_count: 1,
@@ -207,6 +219,30 @@ impl CurrentPipeline {
         self.pass_compare_mode = pass_compare_mode;
     }
 
+    /// Updates the stencil state of the front and back faces, leaving a face alone if its
+    /// state is `None`.
+    pub fn update_stencil_faces(
+        &mut self,
+        front: Option<StencilFaceState>,
+        back: Option<StencilFaceState>,
+    ) {
+        let front = front.unwrap_or(self.stencil_front);
+        let back = back.unwrap_or(self.stencil_back);
+        if self.stencil_front != front || self.stencil_back != back {
+            self.dirty.set(true);
+        }
+        self.stencil_front = front;
+        self.stencil_back = back;
+    }
+
+    pub fn update_stencil_masks(&mut self, read_mask: u32, write_mask: u32) {
+        if self.stencil_read_mask != read_mask || self.stencil_write_mask != write_mask {
+            self.dirty.set(true);
+        }
+        self.stencil_read_mask = read_mask;
+        self.stencil_write_mask = write_mask;
+    }
+
     pub fn update_has_depth_texture(&mut self, has_depth_texture: bool) {
         if self.has_depth_texture != has_depth_texture {
             self.dirty.set(true);
@@ -438,12 +474,11 @@ impl CurrentPipeline {
                 format: TextureFormat::Depth24PlusStencil8,
                 depth_write_enabled: self.depth_mask,
                 depth_compare: self.pass_compare_mode,
-                // FIXME - implement this
                 stencil: wgpu::StencilState {
-                    front: StencilFaceState::IGNORE,
-                    back: StencilFaceState::IGNORE,
-                    read_mask: !0,
-                    write_mask: !0,
+                    front: self.stencil_front,
+                    back: self.stencil_back,
+                    read_mask: self.stencil_read_mask,
+                    write_mask: self.stencil_write_mask,
                 },
                 bias: Default::default(),
             })
@@ -1,7 +1,7 @@
 use ruffle_render::backend::{
     Context3D, Context3DBlendFactor, Context3DCommand, Context3DCompareMode,
-    Context3DTextureFormat, Context3DVertexBufferFormat, IndexBuffer, ProgramType, Texture as _,
-    VertexBuffer,
+    Context3DStencilAction, Context3DTextureFormat, Context3DTriangleFace,
+    Context3DVertexBufferFormat, IndexBuffer, ProgramType, Texture as _, VertexBuffer,
 };
 use ruffle_render::bitmap::BitmapHandle;
 use ruffle_render::error::Error;
@@ -17,7 +17,7 @@ use wgpu::{CommandEncoder, Extent3d, RenderPass};
 
 use crate::context3d::current_pipeline::{BoundTextureData, AGAL_FLOATS_PER_REGISTER};
 use crate::descriptors::Descriptors;
-use crate::Texture;
+use crate::{as_texture, Texture};
 
 use std::num::NonZeroU64;
 use std::rc::Rc;
@@ -95,6 +95,9 @@ pub struct WgpuContext3D {
     seen_clear_command: bool,
 
     scissor_rectangle: Option<Rectangle<Twips>>,
+
+    // The stencil reference value is set on each render pass, rather than the pipeline.
+    stencil_reference: u32,
 }
 
 impl WgpuContext3D {
@@ -168,6 +171,7 @@ impl WgpuContext3D {
             clear_color: None,
             seen_clear_command: false,
             scissor_rectangle: None,
+            stencil_reference: 0,
         }
     }
 
@@ -294,6 +298,7 @@ impl WgpuContext3D {
                 .as_ref()
                 .expect("Missing compiled pipeline"),
         );
+        pass.set_stencil_reference(self.stencil_reference);
         if let Some(rect) = &self.scissor_rectangle {
             let current_size = self.current_texture_size.unwrap();
             if rect.x_min.to_pixels() < 0.0
@@ -385,6 +390,14 @@ impl Context3D for WgpuContext3D {
         self.current_texture_view.is_some()
     }
 
+    fn driver_info(&self) -> String {
+        let info = self.descriptors.adapter.get_info();
+        format!(
+            "{:?} (Renderer={} Driver={} {})",
+            info.backend, info.name, info.driver, info.driver_info
+        )
+    }
+
     fn create_index_buffer(
         &mut self,
         _ruffle_usage: ruffle_render::backend::BufferUsage,
@@ -1069,6 +1082,33 @@ impl Context3D for WgpuContext3D {
                     },
                 );
             }
+            Context3DCommand::CopyBitmapHandleToTexture { source, dest } => {
+                let dest = dest.as_any().downcast_ref::<TextureWrapper>().unwrap();
+                let source = as_texture(&source);
+
+                // Unlike `CopyBitmapToTexture`, this is only used for decoded video frames.
+                // If the video decoder overwrites the source before `buffer_command_encoder`
+                // is submitted, we'll just end up displaying a newer frame.
+                self.buffer_command_encoder.copy_texture_to_texture(
+                    wgpu::ImageCopyTexture {
+                        texture: &source.texture,
+                        mip_level: 0,
+                        origin: wgpu::Origin3d::ZERO,
+                        aspect: wgpu::TextureAspect::All,
+                    },
+                    wgpu::ImageCopyTexture {
+                        texture: &dest.texture,
+                        mip_level: 0,
+                        origin: wgpu::Origin3d::ZERO,
+                        aspect: wgpu::TextureAspect::All,
+                    },
+                    wgpu::Extent3d {
+                        width: dest.width().min(source.texture.width()),
+                        height: dest.height().min(source.texture.height()),
+                        depth_or_array_layers: 1,
+                    },
+                );
+            }
             Context3DCommand::SetTextureAt {
                 sampler,
                 texture,
@@ -1113,18 +1153,42 @@ impl Context3D for WgpuContext3D {
                 depth_mask,
                 pass_compare_mode,
             } => {
-                let function = match pass_compare_mode {
-                    Context3DCompareMode::Always => wgpu::CompareFunction::Always,
-                    Context3DCompareMode::Equal => wgpu::CompareFunction::Equal,
-                    Context3DCompareMode::Greater => wgpu::CompareFunction::Greater,
-                    Context3DCompareMode::GreaterEqual => wgpu::CompareFunction::GreaterEqual,
-                    Context3DCompareMode::Less => wgpu::CompareFunction::Less,
-                    Context3DCompareMode::LessEqual => wgpu::CompareFunction::LessEqual,
-                    Context3DCompareMode::Never => wgpu::CompareFunction::Never,
-                    Context3DCompareMode::NotEqual => wgpu::CompareFunction::NotEqual,
-                };
+                let function = convert_compare_mode(pass_compare_mode);
                 self.current_pipeline.update_depth(depth_mask, function);
             }
+            Context3DCommand::SetStencilActions {
+                face,
+                compare_mode,
+                action_on_both_pass,
+                action_on_depth_fail,
+                action_on_depth_pass_stencil_fail,
+            } => {
+                let state = wgpu::StencilFaceState {
+                    compare: convert_compare_mode(compare_mode),
+                    fail_op: convert_stencil_action(action_on_depth_pass_stencil_fail),
+                    depth_fail_op: convert_stencil_action(action_on_depth_fail),
+                    pass_op: convert_stencil_action(action_on_both_pass),
+                };
+                // Only the selected faces are changed, so two-sided stencil operations
+                // can be set up with separate calls for the front and back faces.
+                // Stage3D's front faces are clockwise, matching `FrontFace::Cw` in the pipeline.
+                let (front, back) = match face {
+                    Context3DTriangleFace::None => (None, None),
+                    Context3DTriangleFace::Front => (Some(state), None),
+                    Context3DTriangleFace::Back => (None, Some(state)),
+                    Context3DTriangleFace::FrontAndBack => (Some(state), Some(state)),
+                };
+                self.current_pipeline.update_stencil_faces(front, back);
+            }
+            Context3DCommand::SetStencilReferenceValue {
+                reference_value,
+                read_mask,
+                write_mask,
+            } => {
+                self.stencil_reference = reference_value;
+                self.current_pipeline
+                    .update_stencil_masks(read_mask, write_mask);
+            }
             Context3DCommand::SetBlendFactors {
                 source_factor,
                 destination_factor,
@@ -1203,6 +1267,32 @@ impl Context3D for WgpuContext3D {
     }
 }
 
+fn convert_compare_mode(mode: Context3DCompareMode) -> wgpu::CompareFunction {
+    match mode {
+        Context3DCompareMode::Always => wgpu::CompareFunction::Always,
+        Context3DCompareMode::Equal => wgpu::CompareFunction::Equal,
+        Context3DCompareMode::Greater => wgpu::CompareFunction::Greater,
+        Context3DCompareMode::GreaterEqual => wgpu::CompareFunction::GreaterEqual,
+        Context3DCompareMode::Less => wgpu::CompareFunction::Less,
+        Context3DCompareMode::LessEqual => wgpu::CompareFunction::LessEqual,
+        Context3DCompareMode::Never => wgpu::CompareFunction::Never,
+        Context3DCompareMode::NotEqual => wgpu::CompareFunction::NotEqual,
+    }
+}
+
+fn convert_stencil_action(action: Context3DStencilAction) -> wgpu::StencilOperation {
+    match action {
+        Context3DStencilAction::Keep => wgpu::StencilOperation::Keep,
+        Context3DStencilAction::Zero => wgpu::StencilOperation::Zero,
+        Context3DStencilAction::Set => wgpu::StencilOperation::Replace,
+        Context3DStencilAction::IncrementSaturate => wgpu::StencilOperation::IncrementClamp,
+        Context3DStencilAction::DecrementSaturate => wgpu::StencilOperation::DecrementClamp,
+        Context3DStencilAction::IncrementWrap => wgpu::StencilOperation::IncrementWrap,
+        Context3DStencilAction::DecrementWrap => wgpu::StencilOperation::DecrementWrap,
+        Context3DStencilAction::Invert => wgpu::StencilOperation::Invert,
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct ClearColor {
     rgb: wgpu::Color,
@@ -7,7 +7,6 @@ use indexmap::IndexMap;
 use ruffle_render::error::Error as BitmapError;
 use ruffle_render::pixel_bender::{
     ImageInputTexture, PixelBenderShaderHandle, PixelBenderShaderImpl, PixelBenderType,
-    OUT_COORD_NAME,
 };
 use ruffle_render::{
     bitmap::BitmapHandle,
@@ -418,18 +417,7 @@ pub(super) fn run_pixelbender_shader_impl(
                     wgpu_texture.create_view(&wgpu::TextureViewDescriptor::default()),
                 );
             }
-            PixelBenderShaderArgument::ValueInput { index, value } => {
-                let param = &compiled_shader.shader.params[*index as usize];
-
-                let name = match param {
-                    PixelBenderParam::Normal { name, .. } => name,
-                    _ => unreachable!(),
-                };
-
-                if name == OUT_COORD_NAME {
-                    continue;
-                }
-
+            PixelBenderShaderArgument::ValueInput { value, .. } => {
                 #[derive(Debug)]
                 enum FloatOrInt {
                     Float(Vec<f32>),
@@ -15,7 +15,7 @@ use ruffle_render::commands::{Command, RenderBlendMode};
 use ruffle_render::matrix::Matrix;
 use ruffle_render::pixel_bender::PixelBenderShaderHandle;
 use ruffle_render::quality::StageQuality;
-use ruffle_render::transform::Transform;
+use ruffle_render::transform::{PerspectiveTransform, Transform};
 use swf::{BlendMode, Color, ColorTransform, Fixed8};
 
 use super::target::PoolOrArcTexture;
@@ -90,6 +90,11 @@ impl<'pass, 'frame: 'pass, 'global: 'frame> CommandRenderer<'pass, 'frame, 'glob
                 *render_stage3d,
                 *pixel_snapping,
             ),
+            DrawCommand::RenderBitmapProjected {
+                bitmap,
+                transform,
+                smoothing,
+            } => self.render_bitmap_projected(bitmap, transform, *smoothing),
             DrawCommand::RenderTexture {
                 _texture,
                 binds,
@@ -195,7 +200,15 @@ impl<'pass, 'frame: 'pass, 'global: 'frame> CommandRenderer<'pass, 'frame, 'glob
                 1.0,
             ],
         ];
+        self.apply_world_matrix(world_matrix, color_adjustments);
+    }
 
+    /// Sets the world matrix directly, allowing for transforms with perspective.
+    pub fn apply_world_matrix(
+        &mut self,
+        world_matrix: [[f32; 4]; 4],
+        color_adjustments: &ColorTransform,
+    ) {
         if self.descriptors.limits.max_push_constant_size > 0 {
             self.render_pass.set_push_constants(
                 wgpu::ShaderStages::VERTEX_FRAGMENT,
@@ -277,6 +290,44 @@ impl<'pass, 'frame: 'pass, 'global: 'frame> CommandRenderer<'pass, 'frame, 'glob
         }
     }
 
+    pub fn render_bitmap_projected(
+        &mut self,
+        bitmap: &'frame BitmapHandle,
+        transform: &PerspectiveTransform,
+        smoothing: bool,
+    ) {
+        if cfg!(feature = "render_debug_labels") {
+            self.render_pass
+                .push_debug_group(&format!("render_bitmap_projected {:?}", bitmap.0));
+        }
+        let texture = as_texture(bitmap);
+
+        let descriptors = self.descriptors;
+        let bind = texture.bind_group(
+            smoothing,
+            &descriptors.device,
+            &descriptors.bind_layouts.bitmap,
+            &descriptors.quad,
+            bitmap.clone(),
+            &descriptors.bitmap_samplers,
+        );
+        self.prep_bitmap(&bind.bind_group, TrivialBlend::Normal, false);
+        let world_matrix = transform.world_matrix(
+            texture.texture.width() as f32,
+            texture.texture.height() as f32,
+        );
+        self.apply_world_matrix(world_matrix, &transform.color_transform);
+
+        self.draw(
+            self.descriptors.quad.vertices_pos.slice(..),
+            self.descriptors.quad.indices.slice(..),
+            6,
+        );
+        if cfg!(feature = "render_debug_labels") {
+            self.render_pass.pop_debug_group();
+        }
+    }
+
     pub fn render_texture(
         &mut self,
         transform: &Transform,
@@ -435,6 +486,11 @@ pub enum DrawCommand {
         render_stage3d: bool,
         pixel_snapping: PixelSnapping,
     },
+    RenderBitmapProjected {
+        bitmap: BitmapHandle,
+        transform: PerspectiveTransform,
+        smoothing: bool,
+    },
     RenderTexture {
         _texture: PoolOrArcTexture,
         binds: wgpu::BindGroup,
@@ -590,6 +646,15 @@ pub fn chunk_blends<'a>(
                 render_stage3d: false,
                 pixel_snapping,
             }),
+            Command::RenderBitmapProjected {
+                bitmap,
+                transform,
+                smoothing,
+            } => current.push(DrawCommand::RenderBitmapProjected {
+                bitmap,
+                transform,
+                smoothing,
+            }),
             Command::RenderStage3D { bitmap, transform } => {
                 current.push(DrawCommand::RenderBitmap {
                     bitmap,
@@ -1,5 +1,5 @@
 use crate::lock::RefLock;
-use crate::{Collect, Collection, GcCell, GcWeak, Mutation};
+use crate::{Collect, Collection, Finalization, GcCell, GcWeak, Mutation};
 
 use core::fmt::{self, Debug};
 
@@ -42,6 +42,13 @@ impl<'gc, T: ?Sized + 'gc> GcWeakCell<'gc, T> {
         self.0.is_dropped()
     }
 
+    /// Returns whether the value referenced by this `GcWeakCell` is going to be dropped at the
+    /// end of the current collection cycle.
+    #[inline]
+    pub fn is_dead(self, fc: &Finalization<'gc>) -> bool {
+        self.0.is_dead(fc)
+    }
+
     #[inline]
     pub fn ptr_eq(this: GcWeakCell<'gc, T>, other: GcWeakCell<'gc, T>) -> bool {
         this.as_ptr() == other.as_ptr()
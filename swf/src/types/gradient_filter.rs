@@ -1,4 +1,4 @@
-use crate::{BlurFilter, BlurFilterFlags, Fixed16, Fixed8, GradientRecord};
+use crate::{BlurFilter, BlurFilterFlags, Fixed16, Fixed8, GradientRecord, Rectangle, Twips};
 use bitflags::bitflags;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -46,6 +46,49 @@ impl GradientFilter {
             flags: BlurFilterFlags::from_passes(self.num_passes()),
         }
     }
+
+    /// Calculates the destination rect of this filter when used as a gradient glow.
+    ///
+    /// The glow is blurred and then offset in a single direction, like a drop shadow.
+    pub fn calculate_glow_dest_rect(&self, source_rect: Rectangle<Twips>) -> Rectangle<Twips> {
+        let mut result = self.inner_blur_filter().calculate_dest_rect(source_rect);
+        let (x, y) = self.offset();
+        if x < Twips::ZERO {
+            result.x_min += x;
+        } else {
+            result.x_max += x;
+        }
+        if y < Twips::ZERO {
+            result.y_min += y;
+        } else {
+            result.y_max += y;
+        }
+        result
+    }
+
+    /// Calculates the destination rect of this filter when used as a gradient bevel.
+    ///
+    /// The highlight and shadow are offset in opposite directions, so both sides grow.
+    pub fn calculate_bevel_dest_rect(&self, source_rect: Rectangle<Twips>) -> Rectangle<Twips> {
+        let mut result = self.inner_blur_filter().calculate_dest_rect(source_rect);
+        let (x, y) = self.offset();
+        let x = if x < Twips::ZERO { -x } else { x };
+        let y = if y < Twips::ZERO { -y } else { y };
+        result.x_min -= x;
+        result.x_max += x;
+        result.y_min -= y;
+        result.y_max += y;
+        result
+    }
+
+    fn offset(&self) -> (Twips, Twips) {
+        let distance = self.distance.to_f64();
+        let angle = self.angle.to_f64();
+        (
+            Twips::from_pixels(angle.cos() * distance),
+            Twips::from_pixels(angle.sin() * distance),
+        )
+    }
 }
 
 bitflags! {
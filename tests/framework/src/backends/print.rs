@@ -0,0 +1,26 @@
+use ruffle_core::backend::print::{PaperSize, PrintBackend, PrintPage};
+use std::sync::mpsc;
+
+/// A print backend that accepts every print job, and hands the printed pages back to the test runner.
+pub struct TestPrintBackend {
+    sender: mpsc::Sender<Vec<PrintPage>>,
+}
+
+impl TestPrintBackend {
+    pub fn new() -> (Self, mpsc::Receiver<Vec<PrintPage>>) {
+        let (sender, receiver) = mpsc::channel();
+        (Self { sender }, receiver)
+    }
+}
+
+impl PrintBackend for TestPrintBackend {
+    fn start_job(&mut self) -> Option<PaperSize> {
+        Some(PaperSize::LETTER)
+    }
+
+    fn print(&mut self, pages: Vec<PrintPage>) {
+        self.sender
+            .send(pages)
+            .expect("Test print channel should be available for the lifetime of the movie");
+    }
+}
@@ -1,8 +1,8 @@
 use chrono::{DateTime, Utc};
 use image::EncodableLayout;
 use ruffle_core::backend::ui::{
-    DialogLoaderError, DialogResultFuture, FileDialogResult, FileFilter, FontDefinition,
-    FullscreenError, LanguageIdentifier, MouseCursor, UiBackend, US_ENGLISH,
+    CustomMouseCursor, DialogLoaderError, DialogResultFuture, FileDialogResult, FileFilter,
+    FontDefinition, FullscreenError, LanguageIdentifier, MouseCursor, UiBackend, US_ENGLISH,
 };
 use url::Url;
 
@@ -76,8 +76,37 @@ impl FileDialogResult for TestFileDialogResult {
 ///   otherwise a user cancellation will be simulated
 /// * Attempting to display a file save dialog with a file name hint of "debug-success.txt" will simulate successfully selecting a destination
 ///   otherwise a user cancellation will be simulated
-#[derive(Default)]
-pub struct TestUiBackend;
+/// * The most recently set mouse cursor is recorded, and can be inspected with [`TestUiBackend::mouse_cursor`]
+///   and [`TestUiBackend::custom_mouse_cursor`]
+pub struct TestUiBackend {
+    mouse_cursor: MouseCursor,
+    custom_mouse_cursor: Option<CustomMouseCursor>,
+}
+
+impl TestUiBackend {
+    pub fn new() -> Self {
+        Self {
+            mouse_cursor: MouseCursor::Arrow,
+            custom_mouse_cursor: None,
+        }
+    }
+
+    /// The last built-in cursor that was set.
+    pub fn mouse_cursor(&self) -> MouseCursor {
+        self.mouse_cursor
+    }
+
+    /// The custom cursor currently displayed, if any.
+    pub fn custom_mouse_cursor(&self) -> Option<&CustomMouseCursor> {
+        self.custom_mouse_cursor.as_ref()
+    }
+}
+
+impl Default for TestUiBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl UiBackend for TestUiBackend {
     fn mouse_visible(&self) -> bool {
@@ -86,7 +115,18 @@ impl UiBackend for TestUiBackend {
 
     fn set_mouse_visible(&mut self, _visible: bool) {}
 
-    fn set_mouse_cursor(&mut self, _cursor: MouseCursor) {}
+    fn set_mouse_cursor(&mut self, cursor: MouseCursor) {
+        self.mouse_cursor = cursor;
+        self.custom_mouse_cursor = None;
+    }
+
+    fn set_custom_mouse_cursor(&mut self, cursor: CustomMouseCursor) {
+        self.custom_mouse_cursor = Some(cursor);
+    }
+
+    fn supports_custom_mouse_cursor(&self) -> bool {
+        true
+    }
 
     fn clipboard_content(&mut self) -> String {
         "".to_string()
@@ -8,6 +8,7 @@ pub enum ImageTrigger {
     LastFrame,
     SpecificIteration(u32),
     FsCommand,
+    Print,
 }
 
 impl<'de> Deserialize<'de> for ImageTrigger {
@@ -25,7 +26,7 @@ impl<'de> Visitor<'de> for ImageTriggerVisitor {
     type Value = ImageTrigger;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("one of either: a numeric frame/tick number, the string \"last_frame\", or the string \"fs_command\"")
+        formatter.write_str("one of either: a numeric frame/tick number, the string \"last_frame\", the string \"fs_command\", or the string \"print\"")
     }
 
     fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
@@ -60,10 +61,17 @@ impl<'de> Visitor<'de> for ImageTriggerVisitor {
             Ok(ImageTrigger::LastFrame)
         } else if value == "fs_command" {
             Ok(ImageTrigger::FsCommand)
+        } else if value == "print" {
+            Ok(ImageTrigger::Print)
         } else {
             Err(E::unknown_variant(
                 value,
-                &["'last_frame'", "'fs_command'", "a frame/tick number"],
+                &[
+                    "'last_frame'",
+                    "'fs_command'",
+                    "'print'",
+                    "a frame/tick number",
+                ],
             ))
         }
     }
@@ -1,3 +1,4 @@
+pub mod backends;
 pub mod environment;
 pub mod fs_commands;
 pub mod image_trigger;
@@ -7,7 +8,6 @@ pub mod test;
 
 pub use vfs;
 
-mod backends;
 mod util;
 
 pub fn set_logger() {
@@ -62,8 +62,10 @@ impl TestOptions {
         if !self.image_comparisons.is_empty() {
             let mut seen_triggers = HashSet::new();
             for comparison in self.image_comparisons.values() {
-                if comparison.trigger != ImageTrigger::FsCommand
-                    && !seen_triggers.insert(comparison.trigger)
+                if !matches!(
+                    comparison.trigger,
+                    ImageTrigger::FsCommand | ImageTrigger::Print
+                ) && !seen_triggers.insert(comparison.trigger)
                 {
                     return Err(anyhow!(
                         "Multiple captures are set to trigger {:?}. This likely isn't intended!",
@@ -1,4 +1,4 @@
-use crate::backends::{TestLogBackend, TestNavigatorBackend, TestUiBackend};
+use crate::backends::{TestLogBackend, TestNavigatorBackend, TestPrintBackend, TestUiBackend};
 use crate::environment::RenderInterface;
 use crate::fs_commands::{FsCommand, TestFsCommandProvider};
 use crate::image_trigger::ImageTrigger;
@@ -46,6 +46,7 @@ pub fn run_swf(
 
     let log = TestLogBackend::default();
     let (fs_command_provider, fs_commands) = TestFsCommandProvider::new();
+    let (print_backend, printed_pages) = TestPrintBackend::new();
     let navigator = TestNavigatorBackend::new(
         test.root_path.clone(),
         &executor,
@@ -58,7 +59,8 @@ pub fn run_swf(
         .with_navigator(navigator)
         .with_max_execution_duration(Duration::from_secs(300))
         .with_fs_commands(Box::new(fs_command_provider))
-        .with_ui(TestUiBackend)
+        .with_ui(TestUiBackend::new())
+        .with_print(print_backend)
         .with_viewport_dimensions(
             viewport_dimensions.width,
             viewport_dimensions.height,
@@ -98,6 +100,7 @@ pub fn run_swf(
         .or(test.options.num_ticks)
         .expect("valid iteration count");
     let mut current_iteration = 0;
+    let mut printed_page_count = 0;
 
     while remaining_iterations > 0 {
         // If requested, ensure that the 'expected' amount of
@@ -159,6 +162,29 @@ pub fn run_swf(
             }
         }
 
+        for page in printed_pages.try_iter().flatten() {
+            printed_page_count += 1;
+            let name = format!("print-{printed_page_count}");
+            if let Some(image_comparison) = images.remove(&name) {
+                if image_comparison.trigger != ImageTrigger::Print {
+                    return Err(anyhow!(
+                        "Printed page '{name}', but the trigger was expected to be {:?}",
+                        image_comparison.trigger
+                    ));
+                }
+                let actual_image = image::RgbaImage::from_raw(page.width, page.height, page.rgba)
+                    .expect("Printed page should match its dimensions");
+                compare_image(
+                    &test.root_path,
+                    &name,
+                    actual_image,
+                    image_comparison,
+                    test.options.known_failure,
+                    render_interface.as_deref(),
+                )?;
+            }
+        }
+
         injector.next(|evt, _btns_down| {
             player.lock().unwrap().handle_event(match evt {
                 AutomatedEvent::MouseDown { pos, btn } => PlayerEvent::MouseDown {
@@ -189,8 +215,8 @@ pub fn run_swf(
                 },
                 AutomatedEvent::TextControl { code } => PlayerEvent::TextControl {
                     code: match code {
-                        InputTextControlCode::MoveLeft => RuffleTextControlCode::Backspace,
-                        InputTextControlCode::MoveRight => RuffleTextControlCode::Delete,
+                        InputTextControlCode::MoveLeft => RuffleTextControlCode::MoveLeft,
+                        InputTextControlCode::MoveRight => RuffleTextControlCode::MoveRight,
                         InputTextControlCode::SelectLeft => RuffleTextControlCode::SelectLeft,
                         InputTextControlCode::SelectRight => RuffleTextControlCode::SelectRight,
                         InputTextControlCode::SelectAll => RuffleTextControlCode::SelectAll,
@@ -273,33 +299,65 @@ fn capture_and_compare_image(
     known_failure: bool,
     render_interface: Option<&dyn RenderInterface>,
 ) -> Result<()> {
-    use anyhow::Context;
-
-    if let Some(render_interface) = render_interface {
+    let actual_image = render_interface.map(|render_interface| {
         let mut player_lock = player.lock().unwrap();
         player_lock.render();
+        render_interface.capture(player_lock.renderer_mut())
+    });
 
-        let actual_image = render_interface.capture(player_lock.renderer_mut());
-
-        let expected_image_path = base_path.join(format!("{name}.expected.png"))?;
-        if expected_image_path.is_file()? {
-            let expected_image = image::load_from_memory(&read_bytes(&expected_image_path)?)
-                .context("Failed to open expected image")?
-                .into_rgba8();
-
-            image_comparison.test(
-                name,
-                actual_image,
-                expected_image,
-                base_path,
-                render_interface.name(),
-                known_failure,
-            )?;
-        } else if !known_failure {
-            // If we're expecting this to be wrong, don't save a likely wrong image
-            write_image(&expected_image_path, &actual_image, ImageOutputFormat::Png)?;
-        }
-    } else if known_failure {
+    match actual_image {
+        Some(actual_image) => compare_image(
+            base_path,
+            name,
+            actual_image,
+            image_comparison,
+            known_failure,
+            render_interface,
+        ),
+        None => compare_image_without_renderer(known_failure),
+    }
+}
+
+/// Compares an image produced by the movie against the expected image for `name`,
+/// or saves it as the expected image if there isn't one yet.
+fn compare_image(
+    base_path: &VfsPath,
+    name: &str,
+    actual_image: image::RgbaImage,
+    image_comparison: ImageComparison,
+    known_failure: bool,
+    render_interface: Option<&dyn RenderInterface>,
+) -> Result<()> {
+    use anyhow::Context;
+
+    let Some(render_interface) = render_interface else {
+        return compare_image_without_renderer(known_failure);
+    };
+
+    let expected_image_path = base_path.join(format!("{name}.expected.png"))?;
+    if expected_image_path.is_file()? {
+        let expected_image = image::load_from_memory(&read_bytes(&expected_image_path)?)
+            .context("Failed to open expected image")?
+            .into_rgba8();
+
+        image_comparison.test(
+            name,
+            actual_image,
+            expected_image,
+            base_path,
+            render_interface.name(),
+            known_failure,
+        )?;
+    } else if !known_failure {
+        // If we're expecting this to be wrong, don't save a likely wrong image
+        write_image(&expected_image_path, &actual_image, ImageOutputFormat::Png)?;
+    }
+
+    Ok(())
+}
+
+fn compare_image_without_renderer(known_failure: bool) -> Result<()> {
+    if known_failure {
         // It's possible that the trace output matched but the image might not.
         // If we aren't checking the image, pretend the match failed (which makes it actually pass, since it's expecting failure).
         return Err(anyhow!(
@@ -0,0 +1,48 @@
+use ruffle_core::backend::ui::MouseCursor;
+use ruffle_test_framework::backends::TestUiBackend;
+use ruffle_test_framework::environment::Environment;
+use ruffle_test_framework::options::TestOptions;
+use ruffle_test_framework::set_logger;
+use ruffle_test_framework::test::Test;
+use ruffle_test_framework::vfs::{PhysicalFS, VfsPath};
+
+pub fn mouse_cursor_avm2(environment: &impl Environment) -> Result<(), libtest_mimic::Failed> {
+    set_logger();
+    Ok(Test::from_options(
+        TestOptions {
+            num_frames: Some(1),
+            ..Default::default()
+        },
+        VfsPath::new(PhysicalFS::new("tests/swfs/avm2/mouse_cursor/")),
+        "mouse_cursor_avm2".to_string(),
+    )?
+    .run(
+        |_| Ok(()),
+        |player| {
+            let player = player.lock().unwrap();
+            let ui = player
+                .ui()
+                .downcast_ref::<TestUiBackend>()
+                .expect("Test should use TestUiBackend");
+
+            // The built-in cursor is left as it was before the custom one was selected.
+            assert_eq!(ui.mouse_cursor(), MouseCursor::Hand);
+
+            let cursor = ui
+                .custom_mouse_cursor()
+                .expect("Custom cursor should be displayed");
+            assert_eq!(cursor.hot_spot, (3, 2));
+            assert_eq!(cursor.frame_rate, 5.0);
+            let sizes: Vec<_> = cursor
+                .frames
+                .iter()
+                .map(|frame| (frame.width, frame.height))
+                .collect();
+            assert_eq!(sizes, [(16, 16), (16, 8)]);
+            assert_eq!(cursor.frames[0].rgba[..4], [0x00, 0xFF, 0x00, 0xFF]);
+            assert_eq!(cursor.frames[1].rgba[..4], [0x00, 0x00, 0xFF, 0x80]);
+            Ok(())
+        },
+        environment,
+    )?)
+}
@@ -4,6 +4,7 @@
 
 use crate::environment::NativeEnvironment;
 use crate::external_interface::tests::{external_interface_avm1, external_interface_avm2};
+use crate::mouse_cursor::mouse_cursor_avm2;
 use crate::shared_object::{shared_object_avm1, shared_object_avm2, shared_object_self_ref_avm1};
 use anyhow::Context;
 use anyhow::Result;
@@ -17,6 +18,7 @@ use walkdir::DirEntry;
 
 mod environment;
 mod external_interface;
+mod mouse_cursor;
 mod shared_object;
 
 fn is_candidate(args: &Arguments, test_name: &str) -> bool {
@@ -80,6 +82,9 @@ fn main() {
     tests.push(Trial::test("external_interface_avm2", || {
         external_interface_avm2(&NativeEnvironment)
     }));
+    tests.push(Trial::test("mouse_cursor_avm2", || {
+        mouse_cursor_avm2(&NativeEnvironment)
+    }));
 
     tests.sort_unstable_by(|a, b| a.name().cmp(b.name()));
 
@@ -0,0 +1,50 @@
+package {
+	import flash.display.BitmapData;
+	import flash.display.MovieClip;
+	import flash.filters.BevelFilter;
+	import flash.filters.BitmapFilterType;
+	import flash.filters.BlurFilter;
+	import flash.filters.ColorMatrixFilter;
+	import flash.filters.ConvolutionFilter;
+	import flash.filters.DisplacementMapFilter;
+	import flash.filters.DisplacementMapFilterMode;
+	import flash.filters.DropShadowFilter;
+	import flash.filters.GlowFilter;
+	import flash.filters.GradientBevelFilter;
+	import flash.filters.GradientGlowFilter;
+	import flash.geom.Point;
+	import flash.geom.Rectangle;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var bitmap:BitmapData = new BitmapData(100, 100);
+			var map:BitmapData = new BitmapData(10, 10);
+			var filters:Array = [
+				["blur", new BlurFilter()],
+				["blur 10x5 x3", new BlurFilter(10, 5, 3)],
+				["glow", new GlowFilter()],
+				["inner glow", new GlowFilter(0xFF0000, 1, 6, 6, 2, 1, true)],
+				["drop shadow", new DropShadowFilter()],
+				["drop shadow 135deg", new DropShadowFilter(10, 135)],
+				["inner drop shadow", new DropShadowFilter(4, 45, 0, 1, 4, 4, 1, 1, true)],
+				["bevel", new BevelFilter()],
+				["outer bevel", new BevelFilter(4, 45, 0xFFFFFF, 1, 0, 1, 4, 4, 1, 1, BitmapFilterType.OUTER)],
+				["full bevel", new BevelFilter(4, 45, 0xFFFFFF, 1, 0, 1, 4, 4, 1, 1, BitmapFilterType.FULL)],
+				["gradient glow", new GradientGlowFilter(4, 45, [0, 0xFF0000], [0, 1], [0, 255], 4, 4, 1, 1, BitmapFilterType.OUTER)],
+				["inner gradient glow", new GradientGlowFilter(4, 45, [0, 0xFF0000], [0, 1], [0, 255], 4, 4, 1, 1, BitmapFilterType.INNER)],
+				["gradient bevel", new GradientBevelFilter(4, 45, [0xFFFFFF, 0], [1, 1], [0, 255], 4, 4, 1, 1, BitmapFilterType.OUTER)],
+				["inner gradient bevel", new GradientBevelFilter(4, 45, [0xFFFFFF, 0], [1, 1], [0, 255], 4, 4, 1, 1, BitmapFilterType.INNER)],
+				["color matrix", new ColorMatrixFilter()],
+				["convolution 3x3", new ConvolutionFilter(3, 3, [0, 0, 0, 0, 1, 0, 0, 0, 0])],
+				["convolution 4x2", new ConvolutionFilter(4, 2, [0, 0, 0, 0, 1, 0, 0, 0])],
+				["convolution 1x1", new ConvolutionFilter(1, 1, [1])],
+				["displacement map wrap", new DisplacementMapFilter(map, new Point(0, 0), 1, 1, 10, 20, DisplacementMapFilterMode.WRAP)],
+				["displacement map color", new DisplacementMapFilter(map, new Point(0, 0), 1, 1, 10, 20, DisplacementMapFilterMode.COLOR)],
+				["displacement map color negative", new DisplacementMapFilter(map, new Point(0, 0), 1, 1, -7, -5, DisplacementMapFilterMode.COLOR)]
+			];
+			for each (var entry:Array in filters) {
+				trace(entry[0] + ": " + bitmap.generateFilterRect(new Rectangle(10, 20, 30, 40), entry[1]));
+			}
+		}
+	}
+}
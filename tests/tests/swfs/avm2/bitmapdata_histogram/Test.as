@@ -0,0 +1,52 @@
+package {
+	import flash.display.BitmapData;
+	import flash.display.MovieClip;
+	import flash.geom.Rectangle;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var bitmap:BitmapData = new BitmapData(4, 3, true, 0x00000000);
+			bitmap.setPixel32(0, 0, 0xFF102030);
+			bitmap.setPixel32(1, 0, 0xFF102030);
+			bitmap.setPixel32(2, 0, 0xFFFFFFFF);
+			bitmap.setPixel32(0, 1, 0x80FF0000);
+			bitmap.setPixel32(3, 2, 0x4000FF00);
+
+			dump("histogram()", bitmap.histogram());
+			dump("histogram(null)", bitmap.histogram(null));
+			dump("histogram(new Rectangle(0, 0, 2, 1))", bitmap.histogram(new Rectangle(0, 0, 2, 1)));
+			dump("histogram(new Rectangle(2, 1, 10, 10))", bitmap.histogram(new Rectangle(2, 1, 10, 10)));
+			dump("histogram(new Rectangle(-2, -2, 3, 3))", bitmap.histogram(new Rectangle(-2, -2, 3, 3)));
+			dump("histogram(new Rectangle(0, 0, 0, 0))", bitmap.histogram(new Rectangle(0, 0, 0, 0)));
+			dump("histogram(new Rectangle(10, 10, 5, 5))", bitmap.histogram(new Rectangle(10, 10, 5, 5)));
+
+			var opaque:BitmapData = new BitmapData(2, 2, false, 0x123456);
+			dump("opaque histogram()", opaque.histogram());
+
+			bitmap.dispose();
+			try {
+				bitmap.histogram();
+			} catch (e:Error) {
+				trace("// disposed histogram()");
+				trace(e);
+			}
+		}
+
+		private function dump(name:String, result:Vector.<Vector.<Number>>):void {
+			trace("// " + name);
+			trace("channels: " + result.length);
+			var channelNames:Array = ["red", "green", "blue", "alpha"];
+			for (var channel:int = 0; channel < result.length; channel++) {
+				var counts:Vector.<Number> = result[channel];
+				var entries:Array = [];
+				for (var value:int = 0; value < counts.length; value++) {
+					if (counts[value] != 0) {
+						entries.push(value + ": " + counts[value]);
+					}
+				}
+				trace(channelNames[channel] + " (" + counts.length + " values): " + entries.join(", "));
+			}
+			trace("");
+		}
+	}
+}
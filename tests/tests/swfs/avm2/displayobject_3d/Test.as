@@ -0,0 +1,139 @@
+package {
+	import flash.display.Sprite;
+	import flash.geom.Matrix;
+	import flash.geom.Matrix3D;
+	import flash.geom.PerspectiveProjection;
+	import flash.geom.Point;
+
+	public class Test extends Sprite {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			addChild(sprite);
+
+			trace("// New sprite");
+			printProperties(sprite);
+			trace("matrix3D: " + sprite.transform.matrix3D);
+			trace("matrix: " + sprite.transform.matrix);
+
+			trace("// x = 10, y = 20, z = 30");
+			sprite.x = 10;
+			sprite.y = 20;
+			sprite.z = 30;
+			printProperties(sprite);
+			trace("matrix: " + sprite.transform.matrix);
+			printMatrix3D(sprite.transform.matrix3D);
+
+			trace("// rotationX = 90");
+			sprite.rotationX = 90;
+			printProperties(sprite);
+			printMatrix3D(sprite.transform.matrix3D);
+
+			trace("// rotationX = 0, rotationY = 90");
+			sprite.rotationX = 0;
+			sprite.rotationY = 90;
+			printProperties(sprite);
+			printMatrix3D(sprite.transform.matrix3D);
+
+			trace("// rotationY = 0, rotationZ = 90, scaleZ = 2");
+			sprite.rotationY = 0;
+			sprite.rotationZ = 90;
+			sprite.scaleZ = 2;
+			printProperties(sprite);
+			trace("rotation: " + round(sprite.rotation));
+			printMatrix3D(sprite.transform.matrix3D);
+
+			trace("// matrix3D = skewed matrix");
+			sprite.transform.matrix3D = new Matrix3D(Vector.<Number>([
+				2, 0, 0, 0,
+				1, 1, 0, 0,
+				0, 0, 3, 0,
+				5, 6, 7, 1
+			]));
+			printProperties(sprite);
+			trace("x: " + round(sprite.x) + ", y: " + round(sprite.y));
+			trace("scaleX: " + round(sprite.scaleX) + ", scaleY: " + round(sprite.scaleY));
+			trace("matrix: " + sprite.transform.matrix);
+			printMatrix3D(sprite.transform.matrix3D);
+
+			trace("// matrix3D = null");
+			sprite.transform.matrix3D = null;
+			printProperties(sprite);
+			trace("matrix3D: " + sprite.transform.matrix3D);
+			printMatrix(sprite.transform.matrix);
+
+			trace("// z = 5, then matrix = new Matrix()");
+			sprite.z = 5;
+			trace("matrix: " + sprite.transform.matrix);
+			sprite.transform.matrix = new Matrix();
+			printProperties(sprite);
+			trace("matrix3D: " + sprite.transform.matrix3D);
+			printMatrix(sprite.transform.matrix);
+
+			trace("// perspectiveProjection");
+			printProjection(this.transform.perspectiveProjection);
+			trace("sprite: " + sprite.transform.perspectiveProjection);
+			sprite.transform.perspectiveProjection = new PerspectiveProjection();
+			var projection:PerspectiveProjection = sprite.transform.perspectiveProjection;
+			printProjection(projection);
+			projection.focalLength = 50;
+			projection.projectionCenter = new Point(10, 20);
+			printProjection(sprite.transform.perspectiveProjection);
+			try {
+				projection.fieldOfView = 180;
+			} catch (e:Error) {
+				trace("fieldOfView = 180: " + e);
+			}
+			sprite.transform.perspectiveProjection = null;
+			trace("sprite: " + sprite.transform.perspectiveProjection);
+
+			trace("// getRelativeMatrix3D");
+			var parent:Sprite = new Sprite();
+			parent.x = 10;
+			parent.z = 5;
+			addChild(parent);
+			var child:Sprite = new Sprite();
+			child.x = 1;
+			child.y = 20;
+			child.z = 3;
+			child.rotationY = 90;
+			parent.addChild(child);
+			trace("relative to this");
+			printMatrix3D(child.transform.getRelativeMatrix3D(this));
+			trace("relative to stage");
+			printMatrix3D(child.transform.getRelativeMatrix3D(stage));
+			trace("relative to parent");
+			printMatrix3D(child.transform.getRelativeMatrix3D(parent));
+			trace("parent relative to child");
+			printMatrix3D(parent.transform.getRelativeMatrix3D(child));
+		}
+
+		private function round(value:Number):Number {
+			return Math.round(value * 1000) / 1000;
+		}
+
+		private function printProperties(sprite:Sprite):void {
+			trace("z: " + round(sprite.z) +
+				", rotationX: " + round(sprite.rotationX) +
+				", rotationY: " + round(sprite.rotationY) +
+				", rotationZ: " + round(sprite.rotationZ) +
+				", scaleZ: " + round(sprite.scaleZ));
+		}
+
+		private function printMatrix3D(matrix:Matrix3D):void {
+			var rawData:Vector.<Number> = matrix.rawData;
+			for (var i:int = 0; i < 16; i += 4) {
+				trace("  " + [round(rawData[i]), round(rawData[i + 1]), round(rawData[i + 2]), round(rawData[i + 3])].join(", "));
+			}
+		}
+
+		private function printMatrix(matrix:Matrix):void {
+			trace("matrix: " + [round(matrix.a), round(matrix.b), round(matrix.c), round(matrix.d), round(matrix.tx), round(matrix.ty)].join(", "));
+		}
+
+		private function printProjection(projection:PerspectiveProjection):void {
+			trace("fieldOfView: " + round(projection.fieldOfView) +
+				", focalLength: " + round(projection.focalLength) +
+				", projectionCenter: " + projection.projectionCenter);
+		}
+	}
+}
@@ -0,0 +1,21 @@
+package {
+	import flash.display.Sprite;
+
+	public class Test extends Sprite {
+		public function Test() {
+			graphics.beginFill(0xFFFFFF);
+			graphics.drawRect(0, 0, 100, 100);
+			graphics.endFill();
+
+			// A card half way through flipping over, centered on the default projection center.
+			var card:Sprite = new Sprite();
+			card.graphics.beginFill(0xFF0000);
+			card.graphics.drawRect(-30, -30, 60, 60);
+			card.graphics.endFill();
+			card.x = 50;
+			card.y = 50;
+			card.rotationY = 60;
+			addChild(card);
+		}
+	}
+}
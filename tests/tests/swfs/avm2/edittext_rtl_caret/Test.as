@@ -0,0 +1,63 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.text.TextField;
+	import flash.text.TextFieldType;
+	import flash.text.TextLineMetrics;
+
+	public class Test extends MovieClip {
+		private var mixed:TextField;
+		private var rtl:TextField;
+		private var frame:int = 0;
+
+		public function Test() {
+			// Left-to-right text with a Hebrew word in the middle.
+			mixed = makeField(0, "abc אבג def");
+			// A right-to-left paragraph, right-aligned by default.
+			rtl = makeField(40, "א ב");
+
+			var metrics:TextLineMetrics = rtl.getLineMetrics(0);
+			trace("rtl line x: " + metrics.x + ", width: " + metrics.width);
+
+			stage.focus = mixed;
+			mixed.setSelection(5, 5);
+			traceSelection("start");
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		private function makeField(y:Number, text:String):TextField {
+			var field:TextField = new TextField();
+			field.type = TextFieldType.INPUT;
+			field.y = y;
+			field.width = 200;
+			field.height = 30;
+			field.text = text;
+			addChild(field);
+			return field;
+		}
+
+		private function traceSelection(step:String):void {
+			var field:TextField = stage.focus as TextField;
+			var name:String = field == mixed ? "mixed" : field == rtl ? "rtl" : "none";
+			trace(step + ": " + name + " " + field.selectionBeginIndex + "-" + field.selectionEndIndex + ", caret " + field.caretIndex);
+		}
+
+		private function onEnterFrame(e:Event):void {
+			frame++;
+			traceSelection("frame " + frame);
+			if (frame == 4) {
+				// Move the caret to the start of the Hebrew word.
+				mixed.setSelection(4, 4);
+				traceSelection("reset");
+			} else if (frame == 7) {
+				// Move the caret to the end of the Hebrew word.
+				mixed.setSelection(7, 7);
+				traceSelection("reset");
+			} else if (frame == 10) {
+				// The following frames click on the left and right halves of
+				// the space between the two Hebrew letters.
+				traceSelection("clicking");
+			}
+		}
+	}
+}
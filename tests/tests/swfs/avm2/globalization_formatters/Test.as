@@ -0,0 +1,182 @@
+package {
+	import flash.display.Sprite;
+	import flash.globalization.Collator;
+	import flash.globalization.CollatorMode;
+	import flash.globalization.CurrencyFormatter;
+	import flash.globalization.CurrencyParseResult;
+	import flash.globalization.DateTimeFormatter;
+	import flash.globalization.DateTimeNameStyle;
+	import flash.globalization.DateTimeStyle;
+	import flash.globalization.LocaleID;
+	import flash.globalization.NumberFormatter;
+	import flash.globalization.NumberParseResult;
+	import flash.globalization.StringTools;
+
+	public class Test extends Sprite {
+		public function Test() {
+			testLocales();
+			testNumberFormatter();
+			testCurrencyFormatter();
+			testDateTimeFormatter();
+			testCollator();
+			testStringTools();
+			testLocaleID();
+		}
+
+		private function testLocales():void {
+			trace("/// Locales");
+			for each (var name:String in ["en-US", "en_gb", "fr", "de-AT"]) {
+				var formatter:NumberFormatter = new NumberFormatter(name);
+				trace(name + ": " + formatter.actualLocaleIDName + " " + formatter.lastOperationStatus);
+			}
+			trace("xx-YY: " + new NumberFormatter("xx-YY").lastOperationStatus);
+			trace("available: " + NumberFormatter.getAvailableLocaleIDNames().length);
+			trace("");
+		}
+
+		private function traceParse(formatter:NumberFormatter, input:String):void {
+			var result:NumberParseResult = formatter.parse(input);
+			trace("parse(" + input + "): " + result.value + " " + result.startIndex + " " + result.endIndex + " " + formatter.lastOperationStatus);
+		}
+
+		private function testNumberFormatter():void {
+			trace("/// NumberFormatter");
+			var en:NumberFormatter = new NumberFormatter("en-US");
+			trace("defaults: " + en.decimalSeparator + " " + en.groupingSeparator + " " + en.groupingPattern + " " + en.fractionalDigits + " " + en.negativeNumberFormat);
+			trace("formatNumber(1234567.891): " + en.formatNumber(1234567.891));
+			trace("formatNumber(-1234.5): " + en.formatNumber(-1234.5));
+			trace("formatNumber(-0.001): " + en.formatNumber(-0.001));
+			trace("formatInt(-42): " + en.formatInt(-42));
+			trace("formatUint(4000000000): " + en.formatUint(4000000000));
+
+			en.negativeNumberFormat = 0;
+			trace("negativeNumberFormat = 0: " + en.formatNumber(-3.14159) + " " + en.lastOperationStatus);
+			en.negativeNumberFormat = 7;
+			trace("negativeNumberFormat = 7: " + en.negativeNumberFormat + " " + en.lastOperationStatus);
+
+			en.fractionalDigits = 3;
+			en.trailingZeros = false;
+			en.leadingZero = false;
+			trace("no leading or trailing zeros: " + en.formatNumber(0.5));
+			en.useGrouping = false;
+			trace("no grouping: " + en.formatNumber(12345.6789));
+
+			en = new NumberFormatter("en-US");
+			traceParse(en, "  -1,234.5 apples");
+			traceParse(en, "(42)");
+			traceParse(en, "7-");
+			traceParse(en, "abc");
+			trace("parseNumber(1,000.25): " + en.parseNumber("1,000.25") + " " + en.lastOperationStatus);
+			trace("parseNumber(12 apples): " + en.parseNumber("12 apples") + " " + en.lastOperationStatus);
+
+			var de:NumberFormatter = new NumberFormatter("de-DE");
+			trace("de-DE formatNumber(1234.5): " + de.formatNumber(1234.5));
+			trace("de-DE parseNumber(1.234,5): " + de.parseNumber("1.234,5"));
+			trace("");
+		}
+
+		private function traceCurrencyParse(formatter:CurrencyFormatter, input:String):void {
+			var result:CurrencyParseResult = formatter.parse(input);
+			trace("parse(" + input + "): " + result.value + " [" + result.currencyString + "] " + formatter.lastOperationStatus);
+		}
+
+		private function testCurrencyFormatter():void {
+			trace("/// CurrencyFormatter");
+			var en:CurrencyFormatter = new CurrencyFormatter("en-US");
+			trace("defaults: " + en.currencyISOCode + " " + en.currencySymbol + " " + en.fractionalDigits + " " + en.positiveCurrencyFormat + " " + en.negativeCurrencyFormat);
+			trace("format(1234.5): " + en.format(1234.5));
+			trace("format(1234.5, true): " + en.format(1234.5, true));
+			trace("format(-1234.5, true): " + en.format(-1234.5, true));
+			trace("safe with usd: " + en.formattingWithCurrencySymbolIsSafe("usd"));
+			trace("safe with EUR: " + en.formattingWithCurrencySymbolIsSafe("EUR"));
+			en.positiveCurrencyFormat = 4;
+			trace("positiveCurrencyFormat = 4: " + en.positiveCurrencyFormat + " " + en.lastOperationStatus);
+			traceCurrencyParse(en, "$1,234.56");
+			traceCurrencyParse(en, "-$5.00");
+			traceCurrencyParse(en, "five dollars");
+
+			var de:CurrencyFormatter = new CurrencyFormatter("de-DE");
+			trace("de-DE format(1234.5): " + de.format(1234.5));
+			trace("de-DE format(1234.5, true): " + de.format(1234.5, true));
+			trace("de-DE format(-1234.5, true): " + de.format(-1234.5, true));
+			de.setCurrency("CHF", "Fr.");
+			trace("de-DE setCurrency: " + de.format(10, true) + " " + de.lastOperationStatus);
+			trace("");
+		}
+
+		private function testDateTimeFormatter():void {
+			trace("/// DateTimeFormatter");
+			var date:Date = new Date(Date.UTC(2024, 0, 15, 14, 5, 9));
+			var en:DateTimeFormatter = new DateTimeFormatter("en-US");
+			trace("long: " + en.formatUTC(date) + " " + en.lastOperationStatus);
+			trace("pattern: " + en.getDateTimePattern());
+			en.setDateTimeStyles(DateTimeStyle.SHORT, DateTimeStyle.SHORT);
+			trace("short: " + en.formatUTC(date));
+			en.setDateTimeStyles(DateTimeStyle.MEDIUM, DateTimeStyle.NONE);
+			trace("medium date: " + en.formatUTC(date));
+			en.setDateTimePattern("yyyy-MM-dd'T'HH:mm:ss.SSS EEE");
+			trace("custom: " + en.formatUTC(date) + " " + en.getDateStyle() + " " + en.getTimeStyle());
+			trace("months: " + en.getMonthNames(DateTimeNameStyle.LONG_ABBREVIATION).slice(0, 3));
+			trace("weekdays: " + en.getWeekdayNames(DateTimeNameStyle.SHORT_ABBREVIATION));
+			trace("first weekday: " + en.getFirstWeekday());
+			try {
+				en.setDateTimeStyles("bogus", DateTimeStyle.LONG);
+			} catch (e:Error) {
+				trace("bogus style: " + e);
+			}
+
+			var de:DateTimeFormatter = new DateTimeFormatter("de-DE", DateTimeStyle.LONG, DateTimeStyle.SHORT);
+			trace("de-DE: " + de.formatUTC(date));
+			trace("de-DE first weekday: " + de.getFirstWeekday());
+			trace("");
+		}
+
+		private function testCollator():void {
+			trace("/// Collator");
+			var sorting:Collator = new Collator("en-US");
+			trace("apple vs Apple: " + sorting.compare("apple", "Apple"));
+			trace("Apple vs banana: " + sorting.compare("Apple", "banana"));
+			trace("resume vs résumé: " + sorting.compare("resume", "résumé"));
+			trace("file10 vs file9: " + sorting.compare("file10", "file9"));
+			trace("a equals A: " + sorting.equals("a", "A") + " " + sorting.lastOperationStatus);
+			trace("sorted: " + ["banana", "Apple", "cherry", "apple"].sort(sorting.compare));
+			sorting.numericComparison = true;
+			trace("numeric file10 vs file9: " + sorting.compare("file10", "file9"));
+
+			var matching:Collator = new Collator("en-US", CollatorMode.MATCHING);
+			trace("Résumé equals resume: " + matching.equals("Résumé", "resume"));
+			trace("ＡＢＣ equals abc: " + matching.equals("ＡＢＣ", "abc"));
+			trace("a-b equals ab: " + matching.equals("a-b", "ab"));
+			matching.ignoreSymbols = true;
+			trace("ignoring symbols, a-b equals ab: " + matching.equals("a-b", "ab"));
+
+			trace("de-DE ö vs z: " + new Collator("de-DE").compare("ö", "z"));
+			trace("sv-SE ö vs z: " + new Collator("sv-SE").compare("ö", "z"));
+			try {
+				new Collator("en-US", "bogus");
+			} catch (e:Error) {
+				trace("bogus mode: " + e);
+			}
+			trace("");
+		}
+
+		private function testStringTools():void {
+			trace("/// StringTools");
+			var en:StringTools = new StringTools("en-US");
+			trace("en-US: " + en.toUpperCase("istanbul") + " " + en.toLowerCase("ISTANBUL") + " " + en.lastOperationStatus);
+			var tr:StringTools = new StringTools("tr-TR");
+			trace("tr-TR: " + tr.toUpperCase("istanbul") + " " + tr.toLowerCase("ISTANBUL"));
+			trace("");
+		}
+
+		private function testLocaleID():void {
+			trace("/// LocaleID");
+			var id:LocaleID = new LocaleID("zh_Hant_TW@collation=stroke");
+			trace("name: " + id.name);
+			trace("parts: " + id.getLanguage() + " " + id.getScript() + " " + id.getRegion() + " [" + id.getVariant() + "]");
+			trace("collation: " + id.getKeysAndValues().collation);
+			trace("right to left: " + id.isRightToLeft() + " " + new LocaleID("ar-EG").isRightToLeft());
+			trace("preferred: " + LocaleID.determinePreferredLocales(new <String>["fr-CA", "de-DE", "ja"], new <String>["en-US", "de_DE", "fr-FR"]));
+		}
+	}
+}
@@ -0,0 +1,57 @@
+package {
+	import flash.display.GraphicsPath;
+	import flash.display.IGraphicsData;
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			// A different radius in each corner, with a square top-right corner.
+			var shape:Shape = draw(10, 10, 100, 60, 20, 0, 5, 30);
+			test(shape, "top-left corner", 12, 12);
+			test(shape, "inside top-left curve", 20, 20);
+			test(shape, "top-right corner", 109, 11);
+			test(shape, "bottom-left corner", 11, 69);
+			test(shape, "bottom-right corner", 106, 66);
+			test(shape, "inside bottom-right curve", 95, 55);
+			trace("");
+
+			// Radii larger than half the shortest side are clamped.
+			shape = draw(0, 0, 100, 40, 100, 100, 100, 100);
+			test(shape, "left edge middle", 1, 20);
+			test(shape, "left edge near top", 1, 5);
+			test(shape, "top edge middle", 50, 1);
+			trace("");
+
+			// Without radii, this is a plain rectangle.
+			draw(0, 0, 10, 10, 0, 0, 0, 0);
+			trace("");
+
+			// Negative radii are treated as zero.
+			draw(0, 0, 10, 10, -5, 0, 0, 0);
+		}
+
+		private function draw(x:Number, y:Number, width:Number, height:Number, topLeft:Number, topRight:Number, bottomLeft:Number, bottomRight:Number):Shape {
+			var shape:Shape = new Shape();
+			shape.graphics.beginFill(0xFF0000);
+			shape.graphics.drawRoundRectComplex(x, y, width, height, topLeft, topRight, bottomLeft, bottomRight);
+			shape.graphics.endFill();
+			addChild(shape);
+
+			trace("// drawRoundRectComplex(" + [x, y, width, height, topLeft, topRight, bottomLeft, bottomRight] + ")");
+			trace("bounds: " + shape.getBounds(shape));
+			for each (var item:IGraphicsData in shape.graphics.readGraphicsData()) {
+				if (item is GraphicsPath) {
+					var path:GraphicsPath = item as GraphicsPath;
+					trace("commands: " + path.commands);
+					trace("data: " + path.data);
+				}
+			}
+			return shape;
+		}
+
+		private function test(shape:Shape, name:String, x:Number, y:Number):void {
+			trace(name + " (" + x + ", " + y + "): " + shape.hitTestPoint(x, y, true));
+		}
+	}
+}
@@ -0,0 +1,91 @@
+package {
+	import flash.display.CapsStyle;
+	import flash.display.GradientType;
+	import flash.display.GraphicsEndFill;
+	import flash.display.GraphicsGradientFill;
+	import flash.display.GraphicsPath;
+	import flash.display.GraphicsSolidFill;
+	import flash.display.GraphicsStroke;
+	import flash.display.IGraphicsData;
+	import flash.display.JointStyle;
+	import flash.display.LineScaleMode;
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.display.Sprite;
+	import flash.geom.Matrix;
+	import flash.utils.getQualifiedClassName;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite:Sprite = new Sprite();
+			sprite.x = 300;
+
+			sprite.graphics.beginFill(0xFF0000, 0.5);
+			sprite.graphics.drawRect(0, 0, 10, 20);
+			sprite.graphics.endFill();
+
+			sprite.graphics.lineStyle(2, 0x00FF00, 1, true, LineScaleMode.NONE, CapsStyle.SQUARE, JointStyle.BEVEL);
+			sprite.graphics.moveTo(0, 0);
+			sprite.graphics.lineTo(5, 5);
+			sprite.graphics.curveTo(10, 0, 15, 5);
+			sprite.graphics.cubicCurveTo(20, 0, 25, 10, 30, 5);
+			sprite.graphics.lineStyle();
+
+			var matrix:Matrix = new Matrix();
+			matrix.createGradientBox(40, 20);
+			sprite.graphics.beginGradientFill(GradientType.RADIAL, [0x0000FF, 0xFFFF00], [1, 0.25], [0, 255], matrix, "reflect", "linearRGB", 0.5);
+			sprite.graphics.drawRect(0, 30, 40, 20);
+			sprite.graphics.endFill();
+
+			var child:Shape = new Shape();
+			child.x = 100;
+			child.y = 50;
+			child.scaleX = 2;
+			child.graphics.beginFill(0x123456);
+			child.graphics.drawRect(0, 0, 5, 5);
+			child.graphics.endFill();
+			sprite.addChild(child);
+			addChild(sprite);
+
+			trace("// sprite.graphics.readGraphicsData(false)");
+			dump(sprite.graphics.readGraphicsData(false));
+			trace("");
+			trace("// sprite.graphics.readGraphicsData(true)");
+			dump(sprite.graphics.readGraphicsData(true));
+			trace("");
+			trace("// new Shape().graphics.readGraphicsData()");
+			dump(new Shape().graphics.readGraphicsData());
+		}
+
+		private function dump(data:Vector.<IGraphicsData>):void {
+			trace("length: " + data.length);
+			for each (var item:IGraphicsData in data) {
+				trace(getQualifiedClassName(item));
+				if (item is GraphicsSolidFill) {
+					var solid:GraphicsSolidFill = item as GraphicsSolidFill;
+					trace("  color: " + solid.color.toString(16) + ", alpha: " + solid.alpha);
+				} else if (item is GraphicsGradientFill) {
+					var gradient:GraphicsGradientFill = item as GraphicsGradientFill;
+					trace("  type: " + gradient.type + ", colors: " + gradient.colors + ", alphas: " + gradient.alphas + ", ratios: " + gradient.ratios);
+					trace("  matrix: " + gradient.matrix);
+					trace("  spreadMethod: " + gradient.spreadMethod + ", interpolationMethod: " + gradient.interpolationMethod + ", focalPointRatio: " + gradient.focalPointRatio);
+				} else if (item is GraphicsPath) {
+					var path:GraphicsPath = item as GraphicsPath;
+					trace("  commands: " + path.commands);
+					trace("  data: " + path.data);
+					trace("  winding: " + path.winding);
+				} else if (item is GraphicsStroke) {
+					var stroke:GraphicsStroke = item as GraphicsStroke;
+					trace("  thickness: " + stroke.thickness + ", pixelHinting: " + stroke.pixelHinting + ", scaleMode: " + stroke.scaleMode);
+					trace("  caps: " + stroke.caps + ", joints: " + stroke.joints + ", miterLimit: " + stroke.miterLimit);
+					if (stroke.fill is GraphicsSolidFill) {
+						var strokeFill:GraphicsSolidFill = stroke.fill as GraphicsSolidFill;
+						trace("  fill color: " + strokeFill.color.toString(16) + ", alpha: " + strokeFill.alpha);
+					} else {
+						trace("  fill: " + stroke.fill);
+					}
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,101 @@
+package {
+	import flash.display.BitmapData;
+	import flash.display.GraphicsEndFill;
+	import flash.display.GraphicsPath;
+	import flash.display.GraphicsShaderFill;
+	import flash.display.IGraphicsData;
+	import flash.display.MovieClip;
+	import flash.display.Shader;
+	import flash.display.Shape;
+	import flash.geom.Matrix;
+	import flash.geom.Rectangle;
+	import flash.utils.ByteArray;
+
+	public class Test extends MovieClip {
+		// The bytecode of `simple_shader.pbk`.
+		private static const SHADER_BYTES:Array = [
+			0xa5, 0x01, 0x00, 0x00, 0x00, 0xa4, 0x09, 0x00, 0x44, 0x6f, 0x4e, 0x6f, 0x74, 0x68, 0x69, 0x6e,
+			0x67, 0xa0, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x00, 0x41, 0x64, 0x6f,
+			0x62, 0x65, 0x3a, 0x3a, 0x45, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x00, 0xa0, 0x0c, 0x76, 0x65,
+			0x6e, 0x64, 0x6f, 0x72, 0x00, 0x41, 0x64, 0x6f, 0x62, 0x65, 0x20, 0x65, 0x78, 0x61, 0x6d, 0x70,
+			0x6c, 0x65, 0x73, 0x00, 0xa0, 0x08, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x00, 0x01, 0x00,
+			0xa0, 0x0c, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x00, 0x41, 0x20,
+			0x73, 0x68, 0x61, 0x64, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x64, 0x6f, 0x65, 0x73,
+			0x20, 0x6e, 0x6f, 0x74, 0x68, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x62, 0x75, 0x74, 0x20, 0x64, 0x6f,
+			0x65, 0x73, 0x20, 0x69, 0x74, 0x20, 0x77, 0x65, 0x6c, 0x6c, 0x2e, 0x00, 0xa1, 0x02, 0x04, 0x00,
+			0x00, 0x0f, 0x64, 0x73, 0x74, 0x00, 0xa1, 0x01, 0x01, 0x01, 0x00, 0x08, 0x72, 0x61, 0x64, 0x69,
+			0x75, 0x73, 0x00, 0xa2, 0x0c, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e,
+			0x00, 0x54, 0x68, 0x65, 0x20, 0x72, 0x61, 0x64, 0x69, 0x75, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74,
+			0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x00, 0xa2, 0x01, 0x6d, 0x69, 0x6e, 0x56,
+			0x61, 0x6c, 0x75, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa2, 0x01, 0x6d, 0x61, 0x78, 0x56, 0x61,
+			0x6c, 0x75, 0x65, 0x00, 0x42, 0x48, 0x00, 0x00, 0xa2, 0x01, 0x64, 0x65, 0x66, 0x61, 0x75, 0x6c,
+			0x74, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x00, 0x41, 0xc8, 0x00, 0x00, 0xa1, 0x01, 0x01, 0x01, 0x00,
+			0x04, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x00, 0xa2, 0x0c, 0x64, 0x65,
+			0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x00, 0x4f, 0x74, 0x68, 0x65, 0x72, 0x20,
+			0x70, 0x61, 0x72, 0x61, 0x6d, 0x00, 0xa2, 0x01, 0x6d, 0x69, 0x6e, 0x56, 0x61, 0x6c, 0x75, 0x65,
+			0x00, 0x00, 0x00, 0x00, 0x00, 0xa2, 0x01, 0x6d, 0x61, 0x78, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x00,
+			0x43, 0x7f, 0x00, 0x00, 0xa2, 0x01, 0x64, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x56, 0x61, 0x6c,
+			0x75, 0x65, 0x00, 0x41, 0xc8, 0x00, 0x00, 0x1d, 0x01, 0x00, 0x20, 0x01, 0x00, 0x40, 0x00, 0x01,
+			0x01, 0x00, 0x20, 0x01, 0x00, 0x00, 0x00, 0x32, 0x01, 0x00, 0x10, 0x43, 0x7f, 0x00, 0x00, 0x04,
+			0x03, 0x00, 0x80, 0x01, 0x00, 0xc0, 0x00, 0x03, 0x03, 0x00, 0x80, 0x01, 0x00, 0x80, 0x00, 0x1d,
+			0x02, 0x00, 0x80, 0x03, 0x00, 0x00, 0x00, 0x32, 0x01, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x1d,
+			0x02, 0x00, 0x40, 0x01, 0x00, 0x80, 0x00, 0x32, 0x01, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x1d,
+			0x02, 0x00, 0x20, 0x01, 0x00, 0x80, 0x00, 0x32, 0x01, 0x00, 0x20, 0x3f, 0x80, 0x00, 0x00, 0x1d,
+			0x02, 0x00, 0x10, 0x01, 0x00, 0x80, 0x00, 0x1d, 0x00, 0x00, 0xf3, 0x02, 0x00, 0x1b, 0x00
+		];
+
+		public function Test() {
+			var bytes:ByteArray = new ByteArray();
+			for each (var b:int in SHADER_BYTES) {
+				bytes.writeByte(b);
+			}
+			var shader:Shader = new Shader(bytes);
+
+			var fill:GraphicsShaderFill = new GraphicsShaderFill(shader);
+			trace("default matrix: " + fill.matrix);
+			trace("shader: " + (fill.shader === shader));
+
+			// The fill extends into negative shader coordinates.
+			var negative:Shape = new Shape();
+			negative.graphics.drawGraphicsData(Vector.<IGraphicsData>([
+				fill,
+				rect(-50, -50, 100, 100),
+				new GraphicsEndFill()
+			]));
+			test("negative", negative);
+
+			// The shader matrix moves and scales the shader coordinates.
+			var transformed:Shape = new Shape();
+			var matrix:Matrix = new Matrix();
+			matrix.scale(2, 2);
+			matrix.translate(-30, 10);
+			transformed.graphics.drawGraphicsData(Vector.<IGraphicsData>([
+				new GraphicsShaderFill(shader, matrix),
+				rect(-40, -40, 80, 80),
+				new GraphicsEndFill()
+			]));
+			test("transformed", transformed);
+		}
+
+		private function rect(x:Number, y:Number, width:Number, height:Number):GraphicsPath {
+			var path:GraphicsPath = new GraphicsPath();
+			path.moveTo(x, y);
+			path.lineTo(x + width, y);
+			path.lineTo(x + width, y + height);
+			path.lineTo(x, y + height);
+			path.lineTo(x, y);
+			return path;
+		}
+
+		private function test(name:String, shape:Shape):void {
+			var bounds:Rectangle = shape.getBounds(shape);
+			trace(name + " bounds: " + bounds);
+
+			var target:BitmapData = new BitmapData(120, 120, true, 0);
+			target.draw(shape, new Matrix(1, 0, 0, 1, 60 - bounds.x, 60 - bounds.y));
+			for each (var point:Array in [[59, 59], [60, 60], [61, 61], [100, 100], [119, 119]]) {
+				trace(name + " pixel " + point + ": " + target.getPixel32(point[0], point[1]).toString(16));
+			}
+		}
+	}
+}
@@ -0,0 +1,63 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.events.TimerEvent;
+	import flash.media.Sound;
+	import flash.media.SoundChannel;
+	import flash.utils.Timer;
+
+	public class Child extends MovieClip {
+		private var timer:Timer;
+		public var channel:SoundChannel;
+
+		public function Child() {
+			addFrameScript(0, frame1, 1, frame2, 2, frame3);
+			addEventListener(Event.ENTER_FRAME, function(e:Event):void {
+				trace("child enterFrame");
+			});
+			addEventListener(Event.FRAME_CONSTRUCTED, function(e:Event):void {
+				trace("child frameConstructed");
+			});
+			addEventListener(Event.EXIT_FRAME, function(e:Event):void {
+				trace("child exitFrame");
+			});
+			addEventListener(Event.ACTIVATE, function(e:Event):void {
+				trace("child activate");
+			});
+			addEventListener(Event.DEACTIVATE, function(e:Event):void {
+				trace("child deactivate");
+			});
+			addEventListener(Event.REMOVED, function(e:Event):void {
+				if (e.target == e.currentTarget) {
+					trace("child removed");
+				}
+			});
+			addEventListener(Event.REMOVED_FROM_STAGE, function(e:Event):void {
+				trace("child removedFromStage");
+			});
+
+			timer = new Timer(100);
+			timer.addEventListener(TimerEvent.TIMER, function(e:TimerEvent):void {
+				trace("child timer");
+			});
+			timer.start();
+
+			channel = new ChildSound().play();
+			channel.addEventListener(Event.SOUND_COMPLETE, function(e:Event):void {
+				trace("child sound complete");
+			});
+		}
+
+		private function frame1():void {
+			trace("child frame 1");
+		}
+
+		private function frame2():void {
+			trace("child frame 2");
+		}
+
+		private function frame3():void {
+			trace("child frame 3");
+		}
+	}
+}
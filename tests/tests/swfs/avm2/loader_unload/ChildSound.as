@@ -0,0 +1,6 @@
+package {
+	import flash.media.Sound;
+
+	public class ChildSound extends Sound {
+	}
+}
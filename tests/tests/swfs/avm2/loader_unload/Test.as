@@ -0,0 +1,71 @@
+package {
+	import flash.display.Loader;
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.events.IOErrorEvent;
+	import flash.net.URLRequest;
+
+	public class Test extends MovieClip {
+		private var loader:Loader;
+		private var frames:int = 0;
+		private var content:MovieClip;
+		private var contentFrame:int;
+		private var innerFrame:int;
+
+		public function Test() {
+			loader = new Loader();
+			addChild(loader);
+			loader.contentLoaderInfo.addEventListener(Event.INIT, onInit);
+			loader.contentLoaderInfo.addEventListener(Event.UNLOAD, function(e:Event):void {
+				trace("loaderInfo unload, loader.numChildren: " + loader.numChildren);
+			});
+			loader.load(new URLRequest("child.swf"));
+		}
+
+		private function onInit(e:Event):void {
+			trace("init");
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		private function onEnterFrame(e:Event):void {
+			frames++;
+			if (frames == 2) {
+				content = MovieClip(loader.content);
+				contentFrame = content.currentFrame;
+				innerFrame = MovieClip(content.getChildAt(0)).currentFrame;
+				trace("calling unload");
+				loader.unload();
+				trace("after unload, loader.content: " + loader.content);
+				trace("calling unload again");
+				loader.unload();
+			} else if (frames == 13) {
+				// Plain `unload` leaves the content running.
+				removeEventListener(Event.ENTER_FRAME, onEnterFrame);
+				trace("content still on frame " + contentFrame + ": " + (content.currentFrame == contentFrame));
+				trace("inner still on frame " + innerFrame + ": " + (MovieClip(content.getChildAt(0)).currentFrame == innerFrame));
+				closeFromHandlers();
+				trace("done");
+			}
+		}
+
+		// `close()` from inside a load's own event handlers must not
+		// break the rest of the load.
+		private function closeFromHandlers():void {
+			var imageLoader:Loader = new Loader();
+			imageLoader.contentLoaderInfo.addEventListener(Event.COMPLETE, function(e:Event):void {
+				trace("image complete, calling close");
+				imageLoader.close();
+				trace("image content after close: " + imageLoader.content);
+			});
+			imageLoader.load(new URLRequest("image.png"));
+
+			var errorLoader:Loader = new Loader();
+			errorLoader.contentLoaderInfo.addEventListener(IOErrorEvent.IO_ERROR, function(e:IOErrorEvent):void {
+				trace("ioError, calling close");
+				errorLoader.close();
+				trace("error content after close: " + errorLoader.content);
+			});
+			errorLoader.load(new URLRequest("missing.swf?debug-error-statuscode"));
+		}
+	}
+}
@@ -0,0 +1,70 @@
+package {
+	import flash.display.Loader;
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.events.IOErrorEvent;
+	import flash.events.TimerEvent;
+	import flash.net.URLRequest;
+	import flash.utils.Timer;
+
+	public class Test extends MovieClip {
+		private var loader:Loader;
+		private var closedLoader:Loader;
+		private var frames:int = 0;
+		private var content:MovieClip;
+		private var contentFrame:int;
+		private var innerFrame:int;
+
+		public function Test() {
+			// Closing a loader straight away cancels the load.
+			closedLoader = new Loader();
+			for each (var type:String in [Event.OPEN, Event.INIT, Event.COMPLETE, IOErrorEvent.IO_ERROR]) {
+				closedLoader.contentLoaderInfo.addEventListener(type, function(e:Event):void {
+					trace("closed loader event: " + e.type);
+				});
+			}
+			closedLoader.load(new URLRequest("child.swf"));
+			closedLoader.close();
+
+			loader = new Loader();
+			addChild(loader);
+			loader.contentLoaderInfo.addEventListener(Event.INIT, onInit);
+			loader.contentLoaderInfo.addEventListener(Event.UNLOAD, function(e:Event):void {
+				trace("loaderInfo unload");
+			});
+			loader.load(new URLRequest("child.swf"));
+		}
+
+		private function onInit(e:Event):void {
+			trace("init");
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		private function onEnterFrame(e:Event):void {
+			frames++;
+			if (frames == 2) {
+				content = MovieClip(loader.content);
+				contentFrame = content.currentFrame;
+				innerFrame = MovieClip(content.getChildAt(0)).currentFrame;
+				trace("calling unloadAndStop");
+				loader.unloadAndStop();
+				trace("after unloadAndStop, loader.content: " + loader.content);
+
+				// Timers started by the loading movie keep running.
+				var timer:Timer = new Timer(100, 1);
+				timer.addEventListener(TimerEvent.TIMER, function(e:TimerEvent):void {
+					trace("parent timer");
+				});
+				timer.start();
+			} else if (frames == 20) {
+				// By now, the child's timer would have fired and its sound
+				// would have finished if they were still running.
+				removeEventListener(Event.ENTER_FRAME, onEnterFrame);
+				trace("content still on frame " + contentFrame + ": " + (content.currentFrame == contentFrame));
+				trace("inner still on frame " + innerFrame + ": " + (MovieClip(content.getChildAt(0)).currentFrame == innerFrame));
+				trace("closed loader content: " + closedLoader.content);
+				trace("done");
+			}
+		}
+	}
+}
@@ -0,0 +1,43 @@
+package {
+	import flash.display.BitmapData;
+	import flash.display.MovieClip;
+	import flash.geom.Point;
+	import flash.ui.Mouse;
+	import flash.ui.MouseCursorData;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("supportsNativeCursor: " + Mouse.supportsNativeCursor);
+
+			tryHotSpot(new Point(-1, 0));
+			tryHotSpot(new Point(0, -1));
+			tryHotSpot(new Point(16, 0));
+			tryHotSpot(new Point(0, 8));
+			tryHotSpot(new Point(15, 7));
+
+			var data:MouseCursorData = cursorData(new Point(3, 2));
+			data.frameRate = 5;
+			Mouse.registerCursor("custom", data);
+			Mouse.cursor = "button";
+			trace("cursor: " + Mouse.cursor);
+			Mouse.cursor = "custom";
+			trace("cursor: " + Mouse.cursor);
+		}
+
+		private static function cursorData(hotSpot:Point):MouseCursorData {
+			var data:MouseCursorData = new MouseCursorData();
+			data.data = new <BitmapData>[new BitmapData(16, 16, true, 0xFF00FF00), new BitmapData(16, 8, true, 0x800000FF)];
+			data.hotSpot = hotSpot;
+			return data;
+		}
+
+		private static function tryHotSpot(hotSpot:Point):void {
+			try {
+				Mouse.registerCursor("hotspot", cursorData(hotSpot));
+				trace("hot spot " + hotSpot + ": registered");
+			} catch (e:Error) {
+				trace("hot spot " + hotSpot + ": " + e);
+			}
+		}
+	}
+}
@@ -0,0 +1,39 @@
+package {
+	import flash.display.Sprite;
+	import flash.geom.Rectangle;
+	import flash.printing.PrintJob;
+
+	public class Test extends Sprite {
+		public function Test() {
+			var page:Sprite = new Sprite();
+			page.graphics.beginFill(0xFF0000);
+			page.graphics.drawRect(0, 0, 100, 50);
+			page.graphics.beginFill(0x0000FF);
+			page.graphics.drawRect(100, 0, 100, 50);
+			page.graphics.endFill();
+
+			var job:PrintJob = new PrintJob();
+			try {
+				job.addPage(page);
+			} catch (e:Error) {
+				trace("addPage before start: " + e.errorID);
+			}
+
+			trace("start: " + job.start());
+			trace("paper: " + job.paperWidth + "x" + job.paperHeight);
+			trace("page: " + job.pageWidth + "x" + job.pageHeight);
+			trace("orientation: " + job.orientation);
+
+			job.addPage(page, new Rectangle(0, 0, 200, 50));
+			job.addPage(page, new Rectangle(50, 0, 100, 50));
+			job.send();
+			trace("sent");
+
+			try {
+				job.addPage(page);
+			} catch (e:Error) {
+				trace("addPage after send: " + e.errorID);
+			}
+		}
+	}
+}
@@ -0,0 +1,15 @@
+package {
+	import flash.display.MovieClip;
+
+	// Frame 1 has a red rectangle, frame 2 replaces it with a blue one.
+	public class Pages extends MovieClip {
+		public function Pages() {
+			trace("Pages constructed on frame " + currentFrame);
+			addFrameScript(1, frame2);
+		}
+
+		private function frame2():void {
+			trace("frame 2 script ran");
+		}
+	}
+}
@@ -0,0 +1,33 @@
+package {
+	import flash.display.DisplayObject;
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.geom.Rectangle;
+	import flash.printing.PrintJob;
+
+	public class Test extends Sprite {
+		public function Test() {
+			var pages:Pages = new Pages();
+			pages.stop();
+			pages.addEventListener(Event.ADDED, function(e:Event):void {
+				trace("added: " + e.target);
+			});
+			pages.addEventListener(Event.REMOVED, function(e:Event):void {
+				trace("removed: " + e.target);
+			});
+			var first:DisplayObject = pages.getChildAt(0);
+
+			var job:PrintJob = new PrintJob();
+			trace("start: " + job.start());
+			// Printing another frame must not move the clip or run any of its scripts.
+			job.addPage(pages, new Rectangle(0, 0, 100, 50), null, 2);
+			job.addPage(pages, new Rectangle(0, 0, 100, 50));
+			job.send();
+			trace("sent");
+
+			trace("currentFrame: " + pages.currentFrame);
+			trace("isPlaying: " + pages.isPlaying);
+			trace("same child: " + (pages.getChildAt(0) == first));
+		}
+	}
+}
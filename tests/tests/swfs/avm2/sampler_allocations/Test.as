@@ -0,0 +1,80 @@
+package {
+	import flash.display.MovieClip;
+	import flash.sampler.*;
+	import flash.utils.getQualifiedClassName;
+
+	public class Test extends MovieClip {
+		public var kept:Array;
+
+		public function Test() {
+			startSampling();
+			kept = [1, 2, 3];
+			var keptFunction:Function = function():int {
+				return 1;
+			};
+			var keptVector:Vector.<int> = new <int>[1, 2];
+			var fromNew:Object = {a: 1};
+			var closure:Function = makeClosure();
+			pauseSampling();
+
+			var types:Object = {};
+			var keptSample:NewObjectSample = null;
+			var makeClosureSamples:int = 0;
+			for each (var sample:Sample in getSamples()) {
+				var newSample:NewObjectSample = sample as NewObjectSample;
+				if (newSample) {
+					types[getQualifiedClassName(newSample.type)] = true;
+					if (newSample.object === kept) {
+						keptSample = newSample;
+					}
+					if (newSample.stack && String(newSample.stack[0]).indexOf("makeClosure") != -1) {
+						makeClosureSamples++;
+					}
+				}
+			}
+			trace("Array sampled: " + (types["Array"] === true));
+			trace("Function sampled: " + (types["Function"] === true));
+			trace("Vector sampled: " + (types["__AS3__.vec::Vector.<int>"] === true));
+			trace("Object sampled: " + (types["Object"] === true));
+			trace("samples from makeClosure (activation and closure): " + makeClosureSamples);
+			trace("kept sample object is kept: " + (keptSample.object === kept));
+
+			// Sizes include the objects a value holds on to.
+			var inner:Array = [];
+			for (var i:int = 0; i < 100; i++) {
+				inner.push("some string " + i);
+			}
+			var outer:Object = {inner: inner};
+			trace("getSize(outer) > getSize(inner): " + (getSize(outer) > getSize(inner)));
+			trace("getSize(inner) > getSize([]): " + (getSize(inner) > getSize([])));
+
+			clearSamples();
+			var count:int = 0;
+			for each (var cleared:Sample in getSamples()) {
+				count++;
+			}
+			trace("samples after clearSamples: " + count);
+			trace("kept sample object after clearSamples: " + keptSample.object);
+
+			startSampling();
+			kept = [4, 5, 6];
+			pauseSampling();
+			var newSample2:NewObjectSample = null;
+			for each (var again:Sample in getSamples()) {
+				if (again is NewObjectSample && NewObjectSample(again).object === kept) {
+					newSample2 = NewObjectSample(again);
+				}
+			}
+			trace("sample for new array found: " + (newSample2 != null));
+			stopSampling();
+			trace("new array sample object after stopSampling: " + newSample2.object);
+		}
+
+		private function makeClosure():Function {
+			var captured:int = 5;
+			return function():int {
+				return captured;
+			};
+		}
+	}
+}
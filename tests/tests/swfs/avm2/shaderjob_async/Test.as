@@ -0,0 +1,100 @@
+package {
+	import flash.display.BitmapData;
+	import flash.display.MovieClip;
+	import flash.display.Shader;
+	import flash.display.ShaderJob;
+	import flash.events.Event;
+	import flash.events.ShaderEvent;
+	import flash.utils.ByteArray;
+
+	public class Test extends MovieClip {
+		// The bytecode of `simple_shader.pbk`.
+		private static const SHADER_BYTES:Array = [
+			0xa5, 0x01, 0x00, 0x00, 0x00, 0xa4, 0x09, 0x00, 0x44, 0x6f, 0x4e, 0x6f, 0x74, 0x68, 0x69, 0x6e,
+			0x67, 0xa0, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x00, 0x41, 0x64, 0x6f,
+			0x62, 0x65, 0x3a, 0x3a, 0x45, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x00, 0xa0, 0x0c, 0x76, 0x65,
+			0x6e, 0x64, 0x6f, 0x72, 0x00, 0x41, 0x64, 0x6f, 0x62, 0x65, 0x20, 0x65, 0x78, 0x61, 0x6d, 0x70,
+			0x6c, 0x65, 0x73, 0x00, 0xa0, 0x08, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x00, 0x01, 0x00,
+			0xa0, 0x0c, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x00, 0x41, 0x20,
+			0x73, 0x68, 0x61, 0x64, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x64, 0x6f, 0x65, 0x73,
+			0x20, 0x6e, 0x6f, 0x74, 0x68, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x62, 0x75, 0x74, 0x20, 0x64, 0x6f,
+			0x65, 0x73, 0x20, 0x69, 0x74, 0x20, 0x77, 0x65, 0x6c, 0x6c, 0x2e, 0x00, 0xa1, 0x02, 0x04, 0x00,
+			0x00, 0x0f, 0x64, 0x73, 0x74, 0x00, 0xa1, 0x01, 0x01, 0x01, 0x00, 0x08, 0x72, 0x61, 0x64, 0x69,
+			0x75, 0x73, 0x00, 0xa2, 0x0c, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e,
+			0x00, 0x54, 0x68, 0x65, 0x20, 0x72, 0x61, 0x64, 0x69, 0x75, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74,
+			0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x00, 0xa2, 0x01, 0x6d, 0x69, 0x6e, 0x56,
+			0x61, 0x6c, 0x75, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa2, 0x01, 0x6d, 0x61, 0x78, 0x56, 0x61,
+			0x6c, 0x75, 0x65, 0x00, 0x42, 0x48, 0x00, 0x00, 0xa2, 0x01, 0x64, 0x65, 0x66, 0x61, 0x75, 0x6c,
+			0x74, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x00, 0x41, 0xc8, 0x00, 0x00, 0xa1, 0x01, 0x01, 0x01, 0x00,
+			0x04, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x00, 0xa2, 0x0c, 0x64, 0x65,
+			0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x00, 0x4f, 0x74, 0x68, 0x65, 0x72, 0x20,
+			0x70, 0x61, 0x72, 0x61, 0x6d, 0x00, 0xa2, 0x01, 0x6d, 0x69, 0x6e, 0x56, 0x61, 0x6c, 0x75, 0x65,
+			0x00, 0x00, 0x00, 0x00, 0x00, 0xa2, 0x01, 0x6d, 0x61, 0x78, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x00,
+			0x43, 0x7f, 0x00, 0x00, 0xa2, 0x01, 0x64, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x56, 0x61, 0x6c,
+			0x75, 0x65, 0x00, 0x41, 0xc8, 0x00, 0x00, 0x1d, 0x01, 0x00, 0x20, 0x01, 0x00, 0x40, 0x00, 0x01,
+			0x01, 0x00, 0x20, 0x01, 0x00, 0x00, 0x00, 0x32, 0x01, 0x00, 0x10, 0x43, 0x7f, 0x00, 0x00, 0x04,
+			0x03, 0x00, 0x80, 0x01, 0x00, 0xc0, 0x00, 0x03, 0x03, 0x00, 0x80, 0x01, 0x00, 0x80, 0x00, 0x1d,
+			0x02, 0x00, 0x80, 0x03, 0x00, 0x00, 0x00, 0x32, 0x01, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x1d,
+			0x02, 0x00, 0x40, 0x01, 0x00, 0x80, 0x00, 0x32, 0x01, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x1d,
+			0x02, 0x00, 0x20, 0x01, 0x00, 0x80, 0x00, 0x32, 0x01, 0x00, 0x20, 0x3f, 0x80, 0x00, 0x00, 0x1d,
+			0x02, 0x00, 0x10, 0x01, 0x00, 0x80, 0x00, 0x1d, 0x00, 0x00, 0xf3, 0x02, 0x00, 0x1b, 0x00
+		];
+
+		private var frame:int = 0;
+		private var jobs:Object = {};
+
+		public function Test() {
+			var bytes:ByteArray = new ByteArray();
+			for each (var b:int in SHADER_BYTES) {
+				bytes.writeByte(b);
+			}
+			var shader:Shader = new Shader(bytes);
+
+			jobs.whole = makeJob("whole", shader, new BitmapData(512, 384), 0, 0);
+			// Only 256x256 pixels of the target are processed.
+			jobs.partial = makeJob("partial", shader, new BitmapData(512, 384), 256, 256);
+			jobs.cancelled = makeJob("cancelled", shader, new BitmapData(512, 384), 0, 0);
+			jobs.restarted = makeJob("restarted", shader, new BitmapData(512, 384), 0, 0);
+			for each (var name:String in ["whole", "partial", "cancelled", "restarted"]) {
+				jobs[name].start();
+			}
+			traceProgress();
+			trace("cancelling");
+			jobs.cancelled.cancel();
+			trace("restarting");
+			jobs.restarted.start();
+			traceProgress();
+
+			var sync:ShaderJob = makeJob("sync", shader, new BitmapData(512, 384), 0, 0);
+			sync.start(true);
+			trace("sync progress after start(true): " + sync.progress);
+
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		private function makeJob(name:String, shader:Shader, target:BitmapData, width:int, height:int):ShaderJob {
+			var job:ShaderJob = new ShaderJob(shader, target, width, height);
+			job.addEventListener(ShaderEvent.COMPLETE, function(e:ShaderEvent):void {
+				trace(name + " complete on frame " + frame + ", progress: " + job.progress + ", bitmapData is target: " + (e.bitmapData === target));
+			});
+			return job;
+		}
+
+		private function traceProgress():void {
+			trace("frame " + frame + ": whole " + jobs.whole.progress.toFixed(3) + ", partial " + jobs.partial.progress.toFixed(3) + ", cancelled " + jobs.cancelled.progress.toFixed(3) + ", restarted " + jobs.restarted.progress.toFixed(3));
+		}
+
+		private function onEnterFrame(e:Event):void {
+			frame++;
+			traceProgress();
+			if (frame == 1) {
+				trace("restarting whole");
+				jobs.whole.start();
+				traceProgress();
+			} else if (frame == 3) {
+				removeEventListener(Event.ENTER_FRAME, onEnterFrame);
+				trace("done");
+			}
+		}
+	}
+}
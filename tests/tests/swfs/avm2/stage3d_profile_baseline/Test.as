@@ -0,0 +1,82 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Stage3D;
+	import flash.display3D.Context3D;
+	import flash.display3D.Context3DProfile;
+	import flash.display3D.Program3D;
+	import flash.events.Event;
+	import flash.utils.ByteArray;
+	import flash.utils.Endian;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var stage3d:Stage3D = stage.stage3Ds[0];
+
+			stage3d.addEventListener(Event.CONTEXT3D_CREATE, function(event:Event):void {
+				var context:Context3D = stage3d.context3D;
+				trace("profile: " + context.profile);
+				trace("maxBackBufferWidth: " + context.maxBackBufferWidth);
+				trace("maxBackBufferHeight: " + context.maxBackBufferHeight);
+
+				context.configureBackBuffer(2048, 2048, 0, false);
+				trace("Configured a 2048x2048 back buffer");
+				try {
+					context.configureBackBuffer(4096, 64, 0, false);
+				} catch (e:Error) {
+					trace("Caught error for a 4096x64 back buffer: " + e);
+				}
+				try {
+					context.configureBackBuffer(64, 2049, 0, false);
+				} catch (e:Error) {
+					trace("Caught error for a 64x2049 back buffer: " + e);
+				}
+
+				context.createTexture(2048, 64, "bgra", false);
+				trace("Created a 2048x64 texture");
+				try {
+					context.createTexture(64, 4096, "bgra", false);
+				} catch (e:Error) {
+					trace("Caught error for a 64x4096 texture: " + e);
+				}
+				try {
+					context.createCubeTexture(4096, "bgra", false);
+				} catch (e:Error) {
+					trace("Caught error for a 4096 cube texture: " + e);
+				}
+				try {
+					context.createTexture(64, 64, "rgbaHalfFloat", false);
+				} catch (e:Error) {
+					trace("Caught error for an rgbaHalfFloat texture: " + e);
+				}
+
+				var program:Program3D = context.createProgram();
+				program.upload(agalHeader(1, 0), agalHeader(1, 1));
+				trace("Uploaded an AGAL1 program");
+				try {
+					program.upload(agalHeader(2, 0), agalHeader(1, 1));
+				} catch (e:Error) {
+					trace("Caught error for an AGAL2 vertex program: " + e);
+				}
+				try {
+					program.upload(agalHeader(1, 0), agalHeader(2, 1));
+				} catch (e:Error) {
+					trace("Caught error for an AGAL2 fragment program: " + e);
+				}
+			});
+
+			stage3d.requestContext3D("auto", Context3DProfile.BASELINE);
+			trace("Requested context");
+		}
+
+		// Just the header of an AGAL program, which is all that the profile check looks at.
+		private function agalHeader(version:uint, programType:uint):ByteArray {
+			var agal:ByteArray = new ByteArray();
+			agal.endian = Endian.LITTLE_ENDIAN;
+			agal.writeByte(0xa0);
+			agal.writeUnsignedInt(version);
+			agal.writeByte(0xa1);
+			agal.writeByte(programType);
+			return agal;
+		}
+	}
+}
@@ -0,0 +1,89 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Stage3D;
+	import flash.display3D.Context3D;
+	import flash.display3D.Context3DProfile;
+	import flash.display3D.Program3D;
+	import flash.events.Event;
+	import flash.utils.ByteArray;
+	import flash.utils.Endian;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var stage3d:Stage3D = stage.stage3Ds[0];
+
+			try {
+				stage3d.requestContext3D("auto", "bogus");
+			} catch (e:Error) {
+				trace("Caught error for unknown profile: " + e);
+			}
+
+			try {
+				stage3d.requestContext3DMatchingProfiles(new Vector.<String>());
+			} catch (e:Error) {
+				trace("Caught error for no profiles: " + e);
+			}
+
+			try {
+				stage3d.requestContext3DMatchingProfiles(Vector.<String>(["baseline", "bogus"]));
+			} catch (e:Error) {
+				trace("Caught error for one unknown profile: " + e);
+			}
+
+			stage3d.addEventListener(Event.CONTEXT3D_CREATE, function(event:Event):void {
+				var context:Context3D = stage3d.context3D;
+				trace("profile: " + context.profile);
+				trace("maxBackBufferWidth: " + context.maxBackBufferWidth);
+				trace("maxBackBufferHeight: " + context.maxBackBufferHeight);
+
+				context.configureBackBuffer(4096, 64, 0, false);
+				trace("Configured a 4096x64 back buffer");
+				try {
+					context.configureBackBuffer(4097, 64, 0, false);
+				} catch (e:Error) {
+					trace("Caught error for a 4097x64 back buffer: " + e);
+				}
+
+				context.createTexture(4096, 64, "bgra", false);
+				trace("Created a 4096x64 texture");
+				try {
+					context.createTexture(8192, 64, "bgra", false);
+				} catch (e:Error) {
+					trace("Caught error for an 8192x64 texture: " + e);
+				}
+				try {
+					context.createCubeTexture(8192, "bgra", false);
+				} catch (e:Error) {
+					trace("Caught error for an 8192 cube texture: " + e);
+				}
+				context.createTexture(64, 64, "rgbaHalfFloat", false);
+				trace("Created an rgbaHalfFloat texture");
+
+				var program:Program3D = context.createProgram();
+				program.upload(agalHeader(2, 0), agalHeader(2, 1));
+				trace("Uploaded an AGAL2 program");
+				program.upload(agalHeader(3, 0), agalHeader(3, 1));
+				trace("Uploaded an AGAL3 program");
+			});
+
+			// The most capable of these should be picked, whatever order they're in.
+			stage3d.requestContext3DMatchingProfiles(Vector.<String>([
+				Context3DProfile.BASELINE,
+				Context3DProfile.STANDARD_EXTENDED,
+				Context3DProfile.STANDARD_CONSTRAINED
+			]));
+			trace("Requested context");
+		}
+
+		// Just the header of an AGAL program, which is all that the profile check looks at.
+		private function agalHeader(version:uint, programType:uint):ByteArray {
+			var agal:ByteArray = new ByteArray();
+			agal.endian = Endian.LITTLE_ENDIAN;
+			agal.writeByte(0xa0);
+			agal.writeUnsignedInt(version);
+			agal.writeByte(0xa1);
+			agal.writeByte(programType);
+			return agal;
+		}
+	}
+}
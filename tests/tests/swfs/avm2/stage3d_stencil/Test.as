@@ -0,0 +1,89 @@
+package {
+	import flash.display.Sprite;
+	import flash.display.Stage3D;
+	import flash.display3D.Context3D;
+	import flash.display3D.Context3DCompareMode;
+	import flash.display3D.Context3DProgramType;
+	import flash.display3D.Context3DStencilAction;
+	import flash.display3D.Context3DTriangleFace;
+	import flash.display3D.Context3DVertexBufferFormat;
+	import flash.display3D.IndexBuffer3D;
+	import flash.display3D.Program3D;
+	import flash.display3D.VertexBuffer3D;
+	import flash.events.Event;
+
+	// Draws a stencil mask over the left half of the back buffer, then uses it to
+	// mask three horizontal bands:
+	// - a red band that is drawn where the mask is set,
+	// - a green band of front (clockwise) faces, with a front face stencil test
+	//   that passes where the mask is set,
+	// - a blue band of back (counter-clockwise) faces, with a back face stencil
+	//   test that passes where the mask is *not* set.
+	// The bottom band is left at the clear color.
+	public class Test extends Sprite {
+		private var stage3D:Stage3D;
+		private var context:Context3D;
+
+		public function Test() {
+			stage3D = stage.stage3Ds[0];
+			stage3D.addEventListener(Event.CONTEXT3D_CREATE, contextCreated);
+			stage3D.requestContext3D();
+		}
+
+		private function contextCreated(event:Event):void {
+			context = stage3D.context3D;
+			context.configureBackBuffer(100, 100, 0, true);
+
+			var vertexAssembly:AGALMiniAssembler = new AGALMiniAssembler();
+			vertexAssembly.assemble(Context3DProgramType.VERTEX, "mov op, va0\nmov v0, va1", 1, false);
+			var fragmentAssembly:AGALMiniAssembler = new AGALMiniAssembler();
+			fragmentAssembly.assemble(Context3DProgramType.FRAGMENT, "mov oc, v0", 1, false);
+			var program:Program3D = context.createProgram();
+			program.upload(vertexAssembly.agalcode, fragmentAssembly.agalcode);
+			context.setProgram(program);
+
+			context.clear(0.5, 0.5, 0.5, 1);
+			context.setDepthTest(false, Context3DCompareMode.ALWAYS);
+
+			// Write 1 to the stencil buffer over the left half, without touching the color.
+			context.setStencilReferenceValue(1);
+			context.setStencilActions(Context3DTriangleFace.FRONT_AND_BACK, Context3DCompareMode.ALWAYS, Context3DStencilAction.SET);
+			context.setColorMask(false, false, false, false);
+			drawQuad(-1, 1, 0, -1, [1, 1, 1], true);
+			context.setColorMask(true, true, true, true);
+
+			context.setStencilActions(Context3DTriangleFace.FRONT_AND_BACK, Context3DCompareMode.EQUAL, Context3DStencilAction.KEEP);
+			drawQuad(-1, 1, 1, 0.5, [1, 0, 0], true);
+
+			// Each call should only change the faces it names.
+			context.setStencilActions(Context3DTriangleFace.FRONT, Context3DCompareMode.EQUAL, Context3DStencilAction.KEEP);
+			context.setStencilActions(Context3DTriangleFace.BACK, Context3DCompareMode.NOT_EQUAL, Context3DStencilAction.KEEP);
+			drawQuad(-1, 0.5, 1, 0, [0, 1, 0], true);
+			drawQuad(-1, 0, 1, -0.5, [0, 0, 1], false);
+
+			context.present();
+		}
+
+		// Draws a rectangle from (left, top) to (right, bottom) in clip space,
+		// with its triangles wound clockwise (front faces) or counter-clockwise (back faces).
+		private function drawQuad(left:Number, top:Number, right:Number, bottom:Number, color:Array, clockwise:Boolean):void {
+			var vertices:Vector.<Number> = Vector.<Number>([
+				left, top, 0, 1, color[0], color[1], color[2], 1,
+				right, top, 0, 1, color[0], color[1], color[2], 1,
+				right, bottom, 0, 1, color[0], color[1], color[2], 1,
+				left, bottom, 0, 1, color[0], color[1], color[2], 1
+			]);
+			var vertexBuffer:VertexBuffer3D = context.createVertexBuffer(4, 8);
+			vertexBuffer.uploadFromVector(vertices, 0, 4);
+			context.setVertexBufferAt(0, vertexBuffer, 0, Context3DVertexBufferFormat.FLOAT_4);
+			context.setVertexBufferAt(1, vertexBuffer, 4, Context3DVertexBufferFormat.FLOAT_4);
+
+			var indices:Vector.<uint> = clockwise
+				? Vector.<uint>([0, 1, 2, 0, 2, 3])
+				: Vector.<uint>([0, 2, 1, 0, 3, 2]);
+			var indexBuffer:IndexBuffer3D = context.createIndexBuffer(6);
+			indexBuffer.uploadFromVector(indices, 0, 6);
+			context.drawTriangles(indexBuffer, 0, 2);
+		}
+	}
+}
@@ -0,0 +1,73 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Stage3D;
+	import flash.display3D.Context3D;
+	import flash.display3D.textures.TextureBase;
+	import flash.display3D.textures.VideoTexture;
+	import flash.events.Event;
+	import flash.events.NetStatusEvent;
+	import flash.events.VideoTextureEvent;
+	import flash.net.NetConnection;
+	import flash.net.NetStream;
+
+	public class Test extends MovieClip {
+		private var texture:VideoTexture;
+		private var stream:NetStream;
+		private var readyEvents:int = 0;
+		private var frame:int = 0;
+
+		public function Test() {
+			stage.stage3Ds[0].addEventListener(Event.CONTEXT3D_CREATE, onContextCreate);
+			stage.stage3Ds[0].requestContext3D();
+		}
+
+		private function onContextCreate(event:Event):void {
+			var context:Context3D = (event.target as Stage3D).context3D;
+			trace("supportsVideoTexture: " + Context3D.supportsVideoTexture);
+
+			texture = context.createVideoTexture();
+			trace("texture is TextureBase: " + (texture is TextureBase));
+			trace("before attach: " + texture.videoWidth + "x" + texture.videoHeight);
+
+			texture.addEventListener(VideoTextureEvent.RENDER_STATE, onRenderState);
+			texture.addEventListener(Event.TEXTURE_READY, onTextureReady);
+
+			var connection:NetConnection = new NetConnection();
+			connection.connect(null);
+			stream = new NetStream(connection);
+			stream.client = {onMetaData: function(info:Object):void {}};
+			stream.addEventListener(NetStatusEvent.NET_STATUS, function(e:NetStatusEvent):void {});
+			texture.attachNetStream(stream);
+			trace("after attach: " + texture.videoWidth + "x" + texture.videoHeight);
+
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		private function onRenderState(event:VideoTextureEvent):void {
+			trace("renderState: " + event.type + ", status: " + event.status + ", colorSpace: " + event.colorSpace);
+			trace("bubbles: " + event.bubbles + ", cancelable: " + event.cancelable + ", target is texture: " + (event.target == texture));
+
+			// Only start decoding frames once the texture is ready to show them.
+			stream.play("test_video.flv");
+		}
+
+		private function onTextureReady(event:Event):void {
+			readyEvents++;
+			if (readyEvents == 1) {
+				trace("textureReady: " + texture.videoWidth + "x" + texture.videoHeight + ", target is texture: " + (event.target == texture));
+
+				// No more frames should arrive once the stream is detached.
+				texture.attachNetStream(null);
+				trace("after detach: " + texture.videoWidth + "x" + texture.videoHeight);
+			}
+		}
+
+		private function onEnterFrame(event:Event):void {
+			frame++;
+			if (frame == 20) {
+				trace("textureReady events: " + readyEvents);
+				removeEventListener(Event.ENTER_FRAME, onEnterFrame);
+			}
+		}
+	}
+}
@@ -0,0 +1,44 @@
+package {
+	import flash.display.MovieClip;
+	import flash.text.TextSnapshot;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var snapshot:TextSnapshot = this.textSnapshot;
+			trace("charCount: " + snapshot.charCount);
+
+			trace("// getText");
+			trace(snapshot.getText(0, snapshot.charCount, false));
+			trace(snapshot.getText(0, snapshot.charCount, true));
+			trace(snapshot.getText(6, 14, true));
+			trace(snapshot.getText(15, 1000, false));
+
+			trace("// findText");
+			trace(snapshot.findText(0, "World", true));
+			trace(snapshot.findText(0, "world", true));
+			trace(snapshot.findText(0, "world", false));
+			trace(snapshot.findText(0, "o", true));
+			trace(snapshot.findText(5, "o", true));
+			trace(snapshot.findText(8, "o", true));
+			trace(snapshot.findText(0, "line", false));
+			trace(snapshot.findText(0, "dsec", true));
+			trace(snapshot.findText(100, "o", true));
+
+			trace("// setSelected");
+			trace(snapshot.getSelected(0, snapshot.charCount));
+			snapshot.setSelected(3, 8, true);
+			trace(snapshot.getSelected(0, 3));
+			trace(snapshot.getSelected(0, 4));
+			trace(snapshot.getSelected(7, 20));
+			trace(snapshot.getSelected(8, 20));
+			trace(snapshot.getSelectedText(false));
+			snapshot.setSelected(9, 14, true);
+			trace(snapshot.getSelectedText(false));
+			trace(snapshot.getSelectedText(true));
+			snapshot.setSelected(4, 12, false);
+			trace(snapshot.getSelectedText(false));
+			snapshot.setSelected(0, snapshot.charCount, false);
+			trace(snapshot.getSelected(0, snapshot.charCount));
+		}
+	}
+}
@@ -0,0 +1,72 @@
+package {
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.system.MessageChannel;
+	import flash.system.Worker;
+	import flash.system.WorkerDomain;
+
+	public class Test extends Sprite {
+		public function Test() {
+			if (Worker.current.isPrimordial) {
+				primordial();
+			} else {
+				background();
+			}
+		}
+
+		private function primordial():void {
+			var worker:Worker = WorkerDomain.current.createWorker(this.loaderInfo.bytes);
+			var toWorker:MessageChannel = Worker.current.createMessageChannel(worker);
+			var fromWorker:MessageChannel = worker.createMessageChannel(Worker.current);
+			worker.setSharedProperty("toWorker", toWorker);
+			worker.setSharedProperty("fromWorker", fromWorker);
+
+			var started:Boolean = false;
+			fromWorker.addEventListener(Event.CHANNEL_MESSAGE, function(e:Event):void {
+				if (started) {
+					trace("primordial channelMessage, messageAvailable: " + fromWorker.messageAvailable);
+					return;
+				}
+				started = true;
+				trace("primordial received: " + fromWorker.receive());
+
+				toWorker.send("ping");
+				trace("primordial blocking receive: " + fromWorker.receive(true));
+
+				toWorker.send("first", 1);
+				trace("primordial sent first");
+				toWorker.send("second", 1);
+				trace("primordial sent second");
+
+				trace("terminate: " + worker.terminate());
+				try {
+					fromWorker.receive(true);
+				} catch (e:Error) {
+					trace("blocking receive after terminate: " + e);
+				}
+				try {
+					toWorker.send("third", 1);
+				} catch (e:Error) {
+					trace("send to a full queue after terminate: " + e);
+				}
+			});
+
+			worker.start();
+		}
+
+		private function background():void {
+			var toWorker:MessageChannel = Worker.current.getSharedProperty("toWorker");
+			var fromWorker:MessageChannel = Worker.current.getSharedProperty("fromWorker");
+
+			toWorker.addEventListener(Event.CHANNEL_MESSAGE, function(e:Event):void {
+				var message:String = toWorker.receive();
+				trace("worker received: " + message);
+				if (message == "ping") {
+					fromWorker.send("pong");
+				}
+			});
+
+			fromWorker.send("ready");
+		}
+	}
+}
@@ -0,0 +1,136 @@
+package {
+	import flash.concurrent.Condition;
+	import flash.concurrent.Mutex;
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.system.MessageChannel;
+	import flash.system.Worker;
+	import flash.system.WorkerDomain;
+	import flash.utils.ByteArray;
+	import flash.utils.setInterval;
+
+	public class Test extends Sprite {
+		public function Test() {
+			if (Worker.current.isPrimordial) {
+				primordial();
+			} else {
+				background();
+			}
+		}
+
+		private function primordial():void {
+			var mutex:Mutex = new Mutex();
+			var condition:Condition = new Condition(mutex);
+			var ticks:ByteArray = new ByteArray();
+			ticks.shareable = true;
+			ticks.writeInt(0);
+
+			var worker:Worker = WorkerDomain.current.createWorker(this.loaderInfo.bytes);
+			var toWorker:MessageChannel = Worker.current.createMessageChannel(worker);
+			var fromWorker:MessageChannel = worker.createMessageChannel(Worker.current);
+			worker.setSharedProperty("mutex", mutex);
+			worker.setSharedProperty("condition", condition);
+			worker.setSharedProperty("ticks", ticks);
+			worker.setSharedProperty("toWorker", toWorker);
+			worker.setSharedProperty("fromWorker", fromWorker);
+
+			try {
+				mutex.unlock();
+			} catch (e:Error) {
+				trace("primordial unlock before locking: " + e);
+			}
+			mutex.lock();
+			trace("primordial tryLock while holding it: " + mutex.tryLock());
+			mutex.unlock();
+
+			var frames:int = 0;
+			var ticksAtTermination:int = -1;
+
+			fromWorker.addEventListener(Event.CHANNEL_MESSAGE, function(e:Event):void {
+				var message:String = fromWorker.receive();
+				trace("primordial received: " + message);
+
+				trace("primordial waiting");
+				toWorker.send("notify");
+				trace("primordial woken: " + condition.wait());
+				mutex.unlock();
+				trace("primordial unlocked after waking");
+
+				mutex.lock();
+				toWorker.send("hold");
+				try {
+					condition.wait(50);
+				} catch (e:Error) {
+					trace("wait while the worker holds it: " + e);
+				}
+				trace("primordial tryLock after the wait: " + mutex.tryLock());
+				toWorker.send("release");
+				mutex.lock();
+				trace("primordial locked after the worker released it");
+				mutex.unlock();
+
+				trace("terminate: " + worker.terminate());
+				trace("state: " + worker.state);
+				ticks.position = 0;
+				ticksAtTermination = ticks.readInt();
+
+				mutex.lock();
+				trace("wait with nobody to notify: " + condition.wait(50));
+				mutex.unlock();
+			});
+
+			addEventListener(Event.ENTER_FRAME, function(e:Event):void {
+				frames++;
+				if (ticksAtTermination >= 0 && frames == 20) {
+					ticks.position = 0;
+					trace("worker timers stopped: " + (ticks.readInt() == ticksAtTermination));
+					trace("worker timers ran before terminating: " + (ticksAtTermination > 0));
+				}
+			});
+
+			worker.start();
+		}
+
+		private function background():void {
+			var mutex:Mutex = Worker.current.getSharedProperty("mutex");
+			var condition:Condition = Worker.current.getSharedProperty("condition");
+			var ticks:ByteArray = Worker.current.getSharedProperty("ticks");
+			var toWorker:MessageChannel = Worker.current.getSharedProperty("toWorker");
+			var fromWorker:MessageChannel = Worker.current.getSharedProperty("fromWorker");
+
+			trace("worker tryLock while the primordial worker holds it: " + mutex.tryLock());
+			try {
+				mutex.unlock();
+			} catch (e:Error) {
+				trace("worker unlock: " + e);
+			}
+
+			setInterval(function():void {
+				ticks.position = 0;
+				var count:int = ticks.readInt();
+				ticks.position = 0;
+				ticks.writeInt(count + 1);
+			}, 10);
+
+			toWorker.addEventListener(Event.CHANNEL_MESSAGE, function(e:Event):void {
+				var message:String = toWorker.receive();
+				trace("worker received: " + message);
+				if (message == "notify") {
+					mutex.lock();
+					trace("worker locked");
+					condition.notify();
+					mutex.unlock();
+					trace("worker notified");
+				} else if (message == "hold") {
+					mutex.lock();
+					trace("worker holding the mutex");
+				} else if (message == "release") {
+					mutex.unlock();
+					trace("worker released the mutex");
+				}
+			});
+
+			fromWorker.send("checked mutex");
+		}
+	}
+}
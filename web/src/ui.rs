@@ -1,7 +1,7 @@
 use super::JavascriptPlayer;
 use rfd::{AsyncFileDialog, FileHandle};
 use ruffle_core::backend::ui::{
-    DialogLoaderError, DialogResultFuture, FileDialogResult, FileFilter,
+    CustomMouseCursor, DialogLoaderError, DialogResultFuture, FileDialogResult, FileFilter,
 };
 use ruffle_core::backend::ui::{
     FontDefinition, FullscreenError, LanguageIdentifier, MouseCursor, UiBackend, US_ENGLISH,
@@ -183,6 +183,16 @@ impl UiBackend for WebUiBackend {
         self.update_mouse_cursor();
     }
 
+    fn set_custom_mouse_cursor(&mut self, _cursor: CustomMouseCursor) {
+        // TODO: Custom cursors aren't supported on web yet, so fall back to the default cursor.
+        self.cursor = MouseCursor::Arrow;
+        self.update_mouse_cursor();
+    }
+
+    fn supports_custom_mouse_cursor(&self) -> bool {
+        false
+    }
+
     fn clipboard_content(&mut self) -> String {
         // On web, clipboard content is not directly accessible due to security restrictions,
         // but pasting from the clipboard is supported via the JS `paste` event